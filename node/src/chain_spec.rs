@@ -18,10 +18,12 @@
 
 use cumulus_primitives_core::ParaId;
 use diora_runtime::{
-	AccountId, AuthorFilterConfig, AuthorMappingConfig, Balance, BalancesConfig, BlockRewardConfig,
-	EVMConfig, EligibilityValue, EthereumChainIdConfig, GenesisConfig, InflationInfo, NimbusId,
+	AccountId, AuthorFilterConfig, AuthorMappingConfig, Balance, BalancesConfig, BlockNumber,
+	BlockRewardConfig, CouncilConfig, DappsStakingConfig, EVMConfig, EligibilityValue,
+	EthereumChainIdConfig, GenesisConfig, InflationInfo, InitialIssuanceSchedule, NimbusId,
 	ParachainInfoConfig, ParachainStakingConfig, Perbill, Precompiles, Range, Signature,
-	SudoConfig, SystemConfig, DIOR, HOURS, SUPPLY_FACTOR, WASM_BINARY,
+	SmartContract, SudoConfig, SystemConfig, VestingConfig, VrfId, DIOR, HOURS, SUPPLY_FACTOR,
+	WASM_BINARY,
 };
 use hex_literal::hex;
 use pallet_evm::{AddressMapping, HashedAddressMapping};
@@ -221,9 +223,9 @@ pub fn diora_rococo_config() -> ChainSpec {
 	)
 }
 
-const COLLATOR_COMMISSION: Perbill = Perbill::from_percent(20);
-const PARACHAIN_BOND_RESERVE_PERCENT: Percent = Percent::from_percent(30);
-const BLOCKS_PER_ROUND: u32 = 6 * HOURS;
+pub const COLLATOR_COMMISSION: Perbill = Perbill::from_percent(20);
+pub const PARACHAIN_BOND_RESERVE_PERCENT: Percent = Percent::from_percent(30);
+pub const BLOCKS_PER_ROUND: u32 = 6 * HOURS;
 const NUM_SELECTED_CANDIDATES: u32 = 8;
 pub fn diora_inflation_config() -> InflationInfo<Balance> {
 	let annual = Range {
@@ -249,12 +251,98 @@ pub fn diora_inflation_config() -> InflationInfo<Balance> {
 	}
 }
 
+/// Parameters from which the Diora genesis configuration is built.
+pub struct GenesisParameters {
+	/// Id of the parachain.
+	pub para_id: ParaId,
+	/// EVM chain id.
+	pub evm_chain_id: u64,
+	/// Sudo key, if any.
+	pub sudo_key: Option<AccountId>,
+	/// Accounts endowed with a free balance at genesis.
+	pub endowed_accounts: Vec<(AccountId, Balance)>,
+	/// Collator candidates with their author id, VRF key and self bond.
+	pub candidates: Vec<(AccountId, NimbusId, VrfId, Balance)>,
+	/// Delegations as (delegator, candidate, amount, auto-compound percent).
+	pub delegations: Vec<(AccountId, AccountId, Balance, Percent)>,
+	/// Initial council members.
+	pub council: Vec<AccountId>,
+	/// Parachain staking inflation configuration.
+	pub inflation_config: InflationInfo<Balance>,
+	/// Commission taken by collators from the rewards.
+	pub collator_commission: Perbill,
+	/// Percent of inflation set aside for the parachain bond account.
+	pub parachain_bond_reserve_percent: Percent,
+	/// Number of blocks per staking round.
+	pub blocks_per_round: u32,
+	/// Number of collators selected every round.
+	pub num_selected_candidates: u32,
+	/// Block reward distribution configuration.
+	pub reward_config: pallet_block_reward::RewardDistributionConfig<AccountId>,
+	/// Contracts registered in dapps staking as (developer, contract).
+	pub dapps: Vec<(AccountId, SmartContract<AccountId>)>,
+	/// Dapps staking stakes as (staker, contract, amount).
+	pub dapp_stakes: Vec<(AccountId, SmartContract<AccountId>, Balance)>,
+	/// Vesting schedules as (account, first block, number of blocks, liquid amount). The rest
+	/// of the account's endowment is unlocked linearly over the given number of blocks.
+	pub vesting: Vec<(AccountId, BlockNumber, BlockNumber, Balance)>,
+}
+
 fn diora_genesis(
 	sudo_key: AccountId,
 	candidates: Vec<(AccountId, NimbusId, Balance)>,
 	endowed_accounts: Vec<AccountId>,
 	id: ParaId,
 ) -> GenesisConfig {
+	genesis_config(GenesisParameters {
+		para_id: id,
+		evm_chain_id: 201u64,
+		sudo_key: Some(sudo_key),
+		endowed_accounts: endowed_accounts.into_iter().map(|k| (k, 50_00000 * DIOR)).collect(),
+		candidates: candidates
+			.into_iter()
+			.map(|(account, author_id, bond)| (account, author_id.clone(), author_id.into(), bond))
+			.collect(),
+		delegations: vec![],
+		council: vec![],
+		inflation_config: diora_inflation_config(),
+		collator_commission: COLLATOR_COMMISSION,
+		parachain_bond_reserve_percent: PARACHAIN_BOND_RESERVE_PERCENT,
+		blocks_per_round: BLOCKS_PER_ROUND,
+		num_selected_candidates: NUM_SELECTED_CANDIDATES,
+		reward_config: diora_reward_config(),
+		dapps: vec![],
+		dapp_stakes: vec![],
+		vesting: vec![],
+	})
+}
+
+/// Block reward distribution used by the built-in chain specs.
+pub fn diora_reward_config() -> pallet_block_reward::RewardDistributionConfig<AccountId> {
+	use pallet_block_reward::{Beneficiary, BeneficiaryShare};
+
+	let share = |beneficiary, base_percent, adjustable_percent| BeneficiaryShare {
+		beneficiary,
+		base_percent: Perbill::from_percent(base_percent),
+		adjustable_percent: Perbill::from_percent(adjustable_percent),
+	};
+
+	// Make sure sum is 100
+	pallet_block_reward::RewardDistributionConfig {
+		beneficiaries: vec![
+			share(Beneficiary::Treasury, 10, 0),
+			share(Beneficiary::DappsStakers, 15, 35),
+			share(Beneficiary::Dapps, 15, 0),
+			share(Beneficiary::ParachainStaking, 25, 0),
+		]
+		.try_into()
+		.expect("Number of beneficiaries is within bounds"),
+		ideal_dapps_staking_tvl: Perbill::from_percent(40),
+	}
+}
+
+/// Builds the genesis configuration from the given parameters.
+pub fn genesis_config(params: GenesisParameters) -> GenesisConfig {
 	// This is the simplest bytecode to revert without returning any data.
 	// We will pre-deploy it under all of our precompiles to ensure they can be called from
 	// within contracts.
@@ -265,13 +353,12 @@ fn diora_genesis(
 		system: SystemConfig {
 			code: WASM_BINARY.expect("WASM binary was not build, please build it!").to_vec(),
 		},
-		sudo: SudoConfig { key: Some(sudo_key) },
-		balances: BalancesConfig {
-			balances: endowed_accounts.iter().cloned().map(|k| (k, 50_00000 * DIOR)).collect(),
-		},
-		parachain_info: ParachainInfoConfig { parachain_id: id },
+		sudo: SudoConfig { key: params.sudo_key },
+		balances: BalancesConfig { balances: params.endowed_accounts },
+		vesting: VestingConfig { vesting: params.vesting },
+		parachain_info: ParachainInfoConfig { parachain_id: params.para_id },
 		parachain_system: Default::default(),
-		ethereum_chain_id: EthereumChainIdConfig { chain_id: 201u64 },
+		ethereum_chain_id: EthereumChainIdConfig { chain_id: params.evm_chain_id },
 		evm: EVMConfig {
 			// We need _some_ code inserted at the precompile address so that
 			// the evm will actually call the address.
@@ -290,40 +377,46 @@ fn diora_genesis(
 				.collect(),
 		},
 		ethereum: Default::default(),
-		council: Default::default(),
+		council: CouncilConfig { members: params.council, phantom: Default::default() },
 		democracy: Default::default(),
 		technical_committee: Default::default(),
 		treasury: Default::default(),
 		transaction_payment: Default::default(),
 		polkadot_xcm: Default::default(),
 		parachain_staking: ParachainStakingConfig {
-			candidates: candidates
+			candidates: params
+				.candidates
 				.iter()
 				.cloned()
-				.map(|(account, _, bond)| (account, bond))
+				.map(|(account, _, _, bond)| (account, bond))
 				.collect(),
-			delegations: vec![],
-			inflation_config: diora_inflation_config(),
-			collator_commission: COLLATOR_COMMISSION,
-			parachain_bond_reserve_percent: PARACHAIN_BOND_RESERVE_PERCENT,
-			blocks_per_round: BLOCKS_PER_ROUND,
-			num_selected_candidates: NUM_SELECTED_CANDIDATES,
+			delegations: params.delegations,
+			inflation_config: params.inflation_config,
+			collator_commission: params.collator_commission,
+			parachain_bond_reserve_percent: params.parachain_bond_reserve_percent,
+			blocks_per_round: params.blocks_per_round,
+			num_selected_candidates: params.num_selected_candidates,
 		},
 		block_reward: BlockRewardConfig {
-			// Make sure sum is 100
-			reward_config: pallet_block_reward::RewardDistributionConfig {
-				base_treasury_percent: Perbill::from_percent(10),
-				base_staker_percent: Perbill::from_percent(20),
-				dapps_percent: Perbill::from_percent(20),
-				adjustable_percent: Perbill::from_percent(50),
-				ideal_dapps_staking_tvl: Perbill::from_percent(40),
-			},
+			reward_config: params.reward_config,
+			issuance_schedule: InitialIssuanceSchedule::get(),
+		},
+		dapps_staking: DappsStakingConfig {
+			dapps: params.dapps,
+			stakes: params.dapp_stakes,
+			..Default::default()
 		},
 		author_mapping: AuthorMappingConfig {
-			mappings: candidates
+			mappings: params
+				.candidates
 				.iter()
 				.cloned()
-				.map(|(account_id, author_id, _)| (author_id, account_id))
+				.map(|(account_id, author_id, _, _)| (author_id, account_id))
+				.collect(),
+			keys: params
+				.candidates
+				.into_iter()
+				.map(|(_, author_id, vrf_id, _)| (author_id, vrf_id))
 				.collect(),
 		},
 		author_filter: AuthorFilterConfig { eligible_count: EligibilityValue::default() },
@@ -0,0 +1,1165 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `generate-spec` sub-command.
+//!
+//! Builds a chain specification from a genesis description written in TOML or JSON, so new
+//! networks can be launched without editing `chain_spec.rs`. A minimal description looks like:
+//!
+//! ```toml
+//! name = "Diora Testnet"
+//! id = "diora_testnet"
+//! relay_chain = "rococo-local"
+//! para_id = 4202
+//! evm_chain_id = 201
+//! sudo = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+//!
+//! [[endowed_accounts]]
+//! account = "0x4597C97a43dFBb4a398E2b16AA9cE61f90d801DD"
+//! balance = 1000000
+//!
+//! [[collators]]
+//! account = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+//! nimbus_id = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+//! bond = 25000
+//! ```
+//!
+//! Accounts are given either as SS58 addresses, 32 byte hex public keys or 20 byte hex EVM
+//! addresses. Balances are expressed in DIOR and may be integers or decimal strings such as
+//! `"0.5"`. Percentages are numbers between 0 and 100.
+
+use crate::chain_spec::{self, ChainSpec, Extensions, GenesisParameters};
+use diora_runtime::{
+	AccountId, Balance, InflationInfo, NimbusId, Range, Runtime, SmartContract, VrfId, DIOR,
+};
+use pallet_block_reward::{
+	Beneficiary, BeneficiaryShare, MaxBeneficiaries, RewardDistributionConfig,
+};
+use pallet_evm::{AddressMapping, HashedAddressMapping};
+use sc_service::ChainType;
+use serde::Deserialize;
+use sp_core::{crypto::Ss58Codec, sr25519, ByteArray, Get, H160};
+use sp_runtime::{traits::BlakeTwo256, Perbill, Percent};
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	io::Write,
+	path::{Path, PathBuf},
+	str::FromStr,
+};
+
+/// Number of decimals of the native token.
+const DIOR_DECIMALS: usize = 18;
+
+/// Generate a chain specification from a genesis description file.
+#[derive(Debug, clap::Parser)]
+pub struct GenerateSpecCmd {
+	/// Genesis description file. The format is inferred from the `.toml` or `.json` extension.
+	#[clap(value_parser)]
+	pub input: PathBuf,
+
+	/// Output file name or stdout if unspecified.
+	#[clap(long, short)]
+	pub output: Option<PathBuf>,
+
+	/// Write the chain spec in raw (storage) format.
+	#[clap(long)]
+	pub raw: bool,
+}
+
+impl GenerateSpecCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let description = GenesisDescription::from_file(&self.input)?;
+		let spec = description.into_chain_spec()?;
+		let json = sc_service::ChainSpec::as_json(&spec, self.raw)?;
+
+		if let Some(output) = &self.output {
+			std::fs::write(output, json)?;
+		} else {
+			std::io::stdout().write_all(json.as_bytes())?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Kind of the generated chain.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainKind {
+	Development,
+	Local,
+	Live,
+}
+
+impl Default for ChainKind {
+	fn default() -> Self {
+		ChainKind::Live
+	}
+}
+
+impl From<ChainKind> for ChainType {
+	fn from(kind: ChainKind) -> Self {
+		match kind {
+			ChainKind::Development => ChainType::Development,
+			ChainKind::Local => ChainType::Local,
+			ChainKind::Live => ChainType::Live,
+		}
+	}
+}
+
+/// A token amount expressed in DIOR.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Amount {
+	Integer(u64),
+	Decimal(String),
+}
+
+impl Amount {
+	/// Converts the amount to the smallest unit of the native token.
+	fn to_balance(&self) -> Result<Balance, String> {
+		match self {
+			Amount::Integer(value) => (*value as Balance)
+				.checked_mul(DIOR)
+				.ok_or_else(|| format!("amount {} DIOR overflows the balance type", value)),
+			Amount::Decimal(value) => parse_dior(value),
+		}
+	}
+}
+
+/// A percentage between 0 and 100, fractional values are allowed.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct Percentage(pub f64);
+
+impl Percentage {
+	fn zero() -> Self {
+		Percentage(0.0)
+	}
+
+	fn to_perbill(self) -> Result<Perbill, String> {
+		if !(0.0..=100.0).contains(&self.0) {
+			return Err(format!("percentage {} must be between 0 and 100", self.0));
+		}
+		Ok(Perbill::from_parts((self.0 * 10_000_000f64).round() as u32))
+	}
+
+	fn to_percent(self) -> Result<Percent, String> {
+		if !(0.0..=100.0).contains(&self.0) || self.0.fract() != 0.0 {
+			return Err(format!("percentage {} must be an integer between 0 and 100", self.0));
+		}
+		Ok(Percent::from_percent(self.0 as u8))
+	}
+}
+
+/// A `min`/`ideal`/`max` triple.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RangeDescription<T> {
+	pub min: T,
+	pub ideal: T,
+	pub max: T,
+}
+
+/// Genesis description of the whole chain.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisDescription {
+	/// Human readable chain name.
+	pub name: String,
+	/// Chain id, used to identify the chain on the network.
+	pub id: String,
+	#[serde(default)]
+	pub chain_type: ChainKind,
+	/// Relay chain the parachain is connected to.
+	pub relay_chain: String,
+	pub para_id: u32,
+	pub evm_chain_id: u64,
+	pub protocol_id: Option<String>,
+	#[serde(default)]
+	pub boot_nodes: Vec<String>,
+	pub sudo: Option<String>,
+	#[serde(default)]
+	pub council: Vec<String>,
+	#[serde(default)]
+	pub endowed_accounts: Vec<EndowedAccountDescription>,
+	pub collators: Vec<CollatorDescription>,
+	#[serde(default)]
+	pub delegations: Vec<DelegationDescription>,
+	#[serde(default)]
+	pub staking: StakingDescription,
+	#[serde(default)]
+	pub reward_distribution: Option<RewardDistributionDescription>,
+	#[serde(default)]
+	pub dapps_staking: Vec<DappDescription>,
+	#[serde(default)]
+	pub vesting: Vec<VestingDescription>,
+}
+
+/// An account endowed with a free balance.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EndowedAccountDescription {
+	pub account: String,
+	pub balance: Amount,
+}
+
+/// A collator candidate.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CollatorDescription {
+	pub account: String,
+	/// Author id used by nimbus, an sr25519 public key.
+	pub nimbus_id: String,
+	/// VRF key, an sr25519 public key. Defaults to the nimbus id.
+	pub vrf_key: Option<String>,
+	/// Self bond.
+	pub bond: Amount,
+}
+
+/// A delegation to a collator candidate.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DelegationDescription {
+	pub delegator: String,
+	pub collator: String,
+	pub amount: Amount,
+	#[serde(default)]
+	pub auto_compound: Option<Percentage>,
+}
+
+/// Parachain staking parameters.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct StakingDescription {
+	pub collator_commission: Percentage,
+	pub parachain_bond_reserve: Percentage,
+	pub blocks_per_round: u32,
+	pub num_selected_candidates: u32,
+	pub inflation: InflationDescription,
+}
+
+impl Default for StakingDescription {
+	fn default() -> Self {
+		let inflation = chain_spec::diora_inflation_config();
+		let percentage = |p: Perbill| Percentage(p.deconstruct() as f64 / 10_000_000f64);
+		let amount = |b: Balance| Amount::Decimal(format_dior(b));
+		StakingDescription {
+			collator_commission: percentage(chain_spec::COLLATOR_COMMISSION),
+			parachain_bond_reserve: Percentage(
+				chain_spec::PARACHAIN_BOND_RESERVE_PERCENT.deconstruct() as f64,
+			),
+			blocks_per_round: chain_spec::BLOCKS_PER_ROUND,
+			num_selected_candidates: chain_spec::NUM_SELECTED_CANDIDATES,
+			inflation: InflationDescription {
+				expect: RangeDescription {
+					min: amount(inflation.expect.min),
+					ideal: amount(inflation.expect.ideal),
+					max: amount(inflation.expect.max),
+				},
+				annual: RangeDescription {
+					min: percentage(inflation.annual.min),
+					ideal: percentage(inflation.annual.ideal),
+					max: percentage(inflation.annual.max),
+				},
+			},
+		}
+	}
+}
+
+/// Staking expectations and annual inflation.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct InflationDescription {
+	/// Expected total staked amount.
+	pub expect: RangeDescription<Amount>,
+	/// Annual inflation rates, the per round rates are derived from them.
+	pub annual: RangeDescription<Percentage>,
+}
+
+/// Block reward distribution, mirrors `RewardDistributionConfig`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RewardDistributionDescription {
+	pub beneficiaries: Vec<BeneficiaryShareDescription>,
+	pub ideal_dapps_staking_tvl: Percentage,
+}
+
+/// Share of the block reward paid out to a beneficiary, mirrors `BeneficiaryShare`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct BeneficiaryShareDescription {
+	pub beneficiary: BeneficiaryDescription,
+	pub base_percent: Percentage,
+	#[serde(default = "Percentage::zero")]
+	pub adjustable_percent: Percentage,
+}
+
+/// Receiver of a block reward share, either `"treasury"`, `"dapps_stakers"`, `"dapps"`,
+/// `"burn"`, `"parachain_staking"` or `{ account = "..." }`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BeneficiaryDescription {
+	Treasury,
+	DappsStakers,
+	Dapps,
+	Burn,
+	Account(String),
+	ParachainStaking,
+}
+
+/// A contract registered in dapps staking at genesis.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DappDescription {
+	pub developer: String,
+	/// EVM address of the contract.
+	pub contract: String,
+	#[serde(default)]
+	pub stakes: Vec<DappStakeDescription>,
+}
+
+/// An initial stake on a dapps staking contract.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DappStakeDescription {
+	pub staker: String,
+	pub amount: Amount,
+}
+
+/// A vesting schedule locking part of an account's endowment.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct VestingDescription {
+	pub account: String,
+	/// Block from which the locked amount starts being unlocked.
+	#[serde(default)]
+	pub begin: u32,
+	/// Number of blocks over which the locked amount is unlocked linearly.
+	pub length: u32,
+	/// Part of the endowment that is not locked. Defaults to nothing.
+	#[serde(default)]
+	pub liquid: Option<Amount>,
+}
+
+impl GenesisDescription {
+	/// Reads the description from a `.toml` or `.json` file.
+	pub fn from_file(path: &Path) -> Result<Self, String> {
+		let content = std::fs::read_to_string(path)
+			.map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+		match path.extension().and_then(|ext| ext.to_str()) {
+			Some("toml") => toml::from_str(&content)
+				.map_err(|e| format!("Invalid genesis description {}: {}", path.display(), e)),
+			Some("json") => serde_json::from_str(&content)
+				.map_err(|e| format!("Invalid genesis description {}: {}", path.display(), e)),
+			_ => Err(format!(
+				"Unsupported genesis description {}, expected a .toml or .json file",
+				path.display()
+			)),
+		}
+	}
+
+	/// Validates the description and builds the chain spec.
+	pub fn into_chain_spec(self) -> Result<ChainSpec, String> {
+		// Validate upfront, the genesis constructor below can't report errors.
+		self.genesis_parameters()?;
+		let boot_nodes = self
+			.boot_nodes
+			.iter()
+			.map(|node| node.parse().map_err(|e| format!("boot_nodes: invalid `{}`: {}", node, e)))
+			.collect::<Result<Vec<_>, String>>()?;
+		let properties = serde_json::json!({
+			"tokenDecimals": DIOR_DECIMALS,
+			"tokenSymbol": "DIOR",
+			"SS58Prefix": 42,
+		})
+		.as_object()
+		.cloned();
+		let extensions =
+			Extensions { relay_chain: self.relay_chain.clone(), para_id: self.para_id };
+
+		let description = self.clone();
+		Ok(ChainSpec::from_genesis(
+			&self.name,
+			&self.id,
+			self.chain_type.into(),
+			move || {
+				chain_spec::genesis_config(
+					description.genesis_parameters().expect("description was validated; qed"),
+				)
+			},
+			boot_nodes,
+			None,
+			self.protocol_id.as_deref(),
+			None,
+			properties,
+			extensions,
+		))
+	}
+
+	/// Validates the description and converts it to genesis parameters.
+	pub fn genesis_parameters(&self) -> Result<GenesisParameters, String> {
+		if self.para_id == 0 {
+			return Err("para_id: must not be zero".into());
+		}
+		if self.evm_chain_id == 0 {
+			return Err("evm_chain_id: must not be zero".into());
+		}
+		if self.relay_chain.is_empty() {
+			return Err("relay_chain: must not be empty".into());
+		}
+
+		// Endowed accounts
+		let mut balances = BTreeMap::<AccountId, Balance>::new();
+		for (i, endowed) in self.endowed_accounts.iter().enumerate() {
+			let field = format!("endowed_accounts[{}]", i);
+			let account = parse_account(&endowed.account).map_err(in_field(&field, "account"))?;
+			let balance = endowed.balance.to_balance().map_err(in_field(&field, "balance"))?;
+			if balances.insert(account, balance).is_some() {
+				return Err(format!("{}.account: `{}` is endowed twice", field, endowed.account));
+			}
+		}
+		// Amount each account has to reserve or lock at genesis.
+		let mut required = BTreeMap::<AccountId, Balance>::new();
+
+		// Collators
+		let min_collator_stk = <Runtime as pallet_parachain_staking::Config>::MinCollatorStk::get();
+		let mapping_deposit = <Runtime as pallet_author_mapping::Config>::DepositAmount::get();
+		if self.collators.is_empty() {
+			return Err("collators: at least one collator is required".into());
+		}
+		let mut candidates = Vec::new();
+		let mut nimbus_ids = BTreeSet::new();
+		for (i, collator) in self.collators.iter().enumerate() {
+			let field = format!("collators[{}]", i);
+			let account = parse_account(&collator.account).map_err(in_field(&field, "account"))?;
+			let nimbus_id: NimbusId =
+				parse_public(&collator.nimbus_id).map_err(in_field(&field, "nimbus_id"))?.into();
+			let vrf_key: VrfId = match &collator.vrf_key {
+				Some(key) => parse_public(key).map_err(in_field(&field, "vrf_key"))?.into(),
+				None => nimbus_id.clone().into(),
+			};
+			let bond = collator.bond.to_balance().map_err(in_field(&field, "bond"))?;
+
+			if candidates.iter().any(|(other, _, _, _)| other == &account) {
+				return Err(format!("{}.account: `{}` is listed twice", field, collator.account));
+			}
+			if !nimbus_ids.insert(nimbus_id.to_raw_vec()) {
+				return Err(format!(
+					"{}.nimbus_id: `{}` is used by another collator",
+					field, collator.nimbus_id
+				));
+			}
+			if bond < min_collator_stk {
+				return Err(format!(
+					"{}.bond: {} DIOR is below the minimum collator stake of {} DIOR",
+					field,
+					format_dior(bond),
+					format_dior(min_collator_stk)
+				));
+			}
+			add_required(&mut required, &account, bond)
+				.and_then(|_| add_required(&mut required, &account, mapping_deposit))
+				.map_err(in_field(&field, "bond"))?;
+			candidates.push((account, nimbus_id, vrf_key, bond));
+		}
+
+		// Delegations
+		let min_delegation = <Runtime as pallet_parachain_staking::Config>::MinDelegation::get();
+		let min_delegator_stk =
+			<Runtime as pallet_parachain_staking::Config>::MinDelegatorStk::get();
+		let mut delegations = Vec::new();
+		for (i, delegation) in self.delegations.iter().enumerate() {
+			let field = format!("delegations[{}]", i);
+			let delegator =
+				parse_account(&delegation.delegator).map_err(in_field(&field, "delegator"))?;
+			let collator =
+				parse_account(&delegation.collator).map_err(in_field(&field, "collator"))?;
+			let amount = delegation.amount.to_balance().map_err(in_field(&field, "amount"))?;
+			let auto_compound = delegation
+				.auto_compound
+				.map(Percentage::to_percent)
+				.transpose()
+				.map_err(in_field(&field, "auto_compound"))?
+				.unwrap_or_default();
+
+			if !candidates.iter().any(|(account, _, _, _)| account == &collator) {
+				return Err(format!(
+					"{}.collator: `{}` is not a genesis collator",
+					field, delegation.collator
+				));
+			}
+			if candidates.iter().any(|(account, _, _, _)| account == &delegator) {
+				return Err(format!(
+					"{}.delegator: `{}` is a collator and cannot delegate",
+					field, delegation.delegator
+				));
+			}
+			if delegations.iter().any(|(d, c, _, _)| d == &delegator && c == &collator) {
+				return Err(format!(
+					"{}: `{}` already delegates to `{}`",
+					field, delegation.delegator, delegation.collator
+				));
+			}
+			if amount < min_delegation.max(min_delegator_stk) {
+				return Err(format!(
+					"{}.amount: {} DIOR is below the minimum delegation of {} DIOR",
+					field,
+					format_dior(amount),
+					format_dior(min_delegation.max(min_delegator_stk))
+				));
+			}
+			add_required(&mut required, &delegator, amount).map_err(in_field(&field, "amount"))?;
+			delegations.push((delegator, collator, amount, auto_compound));
+		}
+
+		// Dapps staking
+		let register_deposit = <Runtime as pallet_dapps_staking::Config>::RegisterDeposit::get();
+		let min_staking_amount =
+			<Runtime as pallet_dapps_staking::Config>::MinimumStakingAmount::get();
+		let max_stakers =
+			<Runtime as pallet_dapps_staking::Config>::MaxNumberOfStakersPerContract::get();
+		// Stakers can't stake their whole free balance, this amount stays free once per staker
+		let min_remaining =
+			<Runtime as pallet_dapps_staking::Config>::MinimumRemainingAmount::get();
+		let mut dapps = Vec::new();
+		let mut dapp_stakes = Vec::new();
+		for (i, dapp) in self.dapps_staking.iter().enumerate() {
+			let field = format!("dapps_staking[{}]", i);
+			let developer =
+				parse_account(&dapp.developer).map_err(in_field(&field, "developer"))?;
+			let contract = H160::from_str(dapp.contract.trim_start_matches("0x"))
+				.map(SmartContract::Evm)
+				.map_err(|_| {
+					format!("{}.contract: `{}` is not a valid EVM address", field, dapp.contract)
+				})?;
+
+			if dapps.iter().any(|(other, _)| other == &developer) {
+				return Err(format!(
+					"{}.developer: `{}` already registered a contract",
+					field, dapp.developer
+				));
+			}
+			if dapps.iter().any(|(_, other)| other == &contract) {
+				return Err(format!("{}.contract: `{}` is registered twice", field, dapp.contract));
+			}
+			if dapp.stakes.len() > max_stakers as usize {
+				return Err(format!(
+					"{}.stakes: at most {} stakers are allowed",
+					field, max_stakers
+				));
+			}
+			add_required(&mut required, &developer, register_deposit)
+				.map_err(in_field(&field, "developer"))?;
+
+			for (j, stake) in dapp.stakes.iter().enumerate() {
+				let field = format!("{}.stakes[{}]", field, j);
+				let staker = parse_account(&stake.staker).map_err(in_field(&field, "staker"))?;
+				let amount = stake.amount.to_balance().map_err(in_field(&field, "amount"))?;
+
+				if dapp_stakes.iter().any(|(s, c, _)| s == &staker && c == &contract) {
+					return Err(format!(
+						"{}.staker: `{}` already stakes on `{}`",
+						field, stake.staker, dapp.contract
+					));
+				}
+				if amount < min_staking_amount {
+					return Err(format!(
+						"{}.amount: {} DIOR is below the minimum staking amount of {} DIOR",
+						field,
+						format_dior(amount),
+						format_dior(min_staking_amount)
+					));
+				}
+				add_required(&mut required, &staker, amount).map_err(in_field(&field, "amount"))?;
+				if dapp_stakes.iter().all(|(s, _, _)| s != &staker) {
+					add_required(&mut required, &staker, min_remaining)
+						.map_err(in_field(&field, "staker"))?;
+				}
+				dapp_stakes.push((staker, contract, amount));
+			}
+			dapps.push((developer, contract));
+		}
+
+		// Vesting
+		let mut vesting = Vec::new();
+		for (i, schedule) in self.vesting.iter().enumerate() {
+			let field = format!("vesting[{}]", i);
+			let account = parse_account(&schedule.account).map_err(in_field(&field, "account"))?;
+			let liquid = schedule
+				.liquid
+				.as_ref()
+				.map(Amount::to_balance)
+				.transpose()
+				.map_err(in_field(&field, "liquid"))?
+				.unwrap_or_default();
+
+			let endowment = balances.get(&account).copied().ok_or_else(|| {
+				format!("{}.account: `{}` is not an endowed account", field, schedule.account)
+			})?;
+			if vesting.iter().any(|(other, _, _, _)| other == &account) {
+				return Err(format!(
+					"{}.account: `{}` already has a vesting schedule",
+					field, schedule.account
+				));
+			}
+			if schedule.length == 0 {
+				return Err(format!("{}.length: must not be zero", field));
+			}
+			let locked = endowment.saturating_sub(liquid);
+			if locked == 0 {
+				return Err(format!(
+					"{}.liquid: {} DIOR leaves nothing to vest out of the {} DIOR endowment",
+					field,
+					format_dior(liquid),
+					format_dior(endowment)
+				));
+			}
+			if locked < schedule.length as Balance {
+				return Err(format!(
+					"{}.length: {} DIOR can't be unlocked over {} blocks",
+					field,
+					format_dior(locked),
+					schedule.length
+				));
+			}
+			vesting.push((account, schedule.begin, schedule.length, liquid));
+		}
+
+		for (account, amount) in &required {
+			let free = balances.get(account).copied().unwrap_or_default();
+			if free < *amount {
+				return Err(format!(
+					"endowed_accounts: `{}` needs at least {} DIOR to cover its bonds and deposits \
+					but is endowed with {} DIOR",
+					account,
+					format_dior(*amount),
+					format_dior(free)
+				));
+			}
+		}
+
+		// Governance
+		let sudo_key = self
+			.sudo
+			.as_deref()
+			.map(parse_account)
+			.transpose()
+			.map_err(|e| format!("sudo: {}", e))?;
+		let mut council = Vec::new();
+		for (i, member) in self.council.iter().enumerate() {
+			let account = parse_account(member).map_err(|e| format!("council[{}]: {}", i, e))?;
+			if council.contains(&account) {
+				return Err(format!("council[{}]: `{}` is listed twice", i, member));
+			}
+			council.push(account);
+		}
+
+		// Staking parameters
+		let staking = &self.staking;
+		let min_blocks_per_round =
+			<Runtime as pallet_parachain_staking::Config>::MinBlocksPerRound::get();
+		if staking.blocks_per_round < min_blocks_per_round {
+			return Err(format!(
+				"staking.blocks_per_round: must be at least {}",
+				min_blocks_per_round
+			));
+		}
+		let min_selected_candidates =
+			<Runtime as pallet_parachain_staking::Config>::MinSelectedCandidates::get();
+		if staking.num_selected_candidates < min_selected_candidates {
+			return Err(format!(
+				"staking.num_selected_candidates: must be at least {}",
+				min_selected_candidates
+			));
+		}
+		let collator_commission = staking
+			.collator_commission
+			.to_perbill()
+			.map_err(|e| format!("staking.collator_commission: {}", e))?;
+		let parachain_bond_reserve_percent = staking
+			.parachain_bond_reserve
+			.to_percent()
+			.map_err(|e| format!("staking.parachain_bond_reserve: {}", e))?;
+		let inflation_config = self.inflation_config()?;
+
+		let reward_config = match &self.reward_distribution {
+			Some(reward) => {
+				let percent = |value: Percentage, name: &str| {
+					value.to_perbill().map_err(|e| format!("reward_distribution.{}: {}", name, e))
+				};
+				let beneficiaries = reward
+					.beneficiaries
+					.iter()
+					.map(|share| {
+						let beneficiary = match &share.beneficiary {
+							BeneficiaryDescription::Treasury => Beneficiary::Treasury,
+							BeneficiaryDescription::DappsStakers => Beneficiary::DappsStakers,
+							BeneficiaryDescription::Dapps => Beneficiary::Dapps,
+							BeneficiaryDescription::Burn => Beneficiary::Burn,
+							BeneficiaryDescription::Account(account) => Beneficiary::Account(
+								parse_account(account)
+									.map_err(in_field("reward_distribution", "beneficiaries"))?,
+							),
+							BeneficiaryDescription::ParachainStaking =>
+								Beneficiary::ParachainStaking,
+						};
+						Ok(BeneficiaryShare {
+							beneficiary,
+							base_percent: percent(share.base_percent, "base_percent")?,
+							adjustable_percent: percent(
+								share.adjustable_percent,
+								"adjustable_percent",
+							)?,
+						})
+					})
+					.collect::<Result<Vec<_>, String>>()?;
+				let config = RewardDistributionConfig {
+					beneficiaries: beneficiaries.try_into().map_err(|_| {
+						format!(
+							"reward_distribution.beneficiaries: at most {} beneficiaries are allowed",
+							MaxBeneficiaries::get()
+						)
+					})?,
+					ideal_dapps_staking_tvl: percent(
+						reward.ideal_dapps_staking_tvl,
+						"ideal_dapps_staking_tvl",
+					)?,
+				};
+				if !config.is_consistent() {
+					return Err("reward_distribution: base and adjustable percentages of all \
+						beneficiaries must sum up to 100"
+						.into());
+				}
+				config
+			},
+			None => chain_spec::diora_reward_config(),
+		};
+
+		Ok(GenesisParameters {
+			para_id: self.para_id.into(),
+			evm_chain_id: self.evm_chain_id,
+			sudo_key,
+			endowed_accounts: balances.into_iter().collect(),
+			candidates,
+			delegations,
+			council,
+			inflation_config,
+			collator_commission,
+			parachain_bond_reserve_percent,
+			blocks_per_round: staking.blocks_per_round,
+			num_selected_candidates: staking.num_selected_candidates,
+			reward_config,
+			dapps,
+			dapp_stakes,
+			vesting,
+		})
+	}
+
+	fn inflation_config(&self) -> Result<InflationInfo<Balance>, String> {
+		let inflation = &self.staking.inflation;
+		let expect = Range {
+			min: inflation
+				.expect
+				.min
+				.to_balance()
+				.map_err(|e| format!("staking.inflation.expect.min: {}", e))?,
+			ideal: inflation
+				.expect
+				.ideal
+				.to_balance()
+				.map_err(|e| format!("staking.inflation.expect.ideal: {}", e))?,
+			max: inflation
+				.expect
+				.max
+				.to_balance()
+				.map_err(|e| format!("staking.inflation.expect.max: {}", e))?,
+		};
+		if !expect.is_valid() {
+			return Err("staking.inflation.expect: must satisfy min <= ideal <= max".into());
+		}
+		let annual = Range {
+			min: inflation
+				.annual
+				.min
+				.to_perbill()
+				.map_err(|e| format!("staking.inflation.annual.min: {}", e))?,
+			ideal: inflation
+				.annual
+				.ideal
+				.to_perbill()
+				.map_err(|e| format!("staking.inflation.annual.ideal: {}", e))?,
+			max: inflation
+				.annual
+				.max
+				.to_perbill()
+				.map_err(|e| format!("staking.inflation.annual.max: {}", e))?,
+		};
+		if !annual.is_valid() {
+			return Err("staking.inflation.annual: must satisfy min <= ideal <= max".into());
+		}
+
+		Ok(InflationInfo::new::<Runtime>(annual, expect))
+	}
+}
+
+/// Prefixes an error with the name of the field it originates from.
+fn in_field<'a>(parent: &'a str, field: &'a str) -> impl Fn(String) -> String + 'a {
+	move |error| format!("{}.{}: {}", parent, field, error)
+}
+
+/// Adds `amount` to the balance `account` has to reserve or lock at genesis.
+fn add_required(
+	required: &mut BTreeMap<AccountId, Balance>,
+	account: &AccountId,
+	amount: Balance,
+) -> Result<(), String> {
+	let total = required.entry(account.clone()).or_default();
+	*total = total
+		.checked_add(amount)
+		.ok_or_else(|| format!("bonds and deposits of `{}` overflow the balance type", account))?;
+	Ok(())
+}
+
+/// Parses an SS58 address, a 32 byte hex public key or a 20 byte hex EVM address.
+fn parse_account(input: &str) -> Result<AccountId, String> {
+	if let Some(hex) = input.strip_prefix("0x") {
+		return match hex.len() {
+			40 => H160::from_str(hex)
+				.map(HashedAddressMapping::<BlakeTwo256>::into_account_id)
+				.map_err(|_| format!("`{}` is not a valid EVM address", input)),
+			64 => sp_core::H256::from_str(hex)
+				.map(|key| AccountId::from(key.0))
+				.map_err(|_| format!("`{}` is not a valid public key", input)),
+			_ => Err(format!("`{}` must be a 20 byte EVM address or a 32 byte public key", input)),
+		};
+	}
+
+	AccountId::from_ss58check(input)
+		.map_err(|e| format!("`{}` is not a valid address: {:?}", input, e))
+}
+
+/// Parses an sr25519 public key given as SS58 address or 32 byte hex string.
+fn parse_public(input: &str) -> Result<sr25519::Public, String> {
+	if let Some(hex) = input.strip_prefix("0x") {
+		return sp_core::H256::from_str(hex)
+			.map(|key| sr25519::Public::from_raw(key.0))
+			.map_err(|_| format!("`{}` is not a valid 32 byte public key", input));
+	}
+
+	sr25519::Public::from_ss58check(input)
+		.map_err(|e| format!("`{}` is not a valid public key: {:?}", input, e))
+}
+
+/// Parses a decimal amount of DIOR, e.g. `"1000"` or `"0.125"`.
+fn parse_dior(input: &str) -> Result<Balance, String> {
+	let invalid = || format!("`{}` is not a valid DIOR amount", input);
+	let (integer, fraction) = input.trim().split_once('.').unwrap_or((input.trim(), ""));
+	if integer.is_empty() && fraction.is_empty() {
+		return Err(invalid());
+	}
+	if fraction.len() > DIOR_DECIMALS {
+		return Err(format!("`{}` has more than {} decimals", input, DIOR_DECIMALS));
+	}
+	let integer: Balance =
+		if integer.is_empty() { 0 } else { integer.parse().map_err(|_| invalid())? };
+	let fraction: Balance = if fraction.is_empty() {
+		0
+	} else {
+		format!("{:0<width$}", fraction, width = DIOR_DECIMALS)
+			.parse()
+			.map_err(|_| invalid())?
+	};
+
+	integer
+		.checked_mul(DIOR)
+		.and_then(|value| value.checked_add(fraction))
+		.ok_or_else(|| format!("`{}` overflows the balance type", input))
+}
+
+/// Formats an amount of the smallest unit as a decimal amount of DIOR.
+fn format_dior(value: Balance) -> String {
+	let fraction = value % DIOR;
+	if fraction == 0 {
+		format!("{}", value / DIOR)
+	} else {
+		let fraction = format!("{:0>width$}", fraction, width = DIOR_DECIMALS);
+		format!("{}.{}", value / DIOR, fraction.trim_end_matches('0'))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+	const BOB: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+	const CHARLIE: &str = "0x4597C97a43dFBb4a398E2b16AA9cE61f90d801DD";
+
+	fn description() -> GenesisDescription {
+		toml::from_str(&format!(
+			r#"
+			name = "Diora Test"
+			id = "diora_test"
+			relay_chain = "rococo-local"
+			para_id = 4202
+			evm_chain_id = 201
+			sudo = "{alice}"
+			council = ["{alice}", "{bob}"]
+
+			[[endowed_accounts]]
+			account = "{alice}"
+			balance = 100000
+
+			[[endowed_accounts]]
+			account = "{charlie}"
+			balance = "1000.5"
+
+			[[collators]]
+			account = "{alice}"
+			nimbus_id = "{alice}"
+			bond = 25000
+
+			[[delegations]]
+			delegator = "{charlie}"
+			collator = "{alice}"
+			amount = 500
+			auto_compound = 50
+			"#,
+			alice = ALICE,
+			bob = BOB,
+			charlie = CHARLIE,
+		))
+		.expect("valid description")
+	}
+
+	#[test]
+	fn valid_description_is_accepted() {
+		let params = description().genesis_parameters().expect("description is valid");
+
+		assert_eq!(params.candidates.len(), 1);
+		assert_eq!(params.delegations[0].2, 500 * DIOR);
+		assert_eq!(params.delegations[0].3, Percent::from_percent(50));
+		assert_eq!(params.council.len(), 2);
+		assert!(params
+			.endowed_accounts
+			.contains(&(parse_account(CHARLIE).unwrap(), 1000 * DIOR + DIOR / 2)));
+	}
+
+	#[test]
+	fn collator_bond_below_minimum_is_rejected() {
+		let mut description = description();
+		description.collators[0].bond = Amount::Integer(1);
+
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("collators[0].bond:"), "{}", error);
+	}
+
+	#[test]
+	fn delegation_to_unknown_collator_is_rejected() {
+		let mut description = description();
+		description.delegations[0].collator = BOB.into();
+
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("delegations[0].collator:"), "{}", error);
+	}
+
+	#[test]
+	fn insufficient_endowment_is_rejected() {
+		let mut description = description();
+		description.delegations[0].amount = Amount::Integer(2000);
+
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("endowed_accounts:"), "{}", error);
+	}
+
+	#[test]
+	fn overflowing_bonds_are_rejected() {
+		let mut description = description();
+		let huge = Amount::Decimal(format!("{}", Balance::MAX / DIOR / 2 + 1));
+		description.collators.push(CollatorDescription {
+			account: BOB.into(),
+			nimbus_id: BOB.into(),
+			vrf_key: None,
+			bond: Amount::Integer(25000),
+		});
+		description.delegations[0].amount = huge.clone();
+		description.delegations.push(DelegationDescription {
+			delegator: CHARLIE.into(),
+			collator: BOB.into(),
+			amount: huge,
+			auto_compound: None,
+		});
+
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("delegations[1].amount:"), "{}", error);
+	}
+
+	#[test]
+	fn inconsistent_reward_distribution_is_rejected() {
+		let mut description = description();
+		description.reward_distribution = Some(RewardDistributionDescription {
+			beneficiaries: vec![
+				BeneficiaryShareDescription {
+					beneficiary: BeneficiaryDescription::Treasury,
+					base_percent: Percentage(50.0),
+					adjustable_percent: Percentage(0.0),
+				},
+				BeneficiaryShareDescription {
+					beneficiary: BeneficiaryDescription::DappsStakers,
+					base_percent: Percentage(50.0),
+					adjustable_percent: Percentage(50.0),
+				},
+			],
+			ideal_dapps_staking_tvl: Percentage(40.0),
+		});
+
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("reward_distribution:"), "{}", error);
+	}
+
+	#[test]
+	fn reward_distribution_beneficiaries_are_parsed() {
+		let mut description = description();
+		description.reward_distribution = Some(
+			toml::from_str(&format!(
+				r#"
+				ideal_dapps_staking_tvl = 40
+
+				[[beneficiaries]]
+				beneficiary = "treasury"
+				base_percent = 50
+
+				[[beneficiaries]]
+				beneficiary = {{ account = "{charlie}" }}
+				base_percent = 25
+				adjustable_percent = 25
+				"#,
+				charlie = CHARLIE,
+			))
+			.expect("valid reward distribution"),
+		);
+
+		let params = description.genesis_parameters().expect("description is valid");
+		let beneficiaries = &params.reward_config.beneficiaries;
+		assert_eq!(beneficiaries.len(), 2);
+		assert_eq!(beneficiaries[0].beneficiary, Beneficiary::Treasury);
+		assert_eq!(
+			beneficiaries[1].beneficiary,
+			Beneficiary::Account(parse_account(CHARLIE).unwrap())
+		);
+		assert_eq!(beneficiaries[1].adjustable_percent, Perbill::from_percent(25));
+	}
+
+	#[test]
+	fn dapps_staking_registrations_are_validated() {
+		let mut description = description();
+		description.dapps_staking = vec![DappDescription {
+			developer: ALICE.into(),
+			contract: "0x0000000000000000000000000000000000000401".into(),
+			stakes: vec![DappStakeDescription {
+				staker: CHARLIE.into(),
+				amount: Amount::Integer(500),
+			}],
+		}];
+		// Delegation, stake and the amount that stays free
+		description.endowed_accounts[1].balance = Amount::Integer(1001);
+
+		let params = description.genesis_parameters().expect("description is valid");
+		assert_eq!(params.dapps.len(), 1);
+		assert_eq!(params.dapp_stakes[0].2, 500 * DIOR);
+
+		description.dapps_staking[0].stakes[0].amount = Amount::Integer(1);
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("dapps_staking[0].stakes[0].amount:"), "{}", error);
+	}
+
+	#[test]
+	fn dapps_stakers_keep_the_minimum_remaining_amount() {
+		let mut description = description();
+		// CHARLIE delegates 500 DIOR and stakes the rest of its endowment
+		description.endowed_accounts[1].balance = Amount::Integer(1000);
+		description.dapps_staking = vec![DappDescription {
+			developer: ALICE.into(),
+			contract: "0x0000000000000000000000000000000000000401".into(),
+			stakes: vec![DappStakeDescription {
+				staker: CHARLIE.into(),
+				amount: Amount::Integer(500),
+			}],
+		}];
+
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("endowed_accounts:"), "{}", error);
+
+		// The remaining amount is required once per staker, not once per stake
+		description.endowed_accounts[1].balance = Amount::Integer(1501);
+		description.endowed_accounts.push(EndowedAccountDescription {
+			account: BOB.into(),
+			balance: Amount::Integer(1000),
+		});
+		description.dapps_staking.push(DappDescription {
+			developer: BOB.into(),
+			contract: "0x0000000000000000000000000000000000000402".into(),
+			stakes: vec![DappStakeDescription {
+				staker: CHARLIE.into(),
+				amount: Amount::Integer(500),
+			}],
+		});
+		description.genesis_parameters().expect("description is valid");
+	}
+
+	#[test]
+	fn vesting_schedules_are_validated() {
+		let mut description = description();
+		description.vesting = vec![VestingDescription {
+			account: CHARLIE.into(),
+			begin: 10,
+			length: 1000,
+			liquid: Some(Amount::Decimal("0.5".into())),
+		}];
+
+		let params = description.genesis_parameters().expect("description is valid");
+		assert_eq!(params.vesting, vec![(parse_account(CHARLIE).unwrap(), 10, 1000, DIOR / 2)]);
+
+		description.vesting[0].liquid = Some(Amount::Integer(2000));
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("vesting[0].liquid:"), "{}", error);
+
+		description.vesting[0].account = BOB.into();
+		let error = description.genesis_parameters().unwrap_err();
+		assert!(error.starts_with("vesting[0].account:"), "{}", error);
+	}
+
+	#[test]
+	fn invalid_addresses_are_rejected() {
+		assert!(parse_account("0x1234").is_err());
+		assert!(parse_account("not an address").is_err());
+		assert!(parse_public(CHARLIE).is_err());
+	}
+
+	#[test]
+	fn dior_amounts_are_parsed() {
+		assert_eq!(parse_dior("1"), Ok(DIOR));
+		assert_eq!(parse_dior("0.125"), Ok(DIOR / 8));
+		assert_eq!(parse_dior(".5"), Ok(DIOR / 2));
+		assert!(parse_dior("1.0000000000000000001").is_err());
+		assert!(parse_dior("abc").is_err());
+		assert_eq!(format_dior(DIOR + DIOR / 2), "1.5");
+	}
+}
@@ -25,7 +25,9 @@ mod chain_spec;
 mod service;
 mod cli;
 mod command;
+mod generate_spec;
 mod rpc;
+mod vrf;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
@@ -125,11 +125,15 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: BlockBuilder<Block>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_block_reward_rpc::BlockRewardRuntimeApi<Block, AccountId, Balance>,
+	C::Api: pallet_parachain_staking_rpc::ParachainStakingRuntimeApi<Block, AccountId, Balance>,
 	C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
 	C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
 	P: TransactionPool<Block = Block> + 'static,
 	A: ChainApi<Block = Block> + 'static,
 {
+	use pallet_block_reward_rpc::{BlockReward, BlockRewardApiServer};
+	use pallet_parachain_staking_rpc::{ParachainStaking, ParachainStakingApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -153,6 +157,8 @@ where
 
 	io.merge(System::new(Arc::clone(&client), Arc::clone(&pool), deny_unsafe).into_rpc())?;
 	io.merge(TransactionPayment::new(Arc::clone(&client)).into_rpc())?;
+	io.merge(BlockReward::new(Arc::clone(&client)).into_rpc())?;
+	io.merge(ParachainStaking::new(Arc::clone(&client)).into_rpc())?;
 
 	// eth api
 	{
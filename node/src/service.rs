@@ -25,6 +25,7 @@ use std::{sync::Arc, time::Duration};
 pub use diora_runtime::{opaque::Block, Hash, RuntimeApi};
 
 use nimbus_consensus::{BuildNimbusConsensusParams, NimbusConsensus};
+use nimbus_primitives::NimbusId;
 
 // Cumulus Imports
 use cumulus_client_cli::CollatorOptions;
@@ -46,7 +47,7 @@ use sc_service::{
 };
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker, TelemetryWorkerHandle};
 use sp_keystore::SyncCryptoStorePtr;
-use sp_runtime::traits::BlakeTwo256;
+use sp_runtime::{generic::DigestItem, traits::BlakeTwo256};
 use substrate_prometheus_endpoint::Registry;
 
 // EVM
@@ -493,6 +494,18 @@ pub async fn start_parachain_node(
 			);
 			proposer_factory.set_soft_deadline(SOFT_DEADLINE_PERCENT);
 
+			let client_clone = client.clone();
+			let keystore_clone = keystore.clone();
+			let maybe_provide_vrf_digest =
+				move |nimbus_id: NimbusId, parent: Hash| -> Option<DigestItem> {
+					crate::vrf::vrf_pre_digest::<Block, FullClient>(
+						&client_clone,
+						&keystore_clone,
+						nimbus_id,
+						parent,
+					)
+				};
+
 			let provider = move |_, (relay_parent, validation_data, _author_id)| {
 				let relay_chain_interface = relay_chain_interface.clone();
 				async move {
@@ -515,10 +528,9 @@ pub async fn start_parachain_node(
 
 					let author = nimbus_primitives::InherentDataProvider;
 
-					// randomness
-					// let randomness = session_keys_primitives::InherentDataProvider;
+					let randomness = session_keys_primitives::InherentDataProvider;
 
-					Ok((time, parachain_inherent, author))
+					Ok((time, parachain_inherent, author, randomness))
 				}
 			};
 
@@ -531,7 +543,7 @@ pub async fn start_parachain_node(
 				keystore,
 				skip_prediction: force_authoring,
 				create_inherent_data_providers: provider,
-				additional_digests_provider: (),
+				additional_digests_provider: maybe_provide_vrf_digest,
 			}))
 		},
 	)
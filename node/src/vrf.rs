@@ -0,0 +1,69 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Signing of the VRF pre-digest included by the collator in every authored block.
+
+use session_keys_primitives::{make_transcript, make_transcript_data, PreDigest, VrfApi, VrfId};
+use sp_api::ProvideRuntimeApi;
+use sp_application_crypto::{AppKey, ByteArray};
+use sp_consensus_vrf::schnorrkel::{PublicKey, VRFOutput, VRFProof};
+use sp_core::H256;
+use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
+use sp_runtime::generic::DigestItem;
+
+/// Uses the runtime API to get the VRF inputs and sign them with the VRF key that
+/// corresponds to the authoring NimbusId.
+pub fn vrf_pre_digest<B, C>(
+	client: &C,
+	keystore: &SyncCryptoStorePtr,
+	nimbus_id: nimbus_primitives::NimbusId,
+	parent: H256,
+) -> Option<DigestItem>
+where
+	B: sp_runtime::traits::Block<Hash = H256>,
+	C: ProvideRuntimeApi<B>,
+	C::Api: VrfApi<B>,
+{
+	let runtime_api = client.runtime_api();
+
+	// first ? for runtime API, second ? for if no relay epoch randomness was recorded yet
+	let vrf_input = runtime_api.get_vrf_input(parent).ok()??;
+	// first ? for runtime API, second ? for not VRF key associated with NimbusId
+	let key: VrfId = runtime_api.vrf_key_lookup(parent, nimbus_id).ok()??;
+	let vrf_pre_digest = sign_vrf(vrf_input, key, keystore)?;
+	Some(session_keys_primitives::digest::CompatibleDigestItem::vrf_pre_digest(vrf_pre_digest))
+}
+
+/// Signs the VRF transcript of the block's VRF input, returning `None` if the key is not in the
+/// keystore or the produced signature does not verify.
+fn sign_vrf(vrf_input: H256, key: VrfId, keystore: &SyncCryptoStorePtr) -> Option<PreDigest> {
+	let transcript = make_transcript(vrf_input);
+	let transcript_data = make_transcript_data(vrf_input);
+	let signature =
+		SyncCryptoStore::sr25519_vrf_sign(&**keystore, VrfId::ID, key.as_ref(), transcript_data)
+			.ok()??;
+	let public = PublicKey::from_bytes(key.as_slice()).ok()?;
+	if signature.output.attach_input_hash(&public, transcript).is_err() {
+		// VRF signature cannot be validated using key and transcript
+		return None;
+	}
+	Some(PreDigest {
+		vrf_output: VRFOutput(signature.output),
+		vrf_proof: VRFProof(signature.proof),
+	})
+}
@@ -308,12 +308,15 @@ pub mod pallet {
 	pub struct GenesisConfig<T: Config> {
 		/// The associations that should exist at chain genesis
 		pub mappings: Vec<(NimbusId, T::AccountId)>,
+		/// Keys registered for the given NimbusIds at chain genesis. Mappings without an entry
+		/// use the keys derived from their NimbusId.
+		pub keys: Vec<(NimbusId, T::Keys)>,
 	}
 
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			Self { mappings: vec![] }
+			Self { mappings: vec![], keys: vec![] }
 		}
 	}
 
@@ -321,11 +324,13 @@ pub mod pallet {
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
 			for (nimbus_id, account_id) in &self.mappings {
-				if let Err(e) = Pallet::<T>::enact_registration(
-					&nimbus_id,
-					&account_id,
-					nimbus_id.clone().into(),
-				) {
+				let keys = self
+					.keys
+					.iter()
+					.find(|(id, _)| id == nimbus_id)
+					.map(|(_, keys)| keys.clone())
+					.unwrap_or_else(|| nimbus_id.clone().into());
+				if let Err(e) = Pallet::<T>::enact_registration(&nimbus_id, &account_id, keys) {
 					log::warn!("Error with genesis author mapping registration: {:?}", e);
 				}
 			}
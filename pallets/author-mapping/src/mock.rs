@@ -143,11 +143,13 @@ pub(crate) struct ExtBuilder {
 	balances: Vec<(AccountId, Balance)>,
 	/// AuthorId -> AccoutId mappings
 	mappings: Vec<(NimbusId, AccountId)>,
+	/// AuthorId -> Keys overrides
+	keys: Vec<(NimbusId, NimbusId)>,
 }
 
 impl Default for ExtBuilder {
 	fn default() -> ExtBuilder {
-		ExtBuilder { balances: vec![], mappings: vec![] }
+		ExtBuilder { balances: vec![], mappings: vec![], keys: vec![] }
 	}
 }
 
@@ -162,6 +164,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub(crate) fn with_keys(mut self, keys: Vec<(NimbusId, NimbusId)>) -> Self {
+		self.keys = keys;
+		self
+	}
+
 	pub(crate) fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::default()
 			.build_storage::<Runtime>()
@@ -171,9 +178,12 @@ impl ExtBuilder {
 			.assimilate_storage(&mut t)
 			.expect("Pallet balances storage can be assimilated");
 
-		pallet_author_mapping::GenesisConfig::<Runtime> { mappings: self.mappings }
-			.assimilate_storage(&mut t)
-			.expect("Pallet author mapping's storage can be assimilated");
+		pallet_author_mapping::GenesisConfig::<Runtime> {
+			mappings: self.mappings,
+			keys: self.keys,
+		}
+		.assimilate_storage(&mut t)
+		.expect("Pallet author mapping's storage can be assimilated");
 
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
@@ -48,6 +48,25 @@ fn genesis_builder_works() {
 		})
 }
 
+#[test]
+fn genesis_builder_uses_provided_keys() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000)])
+		.with_mappings(vec![(TestAuthor::Alice.into(), 1), (TestAuthor::Bob.into(), 2)])
+		.with_keys(vec![(TestAuthor::Alice.into(), TestAuthor::Charlie.into())])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				AuthorMapping::keys_of(&TestAuthor::Alice.into()),
+				Some(TestAuthor::Charlie.into())
+			);
+			assert_eq!(
+				AuthorMapping::keys_of(&TestAuthor::Bob.into()),
+				Some(TestAuthor::Bob.into())
+			);
+		})
+}
+
 #[test]
 fn eligible_account_can_register() {
 	ExtBuilder::default().with_balances(vec![(2, 1000)]).build().execute_with(|| {
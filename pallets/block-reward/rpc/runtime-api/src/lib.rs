@@ -0,0 +1,37 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the block reward pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_block_reward::{Beneficiary, RewardInfo};
+
+sp_api::decl_runtime_apis! {
+	/// Provides the block reward issued at the current state and how it is distributed.
+	pub trait BlockRewardApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Block reward split between the beneficiaries, dapps staking TVL percentage and the
+		/// projected APR of dapps staking.
+		fn reward_info() -> RewardInfo<AccountId, Balance>;
+	}
+}
@@ -0,0 +1,96 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the block reward pallet.
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeSerializeDeserialize};
+use std::{marker::PhantomData, sync::Arc};
+
+pub use pallet_block_reward_rpc_runtime_api::{
+	BlockRewardApi as BlockRewardRuntimeApi, RewardInfo,
+};
+
+#[rpc(server)]
+pub trait BlockRewardApi<BlockHash, AccountId, Balance> {
+	/// Block reward split between the beneficiaries, dapps staking TVL percentage and the
+	/// projected APR of dapps staking at the given block, or the best block if omitted.
+	#[method(name = "blockReward_rewardInfo")]
+	fn reward_info(&self, at: Option<BlockHash>) -> RpcResult<RewardInfo<AccountId, Balance>>;
+}
+
+/// Provides RPC methods to query block reward issuance and distribution.
+pub struct BlockReward<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> BlockReward<C, Block> {
+	/// Creates a new instance of the BlockReward RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance> BlockRewardApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for BlockReward<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: BlockRewardRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + MaybeSerializeDeserialize + Send + Sync + 'static,
+	Balance: Codec + MaybeSerializeDeserialize + Send + Sync + 'static,
+{
+	fn reward_info(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RewardInfo<AccountId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.reward_info(at).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query block reward info.",
+				Some(e.to_string()),
+			))
+			.into()
+		})
+	}
+}
@@ -31,12 +31,25 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 benchmarks! {
 
 	set_configuration {
-		let reward_config = RewardDistributionConfig::default();
+		let reward_config = RewardDistributionConfigOf::<T>::default();
 		assert!(reward_config.is_consistent());
 	}: _(RawOrigin::Root, reward_config.clone())
 	verify {
 		assert_last_event::<T>(Event::<T>::DistributionConfigurationChanged(reward_config).into());
 	}
+
+	set_issuance_schedule {
+		let schedule = IssuanceSchedule {
+			initial_reward: 1_000_000_u32.into(),
+			decay_period: 100_u32.into(),
+			decay_percent: Perbill::from_percent(50),
+			yearly_inflation_target: Some(Perbill::from_percent(5)),
+			supply_cap: Some(1_000_000_000_u32.into()),
+		};
+	}: _(RawOrigin::Root, schedule.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::IssuanceScheduleChanged(schedule).into());
+	}
 }
 
 #[cfg(test)]
@@ -24,8 +24,14 @@
 //!
 //! Pallet that implements block reward issuance and distribution mechanics.
 //!
+//! The amount issued every block follows an issuance schedule kept in storage: a per block
+//! reward which can decay periodically, optionally limited by a yearly inflation target and
+//! a hard supply cap after which issuance stops.
+//!
 //! After issuing a block reward, pallet will calculate how to distribute the reward
-//! based on configurable parameters and chain state.
+//! based on configurable parameters and chain state. The reward is split between a bounded list
+//! of beneficiaries, each one receiving a base percentage of the reward and an adjustable one
+//! which depends on dapps staking TVL.
 //!
 //! Major on-chain factors which can influence reward distribution are total issuance and total
 //! value locked by dapps staking.
@@ -35,11 +41,15 @@
 //! ### Dispatchable Function
 //!
 //! - `set_configuration` - used to change reward distribution configuration parameters
+//! - `set_issuance_schedule` - used to change the block reward issuance schedule
 //!
 //! ### Other
 //!
 //! - `on_timestamp_set` - This pallet implements the `OnTimestampSet` trait to handle block
 //!   production. Note: We assume that it's impossible to set timestamp two times in a block.
+//! - `reward_info` - Current block reward, its split between the beneficiaries and the projected
+//!   APR of dapps staking. Exposed through the `BlockRewardApi` runtime API and the
+//!   `blockReward_rewardInfo` RPC.
 //!
 //! ## Usage
 //!
@@ -55,17 +65,23 @@
 //! 3. `BeneficiaryPayout` handler should be defined as an impl of `BeneficiaryPayout` trait.
 //! For example: ```nocompile
 //! pub struct BeneficiaryPayout();
-//! impl BeneficiaryPayout<NegativeImbalanceOf<T>> for BeneficiaryPayout {
-//!
-//!     fn treasury(reward: NegativeImbalanceOf<T>) {
-//!         Balances::resolve_creating(&TREASURY_POT.into_account(), reward);
-//!     }
+//! impl BeneficiaryPayout<NegativeImbalanceOf<T>, AccountId> for BeneficiaryPayout {
 //!
-//!     fn dapps_staking(stakers: NegativeImbalanceOf<T>, dapps: NegativeImbalanceOf<T>) {
-//!         DappsStaking::rewards(stakers, dapps);
+//!     fn payout(beneficiary: &Beneficiary<AccountId>, reward: NegativeImbalanceOf<T>) {
+//!         match beneficiary {
+//!             Beneficiary::Treasury =>
+//!                 Balances::resolve_creating(&TREASURY_POT.into_account(), reward),
+//!             Beneficiary::DappsStakers => DappsStaking::rewards(reward, Zero::zero()),
+//!             Beneficiary::Dapps => DappsStaking::rewards(Zero::zero(), reward),
+//!             Beneficiary::Burn => drop(reward),
+//!             Beneficiary::Account(account) => Balances::resolve_creating(account, reward),
+//!             Beneficiary::ParachainStaking =>
+//!                 Balances::resolve_creating(&STAKING_POT.into_account(), reward),
+//!         }
 //!     }
 //! }
-//! 4. Set `RewardAmount` to desired block reward value in native currency.
+//! 4. Set the initial `IssuanceSchedule` in genesis, or through the `v1` migration for chains
+//! which used the former constant `RewardAmount`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -78,10 +94,10 @@ use frame_support::{
 };
 use frame_system::{ensure_root, pallet_prelude::*};
 use sp_runtime::{
-	traits::{CheckedAdd, Zero},
-	Perbill,
+	traits::{CheckedAdd, One, Saturating, UniqueSaturatedInto, Zero},
+	FixedPointNumber, FixedU128, Perbill,
 };
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 #[cfg(any(feature = "runtime-benchmarks"))]
 pub mod benchmarking;
@@ -90,6 +106,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -98,13 +115,25 @@ pub mod pallet {
 
 	use super::*;
 
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	/// The balance type of this pallet.
 	pub(crate) type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	/// The reward distribution configuration type of this pallet.
+	pub type RewardDistributionConfigOf<T> =
+		RewardDistributionConfig<<T as frame_system::Config>::AccountId>;
+
+	/// The issuance schedule type of this pallet.
+	pub type IssuanceScheduleOf<T> =
+		IssuanceSchedule<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
 	// Negative imbalance type of this pallet.
 	pub(crate) type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 		<T as frame_system::Config>::AccountId,
@@ -119,11 +148,11 @@ pub mod pallet {
 		type DappsStakingTvlProvider: Get<BalanceOf<Self>>;
 
 		/// Used to payout rewards
-		type BeneficiaryPayout: BeneficiaryPayout<NegativeImbalanceOf<Self>>;
+		type BeneficiaryPayout: BeneficiaryPayout<NegativeImbalanceOf<Self>, Self::AccountId>;
 
-		/// The amount of issuance for each block.
+		/// The number of blocks produced in a year, used to apply the yearly inflation target.
 		#[pallet::constant]
-		type RewardAmount: Get<BalanceOf<Self>>;
+		type BlocksPerYear: Get<u32>;
 
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -135,13 +164,41 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn reward_config)]
 	pub type RewardDistributionConfigStorage<T: Config> =
-		StorageValue<_, RewardDistributionConfig, ValueQuery>;
+		StorageValue<_, RewardDistributionConfigOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn issuance_schedule)]
+	pub type IssuanceScheduleStorage<T: Config> =
+		StorageValue<_, IssuanceScheduleOf<T>, ValueQuery>;
+
+	/// Block reward of the current decay period, before the inflation target and supply cap
+	/// are applied.
+	#[pallet::storage]
+	#[pallet::getter(fn current_block_reward)]
+	pub type CurrentBlockReward<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Block at which the block reward decays next.
+	#[pallet::storage]
+	#[pallet::getter(fn next_decay_at)]
+	pub type NextDecayAt<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Distribution configuration has been updated.
-		DistributionConfigurationChanged(RewardDistributionConfig),
+		DistributionConfigurationChanged(RewardDistributionConfigOf<T>),
+		/// Issuance schedule has been updated, the block reward restarts from its initial value.
+		IssuanceScheduleChanged(IssuanceScheduleOf<T>),
+		/// Block reward has decayed to the given value.
+		BlockRewardDecayed(BalanceOf<T>),
+		/// Total issuance has reached the given supply cap, no more block reward is issued.
+		SupplyCapReached(BalanceOf<T>),
+		/// Block reward has been distributed.
+		/// \[block_reward, tvl_percentage, payouts\]
+		///
+		/// `payouts` are the amounts paid to the configured beneficiaries, in configuration
+		/// order. The rest of the block reward is unearned adjustable reward paid to the treasury.
+		RewardsDistributed(BalanceOf<T>, Perbill, BoundedVec<BalanceOf<T>, MaxBeneficiaries>),
 	}
 
 	#[pallet::error]
@@ -151,16 +208,39 @@ pub mod pallet {
 	}
 
 	#[pallet::genesis_config]
-	#[cfg_attr(feature = "std", derive(Default))]
-	pub struct GenesisConfig {
-		pub reward_config: RewardDistributionConfig,
+	pub struct GenesisConfig<T: Config> {
+		pub reward_config: RewardDistributionConfigOf<T>,
+		pub issuance_schedule: IssuanceScheduleOf<T>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { reward_config: Default::default(), issuance_schedule: Default::default() }
+		}
 	}
 
 	#[pallet::genesis_build]
-	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
-			assert!(self.reward_config.is_consistent());
-			RewardDistributionConfigStorage::<T>::put(self.reward_config.clone())
+			assert!(
+				self.reward_config.is_consistent(),
+				"Reward distribution configuration must sum up to one whole."
+			);
+			RewardDistributionConfigStorage::<T>::put(self.reward_config.clone());
+			Pallet::<T>::start_issuance_schedule(self.issuance_schedule.clone(), Zero::zero());
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			ensure!(
+				Self::reward_config().is_consistent(),
+				"reward distribution configuration is not consistent"
+			);
+			Ok(())
 		}
 	}
 
@@ -179,7 +259,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_configuration())]
 		pub fn set_configuration(
 			origin: OriginFor<T>,
-			reward_distro_params: RewardDistributionConfig,
+			reward_distro_params: RewardDistributionConfigOf<T>,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
@@ -193,46 +273,245 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Sets the issuance schedule which will be used from next block reward issuance.
+		///
+		/// The block reward restarts from `initial_reward` and the next decay happens
+		/// `decay_period` blocks after the current one.
+		///
+		/// - `schedule` - block reward issuance schedule
+		///
+		/// Emits `IssuanceScheduleChanged` with schedule embedded into event itself.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_issuance_schedule())]
+		pub fn set_issuance_schedule(
+			origin: OriginFor<T>,
+			schedule: IssuanceScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::start_issuance_schedule(schedule.clone(), now);
+
+			Self::deposit_event(Event::<T>::IssuanceScheduleChanged(schedule));
+
+			Ok(().into())
+		}
 	}
 
 	impl<Moment, T: Config> OnTimestampSet<Moment> for Pallet<T> {
 		fn on_timestamp_set(_moment: Moment) {
-			let inflation = T::Currency::issue(T::RewardAmount::get());
+			let now = frame_system::Pallet::<T>::block_number();
+			let reward = Self::issuance_for_block(now);
+			if reward.is_zero() {
+				return;
+			}
+
+			let inflation = T::Currency::issue(reward);
 			Self::distribute_rewards(inflation);
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Stores the schedule and restarts the block reward from its initial value.
+		pub(crate) fn start_issuance_schedule(
+			schedule: IssuanceScheduleOf<T>,
+			now: T::BlockNumber,
+		) {
+			CurrentBlockReward::<T>::put(schedule.initial_reward);
+			NextDecayAt::<T>::put(now.saturating_add(schedule.decay_period));
+			IssuanceScheduleStorage::<T>::put(schedule);
+		}
+
+		/// Amount to issue in the given block, applying the schedule decay, the yearly
+		/// inflation target and the supply cap.
+		fn issuance_for_block(now: T::BlockNumber) -> BalanceOf<T> {
+			let schedule = Self::issuance_schedule();
+			let mut reward = Self::current_block_reward();
+
+			if schedule.decays() && now >= Self::next_decay_at() {
+				reward = reward.saturating_sub(schedule.decay_percent * reward);
+				CurrentBlockReward::<T>::put(reward);
+				NextDecayAt::<T>::put(now.saturating_add(schedule.decay_period));
+				Self::deposit_event(Event::<T>::BlockRewardDecayed(reward));
+			}
+
+			let total_issuance = T::Currency::total_issuance();
+			reward = Self::apply_inflation_target(&schedule, reward, total_issuance);
+
+			if let Some(supply_cap) = schedule.supply_cap {
+				let remaining = supply_cap.saturating_sub(total_issuance);
+				if reward >= remaining {
+					if !remaining.is_zero() {
+						Self::deposit_event(Event::<T>::SupplyCapReached(supply_cap));
+					}
+					reward = remaining;
+				}
+			}
+
+			reward
+		}
+
+		/// Limits the block reward so yearly issuance doesn't exceed the inflation target.
+		fn apply_inflation_target(
+			schedule: &IssuanceScheduleOf<T>,
+			reward: BalanceOf<T>,
+			total_issuance: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			match schedule.yearly_inflation_target {
+				Some(yearly_inflation_target) => {
+					let blocks_per_year = BalanceOf::<T>::from(T::BlocksPerYear::get().max(1));
+					reward.min(yearly_inflation_target * total_issuance / blocks_per_year)
+				},
+				None => reward,
+			}
+		}
+
+		/// Effective annual inflation, relative to total issuance, if the current block reward
+		/// is issued for a whole year.
+		///
+		/// This is the inflation of the whole chain when staking rewards are funded as a
+		/// beneficiary of the block reward, since nothing else is minted then.
+		pub fn annual_inflation() -> Perbill {
+			let schedule = Self::issuance_schedule();
+			let total_issuance = T::Currency::total_issuance();
+			let reward = Self::apply_inflation_target(
+				&schedule,
+				Self::current_block_reward(),
+				total_issuance,
+			);
+
+			let mut yearly_issuance =
+				reward.saturating_mul(BalanceOf::<T>::from(T::BlocksPerYear::get()));
+			if let Some(supply_cap) = schedule.supply_cap {
+				yearly_issuance = yearly_issuance.min(supply_cap.saturating_sub(total_issuance));
+			}
+
+			if total_issuance.is_zero() {
+				Perbill::zero()
+			} else {
+				Perbill::from_rational(yearly_issuance, total_issuance)
+			}
+		}
+
 		/// Distribute reward between beneficiaries.
 		///
 		/// # Arguments
 		/// * `reward` - reward that will be split and distributed
 		fn distribute_rewards(block_reward: NegativeImbalanceOf<T>) {
 			let distro_params = Self::reward_config();
+			let total_reward = block_reward.peek();
+			let tvl_percentage = Self::tvl_percentage();
+			let payouts = Self::split_reward(&distro_params, tvl_percentage, total_reward);
+
+			// Payout beneficiaries
+			let mut remainder = block_reward;
+			for (share, balance) in distro_params.beneficiaries.iter().zip(payouts.iter()) {
+				let (reward, rest) = remainder.split(*balance);
+				remainder = rest;
+
+				T::BeneficiaryPayout::payout(&share.beneficiary, reward);
+			}
 
-			// Pre-calculate balance which will be deposited for each beneficiary
-			let base_staker_balance = distro_params.base_staker_percent * block_reward.peek();
-			let dapps_balance = distro_params.dapps_percent * block_reward.peek();
+			// Adjustable reward which wasn't earned goes to the treasury
+			if !remainder.peek().is_zero() {
+				T::BeneficiaryPayout::payout(&Beneficiary::Treasury, remainder);
+			}
 
-			// This is part that's distributed between stakers and treasury
-			let adjustable_balance = distro_params.adjustable_percent * block_reward.peek();
+			Self::deposit_event(Event::<T>::RewardsDistributed(
+				total_reward,
+				tvl_percentage,
+				payouts,
+			));
+		}
 
-			// Calculate total staker and treasury reward balance
-			let adjustable_staker_part = if distro_params.ideal_dapps_staking_tvl.is_zero() {
-				adjustable_balance
+		/// Amounts of `total_reward` earned by the beneficiaries of `distro_params`, in the same
+		/// order. Adjustable reward which isn't earned with the given TVL is left out.
+		fn split_reward(
+			distro_params: &RewardDistributionConfigOf<T>,
+			tvl_percentage: Perbill,
+			total_reward: BalanceOf<T>,
+		) -> BoundedVec<BalanceOf<T>, MaxBeneficiaries> {
+			// Share of the adjustable percentages which is earned with the current TVL
+			let adjustable_factor = if distro_params.ideal_dapps_staking_tvl.is_zero() {
+				Perbill::one()
 			} else {
-				Self::tvl_percentage() / distro_params.ideal_dapps_staking_tvl * adjustable_balance
+				tvl_percentage / distro_params.ideal_dapps_staking_tvl
 			};
 
-			let total_staker_balance = base_staker_balance + adjustable_staker_part;
+			BoundedVec::truncate_from(
+				distro_params
+					.beneficiaries
+					.iter()
+					.map(|share| {
+						share.base_percent * total_reward +
+							adjustable_factor * (share.adjustable_percent * total_reward)
+					})
+					.collect(),
+			)
+		}
 
-			// Prepare imbalances
-			let (dapps_imbalance, remainder) = block_reward.split(dapps_balance);
-			let (stakers_imbalance, treasury_imbalance) = remainder.split(total_staker_balance);
+		/// Block reward issued in the next block if the state doesn't change, without applying
+		/// a decay which is due.
+		fn projected_block_reward() -> BalanceOf<T> {
+			let schedule = Self::issuance_schedule();
+			let total_issuance = T::Currency::total_issuance();
+			let reward = Self::apply_inflation_target(
+				&schedule,
+				Self::current_block_reward(),
+				total_issuance,
+			);
 
-			// Payout beneficiaries
-			T::BeneficiaryPayout::treasury(treasury_imbalance);
-			T::BeneficiaryPayout::dapps_staking(stakers_imbalance, dapps_imbalance);
+			match schedule.supply_cap {
+				Some(supply_cap) => reward.min(supply_cap.saturating_sub(total_issuance)),
+				None => reward,
+			}
+		}
+
+		/// Current block reward, how it is split between the beneficiaries and the yearly
+		/// returns of dapps staking it leads to.
+		pub fn reward_info() -> RewardInfo<T::AccountId, BalanceOf<T>> {
+			let distro_params = Self::reward_config();
+			let block_reward = Self::projected_block_reward();
+			// TVL percentage seen by the distribution, once the block reward is issued
+			let tvl_percentage = Perbill::from_rational(
+				T::DappsStakingTvlProvider::get(),
+				T::Currency::total_issuance().saturating_add(block_reward).max(One::one()),
+			);
+			let amounts = Self::split_reward(&distro_params, tvl_percentage, block_reward);
+
+			let mut unearned = block_reward;
+			let mut stakers_reward = BalanceOf::<T>::zero();
+			let mut dapps_reward = BalanceOf::<T>::zero();
+			let mut payouts = Vec::with_capacity(amounts.len());
+			for (share, amount) in distro_params.beneficiaries.into_iter().zip(amounts) {
+				unearned = unearned.saturating_sub(amount);
+				match share.beneficiary {
+					Beneficiary::DappsStakers => stakers_reward.saturating_accrue(amount),
+					Beneficiary::Dapps => dapps_reward.saturating_accrue(amount),
+					_ => (),
+				}
+				payouts.push((share.beneficiary, amount));
+			}
+
+			// Yearly reward relative to the value locked in dapps staking
+			let tvl: u128 = T::DappsStakingTvlProvider::get().unique_saturated_into();
+			let blocks_per_year = u128::from(T::BlocksPerYear::get());
+			let apr = |reward: BalanceOf<T>| {
+				let yearly_reward = UniqueSaturatedInto::<u128>::unique_saturated_into(reward)
+					.saturating_mul(blocks_per_year);
+				FixedU128::checked_from_rational(yearly_reward, tvl).unwrap_or_default()
+			};
+
+			RewardInfo {
+				block_reward,
+				tvl_percentage,
+				payouts,
+				unearned,
+				annual_inflation: Self::annual_inflation(),
+				stakers_apr: apr(stakers_reward),
+				dapps_apr: apr(dapps_reward),
+			}
 		}
 
 		/// Provides TVL as percentage of total issuance
@@ -248,63 +527,87 @@ pub mod pallet {
 	}
 }
 
-/// List of configuration parameters used to calculate reward distribution portions for all the
-/// beneficiaries.
-///
-/// Note that if `ideal_dapps_staking_tvl` is set to `Zero`, entire `adjustable_percent` goes to the
-/// stakers.
+/// Maximum number of beneficiaries in a reward distribution configuration.
+pub type MaxBeneficiaries = ConstU32<16>;
+
+/// Receiver of a share of the block reward.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
-pub struct RewardDistributionConfig {
-	/// Base percentage of reward that goes to treasury
-	#[codec(compact)]
-	pub base_treasury_percent: Perbill,
-	/// Base percentage of reward that goes to stakers
-	#[codec(compact)]
-	pub base_staker_percent: Perbill,
-	/// Percentage of rewards that goes to dApps
+pub enum Beneficiary<AccountId> {
+	/// On-chain treasury, it also receives the adjustable reward that isn't earned
+	Treasury,
+	/// Dapps staking stakers reward pot
+	DappsStakers,
+	/// Dapps staking dapps reward pot
+	Dapps,
+	/// Reward is burned
+	Burn,
+	/// Any other account, e.g. the pot of an ecosystem program
+	Account(AccountId),
+	/// Collators and delegators of parachain staking
+	ParachainStaking,
+}
+
+/// Share of the block reward paid out to a single beneficiary.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct BeneficiaryShare<AccountId> {
+	/// Receiver of the reward
+	pub beneficiary: Beneficiary<AccountId>,
+	/// Percentage of reward that always goes to the beneficiary
 	#[codec(compact)]
-	pub dapps_percent: Perbill,
-	/// Adjustable reward percentage that either goes to treasury or to stakers
+	pub base_percent: Perbill,
+	/// Percentage of reward that goes to the beneficiary once dapps staking TVL reaches the ideal
+	/// value, only part of it is received below that
 	#[codec(compact)]
 	pub adjustable_percent: Perbill,
-	/// Target dapps-staking TVL percentage at which adjustable inflation towards stakers becomes
-	/// saturated
+}
+
+/// List of configuration parameters used to calculate reward distribution portions for all the
+/// beneficiaries.
+///
+/// Each beneficiary receives its adjustable percentage in proportion to how close dapps staking
+/// TVL is to `ideal_dapps_staking_tvl`, the rest of it goes to the treasury. Note that if
+/// `ideal_dapps_staking_tvl` is set to `Zero`, adjustable percentages are always fully received.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardDistributionConfig<AccountId> {
+	/// Beneficiaries of the block reward, in payout order
+	pub beneficiaries: BoundedVec<BeneficiaryShare<AccountId>, MaxBeneficiaries>,
+	/// Target dapps-staking TVL percentage at which adjustable inflation becomes saturated
 	#[codec(compact)]
 	pub ideal_dapps_staking_tvl: Perbill,
 }
 
-impl Default for RewardDistributionConfig {
+impl<AccountId> Default for RewardDistributionConfig<AccountId> {
 	/// `default` values based on configuration at the time of writing this code.
 	/// Should be overriden by desired params.
 	fn default() -> Self {
-		RewardDistributionConfig {
-			base_treasury_percent: Perbill::from_percent(40),
-			base_staker_percent: Perbill::from_percent(30),
-			dapps_percent: Perbill::from_percent(30),
+		let share = |beneficiary, percent| BeneficiaryShare {
+			beneficiary,
+			base_percent: Perbill::from_percent(percent),
 			adjustable_percent: Zero::zero(),
+		};
+
+		RewardDistributionConfig {
+			beneficiaries: BoundedVec::truncate_from(vec![
+				share(Beneficiary::Treasury, 40),
+				share(Beneficiary::DappsStakers, 30),
+				share(Beneficiary::Dapps, 30),
+			]),
 			ideal_dapps_staking_tvl: Zero::zero(),
 		}
 	}
 }
 
-impl RewardDistributionConfig {
+impl<AccountId> RewardDistributionConfig<AccountId> {
 	/// `true` if sum of all percentages is `one whole`, `false` otherwise.
 	pub fn is_consistent(&self) -> bool {
-		// TODO: perhaps this can be writen in a more cleaner way?
-		// experimental-only `try_reduce` could be used but it's not available
-		// https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.try_reduce
-
-		let variables = vec![
-			&self.base_treasury_percent,
-			&self.base_staker_percent,
-			&self.dapps_percent,
-			&self.adjustable_percent,
-		];
-
 		let mut accumulator = Perbill::zero();
-		for config_param in variables {
-			let result = accumulator.checked_add(config_param);
+		for share in self.beneficiaries.iter() {
+			let result = accumulator
+				.checked_add(&share.base_percent)
+				.and_then(|sum| sum.checked_add(&share.adjustable_percent));
 			if let Some(mid_result) = result {
 				accumulator = mid_result;
 			} else {
@@ -316,16 +619,63 @@ impl RewardDistributionConfig {
 	}
 }
 
-/// Defines functions used to payout the beneficiaries of block rewards
-pub trait BeneficiaryPayout<Imbalance> {
-	/// Payout reward to the treasury
-	fn treasury(reward: Imbalance);
+/// Schedule followed by the amount issued every block.
+///
+/// The block reward starts at `initial_reward` and is reduced by `decay_percent` every
+/// `decay_period` blocks, e.g. a `decay_percent` of 50% halves it. The result is then limited so
+/// that yearly issuance doesn't exceed `yearly_inflation_target` of the total issuance, and so
+/// that total issuance never exceeds `supply_cap`.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssuanceSchedule<Balance, BlockNumber> {
+	/// Amount issued every block until the first decay
+	pub initial_reward: Balance,
+	/// Number of blocks between two decays, zero disables the decay
+	pub decay_period: BlockNumber,
+	/// Percentage by which the block reward is reduced at every decay
+	pub decay_percent: Perbill,
+	/// Maximum yearly issuance, relative to the total issuance
+	pub yearly_inflation_target: Option<Perbill>,
+	/// Total issuance after which no more block reward is issued
+	pub supply_cap: Option<Balance>,
+}
 
-	/// Payout reward to dapps staking
+impl<Balance, BlockNumber: Zero> IssuanceSchedule<Balance, BlockNumber> {
+	/// `true` if the block reward is reduced periodically, `false` otherwise.
+	pub fn decays(&self) -> bool {
+		!self.decay_period.is_zero() && !self.decay_percent.is_zero()
+	}
+}
+
+/// Block reward issued at the current state and how it is distributed, see
+/// [`Pallet::reward_info`].
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RewardInfo<AccountId, Balance> {
+	/// Amount issued in the next block
+	pub block_reward: Balance,
+	/// Dapps staking TVL as percentage of total issuance
+	pub tvl_percentage: Perbill,
+	/// Amount paid to each configured beneficiary
+	pub payouts: Vec<(Beneficiary<AccountId>, Balance)>,
+	/// Adjustable reward which isn't earned with the current TVL, paid to the treasury
+	pub unearned: Balance,
+	/// Yearly issuance relative to total issuance, see [`Pallet::annual_inflation`]
+	pub annual_inflation: Perbill,
+	/// Projected yearly reward of stakers relative to dapps staking TVL
+	pub stakers_apr: FixedU128,
+	/// Projected yearly reward of dapps relative to dapps staking TVL
+	pub dapps_apr: FixedU128,
+}
+
+/// Defines functions used to payout the beneficiaries of block rewards
+pub trait BeneficiaryPayout<Imbalance, AccountId> {
+	/// Payout reward to the given beneficiary
 	///
 	/// # Arguments
 	///
-	/// * `stakers` - reward that goes towards staker reward pot
-	/// * `dapps`   - reward that goes towards dapps reward pot
-	fn dapps_staking(stakers: Imbalance, dapps: Imbalance);
+	/// * `beneficiary` - receiver of the reward
+	/// * `reward`      - reward that goes towards the beneficiary
+	fn payout(beneficiary: &Beneficiary<AccountId>, reward: Imbalance);
 }
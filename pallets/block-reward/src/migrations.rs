@@ -0,0 +1,298 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Block reward storage migrations.
+
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Migration from the constant `RewardAmount` to the issuance schedule kept in storage.
+pub mod v1 {
+	use super::*;
+
+	/// Initializes the issuance schedule with `Schedule`, starting its first decay period at
+	/// the block of the runtime upgrade.
+	///
+	/// `Schedule` should keep the `RewardAmount` used before the upgrade as `initial_reward`,
+	/// otherwise the block reward changes with the runtime upgrade.
+	pub struct InitializeIssuanceSchedule<T, Schedule>(sp_std::marker::PhantomData<(T, Schedule)>);
+
+	impl<T: Config, Schedule: Get<pallet::IssuanceScheduleOf<T>>> OnRuntimeUpgrade
+		for InitializeIssuanceSchedule<T, Schedule>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				log::info!(
+					target: "block-reward",
+					"skipping migration to v1, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Pallet::<T>::start_issuance_schedule(Schedule::get(), now);
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: "block-reward", "initialized issuance schedule, migrated to v1");
+
+			T::DbWeight::get().reads_writes(2, 4)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"storage version was not bumped to v1"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::issuance_schedule().initial_reward == Schedule::get().initial_reward,
+				"issuance schedule was not initialized"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration from the fixed treasury, stakers and dapps split to the list of beneficiaries.
+pub mod v2 {
+	use super::*;
+
+	/// Reward distribution configuration used before the list of beneficiaries.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct OldRewardDistributionConfig {
+		#[codec(compact)]
+		pub base_treasury_percent: Perbill,
+		#[codec(compact)]
+		pub base_staker_percent: Perbill,
+		#[codec(compact)]
+		pub dapps_percent: Perbill,
+		#[codec(compact)]
+		pub adjustable_percent: Perbill,
+		#[codec(compact)]
+		pub ideal_dapps_staking_tvl: Perbill,
+	}
+
+	impl<AccountId> From<OldRewardDistributionConfig> for RewardDistributionConfig<AccountId> {
+		/// Adjustable percentage goes to the stakers, the part they don't earn goes to the
+		/// treasury as before.
+		fn from(old: OldRewardDistributionConfig) -> Self {
+			let share = |beneficiary, base_percent, adjustable_percent| BeneficiaryShare {
+				beneficiary,
+				base_percent,
+				adjustable_percent,
+			};
+
+			RewardDistributionConfig {
+				beneficiaries: BoundedVec::truncate_from(vec![
+					share(Beneficiary::Treasury, old.base_treasury_percent, Zero::zero()),
+					share(
+						Beneficiary::DappsStakers,
+						old.base_staker_percent,
+						old.adjustable_percent,
+					),
+					share(Beneficiary::Dapps, old.dapps_percent, Zero::zero()),
+				]),
+				ideal_dapps_staking_tvl: old.ideal_dapps_staking_tvl,
+			}
+		}
+	}
+
+	/// Translates the stored reward distribution configuration to the list of beneficiaries.
+	pub struct MigrateToBeneficiaryList<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToBeneficiaryList<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 2 {
+				log::info!(
+					target: "block-reward",
+					"skipping migration to v2, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let translated = RewardDistributionConfigStorage::<T>::translate::<
+				OldRewardDistributionConfig,
+				_,
+			>(|old| old.map(Into::into));
+			if translated.is_err() {
+				log::error!(
+					target: "block-reward",
+					"failed to decode the reward distribution configuration, it was reset to default"
+				);
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(target: "block-reward", "translated reward distribution configuration, migrated to v2");
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			if Pallet::<T>::on_chain_storage_version() >= 2 {
+				return Ok(Vec::new());
+			}
+
+			let old = frame_support::storage::unhashed::get::<OldRewardDistributionConfig>(
+				&RewardDistributionConfigStorage::<T>::hashed_key(),
+			)
+			.ok_or("reward distribution configuration can't be decoded")?;
+			Ok(RewardDistributionConfigOf::<T>::from(old).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 2,
+				"storage version was not bumped to v2"
+			);
+			if !state.is_empty() {
+				let expected = RewardDistributionConfigOf::<T>::decode(&mut &state[..])
+					.map_err(|_| "expected reward distribution configuration can't be decoded")?;
+				frame_support::ensure!(
+					Pallet::<T>::reward_config() == expected,
+					"reward distribution configuration was not translated"
+				);
+			}
+			frame_support::ensure!(
+				Pallet::<T>::reward_config().is_consistent(),
+				"reward distribution configuration is not consistent"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration which moves issuance done elsewhere, e.g. staking rewards minted by another pallet,
+/// to the block reward.
+pub mod v3 {
+	use super::*;
+
+	/// Adds `NewBeneficiary` to the reward distribution and increases the block reward by
+	/// `AdditionalReward`, which the new beneficiary receives. Other beneficiaries keep receiving
+	/// the same amounts, so total issuance is unchanged once the other source stops minting.
+	pub struct IncludeBeneficiary<T, NewBeneficiary, AdditionalReward>(
+		sp_std::marker::PhantomData<(T, NewBeneficiary, AdditionalReward)>,
+	);
+
+	impl<T, NewBeneficiary, AdditionalReward> OnRuntimeUpgrade
+		for IncludeBeneficiary<T, NewBeneficiary, AdditionalReward>
+	where
+		T: Config,
+		NewBeneficiary: Get<Beneficiary<T::AccountId>>,
+		AdditionalReward: Get<BalanceOf<T>>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 3 {
+				log::info!(
+					target: "block-reward",
+					"skipping migration to v3, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let current_reward = Pallet::<T>::current_block_reward();
+			let additional_reward = AdditionalReward::get();
+			let new_reward = current_reward.saturating_add(additional_reward);
+
+			// Scale down existing shares so they keep receiving the same amounts, the rest goes to
+			// the new beneficiary.
+			let kept = if new_reward.is_zero() {
+				Perbill::one()
+			} else {
+				Perbill::from_rational(current_reward, new_reward)
+			};
+			let mut config = Pallet::<T>::reward_config();
+			let mut distributed = Perbill::zero();
+			for share in config.beneficiaries.iter_mut() {
+				share.base_percent = kept * share.base_percent;
+				share.adjustable_percent = kept * share.adjustable_percent;
+				distributed = distributed
+					.saturating_add(share.base_percent)
+					.saturating_add(share.adjustable_percent);
+			}
+			let new_share = BeneficiaryShare {
+				beneficiary: NewBeneficiary::get(),
+				base_percent: Perbill::one().saturating_sub(distributed),
+				adjustable_percent: Zero::zero(),
+			};
+			if config.beneficiaries.try_push(new_share).is_err() {
+				log::error!(
+					target: "block-reward",
+					"too many beneficiaries to include a new one, skipping migration to v3"
+				);
+				return T::DbWeight::get().reads(3);
+			}
+
+			RewardDistributionConfigStorage::<T>::put(config);
+			CurrentBlockReward::<T>::put(new_reward);
+			IssuanceScheduleStorage::<T>::mutate(|schedule| {
+				schedule.initial_reward = schedule.initial_reward.saturating_add(additional_reward)
+			});
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+			log::info!(
+				target: "block-reward",
+				"included new beneficiary with block reward {:?}, migrated to v3",
+				additional_reward
+			);
+
+			T::DbWeight::get().reads_writes(4, 4)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			Ok(Pallet::<T>::current_block_reward().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 3,
+				"storage version was not bumped to v3"
+			);
+			let old_reward = BalanceOf::<T>::decode(&mut &state[..])
+				.map_err(|_| "block reward before the upgrade can't be decoded")?;
+			frame_support::ensure!(
+				Pallet::<T>::current_block_reward() >= old_reward,
+				"block reward was decreased"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::reward_config().is_consistent(),
+				"reward distribution configuration is not consistent"
+			);
+			Ok(())
+		}
+	}
+}
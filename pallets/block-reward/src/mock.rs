@@ -16,12 +16,12 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_block_reward, NegativeImbalanceOf};
+use crate::{self as pallet_block_reward, Beneficiary, IssuanceSchedule, NegativeImbalanceOf};
 
 use frame_support::{
 	construct_runtime, parameter_types,
 	sp_io::TestExternalities,
-	traits::{Currency, Get},
+	traits::{Currency, GenesisBuild, Get},
 	weights::Weight,
 	PalletId,
 };
@@ -122,14 +122,17 @@ impl pallet_timestamp::Config for TestRuntime {
 // due to TVL changes.
 pub(crate) const BLOCK_REWARD: Balance = 1_000_000;
 
+// Low enough to reach the yearly inflation target with the test issuance.
+pub(crate) const BLOCKS_PER_YEAR: u32 = 100;
+
 // This gives us enough flexibility to get valid percentages by controlling issuance.
 pub(crate) const TVL: Balance = 1_000_000_000;
 
 // Fake accounts used to simulate reward beneficiaries balances
 pub(crate) const TREASURY_POT: PalletId = PalletId(*b"moktrsry");
-pub(crate) const COLLATOR_POT: PalletId = PalletId(*b"mokcolat");
 pub(crate) const STAKERS_POT: PalletId = PalletId(*b"mokstakr");
 pub(crate) const DAPPS_POT: PalletId = PalletId(*b"mokdapps");
+pub(crate) const STAKING_POT: PalletId = PalletId(*b"mokstkng");
 
 // Type used as TVL provider
 pub struct TvlProvider();
@@ -141,43 +144,52 @@ impl Get<Balance> for TvlProvider {
 
 // Type used as beneficiary payout handle
 pub struct BeneficiaryPayout();
-impl pallet_block_reward::BeneficiaryPayout<NegativeImbalanceOf<TestRuntime>>
+impl pallet_block_reward::BeneficiaryPayout<NegativeImbalanceOf<TestRuntime>, AccountId>
 	for BeneficiaryPayout
 {
-	fn treasury(reward: NegativeImbalanceOf<TestRuntime>) {
-		Balances::resolve_creating(&TREASURY_POT.into_account_truncating(), reward);
-	}
-
-	fn collators(reward: NegativeImbalanceOf<TestRuntime>) {
-		Balances::resolve_creating(&COLLATOR_POT.into_account_truncating(), reward);
-	}
-
-	fn dapps_staking(
-		stakers: NegativeImbalanceOf<TestRuntime>,
-		dapps: NegativeImbalanceOf<TestRuntime>,
-	) {
-		Balances::resolve_creating(&STAKERS_POT.into_account_truncating(), stakers);
-		Balances::resolve_creating(&DAPPS_POT.into_account_truncating(), dapps);
+	fn payout(beneficiary: &Beneficiary<AccountId>, reward: NegativeImbalanceOf<TestRuntime>) {
+		match beneficiary {
+			Beneficiary::Treasury =>
+				Balances::resolve_creating(&TREASURY_POT.into_account_truncating(), reward),
+			Beneficiary::DappsStakers =>
+				Balances::resolve_creating(&STAKERS_POT.into_account_truncating(), reward),
+			Beneficiary::Dapps =>
+				Balances::resolve_creating(&DAPPS_POT.into_account_truncating(), reward),
+			Beneficiary::Burn => drop(reward),
+			Beneficiary::Account(account) => Balances::resolve_creating(account, reward),
+			Beneficiary::ParachainStaking =>
+				Balances::resolve_creating(&STAKING_POT.into_account_truncating(), reward),
+		}
 	}
 }
 
-parameter_types! {
-	pub const RewardAmount: Balance = BLOCK_REWARD;
-}
-
 impl pallet_block_reward::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type RewardAmount = RewardAmount;
+	type BlocksPerYear = frame_support::traits::ConstU32<BLOCKS_PER_YEAR>;
 	type DappsStakingTvlProvider = TvlProvider;
 	type BeneficiaryPayout = BeneficiaryPayout;
 	type WeightInfo = ();
 }
 
+/// Issuance schedule which issues `BLOCK_REWARD` every block, without decay or limits.
+pub(crate) fn constant_issuance() -> IssuanceSchedule<Balance, BlockNumber> {
+	IssuanceSchedule { initial_reward: BLOCK_REWARD, ..Default::default() }
+}
+
 pub struct ExternalityBuilder;
 
 impl ExternalityBuilder {
 	pub fn build() -> TestExternalities {
+		Self::build_with_genesis(pallet_block_reward::GenesisConfig {
+			issuance_schedule: constant_issuance(),
+			..Default::default()
+		})
+	}
+
+	pub fn build_with_genesis(
+		block_reward: pallet_block_reward::GenesisConfig<TestRuntime>,
+	) -> TestExternalities {
 		let mut storage =
 			frame_system::GenesisConfig::default().build_storage::<TestRuntime>().unwrap();
 
@@ -188,6 +200,12 @@ impl ExternalityBuilder {
 		.assimilate_storage(&mut storage)
 		.ok();
 
+		<pallet_block_reward::GenesisConfig<TestRuntime> as GenesisBuild<TestRuntime>>::assimilate_storage(
+			&block_reward,
+			&mut storage,
+		)
+		.unwrap();
+
 		let mut ext = TestExternalities::from(storage);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
@@ -17,7 +17,10 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{pallet::Error, Event, *};
-use frame_support::{assert_noop, assert_ok, traits::OnTimestampSet};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, OnTimestampSet},
+};
 use mock::*;
 use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin, Zero},
@@ -26,96 +29,48 @@ use sp_runtime::{
 
 #[test]
 fn default_reward_distribution_config_is_consitent() {
-	let reward_config = RewardDistributionConfig::default();
+	let reward_config = RewardDistributionConfig::<AccountId>::default();
 	assert!(reward_config.is_consistent());
 }
 
 #[test]
 fn reward_distribution_config_is_consistent() {
 	// 1
-	let reward_config = RewardDistributionConfig {
-		base_treasury_percent: Perbill::from_percent(100),
-		base_staker_percent: Zero::zero(),
-		dapps_percent: Zero::zero(),
-		collators_percent: Zero::zero(),
-		adjustable_percent: Zero::zero(),
-		ideal_dapps_staking_tvl: Zero::zero(),
-	};
+	let reward_config = three_way_config(100, 0, 0, 0, 0);
 	assert!(reward_config.is_consistent());
 
 	// 2
-	let reward_config = RewardDistributionConfig {
-		base_treasury_percent: Zero::zero(),
-		base_staker_percent: Perbill::from_percent(100),
-		dapps_percent: Zero::zero(),
-		collators_percent: Zero::zero(),
-		adjustable_percent: Zero::zero(),
-		ideal_dapps_staking_tvl: Zero::zero(),
-	};
+	let reward_config = three_way_config(0, 100, 0, 0, 0);
 	assert!(reward_config.is_consistent());
 
 	// 3
-	let reward_config = RewardDistributionConfig {
-		base_treasury_percent: Zero::zero(),
-		base_staker_percent: Zero::zero(),
-		dapps_percent: Zero::zero(),
-		collators_percent: Zero::zero(),
-		adjustable_percent: Perbill::from_percent(100),
-		ideal_dapps_staking_tvl: Perbill::from_percent(13),
-	};
+	let reward_config = three_way_config(0, 0, 0, 100, 13);
 	assert!(reward_config.is_consistent());
 
 	// 4
 	// 100%
-	let reward_config = RewardDistributionConfig {
-		base_treasury_percent: Perbill::from_percent(3),
-		base_staker_percent: Perbill::from_percent(14),
-		dapps_percent: Perbill::from_percent(18),
-		collators_percent: Perbill::from_percent(31),
-		adjustable_percent: Perbill::from_percent(34),
-		ideal_dapps_staking_tvl: Zero::zero(),
-	};
+	let reward_config = three_way_config(3, 14, 49, 34, 0);
 	assert!(reward_config.is_consistent());
 }
 
 #[test]
 fn reward_distribution_config_not_consistent() {
 	// 1
-	let reward_config = RewardDistributionConfig {
-		base_treasury_percent: Perbill::from_percent(100),
-		..Default::default()
-	};
+	let reward_config = three_way_config(100, 30, 30, 0, 0);
 	assert!(!reward_config.is_consistent());
 
 	// 2
-	let reward_config = RewardDistributionConfig {
-		adjustable_percent: Perbill::from_percent(100),
-		..Default::default()
-	};
+	let reward_config = three_way_config(40, 30, 30, 100, 0);
 	assert!(!reward_config.is_consistent());
 
 	// 3
 	// 99%
-	let reward_config = RewardDistributionConfig {
-		base_treasury_percent: Perbill::from_percent(10),
-		base_staker_percent: Perbill::from_percent(20),
-		dapps_percent: Perbill::from_percent(20),
-		collators_percent: Perbill::from_percent(30),
-		adjustable_percent: Perbill::from_percent(19),
-		ideal_dapps_staking_tvl: Zero::zero(),
-	};
+	let reward_config = three_way_config(10, 20, 50, 19, 0);
 	assert!(!reward_config.is_consistent());
 
 	// 4
 	// 101%
-	let reward_config = RewardDistributionConfig {
-		base_treasury_percent: Perbill::from_percent(10),
-		base_staker_percent: Perbill::from_percent(20),
-		dapps_percent: Perbill::from_percent(20),
-		collators_percent: Perbill::from_percent(31),
-		adjustable_percent: Perbill::from_percent(20),
-		ideal_dapps_staking_tvl: Zero::zero(),
-	};
+	let reward_config = three_way_config(10, 20, 51, 20, 0);
 	assert!(!reward_config.is_consistent());
 }
 
@@ -129,10 +84,7 @@ pub fn set_configuration_fails() {
 		);
 
 		// 2
-		let reward_config = RewardDistributionConfig {
-			base_treasury_percent: Perbill::from_percent(100),
-			..Default::default()
-		};
+		let reward_config = three_way_config(100, 30, 30, 0, 0);
 		assert!(!reward_config.is_consistent());
 		assert_noop!(
 			BlockReward::set_configuration(RuntimeOrigin::root(), reward_config),
@@ -145,14 +97,7 @@ pub fn set_configuration_fails() {
 pub fn set_configuration_is_ok() {
 	ExternalityBuilder::build().execute_with(|| {
 		// custom config so it differs from the default one
-		let reward_config = RewardDistributionConfig {
-			base_treasury_percent: Perbill::from_percent(3),
-			base_staker_percent: Perbill::from_percent(14),
-			dapps_percent: Perbill::from_percent(18),
-			collators_percent: Perbill::from_percent(31),
-			adjustable_percent: Perbill::from_percent(34),
-			ideal_dapps_staking_tvl: Perbill::from_percent(87),
-		};
+		let reward_config = three_way_config(3, 14, 49, 34, 87);
 		assert!(reward_config.is_consistent());
 
 		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
@@ -164,6 +109,35 @@ pub fn set_configuration_is_ok() {
 	})
 }
 
+#[test]
+pub fn genesis_sets_reward_distribution_config() {
+	let reward_config = three_way_config(10, 20, 20, 50, 40);
+
+	ExternalityBuilder::build_with_genesis(GenesisConfig {
+		reward_config: reward_config.clone(),
+		issuance_schedule: constant_issuance(),
+	})
+	.execute_with(|| {
+		assert_eq!(BlockReward::reward_config(), reward_config);
+
+		let init_balance_state = FreeBalanceSnapshot::new();
+		let rewards = Rewards::calculate(&reward_config);
+		BlockReward::on_timestamp_set(0);
+		init_balance_state.assert_distribution(&FreeBalanceSnapshot::new(), &rewards);
+	})
+}
+
+#[test]
+#[should_panic(expected = "Reward distribution configuration must sum up to one whole.")]
+pub fn genesis_with_inconsistent_config_fails() {
+	let reward_config = three_way_config(100, 30, 30, 0, 0);
+
+	ExternalityBuilder::build_with_genesis(GenesisConfig {
+		reward_config,
+		issuance_schedule: constant_issuance(),
+	});
+}
+
 #[test]
 pub fn inflation_and_total_issuance_as_expected() {
 	ExternalityBuilder::build().execute_with(|| {
@@ -183,6 +157,178 @@ pub fn inflation_and_total_issuance_as_expected() {
 	})
 }
 
+#[test]
+pub fn set_issuance_schedule_fails_for_non_root() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_noop!(
+			BlockReward::set_issuance_schedule(RuntimeOrigin::signed(1), Default::default()),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+pub fn set_issuance_schedule_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		System::set_block_number(7);
+		let schedule = IssuanceSchedule {
+			initial_reward: 3 * BLOCK_REWARD,
+			decay_period: 20,
+			decay_percent: Perbill::from_percent(10),
+			yearly_inflation_target: Some(Perbill::from_percent(5)),
+			supply_cap: Some(TVL),
+		};
+
+		assert_ok!(BlockReward::set_issuance_schedule(RuntimeOrigin::root(), schedule.clone()));
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::IssuanceScheduleChanged(
+			schedule.clone(),
+		)));
+
+		assert_eq!(BlockReward::issuance_schedule(), schedule);
+		assert_eq!(BlockReward::current_block_reward(), 3 * BLOCK_REWARD);
+		assert_eq!(BlockReward::next_decay_at(), 27);
+	})
+}
+
+#[test]
+pub fn block_reward_decays_at_period_boundaries() {
+	ExternalityBuilder::build().execute_with(|| {
+		let decay_period = 10;
+		let schedule = IssuanceSchedule {
+			initial_reward: BLOCK_REWARD,
+			decay_period,
+			decay_percent: Perbill::from_percent(50),
+			..Default::default()
+		};
+		assert_ok!(BlockReward::set_issuance_schedule(RuntimeOrigin::root(), schedule));
+
+		// Two halvings, verify the reward right before and at each boundary
+		let mut expected_reward = BLOCK_REWARD;
+		for block in 1..=2 * decay_period + 1 {
+			System::set_block_number(block);
+			if block > 1 && block % decay_period == 1 {
+				expected_reward /= 2;
+			}
+
+			let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+			BlockReward::on_timestamp_set(0);
+			assert_eq!(
+				<TestRuntime as Config>::Currency::total_issuance(),
+				init_issuance + expected_reward
+			);
+
+			if block > 1 && block % decay_period == 1 {
+				System::assert_has_event(mock::RuntimeEvent::BlockReward(
+					Event::BlockRewardDecayed(expected_reward),
+				));
+				assert_eq!(BlockReward::next_decay_at(), block + decay_period);
+			}
+		}
+		assert_eq!(BlockReward::current_block_reward(), BLOCK_REWARD / 4);
+	})
+}
+
+#[test]
+pub fn yearly_inflation_target_limits_block_reward() {
+	ExternalityBuilder::build().execute_with(|| {
+		// Endow beneficiaries so small rewards aren't lost to the existential deposit
+		BlockReward::on_timestamp_set(0);
+
+		let yearly_inflation_target = Perbill::from_percent(10);
+		let schedule = IssuanceSchedule {
+			initial_reward: BLOCK_REWARD,
+			yearly_inflation_target: Some(yearly_inflation_target),
+			..Default::default()
+		};
+		assert_ok!(BlockReward::set_issuance_schedule(RuntimeOrigin::root(), schedule));
+
+		// Target is below the block reward, only the target is issued
+		let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		let target_reward = yearly_inflation_target * init_issuance / BLOCKS_PER_YEAR as Balance;
+		assert!(target_reward < BLOCK_REWARD);
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::total_issuance(),
+			init_issuance + target_reward
+		);
+
+		// Target is above the block reward, block reward is issued
+		adjust_tvl_percentage(Perbill::from_percent(10));
+		let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		assert!(
+			yearly_inflation_target * init_issuance / BLOCKS_PER_YEAR as Balance > BLOCK_REWARD
+		);
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::total_issuance(),
+			init_issuance + BLOCK_REWARD
+		);
+	})
+}
+
+#[test]
+pub fn supply_cap_limits_last_block_reward() {
+	ExternalityBuilder::build().execute_with(|| {
+		let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		let supply_cap = init_issuance + BLOCK_REWARD + BLOCK_REWARD / 2;
+		let schedule = IssuanceSchedule {
+			initial_reward: BLOCK_REWARD,
+			supply_cap: Some(supply_cap),
+			..Default::default()
+		};
+		assert_ok!(BlockReward::set_issuance_schedule(RuntimeOrigin::root(), schedule));
+
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::total_issuance(),
+			init_issuance + BLOCK_REWARD
+		);
+
+		// Only what is left until the cap is issued
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(<TestRuntime as Config>::Currency::total_issuance(), supply_cap);
+		System::assert_has_event(mock::RuntimeEvent::BlockReward(Event::SupplyCapReached(
+			supply_cap,
+		)));
+
+		// Nothing is issued anymore
+		let events = System::events().len();
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(<TestRuntime as Config>::Currency::total_issuance(), supply_cap);
+		assert_eq!(System::events().len(), events);
+	})
+}
+
+#[test]
+pub fn supply_cap_reached_exactly() {
+	ExternalityBuilder::build().execute_with(|| {
+		let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		let supply_cap = init_issuance + 2 * BLOCK_REWARD;
+		let schedule = IssuanceSchedule {
+			initial_reward: BLOCK_REWARD,
+			supply_cap: Some(supply_cap),
+			..Default::default()
+		};
+		assert_ok!(BlockReward::set_issuance_schedule(RuntimeOrigin::root(), schedule));
+
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::total_issuance(),
+			init_issuance + BLOCK_REWARD
+		);
+
+		// Last full block reward reaches the cap
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(<TestRuntime as Config>::Currency::total_issuance(), supply_cap);
+		System::assert_has_event(mock::RuntimeEvent::BlockReward(Event::SupplyCapReached(
+			supply_cap,
+		)));
+
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(<TestRuntime as Config>::Currency::total_issuance(), supply_cap);
+	})
+}
+
 #[test]
 pub fn reward_distribution_as_expected() {
 	ExternalityBuilder::build().execute_with(|| {
@@ -191,14 +337,7 @@ pub fn reward_distribution_as_expected() {
 		assert!(init_balance_snapshot.is_zero());
 
 		// Prepare a custom config (easily discernable percentages for visual verification)
-		let reward_config = RewardDistributionConfig {
-			base_treasury_percent: Perbill::from_percent(10),
-			base_staker_percent: Perbill::from_percent(20),
-			dapps_percent: Perbill::from_percent(25),
-			collators_percent: Perbill::from_percent(5),
-			adjustable_percent: Perbill::from_percent(40),
-			ideal_dapps_staking_tvl: Perbill::from_percent(50),
-		};
+		let reward_config = three_way_config(10, 20, 30, 40, 50);
 		assert!(reward_config.is_consistent());
 		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
 
@@ -221,14 +360,7 @@ pub fn reward_distribution_as_expected() {
 #[test]
 pub fn reward_distribution_no_adjustable_part() {
 	ExternalityBuilder::build().execute_with(|| {
-		let reward_config = RewardDistributionConfig {
-			base_treasury_percent: Perbill::from_percent(10),
-			base_staker_percent: Perbill::from_percent(45),
-			dapps_percent: Perbill::from_percent(40),
-			collators_percent: Perbill::from_percent(5),
-			adjustable_percent: Perbill::zero(),
-			ideal_dapps_staking_tvl: Perbill::from_percent(50), // this is irrelevant
-		};
+		let reward_config = three_way_config(15, 45, 40, 0, 50);
 		assert!(reward_config.is_consistent());
 		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
 
@@ -252,14 +384,7 @@ pub fn reward_distribution_no_adjustable_part() {
 #[test]
 pub fn reward_distribution_all_zero_except_one() {
 	ExternalityBuilder::build().execute_with(|| {
-		let reward_config = RewardDistributionConfig {
-			base_treasury_percent: Perbill::zero(),
-			base_staker_percent: Perbill::zero(),
-			dapps_percent: Perbill::zero(),
-			collators_percent: Perbill::zero(),
-			adjustable_percent: Perbill::one(),
-			ideal_dapps_staking_tvl: Perbill::from_percent(50), // this is irrelevant
-		};
+		let reward_config = three_way_config(0, 0, 0, 100, 50);
 		assert!(reward_config.is_consistent());
 		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
 
@@ -275,11 +400,244 @@ pub fn reward_distribution_all_zero_except_one() {
 	})
 }
 
+#[test]
+pub fn reward_distribution_to_custom_beneficiaries() {
+	ExternalityBuilder::build().execute_with(|| {
+		let ecosystem_pot = 42;
+		let share = |beneficiary, base_percent, adjustable_percent| BeneficiaryShare {
+			beneficiary,
+			base_percent: Perbill::from_percent(base_percent),
+			adjustable_percent: Perbill::from_percent(adjustable_percent),
+		};
+		let reward_config = RewardDistributionConfig {
+			beneficiaries: BoundedVec::truncate_from(vec![
+				share(Beneficiary::Treasury, 20, 0),
+				share(Beneficiary::DappsStakers, 20, 0),
+				share(Beneficiary::Dapps, 10, 10),
+				share(Beneficiary::Account(ecosystem_pot), 10, 10),
+				share(Beneficiary::Burn, 20, 0),
+			]),
+			ideal_dapps_staking_tvl: Perbill::from_percent(60),
+		};
+		assert!(reward_config.is_consistent());
+		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
+
+		// About half of the adjustable percentages is earned
+		adjust_tvl_percentage(Perbill::from_percent(30));
+
+		let init_balance_state = FreeBalanceSnapshot::new();
+		let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		let rewards = Rewards::calculate(&reward_config);
+
+		BlockReward::on_timestamp_set(0);
+
+		init_balance_state.assert_distribution(&FreeBalanceSnapshot::new(), &rewards);
+
+		// Same percentages as dapps, only part of the adjustable reward is earned
+		let ecosystem_reward = <TestRuntime as Config>::Currency::free_balance(&ecosystem_pot);
+		assert_eq!(ecosystem_reward, rewards.dapps_reward);
+		assert!(ecosystem_reward > Perbill::from_percent(10) * BLOCK_REWARD);
+		assert!(ecosystem_reward < Perbill::from_percent(20) * BLOCK_REWARD);
+
+		// Burned reward isn't issued
+		assert_eq!(
+			<TestRuntime as Config>::Currency::total_issuance(),
+			init_issuance + Perbill::from_percent(80) * BLOCK_REWARD
+		);
+	})
+}
+
+#[test]
+pub fn reward_distribution_emits_event() {
+	ExternalityBuilder::build().execute_with(|| {
+		let reward_config = three_way_config(10, 20, 30, 40, 50);
+		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
+		adjust_tvl_percentage(Perbill::from_percent(30));
+
+		let rewards = Rewards::calculate(&reward_config);
+		let tvl_percentage = Perbill::from_rational(
+			TVL,
+			<TestRuntime as Config>::Currency::total_issuance() + BLOCK_REWARD,
+		);
+
+		BlockReward::on_timestamp_set(0);
+
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::RewardsDistributed(
+			BLOCK_REWARD,
+			tvl_percentage,
+			BoundedVec::truncate_from(vec![
+				rewards.base_treasury_reward,
+				rewards.base_staker_reward + rewards.adjustable_staker_reward,
+				rewards.dapps_reward,
+			]),
+		)));
+	})
+}
+
+#[test]
+pub fn reward_info_matches_next_distribution() {
+	ExternalityBuilder::build().execute_with(|| {
+		let reward_config = three_way_config(10, 20, 30, 40, 50);
+		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
+		adjust_tvl_percentage(Perbill::from_percent(30));
+
+		let rewards = Rewards::calculate(&reward_config);
+		let staker_reward = rewards.base_staker_reward + rewards.adjustable_staker_reward;
+		let reward_info = BlockReward::reward_info();
+
+		assert_eq!(reward_info.block_reward, BLOCK_REWARD);
+		assert_eq!(
+			reward_info.payouts,
+			vec![
+				(Beneficiary::Treasury, rewards.base_treasury_reward),
+				(Beneficiary::DappsStakers, staker_reward),
+				(Beneficiary::Dapps, rewards.dapps_reward),
+			]
+		);
+		assert_eq!(reward_info.unearned, rewards.adjustable_treasury_reward);
+		assert_eq!(reward_info.annual_inflation, BlockReward::annual_inflation());
+		assert_eq!(
+			reward_info.stakers_apr,
+			FixedU128::from_rational(staker_reward * BLOCKS_PER_YEAR as Balance, TVL)
+		);
+		assert_eq!(
+			reward_info.dapps_apr,
+			FixedU128::from_rational(rewards.dapps_reward * BLOCKS_PER_YEAR as Balance, TVL)
+		);
+
+		BlockReward::on_timestamp_set(0);
+
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::RewardsDistributed(
+			reward_info.block_reward,
+			reward_info.tvl_percentage,
+			BoundedVec::truncate_from(
+				reward_info.payouts.into_iter().map(|(_, amount)| amount).collect(),
+			),
+		)));
+	})
+}
+
+#[test]
+pub fn reward_distribution_config_is_migrated_to_beneficiary_list() {
+	ExternalityBuilder::build().execute_with(|| {
+		let old_config = migrations::v2::OldRewardDistributionConfig {
+			base_treasury_percent: Perbill::from_percent(10),
+			base_staker_percent: Perbill::from_percent(20),
+			dapps_percent: Perbill::from_percent(20),
+			adjustable_percent: Perbill::from_percent(50),
+			ideal_dapps_staking_tvl: Perbill::from_percent(40),
+		};
+		frame_support::storage::unhashed::put(
+			&RewardDistributionConfigStorage::<TestRuntime>::hashed_key(),
+			&old_config,
+		);
+		StorageVersion::new(1).put::<BlockReward>();
+
+		migrations::v2::MigrateToBeneficiaryList::<TestRuntime>::on_runtime_upgrade();
+
+		assert_eq!(BlockReward::reward_config(), three_way_config(10, 20, 20, 50, 40));
+		assert_eq!(BlockReward::on_chain_storage_version(), 2);
+	})
+}
+
+#[test]
+pub fn annual_inflation_follows_issuance_schedule() {
+	ExternalityBuilder::build().execute_with(|| {
+		adjust_tvl_percentage(Perbill::from_percent(10));
+		let total_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		assert_eq!(
+			BlockReward::annual_inflation(),
+			Perbill::from_rational(BLOCK_REWARD * BLOCKS_PER_YEAR as Balance, total_issuance)
+		);
+
+		// Limited by the inflation target
+		let schedule = IssuanceSchedule {
+			initial_reward: BLOCK_REWARD,
+			yearly_inflation_target: Some(Perbill::from_rational(1u32, 200u32)),
+			..Default::default()
+		};
+		assert_ok!(BlockReward::set_issuance_schedule(RuntimeOrigin::root(), schedule.clone()));
+		assert_eq!(BlockReward::annual_inflation(), Perbill::from_rational(1u32, 200u32));
+
+		// Limited by the supply cap
+		let schedule = IssuanceSchedule {
+			supply_cap: Some(total_issuance + total_issuance / 1_000),
+			..schedule
+		};
+		assert_ok!(BlockReward::set_issuance_schedule(RuntimeOrigin::root(), schedule));
+		assert_eq!(BlockReward::annual_inflation(), Perbill::from_rational(1u32, 1_000u32));
+
+		// Nothing is issued once the cap is reached
+		let schedule = IssuanceSchedule {
+			initial_reward: BLOCK_REWARD,
+			supply_cap: Some(total_issuance),
+			..Default::default()
+		};
+		assert_ok!(BlockReward::set_issuance_schedule(RuntimeOrigin::root(), schedule));
+		assert!(BlockReward::annual_inflation().is_zero());
+	})
+}
+
+#[test]
+pub fn beneficiary_is_included_with_additional_block_reward() {
+	frame_support::parameter_types! {
+		StakingBeneficiary: Beneficiary<AccountId> = Beneficiary::ParachainStaking;
+	}
+
+	ExternalityBuilder::build_with_genesis(GenesisConfig {
+		reward_config: three_way_config(10, 20, 20, 50, 40),
+		issuance_schedule: constant_issuance(),
+	})
+	.execute_with(|| {
+		StorageVersion::new(2).put::<BlockReward>();
+
+		migrations::v3::IncludeBeneficiary::<
+			TestRuntime,
+			StakingBeneficiary,
+			frame_support::traits::ConstU128<BLOCK_REWARD>,
+		>::on_runtime_upgrade();
+
+		// Reward is doubled, existing beneficiaries keep receiving the same amounts
+		assert_eq!(BlockReward::current_block_reward(), 2 * BLOCK_REWARD);
+		assert_eq!(BlockReward::issuance_schedule().initial_reward, 2 * BLOCK_REWARD);
+		let reward_config = BlockReward::reward_config();
+		assert!(reward_config.is_consistent());
+		assert_eq!(reward_config.beneficiaries.len(), 4);
+		assert_eq!(reward_config.beneficiaries[0].base_percent, Perbill::from_percent(5));
+		assert_eq!(reward_config.beneficiaries[1].base_percent, Perbill::from_percent(10));
+		assert_eq!(reward_config.beneficiaries[1].adjustable_percent, Perbill::from_percent(25));
+		assert_eq!(reward_config.beneficiaries[2].base_percent, Perbill::from_percent(10));
+		assert_eq!(
+			reward_config.beneficiaries[3],
+			BeneficiaryShare {
+				beneficiary: Beneficiary::ParachainStaking,
+				base_percent: Perbill::from_percent(50),
+				adjustable_percent: Zero::zero(),
+			}
+		);
+		assert_eq!(BlockReward::on_chain_storage_version(), 3);
+
+		// Runs only once
+		migrations::v3::IncludeBeneficiary::<
+			TestRuntime,
+			StakingBeneficiary,
+			frame_support::traits::ConstU128<BLOCK_REWARD>,
+		>::on_runtime_upgrade();
+		assert_eq!(BlockReward::current_block_reward(), 2 * BLOCK_REWARD);
+
+		// Staking pot receives the additional reward
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::free_balance(&STAKING_POT.into_account_truncating()),
+			BLOCK_REWARD
+		);
+	})
+}
+
 /// Represents free balance snapshot at a specific point in time
 #[derive(PartialEq, Eq, Clone, RuntimeDebug)]
 struct FreeBalanceSnapshot {
 	treasury: Balance,
-	collators: Balance,
 	stakers: Balance,
 	dapps: Balance,
 }
@@ -293,9 +651,6 @@ impl FreeBalanceSnapshot {
 			treasury: <TestRuntime as Config>::Currency::free_balance(
 				&TREASURY_POT.into_account_truncating(),
 			),
-			collators: <TestRuntime as Config>::Currency::free_balance(
-				&COLLATOR_POT.into_account_truncating(),
-			),
 			stakers: <TestRuntime as Config>::Currency::free_balance(
 				&STAKERS_POT.into_account_truncating(),
 			),
@@ -307,10 +662,7 @@ impl FreeBalanceSnapshot {
 
 	/// `true` if all free balances equal `Zero`, `false` otherwise
 	fn is_zero(&self) -> bool {
-		self.treasury.is_zero() &&
-			self.collators.is_zero() &&
-			self.stakers.is_zero() &&
-			self.dapps.is_zero()
+		self.treasury.is_zero() && self.stakers.is_zero() && self.dapps.is_zero()
 	}
 
 	/// Asserts that `post_reward_state` is as expected.
@@ -325,7 +677,6 @@ impl FreeBalanceSnapshot {
 			self.stakers + rewards.base_staker_reward + rewards.adjustable_staker_reward,
 			post_reward_state.stakers
 		);
-		assert_eq!(self.collators + rewards.collators_reward, post_reward_state.collators);
 		assert_eq!(self.dapps + rewards.dapps_reward, post_reward_state.dapps);
 	}
 }
@@ -336,7 +687,6 @@ struct Rewards {
 	base_treasury_reward: Balance,
 	base_staker_reward: Balance,
 	dapps_reward: Balance,
-	collators_reward: Balance,
 	adjustable_treasury_reward: Balance,
 	adjustable_staker_reward: Balance,
 }
@@ -347,21 +697,14 @@ impl Rewards {
 	///
 	/// Both current `total_issuance` and `TVL` are used. If these are changed after calling this
 	/// function, they won't be reflected in the struct.
-	fn calculate(reward_config: &RewardDistributionConfig) -> Self {
-		// Calculate `tvl-independent` portions
-		let base_treasury_reward = reward_config.base_treasury_percent * BLOCK_REWARD;
-		let base_staker_reward = reward_config.base_staker_percent * BLOCK_REWARD;
-		let dapps_reward = reward_config.dapps_percent * BLOCK_REWARD;
-		let collators_reward = reward_config.collators_percent * BLOCK_REWARD;
-		let adjustable_reward = reward_config.adjustable_percent * BLOCK_REWARD;
-
+	fn calculate(reward_config: &RewardDistributionConfig<AccountId>) -> Self {
 		// Calculate `tvl-dependent` portions
 		let future_total_issuance =
 			<TestRuntime as Config>::Currency::total_issuance() + BLOCK_REWARD;
 		let tvl = <TestRuntime as Config>::DappsStakingTvlProvider::get();
 		let tvl_percentage = Perbill::from_rational(tvl, future_total_issuance);
 
-		// Calculate factor for adjusting staker reward portion
+		// Calculate factor for adjusting reward portions
 		let factor = if reward_config.ideal_dapps_staking_tvl <= tvl_percentage ||
 			reward_config.ideal_dapps_staking_tvl.is_zero()
 		{
@@ -370,18 +713,61 @@ impl Rewards {
 			tvl_percentage / reward_config.ideal_dapps_staking_tvl
 		};
 
-		// Adjustable reward portions
-		let adjustable_staker_reward = factor * adjustable_reward;
-		let adjustable_treasury_reward = adjustable_reward - adjustable_staker_reward;
-
-		Self {
-			base_treasury_reward,
-			base_staker_reward,
-			dapps_reward,
-			collators_reward,
-			adjustable_treasury_reward,
-			adjustable_staker_reward,
+		let mut rewards = Self {
+			base_treasury_reward: Zero::zero(),
+			base_staker_reward: Zero::zero(),
+			dapps_reward: Zero::zero(),
+			adjustable_treasury_reward: Zero::zero(),
+			adjustable_staker_reward: Zero::zero(),
+		};
+		for share in reward_config.beneficiaries.iter() {
+			let base_reward = share.base_percent * BLOCK_REWARD;
+			let adjustable_reward = share.adjustable_percent * BLOCK_REWARD;
+			let earned_adjustable_reward = factor * adjustable_reward;
+
+			// Adjustable reward which isn't earned goes to the treasury
+			rewards.adjustable_treasury_reward += adjustable_reward - earned_adjustable_reward;
+			match share.beneficiary {
+				Beneficiary::Treasury => {
+					rewards.base_treasury_reward += base_reward;
+					rewards.adjustable_treasury_reward += earned_adjustable_reward;
+				},
+				Beneficiary::DappsStakers => {
+					rewards.base_staker_reward += base_reward;
+					rewards.adjustable_staker_reward += earned_adjustable_reward;
+				},
+				Beneficiary::Dapps =>
+					rewards.dapps_reward += base_reward + earned_adjustable_reward,
+				Beneficiary::Burn | Beneficiary::Account(_) | Beneficiary::ParachainStaking => (),
+			}
 		}
+
+		rewards
+	}
+}
+
+/// Creates a configuration which splits the reward between treasury, stakers and dapps, the
+/// adjustable percentage goes to the stakers.
+fn three_way_config(
+	base_treasury_percent: u32,
+	base_staker_percent: u32,
+	dapps_percent: u32,
+	adjustable_percent: u32,
+	ideal_dapps_staking_tvl: u32,
+) -> RewardDistributionConfig<AccountId> {
+	let share = |beneficiary, base_percent, adjustable_percent| BeneficiaryShare {
+		beneficiary,
+		base_percent: Perbill::from_percent(base_percent),
+		adjustable_percent: Perbill::from_percent(adjustable_percent),
+	};
+
+	RewardDistributionConfig {
+		beneficiaries: BoundedVec::truncate_from(vec![
+			share(Beneficiary::Treasury, base_treasury_percent, 0),
+			share(Beneficiary::DappsStakers, base_staker_percent, adjustable_percent),
+			share(Beneficiary::Dapps, dapps_percent, 0),
+		]),
+		ideal_dapps_staking_tvl: Perbill::from_percent(ideal_dapps_staking_tvl),
 	}
 }
 
@@ -26,29 +26,54 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_block_reward.
 pub trait WeightInfo {
 	fn set_configuration() -> Weight;
+	fn set_issuance_schedule() -> Weight;
 }
 
 /// Weights for pallet_block_reward using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: BlockReward RewardDistributionConfigStorage (r:0 w:1)
-	// Proof: BlockReward RewardDistributionConfigStorage (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	// Proof: BlockReward RewardDistributionConfigStorage (max_values: Some(1), max_size: Some(694), added: 1189, mode: MaxEncodedLen)
 	fn set_configuration() -> Weight {
 		// Minimum execution time: 9_085 nanoseconds.
 		Weight::from_ref_time(9_328_000)
 			.saturating_add(Weight::from_proof_size(0))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: BlockReward CurrentBlockReward (r:0 w:1)
+	// Proof: BlockReward CurrentBlockReward (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: BlockReward NextDecayAt (r:0 w:1)
+	// Proof: BlockReward NextDecayAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: BlockReward IssuanceScheduleStorage (r:0 w:1)
+	// Proof: BlockReward IssuanceScheduleStorage (max_values: Some(1), max_size: Some(46), added: 541, mode: MaxEncodedLen)
+	fn set_issuance_schedule() -> Weight {
+		// Minimum execution time: 10_472 nanoseconds.
+		Weight::from_ref_time(10_841_000)
+			.saturating_add(Weight::from_proof_size(0))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: BlockReward RewardDistributionConfigStorage (r:0 w:1)
-	// Proof: BlockReward RewardDistributionConfigStorage (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	// Proof: BlockReward RewardDistributionConfigStorage (max_values: Some(1), max_size: Some(694), added: 1189, mode: MaxEncodedLen)
 	fn set_configuration() -> Weight {
 		// Minimum execution time: 9_085 nanoseconds.
 		Weight::from_ref_time(9_328_000)
 			.saturating_add(Weight::from_proof_size(0))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: BlockReward CurrentBlockReward (r:0 w:1)
+	// Proof: BlockReward CurrentBlockReward (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: BlockReward NextDecayAt (r:0 w:1)
+	// Proof: BlockReward NextDecayAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: BlockReward IssuanceScheduleStorage (r:0 w:1)
+	// Proof: BlockReward IssuanceScheduleStorage (max_values: Some(1), max_size: Some(46), added: 541, mode: MaxEncodedLen)
+	fn set_issuance_schedule() -> Weight {
+		// Minimum execution time: 10_472 nanoseconds.
+		Weight::from_ref_time(10_841_000)
+			.saturating_add(Weight::from_proof_size(0))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
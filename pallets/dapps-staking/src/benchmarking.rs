@@ -298,6 +298,52 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::RewardDestination(staker, option).into());
 	}
 
+	set_staking_parameter {
+		let old = StakingParameter::MinimumStakingAmount(MinimumStakingAmount::<T>::get());
+		let new = StakingParameter::MinimumStakingAmount(
+			T::MinimumStakingAmount::get() + 1u32.into()
+		);
+	}: _(RawOrigin::Root, new)
+	verify {
+		assert_last_event::<T>(Event::<T>::StakingParameterSet(old, new).into());
+	}
+
+	instant_unstake {
+		initialize::<T>();
+
+		let (_, contract_id) = register_contract::<T>(1)?;
+
+		let staker = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
+		let amount = BalanceOf::<T>::max_value() / 2u32.into();
+
+		DappsStaking::<T>::bond_and_stake(RawOrigin::Signed(staker.clone()).into(), contract_id.clone(), amount)?;
+		InstantUnstakeFee::<T>::put(Perbill::from_percent(10));
+		InstantUnstakeCap::<T>::put(amount);
+		let fee = Perbill::from_percent(10) * amount;
+
+	}: _(RawOrigin::Signed(staker.clone()), contract_id.clone(), amount)
+	verify {
+		assert_last_event::<T>(Event::<T>::InstantUnstake(staker, contract_id, amount, fee).into());
+	}
+
+	rebond_and_stake {
+		initialize::<T>();
+
+		let (_, contract_id) = register_contract::<T>(1)?;
+
+		let staker = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value());
+		let amount = BalanceOf::<T>::max_value() / 2u32.into();
+
+		DappsStaking::<T>::bond_and_stake(RawOrigin::Signed(staker.clone()).into(), contract_id.clone(), amount)?;
+		DappsStaking::<T>::unbond_and_unstake(RawOrigin::Signed(staker.clone()).into(), contract_id.clone(), amount)?;
+
+	}: _(RawOrigin::Signed(staker.clone()), contract_id.clone(), amount)
+	verify {
+		assert_last_event::<T>(Event::<T>::RebondAndStake(staker, contract_id, amount).into());
+	}
+
 }
 
 #[cfg(test)]
@@ -52,6 +52,10 @@
 //! - `unbond_and_unstake` - removes nomination from the contract, starting the unbonding process
 //!   for the unstaked funds
 //! - `withdraw_unbonded` - withdraws all funds that have completed the unbonding period
+//! - `instant_unstake` - removes nomination from the contract and unlocks the unstaked funds right
+//!   away, for a fee (avoids unbonding period)
+//! - `rebond_and_stake` - cancels unbonding of some funds, starting with the latest unlocking
+//!   chunks, and stakes them on a contract again
 //! - `nomination_transfer` - transfer nomination from one contract to another contract (avoids
 //!   unbonding period)
 //! - `claim_staker` - claims staker reward for a single era
@@ -63,6 +67,8 @@
 //!   data)
 //! - `burn_stale_reward` - root-only call to burn unclaimed, stale rewards from unregistered
 //!   contracts
+//! - `set_staking_parameter` - governance call to adjust the minimum staking amount, the unbonding
+//!   period, the era length or the instant unstake fee and cap, within the `Config` bounds
 //!
 //! User is encouraged to refer to specific function implementations for more comprehensive
 //! documentation.
@@ -84,10 +90,11 @@ use parity_scale_codec::{Decode, Encode, HasCompact, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Zero},
-	RuntimeDebug,
+	Perbill, RuntimeDebug,
 };
 use sp_std::{ops::Add, prelude::*};
 
+pub mod migrations;
 pub mod pallet;
 pub mod weights;
 
@@ -216,22 +223,6 @@ pub struct ContractStakeInfo<Balance: HasCompact + MaxEncodedLen> {
 	contract_reward_claimed: bool,
 }
 
-/// Storage value representing the current Dapps staking pallet storage version.
-/// Used by `on_runtime_upgrade` to determine whether a storage migration is needed or not.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub enum Version {
-	V1_0_0,
-	V2_0_0,
-	V3_0_0,
-	V4_0_0,
-}
-
-impl Default for Version {
-	fn default() -> Self {
-		Version::V4_0_0
-	}
-}
-
 /// Used to represent how much was staked in a particular era.
 /// E.g. `{staked: 1000, era: 5}` means that in era `5`, staked amount was 1000.
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -530,6 +521,31 @@ where
 		(Self { unlocking_chunks: matching_chunks }, Self { unlocking_chunks: other_chunks })
 	}
 
+	/// Removes up to `value` from the unlocking chunks, starting with the one that would be
+	/// unlocked last. Chunks which are fully consumed are removed.
+	///
+	/// Returns the amount that was actually removed, which can be less than `value` in case
+	/// there isn't enough balance in the unlocking chunks.
+	fn rebond(&mut self, value: Balance) -> Balance {
+		let mut remaining = value;
+
+		while let Some(last) = self.unlocking_chunks.last_mut() {
+			if remaining.is_zero() {
+				break;
+			}
+
+			if last.amount <= remaining {
+				remaining -= last.amount;
+				self.unlocking_chunks.pop();
+			} else {
+				last.amount -= remaining;
+				remaining = Zero::zero();
+			}
+		}
+
+		value - remaining
+	}
+
 	#[cfg(test)]
 	/// Return clone of the internal vector. Should only be used for testing.
 	fn vec(&self) -> Vec<UnlockingChunk<Balance>> {
@@ -555,6 +571,23 @@ impl Default for RewardDestination {
 	}
 }
 
+/// Dapps staking parameter which can be adjusted by `MonetaryGovernanceOrigin`.
+/// The value of the matching `Config` constant is both its default and its minimum. The instant
+/// unstake parameters have no `Config` constant, both default to zero.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum StakingParameter<Balance, BlockNumber> {
+	/// Minimum amount user must have staked on contract.
+	MinimumStakingAmount(Balance),
+	/// Number of eras that need to pass until unstaked value can be withdrawn.
+	UnbondingPeriod(EraIndex),
+	/// Number of blocks per era, applied from the next era on.
+	BlockPerEra(BlockNumber),
+	/// Fee charged on the value unstaked through `instant_unstake`.
+	InstantUnstakeFee(Perbill),
+	/// Maximum value unstaked through `instant_unstake` per era, by all stakers.
+	InstantUnstakeCap(Balance),
+}
+
 /// Contains information about account's locked & unbonding balances.
 #[derive(Clone, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct AccountLedger<Balance: AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen> {
@@ -577,4 +610,9 @@ impl<Balance: AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen> AccountLedg
 	pub fn reward_destination(&self) -> RewardDestination {
 		self.reward_destination
 	}
+
+	/// Total amount in the unbonding chunks
+	pub fn unbonding_amount(&self) -> Balance {
+		self.unbonding_info.sum()
+	}
 }
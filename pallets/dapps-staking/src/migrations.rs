@@ -0,0 +1,100 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Dapps staking storage migrations.
+
+use super::*;
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+
+/// Migration from the pallet-specific `StorageVersion` value to FRAME's `StorageVersion`.
+pub mod v4 {
+	use super::*;
+
+	/// Storage version representation used by the pallet before it switched to FRAME's
+	/// `StorageVersion`. Kept only to decode the deprecated storage value.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum Version {
+		V1_0_0,
+		V2_0_0,
+		V3_0_0,
+		V4_0_0,
+	}
+
+	#[frame_support::storage_alias]
+	type StorageVersion<T: Config> = StorageValue<Pallet<T>, Version>;
+
+	/// Removes the deprecated `StorageVersion` value and writes the matching FRAME storage
+	/// version instead.
+	pub struct MigrateToFrameStorageVersion<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToFrameStorageVersion<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 4 {
+				log::info!(
+					target: "dapps-staking",
+					"skipping migration to v4, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let deprecated = StorageVersion::<T>::take();
+			if deprecated.map_or(false, |version| version != Version::V4_0_0) {
+				log::warn!(
+					target: "dapps-staking",
+					"deprecated storage version {:?} is not the latest one",
+					deprecated
+				);
+			}
+
+			frame_support::traits::StorageVersion::new(4).put::<Pallet<T>>();
+			log::info!(target: "dapps-staking", "migrated to FRAME storage version 4");
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			if Pallet::<T>::on_chain_storage_version() < 4 {
+				frame_support::ensure!(
+					StorageVersion::<T>::get().map_or(true, |version| version == Version::V4_0_0),
+					"deprecated storage version is older than V4_0_0, run the missing migrations first"
+				);
+			}
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 4,
+				"storage version was not bumped to v4"
+			);
+			frame_support::ensure!(
+				!StorageVersion::<T>::exists(),
+				"deprecated storage version value was not removed"
+			);
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
@@ -20,7 +20,7 @@ use crate::{self as pallet_dapps_staking, weights};
 
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{Currency, OnFinalize, OnInitialize},
+	traits::{Currency, GenesisBuild, OnFinalize, OnInitialize},
 	weights::Weight,
 	PalletId,
 };
@@ -31,6 +31,7 @@ use sp_io::TestExternalities;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, ConstU32, IdentityLookup},
+	Perbill,
 };
 
 pub(crate) type AccountId = u64;
@@ -148,27 +149,49 @@ parameter_types! {
 	pub const MaxUnlockingChunks: u32 = MAX_UNLOCKING_CHUNKS;
 	pub const UnbondingPeriod: EraIndex = UNBONDING_PERIOD;
 	pub const MaxEraStakeValues: u32 = MAX_ERA_STAKE_VALUES;
+	pub const MaxBlockPerEra: BlockNumber = BLOCKS_PER_ERA * 10;
+	pub const MaxMinimumStakingAmount: Balance = MINIMUM_STAKING_AMOUNT * 10;
+	pub const MaxUnbondingPeriod: EraIndex = UNBONDING_PERIOD * 10;
+	pub const MaxInstantUnstakeFee: Perbill = Perbill::from_percent(50);
+	pub const MaxInstantUnstakeCap: Balance = 1_000_000;
 }
 
 impl pallet_dapps_staking::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
 	type BlockPerEra = BlockPerEra;
+	type MaxBlockPerEra = MaxBlockPerEra;
 	type RegisterDeposit = RegisterDeposit;
 	type SmartContract = MockSmartContract<AccountId>;
 	type WeightInfo = weights::SubstrateWeight<TestRuntime>;
 	type MaxNumberOfStakersPerContract = MaxNumberOfStakersPerContract;
 	type MinimumStakingAmount = MinimumStakingAmount;
+	type MaxMinimumStakingAmount = MaxMinimumStakingAmount;
 	type PalletId = DappsStakingPalletId;
 	type MinimumRemainingAmount = MinimumRemainingAmount;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
 	type UnbondingPeriod = UnbondingPeriod;
+	type MaxUnbondingPeriod = MaxUnbondingPeriod;
 	type MaxEraStakeValues = MaxEraStakeValues;
 	type UnregisteredDappRewardRetention = ConstU32<REWARD_RETENTION_PERIOD>;
+	type OnInstantUnstakeFee = ();
+	type MaxInstantUnstakeFee = MaxInstantUnstakeFee;
+	type MaxInstantUnstakeCap = MaxInstantUnstakeCap;
 }
 
 #[derive(
-	PartialEq, Eq, Copy, Clone, Encode, Decode, Debug, scale_info::TypeInfo, MaxEncodedLen,
+	PartialEq,
+	Eq,
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	Debug,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+	serde::Serialize,
+	serde::Deserialize,
 )]
 pub enum MockSmartContract<AccountId> {
 	Evm(sp_core::H160),
@@ -185,6 +208,12 @@ pub struct ExternalityBuilder;
 
 impl ExternalityBuilder {
 	pub fn build() -> TestExternalities {
+		Self::build_with_genesis(Default::default())
+	}
+
+	pub fn build_with_genesis(
+		dapps_staking: pallet_dapps_staking::GenesisConfig<TestRuntime>,
+	) -> TestExternalities {
 		let mut storage =
 			frame_system::GenesisConfig::default().build_storage::<TestRuntime>().unwrap();
 
@@ -209,6 +238,12 @@ impl ExternalityBuilder {
 		.assimilate_storage(&mut storage)
 		.ok();
 
+		<pallet_dapps_staking::GenesisConfig<TestRuntime> as GenesisBuild<TestRuntime>>::assimilate_storage(
+			&dapps_staking,
+			&mut storage,
+		)
+		.unwrap();
+
 		let mut ext = TestExternalities::from(storage);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
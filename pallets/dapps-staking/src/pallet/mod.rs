@@ -25,7 +25,7 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, LockableCurrency,
-		ReservableCurrency, WithdrawReasons,
+		OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
 	weights::Weight,
 	PalletId,
@@ -48,8 +48,12 @@ pub mod pallet {
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(crate) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	// Negative imbalance type of this pallet.
@@ -64,12 +68,20 @@ pub mod pallet {
 			+ ReservableCurrency<Self::AccountId>;
 
 		/// Describes smart contract in the context required by dapps staking.
-		type SmartContract: Default + Parameter + Member + MaxEncodedLen;
+		type SmartContract: Default + Parameter + Member + MaxEncodedLen + MaybeSerializeDeserialize;
+
+		/// The origin allowed to adjust the staking parameters.
+		type MonetaryGovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// Number of blocks per era.
+		/// Default and minimum of the `BlockPerEra` storage value.
 		#[pallet::constant]
 		type BlockPerEra: Get<BlockNumberFor<Self>>;
 
+		/// Maximum of the `BlockPerEra` storage value.
+		#[pallet::constant]
+		type MaxBlockPerEra: Get<BlockNumberFor<Self>>;
+
 		/// Deposit that will be reserved as part of new contract registration.
 		#[pallet::constant]
 		type RegisterDeposit: Get<BalanceOf<Self>>;
@@ -81,9 +93,14 @@ pub mod pallet {
 		/// Minimum amount user must have staked on contract.
 		/// User can stake less if they already have the minimum staking amount staked on that
 		/// particular contract.
+		/// Default and minimum of the `MinimumStakingAmount` storage value.
 		#[pallet::constant]
 		type MinimumStakingAmount: Get<BalanceOf<Self>>;
 
+		/// Maximum of the `MinimumStakingAmount` storage value.
+		#[pallet::constant]
+		type MaxMinimumStakingAmount: Get<BalanceOf<Self>>;
+
 		/// Dapps staking pallet Id
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -101,9 +118,14 @@ pub mod pallet {
 		/// Number of eras that need to pass until unstaked value can be withdrawn.
 		/// Current era is always counted as full era (regardless how much blocks are remaining).
 		/// When set to `0`, it's equal to having no unbonding period.
+		/// Default and minimum of the `UnbondingPeriod` storage value.
 		#[pallet::constant]
 		type UnbondingPeriod: Get<u32>;
 
+		/// Maximum of the `UnbondingPeriod` storage value.
+		#[pallet::constant]
+		type MaxUnbondingPeriod: Get<u32>;
+
 		/// Max number of unique `EraStake` values that can exist for a `(staker, contract)`
 		/// pairing. When stakers claims rewards, they will either keep the number of `EraStake`
 		/// values the same or they will reduce them by one. Stakers cannot add an additional
@@ -125,6 +147,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type UnregisteredDappRewardRetention: Get<u32>;
 
+		/// Handler for the fees charged on instant unstakes, e.g. the treasury.
+		/// Fees are burned if `()` is used.
+		type OnInstantUnstakeFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Maximum of the `InstantUnstakeFee` storage value.
+		#[pallet::constant]
+		type MaxInstantUnstakeFee: Get<Perbill>;
+
+		/// Maximum of the `InstantUnstakeCap` storage value.
+		#[pallet::constant]
+		type MaxInstantUnstakeCap: Get<BalanceOf<Self>>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -166,6 +200,55 @@ pub mod pallet {
 	#[pallet::getter(fn force_era)]
 	pub type ForceEra<T> = StorageValue<_, Forcing, ValueQuery, ForceEraOnEmpty>;
 
+	#[pallet::type_value]
+	pub fn MinimumStakingAmountOnEmpty<T: Config>() -> BalanceOf<T> {
+		T::MinimumStakingAmount::get()
+	}
+
+	/// Minimum amount user must have staked on contract.
+	#[pallet::storage]
+	#[pallet::getter(fn minimum_staking_amount)]
+	pub type MinimumStakingAmount<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery, MinimumStakingAmountOnEmpty<T>>;
+
+	#[pallet::type_value]
+	pub fn UnbondingPeriodOnEmpty<T: Config>() -> EraIndex {
+		T::UnbondingPeriod::get()
+	}
+
+	/// Number of eras that need to pass until unstaked value can be withdrawn.
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding_period)]
+	pub type UnbondingPeriod<T: Config> =
+		StorageValue<_, EraIndex, ValueQuery, UnbondingPeriodOnEmpty<T>>;
+
+	#[pallet::type_value]
+	pub fn BlockPerEraOnEmpty<T: Config>() -> BlockNumberFor<T> {
+		T::BlockPerEra::get()
+	}
+
+	/// Number of blocks per era.
+	#[pallet::storage]
+	#[pallet::getter(fn block_per_era)]
+	pub type BlockPerEra<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery, BlockPerEraOnEmpty<T>>;
+
+	/// Fee charged on the value unstaked through `instant_unstake`.
+	#[pallet::storage]
+	#[pallet::getter(fn instant_unstake_fee)]
+	pub type InstantUnstakeFee<T> = StorageValue<_, Perbill, ValueQuery>;
+
+	/// Maximum value unstaked through `instant_unstake` per era.
+	/// Instant unstaking is disabled as long as it is zero.
+	#[pallet::storage]
+	#[pallet::getter(fn instant_unstake_cap)]
+	pub type InstantUnstakeCap<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Value unstaked through `instant_unstake` in the era, counted against the cap.
+	#[pallet::storage]
+	#[pallet::getter(fn instant_unstaked)]
+	pub type InstantUnstaked<T: Config> = StorageValue<_, (EraIndex, BalanceOf<T>), ValueQuery>;
+
 	/// Stores the block number of when the next era starts
 	#[pallet::storage]
 	#[pallet::whitelist_storage]
@@ -216,10 +299,93 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	/// Stores the current pallet storage version.
-	#[pallet::storage]
-	#[pallet::getter(fn storage_version)]
-	pub(crate) type StorageVersion<T> = StorageValue<_, Version, ValueQuery>;
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Contracts registered at genesis: `(developer, contract)`.
+		/// `RegisterDeposit` is reserved from each developer.
+		pub dapps: Vec<(T::AccountId, T::SmartContract)>,
+		/// Initial stakes on the registered contracts: `(staker, contract, amount)`.
+		pub stakes: Vec<(T::AccountId, T::SmartContract, BalanceOf<T>)>,
+		/// Whether a new era should be forced at the first block.
+		pub force_era: Forcing,
+		/// Whether the pallet starts in maintenance mode.
+		pub pallet_disabled: bool,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				dapps: vec![],
+				stakes: vec![],
+				force_era: Forcing::NotForcing,
+				pallet_disabled: false,
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (developer, contract_id) in &self.dapps {
+				assert!(
+					!RegisteredDevelopers::<T>::contains_key(developer),
+					"Developer can register only one contract."
+				);
+				assert!(
+					!RegisteredDapps::<T>::contains_key(contract_id),
+					"Contract is registered more than once."
+				);
+				T::Currency::reserve(developer, T::RegisterDeposit::get())
+					.expect("Developer does not have enough balance for the register deposit.");
+
+				RegisteredDapps::<T>::insert(contract_id, DAppInfo::new(developer.clone()));
+				RegisteredDevelopers::<T>::insert(developer, contract_id);
+			}
+
+			// Stakes are placed in the genesis era and carried over to the first era when the
+			// first block is initialized.
+			let current_era = Pallet::<T>::current_era();
+			let mut era_info = EraInfo::default();
+			for (staker, contract_id, value) in &self.stakes {
+				assert!(
+					Pallet::<T>::is_active(contract_id),
+					"Stake placed on a contract which isn't registered."
+				);
+				let mut ledger = Pallet::<T>::ledger(staker);
+				assert!(
+					Pallet::<T>::available_staking_balance(staker, &ledger) >= *value,
+					"Staker does not have enough balance to stake."
+				);
+
+				let mut staking_info =
+					Pallet::<T>::contract_stake_info(contract_id, current_era).unwrap_or_default();
+				let mut staker_info = Pallet::<T>::staker_info(staker, contract_id);
+				if let Err(error) = Pallet::<T>::stake_on_contract(
+					&mut staker_info,
+					&mut staking_info,
+					*value,
+					current_era,
+				) {
+					panic!("Invalid genesis stake: {:?}", error);
+				}
+
+				ledger.locked = ledger.locked.saturating_add(*value);
+				era_info.staked = era_info.staked.saturating_add(*value);
+				era_info.locked = era_info.locked.saturating_add(*value);
+
+				Pallet::<T>::update_ledger(staker, ledger);
+				Pallet::<T>::update_staker_info(staker, contract_id, staker_info);
+				ContractEraStake::<T>::insert(contract_id, current_era, staking_info);
+			}
+			if !self.stakes.is_empty() {
+				GeneralEraInfo::<T>::insert(current_era, era_info);
+			}
+
+			ForceEra::<T>::put(self.force_era);
+			PalletDisabled::<T>::put(self.pallet_disabled);
+		}
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
@@ -252,6 +418,19 @@ pub mod pallet {
 		///
 		/// \(developer account, smart contract, era, amount burned\)
 		StaleRewardBurned(T::AccountId, T::SmartContract, EraIndex, BalanceOf<T>),
+		/// Staking parameter has been adjusted.
+		///
+		/// \(old value, new value\)
+		StakingParameterSet(
+			StakingParameter<BalanceOf<T>, BlockNumberFor<T>>,
+			StakingParameter<BalanceOf<T>, BlockNumberFor<T>>,
+		),
+		/// Account has unbonded & unstaked some funds, which are unlocked right away.
+		///
+		/// \(staker account, smart contract, amount unstaked, fee charged\)
+		InstantUnstake(T::AccountId, T::SmartContract, BalanceOf<T>, BalanceOf<T>),
+		/// Account has moved funds from unbonding back to staking on a smart contract.
+		RebondAndStake(T::AccountId, T::SmartContract, BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -304,6 +483,16 @@ pub mod pallet {
 		NotActiveStaker,
 		/// Transfering nomination to the same contract
 		NominationTransferToSameContract,
+		/// Staking parameter can't be set below its `Config` value
+		ParameterBelowMinimum,
+		/// Staking parameter can't be set above its `Config` maximum
+		ParameterAboveMaximum,
+		/// Staking parameter already has this value
+		NoParameterChange,
+		/// Instant unstaking is disabled or its cap for the era is reached
+		InstantUnstakeCapExceeded,
+		/// There are no unbonding funds that can be rebonded.
+		NothingToRebond,
 	}
 
 	#[pallet::hooks]
@@ -324,11 +513,11 @@ pub mod pallet {
 
 			// Value is compared to 1 since genesis block is ignored
 			if now >= next_era_starting_block || force_new_era || previous_era.is_zero() {
-				let blocks_per_era = T::BlockPerEra::get();
+				let blocks_per_era = Self::block_per_era();
 				let next_era = previous_era + 1;
 				CurrentEra::<T>::put(next_era);
 
-				NextEraStartingBlock::<T>::put(now + blocks_per_era);
+				NextEraStartingBlock::<T>::put(now.saturating_add(blocks_per_era));
 
 				let reward = BlockRewardAccumulator::<T>::take();
 				Self::reward_balance_snapshot(previous_era, reward);
@@ -340,11 +529,16 @@ pub mod pallet {
 
 				Self::deposit_event(Event::<T>::NewDappStakingEra(next_era));
 
-				consumed_weight + T::DbWeight::get().reads_writes(5, 3)
+				consumed_weight + T::DbWeight::get().reads_writes(6, 3)
 			} else {
 				T::DbWeight::get().reads(4)
 			}
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -568,7 +762,7 @@ pub mod pallet {
 			let mut ledger = Self::ledger(&staker);
 			ledger.unbonding_info.add(UnlockingChunk {
 				amount: value_to_unstake,
-				unlock_era: current_era + T::UnbondingPeriod::get(),
+				unlock_era: current_era.saturating_add(Self::unbonding_period()),
 			});
 			// This should be done AFTER insertion since it's possible for chunks to merge
 			ensure!(
@@ -974,6 +1168,203 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Used to adjust one of the staking parameters, which can't be set below the value of
+		/// the matching `Config` constant nor above the matching `Max*` constant.
+		///
+		/// A new unbonding period only applies to the funds unbonded from now on and a new era
+		/// length takes effect from the next era on.
+		///
+		/// The dispatch origin must be `MonetaryGovernanceOrigin`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_staking_parameter())]
+		pub fn set_staking_parameter(
+			origin: OriginFor<T>,
+			parameter: StakingParameter<BalanceOf<T>, BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_pallet_enabled()?;
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+
+			let old = match parameter {
+				StakingParameter::MinimumStakingAmount(value) => {
+					ensure!(
+						value >= T::MinimumStakingAmount::get(),
+						Error::<T>::ParameterBelowMinimum
+					);
+					ensure!(
+						value <= T::MaxMinimumStakingAmount::get(),
+						Error::<T>::ParameterAboveMaximum
+					);
+					StakingParameter::MinimumStakingAmount(MinimumStakingAmount::<T>::get())
+				},
+				StakingParameter::UnbondingPeriod(value) => {
+					ensure!(value >= T::UnbondingPeriod::get(), Error::<T>::ParameterBelowMinimum);
+					ensure!(
+						value <= T::MaxUnbondingPeriod::get(),
+						Error::<T>::ParameterAboveMaximum
+					);
+					StakingParameter::UnbondingPeriod(UnbondingPeriod::<T>::get())
+				},
+				StakingParameter::BlockPerEra(value) => {
+					ensure!(value >= T::BlockPerEra::get(), Error::<T>::ParameterBelowMinimum);
+					ensure!(value <= T::MaxBlockPerEra::get(), Error::<T>::ParameterAboveMaximum);
+					StakingParameter::BlockPerEra(BlockPerEra::<T>::get())
+				},
+				StakingParameter::InstantUnstakeFee(value) => {
+					ensure!(
+						value <= T::MaxInstantUnstakeFee::get(),
+						Error::<T>::ParameterAboveMaximum
+					);
+					StakingParameter::InstantUnstakeFee(InstantUnstakeFee::<T>::get())
+				},
+				StakingParameter::InstantUnstakeCap(value) => {
+					ensure!(
+						value <= T::MaxInstantUnstakeCap::get(),
+						Error::<T>::ParameterAboveMaximum
+					);
+					StakingParameter::InstantUnstakeCap(InstantUnstakeCap::<T>::get())
+				},
+			};
+			ensure!(old != parameter, Error::<T>::NoParameterChange);
+
+			match parameter {
+				StakingParameter::MinimumStakingAmount(value) =>
+					MinimumStakingAmount::<T>::put(value),
+				StakingParameter::UnbondingPeriod(value) => UnbondingPeriod::<T>::put(value),
+				StakingParameter::BlockPerEra(value) => BlockPerEra::<T>::put(value),
+				StakingParameter::InstantUnstakeFee(value) => InstantUnstakeFee::<T>::put(value),
+				StakingParameter::InstantUnstakeCap(value) => InstantUnstakeCap::<T>::put(value),
+			}
+
+			Self::deposit_event(Event::<T>::StakingParameterSet(old, parameter));
+			Ok(().into())
+		}
+
+		/// Unbond and unstake some funds, which are unlocked right away instead of going through
+		/// the unbonding period.
+		///
+		/// `InstantUnstakeFee` of the unstaked value is charged to the staker and handed to
+		/// `OnInstantUnstakeFee`. At most `InstantUnstakeCap` can be unstaked this way per era, by
+		/// all stakers.
+		///
+		/// Same rules as for `unbond_and_unstake` apply, entire stake for the contract is unstaked
+		/// if the remaining staked balance would be below minimum staking amount.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::instant_unstake())]
+		pub fn instant_unstake(
+			origin: OriginFor<T>,
+			contract_id: T::SmartContract,
+			#[pallet::compact] value: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_pallet_enabled()?;
+			let staker = ensure_signed(origin)?;
+
+			ensure!(value > Zero::zero(), Error::<T>::UnstakingWithNoValue);
+			ensure!(Self::is_active(&contract_id), Error::<T>::NotOperatedContract,);
+
+			let current_era = Self::current_era();
+			let mut staker_info = Self::staker_info(&staker, &contract_id);
+			let mut contract_stake_info =
+				Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
+
+			let value_to_unstake = Self::unstake_from_contract(
+				&mut staker_info,
+				&mut contract_stake_info,
+				value,
+				current_era,
+			)?;
+
+			let (era, unstaked) = Self::instant_unstaked();
+			let unstaked = if era == current_era { unstaked } else { Zero::zero() };
+			let unstaked = unstaked.saturating_add(value_to_unstake);
+			ensure!(unstaked <= Self::instant_unstake_cap(), Error::<T>::InstantUnstakeCapExceeded);
+
+			// Unlock the unstaked value right away
+			let mut ledger = Self::ledger(&staker);
+			ledger.locked = ledger.locked.saturating_sub(value_to_unstake);
+			Self::update_ledger(&staker, ledger);
+
+			// Update total staked & locked value in era.
+			GeneralEraInfo::<T>::mutate(&current_era, |value| {
+				if let Some(x) = value {
+					x.staked = x.staked.saturating_sub(value_to_unstake);
+					x.locked = x.locked.saturating_sub(value_to_unstake);
+				}
+			});
+			Self::update_staker_info(&staker, &contract_id, staker_info);
+			ContractEraStake::<T>::insert(&contract_id, current_era, contract_stake_info);
+			InstantUnstaked::<T>::put((current_era, unstaked));
+
+			// Charge the fee on the funds that were just unlocked, locks which don't restrict fees,
+			// like vesting, don't prevent it
+			let fee = Self::instant_unstake_fee() * value_to_unstake;
+			let imbalance = T::Currency::withdraw(
+				&staker,
+				fee,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			T::OnInstantUnstakeFee::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::<T>::InstantUnstake(
+				staker,
+				contract_id,
+				value_to_unstake,
+				fee,
+			));
+
+			Ok(().into())
+		}
+
+		/// Stop unbonding some funds and stake them on the contract again.
+		///
+		/// Funds are taken from the unlocking chunks, starting with the one that would be unlocked
+		/// last. If there are fewer unbonding funds than `value`, all of them are rebonded.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the staker's account.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::rebond_and_stake())]
+		pub fn rebond_and_stake(
+			origin: OriginFor<T>,
+			contract_id: T::SmartContract,
+			#[pallet::compact] value: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_pallet_enabled()?;
+			let staker = ensure_signed(origin)?;
+
+			ensure!(value > Zero::zero(), Error::<T>::StakingWithNoValue);
+			ensure!(Self::is_active(&contract_id), Error::<T>::NotOperatedContract);
+
+			let mut ledger = Self::ledger(&staker);
+			let value_to_stake = ledger.unbonding_info.rebond(value);
+			ensure!(value_to_stake > Zero::zero(), Error::<T>::NothingToRebond);
+
+			let current_era = Self::current_era();
+			let mut staking_info =
+				Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
+			let mut staker_info = Self::staker_info(&staker, &contract_id);
+
+			Self::stake_on_contract(
+				&mut staker_info,
+				&mut staking_info,
+				value_to_stake,
+				current_era,
+			)?;
+
+			// Rebonded funds were never unlocked, so only the staked value changes.
+			GeneralEraInfo::<T>::mutate(&current_era, |value| {
+				if let Some(x) = value {
+					x.staked = x.staked.saturating_add(value_to_stake);
+				}
+			});
+
+			Self::update_ledger(&staker, ledger);
+			Self::update_staker_info(&staker, &contract_id, staker_info);
+			ContractEraStake::<T>::insert(&contract_id, current_era, staking_info);
+
+			Self::deposit_event(Event::<T>::RebondAndStake(staker, contract_id, value_to_stake));
+			Ok(().into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1046,7 +1437,7 @@ pub mod pallet {
 				Error::<T>::TooManyEraStakeValues
 			);
 			ensure!(
-				staker_info.latest_staked_value() >= T::MinimumStakingAmount::get(),
+				staker_info.latest_staked_value() >= Self::minimum_staking_amount(),
 				Error::<T>::InsufficientValue,
 			);
 
@@ -1087,7 +1478,7 @@ pub mod pallet {
 
 			// Calculate the value which will be unstaked.
 			let remaining = staked_value.saturating_sub(value);
-			let value_to_unstake = if remaining < T::MinimumStakingAmount::get() {
+			let value_to_unstake = if remaining < Self::minimum_staking_amount() {
 				contract_stake_info.number_of_stakers =
 					contract_stake_info.number_of_stakers.saturating_sub(1);
 				staked_value
@@ -1125,6 +1516,32 @@ pub mod pallet {
 			}
 		}
 
+		/// Checks that the amount locked in every staker's `Ledger` equals the sum of the
+		/// latest stakes in `GeneralStakerInfo` plus the value that is still unbonding.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			for (staker, ledger) in Ledger::<T>::iter() {
+				let staked = GeneralStakerInfo::<T>::iter_prefix_values(&staker)
+					.fold(BalanceOf::<T>::zero(), |acc, staker_info| {
+						acc.saturating_add(staker_info.latest_staked_value())
+					});
+				ensure!(
+					ledger.locked == staked.saturating_add(ledger.unbonding_info.sum()),
+					"ledger locked amount does not match staked and unbonding amounts"
+				);
+			}
+
+			for (staker, _, staker_info) in GeneralStakerInfo::<T>::iter() {
+				ensure!(
+					staker_info.latest_staked_value().is_zero() ||
+						Ledger::<T>::contains_key(&staker),
+					"staker info exists without a ledger"
+				);
+			}
+
+			Ok(())
+		}
+
 		/// Update the ledger for a staker. This will also update the stash lock.
 		/// This lock will lock the entire funds except paying for further transactions.
 		fn update_ledger(staker: &T::AccountId, ledger: AccountLedger<BalanceOf<T>>) {
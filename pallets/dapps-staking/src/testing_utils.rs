@@ -240,7 +240,7 @@ pub(crate) fn assert_unbond_and_unstake(
 
 	// Calculate the expected resulting unbonding amount
 	let remaining_staked = init_state.staker_info.latest_staked_value().saturating_sub(value);
-	let expected_unbond_amount = if remaining_staked < MINIMUM_STAKING_AMOUNT {
+	let expected_unbond_amount = if remaining_staked < DappsStaking::minimum_staking_amount() {
 		init_state.staker_info.latest_staked_value()
 	} else {
 		value
@@ -261,7 +261,7 @@ pub(crate) fn assert_unbond_and_unstake(
 
 	// Fetch the latest unbonding info so we can compare it to initial unbonding info
 	let final_state = MemorySnapshot::all(current_era, &contract_id, staker);
-	let expected_unlock_era = current_era + UNBONDING_PERIOD;
+	let expected_unlock_era = current_era + DappsStaking::unbonding_period();
 	match init_state
 		.ledger
 		.unbonding_info
@@ -284,10 +284,8 @@ pub(crate) fn assert_unbond_and_unstake(
 
 	// Push the unlocking chunk we expect to have at the end and compare two structs
 	let mut unbonding_info = init_state.ledger.unbonding_info.clone();
-	unbonding_info.add(UnlockingChunk {
-		amount: expected_unbond_amount,
-		unlock_era: current_era + UNBONDING_PERIOD,
-	});
+	unbonding_info
+		.add(UnlockingChunk { amount: expected_unbond_amount, unlock_era: expected_unlock_era });
 	assert_eq!(unbonding_info, final_state.ledger.unbonding_info);
 
 	// Ensure that total locked value for staker hasn't been changed.
@@ -320,6 +318,128 @@ pub(crate) fn assert_unbond_and_unstake(
 	assert_eq!(init_state.era_info.locked, final_state.era_info.locked);
 }
 
+/// Used to perform instant unstake with success assertion.
+pub(crate) fn assert_instant_unstake(
+	staker: AccountId,
+	contract_id: &MockSmartContract<AccountId>,
+	value: Balance,
+) {
+	// Get latest staking info
+	let current_era = DappsStaking::current_era();
+	let init_state = MemorySnapshot::all(current_era, &contract_id, staker);
+	let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+
+	// Calculate the expected resulting unstaked amount and fee
+	let remaining_staked = init_state.staker_info.latest_staked_value().saturating_sub(value);
+	let expected_unstake_amount = if remaining_staked < DappsStaking::minimum_staking_amount() {
+		init_state.staker_info.latest_staked_value()
+	} else {
+		value
+	};
+	let remaining_staked = init_state.staker_info.latest_staked_value() - expected_unstake_amount;
+	let expected_fee = DappsStaking::instant_unstake_fee() * expected_unstake_amount;
+
+	// Ensure op is successful and event is emitted
+	assert_ok!(DappsStaking::instant_unstake(
+		RuntimeOrigin::signed(staker),
+		contract_id.clone(),
+		value
+	));
+	System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::InstantUnstake(
+		staker,
+		contract_id.clone(),
+		expected_unstake_amount,
+		expected_fee,
+	)));
+
+	let final_state = MemorySnapshot::all(current_era, &contract_id, staker);
+
+	// Ensure that unstaked value is unlocked right away, without any unbonding chunk
+	assert_eq!(init_state.ledger.unbonding_info, final_state.ledger.unbonding_info);
+	assert_eq!(init_state.ledger.locked - expected_unstake_amount, final_state.ledger.locked);
+	if final_state.ledger.is_empty() {
+		assert!(!Ledger::<TestRuntime>::contains_key(&staker));
+	}
+
+	// Ensure that the fee is charged to the staker and burned
+	assert_eq!(init_state.free_balance - expected_fee, final_state.free_balance);
+	assert_eq!(init_issuance - expected_fee, <TestRuntime as Config>::Currency::total_issuance());
+
+	// Ensure that total staked amount has been decreased for contract and staker
+	assert_eq!(
+		init_state.contract_info.total - expected_unstake_amount,
+		final_state.contract_info.total
+	);
+	assert_eq!(remaining_staked, final_state.staker_info.latest_staked_value());
+
+	// Ensure that the number of stakers is as expected
+	let delta = if remaining_staked > 0 { 0 } else { 1 };
+	assert_eq!(
+		init_state.contract_info.number_of_stakers - delta,
+		final_state.contract_info.number_of_stakers
+	);
+
+	// Ensure that both total staked and locked values have been decreased
+	assert_eq!(init_state.era_info.staked - expected_unstake_amount, final_state.era_info.staked);
+	assert_eq!(init_state.era_info.locked - expected_unstake_amount, final_state.era_info.locked);
+}
+
+/// Used to perform rebond and stake with success and storage assertions.
+pub(crate) fn assert_rebond_and_stake(
+	staker: AccountId,
+	contract_id: &MockSmartContract<AccountId>,
+	value: Balance,
+) {
+	let current_era = DappsStaking::current_era();
+	let init_state = MemorySnapshot::all(current_era, &contract_id, staker);
+
+	// Calculate the expected value that will be rebonded.
+	let rebond_value = init_state.ledger.unbonding_info.sum().min(value);
+
+	// Perform op and verify everything is as expected
+	assert_ok!(DappsStaking::rebond_and_stake(
+		RuntimeOrigin::signed(staker),
+		contract_id.clone(),
+		value,
+	));
+	System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::RebondAndStake(
+		staker,
+		contract_id.clone(),
+		rebond_value,
+	)));
+
+	let final_state = MemorySnapshot::all(current_era, &contract_id, staker);
+
+	// Rebonded value is taken from the latest unlocking chunks
+	let mut unbonding_info = init_state.ledger.unbonding_info.clone();
+	assert_eq!(unbonding_info.rebond(value), rebond_value);
+	assert_eq!(unbonding_info, final_state.ledger.unbonding_info);
+	assert_eq!(
+		init_state.ledger.unbonding_info.sum() - rebond_value,
+		final_state.ledger.unbonding_info.sum()
+	);
+
+	// In case staker hasn't been staking this contract until now
+	if init_state.staker_info.latest_staked_value() == 0 {
+		assert!(GeneralStakerInfo::<TestRuntime>::contains_key(&staker, contract_id));
+		assert_eq!(
+			final_state.contract_info.number_of_stakers,
+			init_state.contract_info.number_of_stakers + 1
+		);
+	}
+
+	// Rebonded funds were locked all along, so only staked values increase
+	assert_eq!(final_state.era_info.staked, init_state.era_info.staked + rebond_value);
+	assert_eq!(final_state.era_info.locked, init_state.era_info.locked);
+	assert_eq!(final_state.contract_info.total, init_state.contract_info.total + rebond_value);
+	assert_eq!(
+		final_state.staker_info.latest_staked_value(),
+		init_state.staker_info.latest_staked_value() + rebond_value
+	);
+	assert_eq!(final_state.ledger.locked, init_state.ledger.locked);
+	assert_eq!(final_state.free_balance, init_state.free_balance);
+}
+
 /// Used to perform start_unbonding with success and storage assertions.
 pub(crate) fn assert_withdraw_unbonded(staker: AccountId) {
 	let current_era = DappsStaking::current_era();
@@ -367,7 +487,11 @@ pub(crate) fn assert_nomination_transfer(
 	// Calculate value which will actually be transfered
 	let init_staked_value = origin_init_state.staker_info.latest_staked_value();
 	let expected_transfer_amount =
-		if init_staked_value - value >= MINIMUM_STAKING_AMOUNT { value } else { init_staked_value };
+		if init_staked_value - value >= DappsStaking::minimum_staking_amount() {
+			value
+		} else {
+			init_staked_value
+		};
 
 	// Ensure op is successful and event is emitted
 	assert_ok!(DappsStaking::nomination_transfer(
@@ -20,7 +20,11 @@ use super::{
 	pallet::pallet::{Error, Event},
 	*,
 };
-use frame_support::{assert_noop, assert_ok, traits::OnInitialize, weights::Weight};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{LockableCurrency, OnInitialize, WithdrawReasons},
+	weights::Weight,
+};
 use mock::{Balances, MockSmartContract, *};
 use sp_core::H160;
 use sp_runtime::{
@@ -1201,6 +1205,280 @@ fn withdraw_unbonded_no_unbonding_period() {
 	})
 }
 
+#[test]
+fn instant_unstake_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker_id = 1;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		assert_register(10, &contract_id);
+		assert_bond_and_stake(staker_id, &contract_id, 300 + MINIMUM_STAKING_AMOUNT);
+
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::InstantUnstakeFee(Perbill::from_percent(10))
+		));
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::InstantUnstakeCap(1000)
+		));
+
+		// Unstake such an amount that exactly minimum staking amount will remain staked.
+		assert_instant_unstake(staker_id, &contract_id, 300);
+
+		// Unstake 1 token and expect that the entire staked amount will be unstaked.
+		assert_instant_unstake(staker_id, &contract_id, 1);
+		assert!(!Ledger::<TestRuntime>::contains_key(&staker_id));
+	})
+}
+
+#[test]
+fn instant_unstake_charges_the_fee_from_vesting_locked_balance() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker_id = 2;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		assert_register(10, &contract_id);
+		assert_bond_and_stake(staker_id, &contract_id, 500);
+
+		// Restrictions of the lock set by pallet vesting on unvested funds
+		Balances::set_lock(
+			*b"vesting ",
+			&staker_id,
+			Balances::free_balance(&staker_id),
+			WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE,
+		);
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::InstantUnstakeFee(Perbill::from_percent(10))
+		));
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::InstantUnstakeCap(1000)
+		));
+
+		assert_instant_unstake(staker_id, &contract_id, 100);
+	})
+}
+
+#[test]
+fn instant_unstake_is_capped_per_era() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let (first_staker_id, second_staker_id) = (1, 2);
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		assert_register(10, &contract_id);
+		assert_bond_and_stake(first_staker_id, &contract_id, 500);
+		assert_bond_and_stake(second_staker_id, &contract_id, 500);
+
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::InstantUnstakeCap(100)
+		));
+
+		// Cap is shared by all stakers
+		assert_instant_unstake(first_staker_id, &contract_id, 60);
+		assert_noop!(
+			DappsStaking::instant_unstake(
+				RuntimeOrigin::signed(second_staker_id),
+				contract_id.clone(),
+				50
+			),
+			Error::<TestRuntime>::InstantUnstakeCapExceeded
+		);
+		assert_instant_unstake(second_staker_id, &contract_id, 40);
+
+		// Cap is renewed with the era
+		advance_to_era(DappsStaking::current_era() + 1);
+		assert_instant_unstake(second_staker_id, &contract_id, 100);
+		assert_eq!(DappsStaking::instant_unstaked(), (DappsStaking::current_era(), 100));
+	})
+}
+
+#[test]
+fn instant_unstake_is_disabled_by_default() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker_id = 1;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		assert_register(10, &contract_id);
+		assert_bond_and_stake(staker_id, &contract_id, 100);
+
+		assert_noop!(
+			DappsStaking::instant_unstake(RuntimeOrigin::signed(staker_id), contract_id, 10),
+			Error::<TestRuntime>::InstantUnstakeCapExceeded
+		);
+	})
+}
+
+#[test]
+fn instant_unstake_is_not_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker_id = 1;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::InstantUnstakeCap(1000)
+		));
+
+		assert_noop!(
+			DappsStaking::instant_unstake(
+				RuntimeOrigin::signed(staker_id),
+				contract_id.clone(),
+				100
+			),
+			Error::<TestRuntime>::NotOperatedContract
+		);
+
+		assert_register(10, &contract_id);
+		assert_noop!(
+			DappsStaking::instant_unstake(RuntimeOrigin::signed(staker_id), contract_id.clone(), 0),
+			Error::<TestRuntime>::UnstakingWithNoValue
+		);
+		assert_noop!(
+			DappsStaking::instant_unstake(
+				RuntimeOrigin::signed(staker_id),
+				contract_id.clone(),
+				100
+			),
+			Error::<TestRuntime>::NotStakedContract
+		);
+
+		assert_bond_and_stake(staker_id, &contract_id, 100);
+		assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), true));
+		assert_noop!(
+			DappsStaking::instant_unstake(RuntimeOrigin::signed(staker_id), contract_id, 100),
+			Error::<TestRuntime>::Disabled
+		);
+	})
+}
+
+#[test]
+fn rebond_and_stake_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker_id = 1;
+		let first_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		let second_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+		assert_register(10, &first_contract_id);
+		assert_register(11, &second_contract_id);
+		assert_bond_and_stake(staker_id, &first_contract_id, 1000);
+
+		// Create two unlocking chunks in different eras
+		assert_unbond_and_unstake(staker_id, &first_contract_id, 100);
+		advance_to_era(DappsStaking::current_era() + 1);
+		assert_unbond_and_unstake(staker_id, &first_contract_id, 200);
+		assert_eq!(2, DappsStaking::ledger(&staker_id).unbonding_info.len());
+
+		// Consume the latest chunk entirely and part of the earlier one
+		assert_rebond_and_stake(staker_id, &first_contract_id, 250);
+		assert_eq!(1, DappsStaking::ledger(&staker_id).unbonding_info.len());
+		assert_eq!(50, DappsStaking::ledger(&staker_id).unbonding_amount());
+
+		// Rebonding more than what's unbonding rebonds everything, onto another contract
+		advance_to_era(DappsStaking::current_era() + 1);
+		assert_rebond_and_stake(staker_id, &second_contract_id, 1000);
+		assert!(DappsStaking::ledger(&staker_id).unbonding_info.is_empty());
+		assert!(DappsStaking::ledger(&staker_id).unbonding_amount().is_zero());
+	})
+}
+
+#[test]
+fn rebond_and_stake_frees_unlocking_chunks() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker_id = 1;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		let unstake_amount = 100;
+		assert_register(10, &contract_id);
+		assert_bond_and_stake(staker_id, &contract_id, 1000);
+
+		for _ in 0..MAX_UNLOCKING_CHUNKS {
+			advance_to_era(DappsStaking::current_era() + 1);
+			assert_unbond_and_unstake(staker_id, &contract_id, unstake_amount);
+		}
+		advance_to_era(DappsStaking::current_era() + 1);
+		assert_noop!(
+			DappsStaking::unbond_and_unstake(
+				RuntimeOrigin::signed(staker_id),
+				contract_id.clone(),
+				unstake_amount
+			),
+			Error::<TestRuntime>::TooManyUnlockingChunks,
+		);
+
+		// Rebonding the latest chunk makes room for a new one
+		assert_rebond_and_stake(staker_id, &contract_id, unstake_amount);
+		assert_eq!(MAX_UNLOCKING_CHUNKS - 1, DappsStaking::ledger(&staker_id).unbonding_info.len());
+		assert_unbond_and_unstake(staker_id, &contract_id, unstake_amount);
+	})
+}
+
+#[test]
+fn rebond_and_stake_is_not_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker_id = 1;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		let other_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+		assert_noop!(
+			DappsStaking::rebond_and_stake(
+				RuntimeOrigin::signed(staker_id),
+				contract_id.clone(),
+				100
+			),
+			Error::<TestRuntime>::NotOperatedContract
+		);
+
+		assert_register(10, &contract_id);
+		assert_register(11, &other_contract_id);
+		assert_noop!(
+			DappsStaking::rebond_and_stake(
+				RuntimeOrigin::signed(staker_id),
+				contract_id.clone(),
+				0
+			),
+			Error::<TestRuntime>::StakingWithNoValue
+		);
+		assert_noop!(
+			DappsStaking::rebond_and_stake(
+				RuntimeOrigin::signed(staker_id),
+				contract_id.clone(),
+				100
+			),
+			Error::<TestRuntime>::NothingToRebond
+		);
+
+		// Rebonded value must still respect the minimum staking amount
+		assert_bond_and_stake(staker_id, &contract_id, 100);
+		assert_unbond_and_unstake(staker_id, &contract_id, 50);
+		assert_noop!(
+			DappsStaking::rebond_and_stake(
+				RuntimeOrigin::signed(staker_id),
+				other_contract_id.clone(),
+				MINIMUM_STAKING_AMOUNT - 1
+			),
+			Error::<TestRuntime>::InsufficientValue
+		);
+
+		assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), true));
+		assert_noop!(
+			DappsStaking::rebond_and_stake(RuntimeOrigin::signed(staker_id), contract_id, 50),
+			Error::<TestRuntime>::Disabled
+		);
+	})
+}
+
 #[test]
 fn nomination_transfer_is_ok() {
 	ExternalityBuilder::build().execute_with(|| {
@@ -2052,6 +2330,183 @@ fn maintenance_mode_no_change() {
 	})
 }
 
+#[test]
+fn set_staking_parameter_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let new = StakingParameter::MinimumStakingAmount(MINIMUM_STAKING_AMOUNT + 5);
+		assert_ok!(DappsStaking::set_staking_parameter(RuntimeOrigin::root(), new));
+		System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::StakingParameterSet(
+			StakingParameter::MinimumStakingAmount(MINIMUM_STAKING_AMOUNT),
+			new,
+		)));
+		assert_eq!(DappsStaking::minimum_staking_amount(), MINIMUM_STAKING_AMOUNT + 5);
+
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::UnbondingPeriod(UNBONDING_PERIOD + 1)
+		));
+		assert_eq!(DappsStaking::unbonding_period(), UNBONDING_PERIOD + 1);
+	})
+}
+
+#[test]
+fn set_staking_parameter_is_not_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::signed(1),
+				StakingParameter::UnbondingPeriod(UNBONDING_PERIOD + 1)
+			),
+			BadOrigin
+		);
+		// Config values are the minimum
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MinimumStakingAmount(MINIMUM_STAKING_AMOUNT - 1)
+			),
+			Error::<TestRuntime>::ParameterBelowMinimum
+		);
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::BlockPerEra(BLOCKS_PER_ERA - 1)
+			),
+			Error::<TestRuntime>::ParameterBelowMinimum
+		);
+		// Nor can they exceed their `Max*` value
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MinimumStakingAmount(MaxMinimumStakingAmount::get() + 1)
+			),
+			Error::<TestRuntime>::ParameterAboveMaximum
+		);
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::UnbondingPeriod(MaxUnbondingPeriod::get() + 1)
+			),
+			Error::<TestRuntime>::ParameterAboveMaximum
+		);
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::BlockPerEra(MaxBlockPerEra::get() + 1)
+			),
+			Error::<TestRuntime>::ParameterAboveMaximum
+		);
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeFee(Perbill::from_percent(51))
+			),
+			Error::<TestRuntime>::ParameterAboveMaximum
+		);
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeCap(MaxInstantUnstakeCap::get() + 1)
+			),
+			Error::<TestRuntime>::ParameterAboveMaximum
+		);
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::UnbondingPeriod(UNBONDING_PERIOD)
+			),
+			Error::<TestRuntime>::NoParameterChange
+		);
+
+		assert_ok!(DappsStaking::maintenance_mode(RuntimeOrigin::root(), true));
+		assert_noop!(
+			DappsStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::UnbondingPeriod(UNBONDING_PERIOD + 1)
+			),
+			Error::<TestRuntime>::Disabled
+		);
+	})
+}
+
+#[test]
+fn raised_minimum_staking_amount_applies_to_new_stakes() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker = 1;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		assert_register(10, &contract_id);
+
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::MinimumStakingAmount(MINIMUM_STAKING_AMOUNT * 2)
+		));
+		assert_noop!(
+			DappsStaking::bond_and_stake(
+				RuntimeOrigin::signed(staker),
+				contract_id.clone(),
+				MINIMUM_STAKING_AMOUNT
+			),
+			Error::<TestRuntime>::InsufficientValue
+		);
+		assert_bond_and_stake(staker, &contract_id, MINIMUM_STAKING_AMOUNT * 2);
+
+		// remaining stake below the new minimum is unstaked as well
+		assert_unbond_and_unstake(staker, &contract_id, MINIMUM_STAKING_AMOUNT);
+		assert!(DappsStaking::staker_info(staker, &contract_id).latest_staked_value().is_zero());
+	})
+}
+
+#[test]
+fn raised_unbonding_period_applies_to_new_unbonding() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker = 1;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+		assert_register(10, &contract_id);
+		assert_bond_and_stake(staker, &contract_id, 100);
+		assert_unbond_and_unstake(staker, &contract_id, 20);
+
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::UnbondingPeriod(UNBONDING_PERIOD + 2)
+		));
+		assert_unbond_and_unstake(staker, &contract_id, 20);
+
+		// only the first chunk can be withdrawn after the former unbonding period
+		advance_to_era(DappsStaking::current_era() + UNBONDING_PERIOD);
+		assert_withdraw_unbonded(staker);
+		assert_eq!(DappsStaking::ledger(&staker).unbonding_info.sum(), 20);
+	})
+}
+
+#[test]
+fn raised_block_per_era_applies_from_next_era() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let next_era_starting_block = DappsStaking::next_era_starting_block();
+		assert_ok!(DappsStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::BlockPerEra(BLOCKS_PER_ERA * 2)
+		));
+		assert_eq!(DappsStaking::next_era_starting_block(), next_era_starting_block);
+
+		advance_to_era(DappsStaking::current_era() + 1);
+		assert_eq!(System::block_number(), next_era_starting_block);
+		assert_eq!(
+			DappsStaking::next_era_starting_block(),
+			next_era_starting_block + BLOCKS_PER_ERA * 2
+		);
+	})
+}
+
 #[test]
 fn dev_stakers_split_util() {
 	let base_stakers_reward = 7 * 11 * 13 * 17;
@@ -2284,3 +2739,119 @@ fn burn_stale_reward_negative_checks() {
 		);
 	})
 }
+
+#[test]
+fn genesis_registers_dapps_and_stakes() {
+	let developer = 1;
+	let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+	let genesis = GenesisConfig::<TestRuntime> {
+		dapps: vec![(developer, contract_id)],
+		stakes: vec![(3, contract_id, 100), (4, contract_id, 50)],
+		..Default::default()
+	};
+
+	ExternalityBuilder::build_with_genesis(genesis).execute_with(|| {
+		assert_eq!(DappsStaking::registered_contract(developer), Some(contract_id));
+		assert_eq!(Balances::reserved_balance(developer), REGISTER_DEPOSIT);
+		assert_eq!(DappsStaking::ledger(3).locked, 100);
+		assert_eq!(DappsStaking::ledger(4).locked, 50);
+
+		initialize_first_block();
+
+		// Genesis stakes are carried over to the first era
+		let current_era = DappsStaking::current_era();
+		let staking_info = DappsStaking::contract_stake_info(&contract_id, current_era).unwrap();
+		assert_eq!(staking_info.total, 150);
+		assert_eq!(staking_info.number_of_stakers, 2);
+		assert_eq!(DappsStaking::general_era_info(current_era).unwrap().staked, 150);
+		assert_ok!(DappsStaking::do_try_state());
+
+		// Genesis stakers behave like any other staker
+		assert_bond_and_stake(3, &contract_id, 20);
+		assert_unbond_and_unstake(4, &contract_id, 50);
+		assert_ok!(DappsStaking::do_try_state());
+	})
+}
+
+#[test]
+#[should_panic(expected = "Stake placed on a contract which isn't registered.")]
+fn genesis_stake_on_unregistered_contract_fails() {
+	let genesis = GenesisConfig::<TestRuntime> {
+		stakes: vec![(3, MockSmartContract::Evm(H160::repeat_byte(0x01)), 100)],
+		..Default::default()
+	};
+
+	ExternalityBuilder::build_with_genesis(genesis);
+}
+
+#[test]
+#[should_panic(expected = "Staker does not have enough balance to stake.")]
+fn genesis_stake_exceeding_balance_fails() {
+	let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+	let genesis = GenesisConfig::<TestRuntime> {
+		dapps: vec![(1, contract_id)],
+		// Account 2 has 800 and must keep the minimum remaining amount free
+		stakes: vec![(2, contract_id, 800)],
+		..Default::default()
+	};
+
+	ExternalityBuilder::build_with_genesis(genesis);
+}
+
+#[test]
+fn migration_to_frame_storage_version_removes_deprecated_value() {
+	ExternalityBuilder::build().execute_with(|| {
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+		// Simulate the deprecated, pallet specific storage version value.
+		let deprecated_key =
+			frame_support::storage::storage_prefix(b"DappsStaking", b"StorageVersion");
+		frame_support::storage::unhashed::put(&deprecated_key, &3u8);
+		StorageVersion::new(0).put::<DappsStaking>();
+
+		migrations::v4::MigrateToFrameStorageVersion::<TestRuntime>::on_runtime_upgrade();
+
+		assert!(!frame_support::storage::unhashed::exists(&deprecated_key));
+		assert_eq!(DappsStaking::on_chain_storage_version(), 4);
+	})
+}
+
+#[test]
+fn try_state_holds_for_staking_operations() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let developer = 1;
+		let staker = 3;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+		assert_register(developer, &contract_id);
+		assert_bond_and_stake(staker, &contract_id, 100);
+		assert_unbond_and_unstake(staker, &contract_id, 30);
+		assert_ok!(DappsStaking::do_try_state());
+
+		advance_to_era(DappsStaking::current_era() + UNBONDING_PERIOD + 1);
+		assert_claim_staker(staker, &contract_id);
+		assert_withdraw_unbonded(staker);
+		assert_ok!(DappsStaking::do_try_state());
+	})
+}
+
+#[test]
+fn try_state_detects_ledger_mismatch() {
+	ExternalityBuilder::build().execute_with(|| {
+		initialize_first_block();
+
+		let staker = 3;
+		let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+		assert_register(1, &contract_id);
+		assert_bond_and_stake(staker, &contract_id, 100);
+		Ledger::<TestRuntime>::mutate(staker, |ledger| ledger.locked += 1);
+
+		assert_eq!(
+			DappsStaking::do_try_state(),
+			Err("ledger locked amount does not match staked and unbonding amounts")
+		);
+	})
+}
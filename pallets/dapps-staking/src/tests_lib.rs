@@ -72,6 +72,40 @@ fn unbonding_info_test() {
 	assert_eq!(unbonding_info.sum(), first_info.sum() + second_info.sum());
 }
 
+#[test]
+fn unbonding_info_rebond() {
+	let mut unbonding_info = UnbondingInfo::<Balance>::default();
+
+	// Rebond on empty info does nothing
+	assert!(unbonding_info.rebond(100).is_zero());
+	assert!(unbonding_info.is_empty());
+
+	let chunks = vec![
+		UnlockingChunk { amount: 100, unlock_era: 3 },
+		UnlockingChunk { amount: 200, unlock_era: 5 },
+		UnlockingChunk { amount: 300, unlock_era: 7 },
+	];
+	for chunk in chunks.iter() {
+		unbonding_info.add(*chunk);
+	}
+
+	// Partially consume the latest chunk
+	assert_eq!(unbonding_info.rebond(50), 50);
+	assert_eq!(unbonding_info.len(), 3);
+	assert_eq!(unbonding_info.vec()[2], UnlockingChunk { amount: 250, unlock_era: 7 });
+
+	// Consume the rest of the latest chunk and part of the next one
+	assert_eq!(unbonding_info.rebond(300), 300);
+	assert_eq!(
+		unbonding_info.vec(),
+		vec![chunks[0], UnlockingChunk { amount: 150, unlock_era: 5 }]
+	);
+
+	// Rebond more than available consumes everything
+	assert_eq!(unbonding_info.rebond(1000), 250);
+	assert!(unbonding_info.is_empty());
+}
+
 #[test]
 fn staker_info_basic() {
 	let staker_info = StakerInfo::<Balance>::default();
@@ -45,6 +45,9 @@ pub trait WeightInfo {
 	fn force_new_era() -> Weight;
 	fn maintenance_mode() -> Weight;
 	fn set_reward_destination() -> Weight;
+	fn set_staking_parameter() -> Weight;
+	fn instant_unstake() -> Weight;
+	fn rebond_and_stake() -> Weight;
 }
 
 /// Weights for pallet_dapps_staking using the Substrate node and recommended hardware.
@@ -225,6 +228,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: DappsStaking MinimumStakingAmount (r:1 w:1)
+	// Proof: DappsStaking MinimumStakingAmount (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_staking_parameter() -> Weight {
+		// Minimum execution time: 10_412 nanoseconds.
+		Weight::from_ref_time(10_803_000)
+			.saturating_add(Weight::from_proof_size(511))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Proof: DappsStaking RegisteredDapps (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Proof: DappsStaking GeneralStakerInfo (max_values: None, max_size: Some(298), added: 2773, mode: MaxEncodedLen)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Proof: DappsStaking ContractEraStake (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	// Storage: DappsStaking InstantUnstaked (r:1 w:1)
+	// Proof: DappsStaking InstantUnstaked (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	// Storage: DappsStaking InstantUnstakeCap (r:1 w:0)
+	// Proof: DappsStaking InstantUnstakeCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Proof: DappsStaking Ledger (max_values: None, max_size: Some(266), added: 2741, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Proof: DappsStaking GeneralEraInfo (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: DappsStaking InstantUnstakeFee (r:1 w:0)
+	// Proof: DappsStaking InstantUnstakeFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn instant_unstake() -> Weight {
+		// Minimum execution time: 67_912 nanoseconds.
+		Weight::from_ref_time(68_924_000)
+			.saturating_add(Weight::from_proof_size(23688))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Proof: DappsStaking RegisteredDapps (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Proof: DappsStaking Ledger (max_values: None, max_size: Some(266), added: 2741, mode: MaxEncodedLen)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Proof: DappsStaking ContractEraStake (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Proof: DappsStaking GeneralStakerInfo (max_values: None, max_size: Some(298), added: 2773, mode: MaxEncodedLen)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Proof: DappsStaking GeneralEraInfo (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	fn rebond_and_stake() -> Weight {
+		// Minimum execution time: 44_318 nanoseconds.
+		Weight::from_ref_time(45_127_000)
+			.saturating_add(Weight::from_proof_size(16957))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -404,4 +462,59 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: DappsStaking MinimumStakingAmount (r:1 w:1)
+	// Proof: DappsStaking MinimumStakingAmount (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_staking_parameter() -> Weight {
+		// Minimum execution time: 10_412 nanoseconds.
+		Weight::from_ref_time(10_803_000)
+			.saturating_add(Weight::from_proof_size(511))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Proof: DappsStaking RegisteredDapps (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Proof: DappsStaking GeneralStakerInfo (max_values: None, max_size: Some(298), added: 2773, mode: MaxEncodedLen)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Proof: DappsStaking ContractEraStake (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	// Storage: DappsStaking InstantUnstaked (r:1 w:1)
+	// Proof: DappsStaking InstantUnstaked (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	// Storage: DappsStaking InstantUnstakeCap (r:1 w:0)
+	// Proof: DappsStaking InstantUnstakeCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Proof: DappsStaking Ledger (max_values: None, max_size: Some(266), added: 2741, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Proof: DappsStaking GeneralEraInfo (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: DappsStaking InstantUnstakeFee (r:1 w:0)
+	// Proof: DappsStaking InstantUnstakeFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn instant_unstake() -> Weight {
+		// Minimum execution time: 67_912 nanoseconds.
+		Weight::from_ref_time(68_924_000)
+			.saturating_add(Weight::from_proof_size(23688))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Proof: DappsStaking RegisteredDapps (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	// Storage: DappsStaking Ledger (r:1 w:1)
+	// Proof: DappsStaking Ledger (max_values: None, max_size: Some(266), added: 2741, mode: MaxEncodedLen)
+	// Storage: DappsStaking ContractEraStake (r:1 w:1)
+	// Proof: DappsStaking ContractEraStake (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
+	// Proof: DappsStaking GeneralStakerInfo (max_values: None, max_size: Some(298), added: 2773, mode: MaxEncodedLen)
+	// Storage: DappsStaking GeneralEraInfo (r:1 w:1)
+	// Proof: DappsStaking GeneralEraInfo (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	fn rebond_and_stake() -> Weight {
+		// Minimum execution time: 44_318 nanoseconds.
+		Weight::from_ref_time(45_127_000)
+			.saturating_add(Weight::from_proof_size(16957))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the parachain staking pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_parachain_staking::{
+	CandidateSummary, DelegationAction, DelegationSummary, DelegatorSummary,
+	PendingDelegationRequest,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Provides an overview of the collator candidates and of the delegations of a delegator.
+	pub trait ParachainStakingApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// All the collator candidates with their stake, commission, last round points and
+		/// estimated APR, sorted by total counted stake.
+		fn candidates() -> Vec<CandidateSummary<AccountId, Balance>>;
+
+		/// Delegations of the delegator with their pending requests and expected rewards for
+		/// the next round, `None` if the account isn't delegating.
+		fn delegator_summary(delegator: AccountId) -> Option<DelegatorSummary<AccountId, Balance>>;
+	}
+}
@@ -0,0 +1,124 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the parachain staking pallet.
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeSerializeDeserialize};
+use std::{marker::PhantomData, sync::Arc};
+
+pub use pallet_parachain_staking_rpc_runtime_api::{
+	CandidateSummary, DelegatorSummary, ParachainStakingApi as ParachainStakingRuntimeApi,
+};
+
+#[rpc(server)]
+pub trait ParachainStakingApi<BlockHash, AccountId, Balance> {
+	/// All the collator candidates with their stake, commission, last round points and
+	/// estimated APR at the given block, or the best block if omitted.
+	#[method(name = "parachainStaking_candidates")]
+	fn candidates(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CandidateSummary<AccountId, Balance>>>;
+
+	/// Delegations of the delegator with their pending requests and expected rewards at the
+	/// given block, or the best block if omitted.
+	#[method(name = "parachainStaking_delegatorSummary")]
+	fn delegator_summary(
+		&self,
+		delegator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<DelegatorSummary<AccountId, Balance>>>;
+}
+
+/// Provides RPC methods to query the collator candidates and delegators.
+pub struct ParachainStaking<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> ParachainStaking<C, Block> {
+	/// Creates a new instance of the ParachainStaking RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance>
+	ParachainStakingApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for ParachainStaking<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ParachainStakingRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + MaybeSerializeDeserialize + Send + Sync + 'static,
+	Balance: Codec + MaybeSerializeDeserialize + Send + Sync + 'static,
+{
+	fn candidates(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CandidateSummary<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.candidates(at).map_err(|e| runtime_error("Unable to query candidates.", e))
+	}
+
+	fn delegator_summary(
+		&self,
+		delegator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<DelegatorSummary<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.delegator_summary(at, delegator)
+			.map_err(|e| runtime_error("Unable to query delegator summary.", e))
+	}
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error(message: &str, error: impl ToString) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(error.to_string()),
+	))
+	.into()
+}
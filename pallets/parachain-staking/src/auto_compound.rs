@@ -141,7 +141,7 @@ where
 
 		let mut delegator_state = if let Some(mut state) = <DelegatorState<T>>::get(&delegator) {
 			// delegation after first
-			ensure!(amount >= T::MinDelegation::get(), Error::<T>::DelegationBelowMin);
+			ensure!(amount >= <Pallet<T>>::min_delegation(), Error::<T>::DelegationBelowMin);
 			ensure!(
 				delegation_count_hint >= state.delegations.0.len() as u32,
 				Error::<T>::TooLowDelegationCountToDelegate
@@ -157,7 +157,7 @@ where
 			state
 		} else {
 			// first delegation
-			ensure!(amount >= T::MinDelegatorStk::get(), Error::<T>::DelegatorBondBelowMin);
+			ensure!(amount >= <Pallet<T>>::min_delegator_stk(), Error::<T>::DelegatorBondBelowMin);
 			ensure!(!<Pallet<T>>::is_candidate(&delegator), Error::<T>::CandidateExists);
 			Delegator::new(delegator.clone(), candidate.clone(), amount)
 		};
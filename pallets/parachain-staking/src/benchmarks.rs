@@ -22,7 +22,7 @@
 use crate::{
 	AwardedPts, BalanceOf, Call, CandidateBondLessRequest, Config, DelegationAction, Pallet,
 	ParachainBondConfig, ParachainBondInfo, Points, Range, RewardPayment, Round, ScheduledRequest,
-	Staked, TopDelegations,
+	Staked, StakingParameter, TopDelegations,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, vec};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
@@ -32,12 +32,12 @@ use sp_std::vec::Vec;
 
 /// Minimum collator candidate stake
 fn min_candidate_stk<T: Config>() -> BalanceOf<T> {
-	<<T as Config>::MinCollatorStk as Get<BalanceOf<T>>>::get()
+	Pallet::<T>::min_collator_stk()
 }
 
 /// Minimum delegator stake
 fn min_delegator_stk<T: Config>() -> BalanceOf<T> {
-	<<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get()
+	Pallet::<T>::min_delegator_stk()
 }
 
 /// Create a funded user.
@@ -172,6 +172,13 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::parachain_bond_info().percent, Percent::from_percent(33));
 	}
 
+	set_staking_parameter {
+		let min_delegation = Pallet::<T>::min_delegation() + 1u32.into();
+	}: _(RawOrigin::Root, StakingParameter::MinDelegation(min_delegation))
+	verify {
+		assert_eq!(Pallet::<T>::min_delegation(), min_delegation);
+	}
+
 	// ROOT DISPATCHABLES
 
 	set_total_selected {
@@ -195,7 +202,8 @@ benchmarks! {
 
 	join_candidates {
 		let x in 3..1_000;
-		// Worst Case Complexity is insertion into an ordered list so \exists full list before call
+		// The pool is filled with x candidates to measure whether the candidate bags depend on
+		// their number
 		let mut candidate_count = 1u32;
 		for i in 2..x {
 			let seed = USER_SEED - i;
@@ -218,7 +226,8 @@ benchmarks! {
 	// -> it retains the self-bond and delegator bonds
 	schedule_leave_candidates {
 		let x in 3..1_000;
-		// Worst Case Complexity is removal from an ordered list so \exists full list before call
+		// The pool is filled with x candidates to measure whether the candidate bags depend on
+		// their number
 		let mut candidate_count = 1u32;
 		for i in 2..x {
 			let seed = USER_SEED - i;
@@ -305,7 +314,8 @@ benchmarks! {
 
 	cancel_leave_candidates {
 		let x in 3..1_000;
-		// Worst Case Complexity is removal from an ordered list so \exists full list before call
+		// The pool is filled with x candidates to measure whether the candidate bags depend on
+		// their number
 		let mut candidate_count = 1u32;
 		for i in 2..x {
 			let seed = USER_SEED - i;
@@ -1249,6 +1259,157 @@ benchmarks! {
 		);
 	}
 
+	transfer_delegation {
+		// x controls number of distinct delegations the target collator will have
+		// y controls number of distinct auto-compounding delegations the target collator will have
+		// z controls number of distinct delegations the source collator will have
+		// w controls number of distinct auto-compounding delegations the source collator will have
+		let x in 0..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+		+ <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get());
+		let y in 0..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+		+ <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get());
+		let z in 1..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+		+ <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get());
+		let w in 1..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+		+ <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get());
+
+		use crate::auto_compound::AutoCompoundDelegations;
+
+		let min_candidate_stake = min_candidate_stk::<T>();
+		let min_delegator_stake = min_delegator_stk::<T>();
+		let amount = min_delegator_stake * 2u32.into();
+		let mut seed = Seed::new();
+
+		let from_candidate = create_funded_collator::<T>(
+			"collator",
+			seed.take(),
+			min_candidate_stake,
+			true,
+			1,
+		)?;
+		let to_candidate = create_funded_collator::<T>(
+			"collator",
+			seed.take(),
+			min_candidate_stake,
+			true,
+			2,
+		)?;
+
+		// the prime delegator has an auto-compounding delegation to the source collator, above
+		// all the others
+		let (prime_delegator, _) = create_funded_user::<T>(
+			"delegator",
+			seed.take(),
+			amount,
+		);
+		Pallet::<T>::delegate_with_auto_compound(
+			RawOrigin::Signed(prime_delegator.clone()).into(),
+			from_candidate.clone(),
+			amount,
+			Percent::from_percent(50),
+			0,
+			0,
+			0,
+		)?;
+
+		// have z-1 more distinct delegators delegate to the source collator, w in total are
+		// auto-compounding
+		for i in 1..z {
+			let delegator = create_funded_delegator::<T>(
+				"delegator",
+				seed.take(),
+				min_delegator_stake,
+				from_candidate.clone(),
+				true,
+				i,
+			)?;
+			if i < w {
+				Pallet::<T>::set_auto_compound(
+					RawOrigin::Signed(delegator.clone()).into(),
+					from_candidate.clone(),
+					Percent::from_percent(100),
+					i,
+					1,
+				)?;
+			}
+		}
+
+		// have x distinct delegators delegate to the target collator, of which y are
+		// auto-compounding. With all of top and bottom delegations filled, the transferred
+		// delegation enters the top and the lowest bottom delegation is kicked
+		for i in 0..x {
+			let delegator = create_funded_delegator::<T>(
+				"delegator",
+				seed.take(),
+				min_delegator_stake,
+				to_candidate.clone(),
+				true,
+				i,
+			)?;
+			if i < y {
+				Pallet::<T>::set_auto_compound(
+					RawOrigin::Signed(delegator.clone()).into(),
+					to_candidate.clone(),
+					Percent::from_percent(100),
+					i,
+					1,
+				)?;
+			}
+		}
+	}: _(
+		RawOrigin::Signed(prime_delegator.clone()),
+		from_candidate.clone(),
+		to_candidate.clone(),
+		amount,
+		x,
+		y,
+		z,
+		w
+	)
+	verify {
+		let state = Pallet::<T>::delegator_state(&prime_delegator).expect("just delegated => exists");
+		assert_eq!(state.get_bond_amount(&from_candidate), None);
+		assert_eq!(state.get_bond_amount(&to_candidate), Some(amount));
+		assert_eq!(
+			<AutoCompoundDelegations<T>>::get_storage(&to_candidate)
+				.get_for_delegator(&prime_delegator),
+			Some(Percent::from_percent(50)),
+		);
+	}
+
+	instant_unstake {
+		let collator: T::AccountId = create_funded_collator::<T>(
+			"collator",
+			USER_SEED,
+			0u32.into(),
+			true,
+			1u32
+		)?;
+		let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+		let bond = min_delegator_stk::<T>();
+		Pallet::<T>::delegate_with_auto_compound(
+			RawOrigin::Signed(caller.clone()).into(),
+			collator.clone(),
+			bond,
+			Percent::from_percent(50),
+			0,
+			0,
+			0,
+		)?;
+		Pallet::<T>::set_staking_parameter(
+			RawOrigin::Root.into(),
+			StakingParameter::InstantUnstakeFee(Perbill::from_percent(10)),
+		)?;
+		Pallet::<T>::set_staking_parameter(
+			RawOrigin::Root.into(),
+			StakingParameter::InstantUnstakeCap(bond),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), collator.clone(), bond)
+	verify {
+		assert!(!Pallet::<T>::is_delegator(&caller));
+		assert_eq!(Pallet::<T>::instant_unstaked(), (Pallet::<T>::round().current, bond));
+	}
+
 	mint_collator_reward {
 		let mut seed = Seed::new();
 		let collator = create_funded_collator::<T>(
@@ -1306,6 +1467,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_set_staking_parameter() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_staking_parameter());
+		});
+	}
+
 	#[test]
 	fn bench_set_total_selected() {
 		new_test_ext().execute_with(|| {
@@ -1474,6 +1642,20 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_transfer_delegation() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_transfer_delegation());
+		});
+	}
+
+	#[test]
+	fn bench_instant_unstake() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_instant_unstake());
+		});
+	}
+
 	#[test]
 	fn bench_base_on_initialize() {
 		new_test_ext().execute_with(|| {
@@ -0,0 +1,273 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Bags-list of the active collator candidates, sorted by total counted stake.
+//!
+//! Candidates are held in bags bounded by `Config::CandidateBagThresholds`. A candidate is in the
+//! bag of the smallest threshold greater than or equal to its stake, or in the last bag if its
+//! stake is above all thresholds, so every candidate of a bag has more stake than the candidates
+//! of the lower bags. Within a bag, candidates are kept in insertion order.
+//!
+//! Inserting, removing or updating a candidate only touches the candidate, its neighbours and
+//! two bags, whatever the number of candidates. The top candidates are found by reading the bags
+//! from the highest one until enough candidates are collected. Every candidate of these bags is
+//! read, so the thresholds should keep the bags of the top candidates small: with one crowded bag,
+//! selecting the top candidates reads almost the whole pool.
+
+use crate::{
+	pallet::{BalanceOf, CandidateBags, CandidateNodes, CandidatePoolCount, Config},
+	types::Bond,
+};
+use frame_support::{traits::Get, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Bounded;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// A bag of candidates, as the ends of a doubly linked list of [CandidateNode].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateBag<AccountId> {
+	/// First candidate of the bag
+	pub head: Option<AccountId>,
+	/// Last candidate of the bag
+	pub tail: Option<AccountId>,
+}
+
+/// A candidate of the pool and its neighbours within its bag.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateNode<AccountId, Balance> {
+	/// Account of the candidate
+	pub id: AccountId,
+	/// Previous candidate of the bag
+	pub prev: Option<AccountId>,
+	/// Next candidate of the bag
+	pub next: Option<AccountId>,
+	/// Upper threshold of the bag holding the candidate
+	pub bag_upper: Balance,
+	/// Total counted stake of the candidate
+	pub score: Balance,
+}
+
+/// The pool of active collator candidates, stored as a bags-list.
+pub struct CandidatePool<T>(PhantomData<T>);
+
+impl<T: Config> CandidatePool<T> {
+	/// Number of candidates in the pool.
+	pub fn count() -> u32 {
+		<CandidatePoolCount<T>>::get()
+	}
+
+	/// Returns whether the candidate is in the pool.
+	pub fn contains(candidate: &T::AccountId) -> bool {
+		<CandidateNodes<T>>::contains_key(candidate)
+	}
+
+	/// Returns the stake of the candidate as seen by the pool.
+	pub fn score(candidate: &T::AccountId) -> Option<BalanceOf<T>> {
+		<CandidateNodes<T>>::get(candidate).map(|node| node.score)
+	}
+
+	/// Upper threshold of the bag holding the candidates with the given stake.
+	pub fn notional_bag_for(score: BalanceOf<T>) -> BalanceOf<T> {
+		let thresholds = T::CandidateBagThresholds::get();
+		let index = thresholds.partition_point(|threshold| *threshold < score);
+		thresholds.get(index).copied().unwrap_or_else(Bounded::max_value)
+	}
+
+	/// Inserts a candidate at the end of its bag. Returns false if it is already in the pool.
+	pub fn insert(candidate: T::AccountId, score: BalanceOf<T>) -> bool {
+		if Self::contains(&candidate) {
+			return false;
+		}
+		Self::put_in_bag(candidate, score);
+		<CandidatePoolCount<T>>::mutate(|count| *count = count.saturating_add(1));
+		true
+	}
+
+	/// Removes a candidate from the pool. Returns false if it isn't in the pool.
+	pub fn remove(candidate: &T::AccountId) -> bool {
+		match <CandidateNodes<T>>::take(candidate) {
+			Some(node) => {
+				Self::take_from_bag(&node);
+				<CandidatePoolCount<T>>::mutate(|count| *count = count.saturating_sub(1));
+				true
+			},
+			None => false,
+		}
+	}
+
+	/// Updates the stake of a candidate, moving it to the end of another bag if its stake
+	/// crossed a threshold. The candidate is inserted if it isn't in the pool.
+	pub fn update(candidate: T::AccountId, score: BalanceOf<T>) {
+		match <CandidateNodes<T>>::get(&candidate) {
+			Some(mut node) if node.bag_upper == Self::notional_bag_for(score) => {
+				node.score = score;
+				<CandidateNodes<T>>::insert(&candidate, node);
+			},
+			Some(node) => {
+				Self::take_from_bag(&node);
+				Self::put_in_bag(candidate, score);
+			},
+			None => {
+				Self::insert(candidate, score);
+			},
+		}
+	}
+
+	/// All the candidates of the pool, from the highest bag to the lowest one. Candidates are
+	/// only sorted across bags.
+	pub fn iter() -> impl Iterator<Item = Bond<T::AccountId, BalanceOf<T>>> {
+		Self::bag_uppers()
+			.into_iter()
+			.filter_map(<CandidateBags<T>>::get)
+			.flat_map(Self::iter_bag)
+	}
+
+	/// The `n` candidates with the most stake, greatest first. Ties are ordered by account,
+	/// greatest first. Only the bags holding these candidates are read, but all of their
+	/// candidates are, see [`Self::max_top_reads`].
+	pub fn top(n: usize) -> Vec<Bond<T::AccountId, BalanceOf<T>>> {
+		let mut top = Vec::new();
+		for bag_upper in Self::bag_uppers() {
+			if top.len() >= n {
+				break;
+			}
+			if let Some(bag) = <CandidateBags<T>>::get(bag_upper) {
+				top.extend(Self::iter_bag(bag));
+			}
+		}
+		// the last bag read may hold more candidates than needed, in any order
+		top.sort_by(|a, b| a.amount.cmp(&b.amount).then_with(|| a.owner.cmp(&b.owner)).reverse());
+		top.truncate(n);
+		top
+	}
+
+	/// Upper bound of the storage reads made by [`Self::top`], reached when the candidates are
+	/// read down to the lowest bag: every bag and every candidate node.
+	pub fn max_top_reads() -> u64 {
+		(T::CandidateBagThresholds::get().len() as u64)
+			.saturating_add(1)
+			.saturating_add(Self::count() as u64)
+	}
+
+	/// Upper thresholds of all the bags, highest first.
+	fn bag_uppers() -> Vec<BalanceOf<T>> {
+		let thresholds = T::CandidateBagThresholds::get();
+		let max = BalanceOf::<T>::max_value();
+		let mut uppers = Vec::with_capacity(thresholds.len().saturating_add(1));
+		if thresholds.last() != Some(&max) {
+			uppers.push(max);
+		}
+		uppers.extend(thresholds.iter().rev().copied());
+		uppers
+	}
+
+	/// Candidates of a bag, in insertion order.
+	fn iter_bag(
+		bag: CandidateBag<T::AccountId>,
+	) -> impl Iterator<Item = Bond<T::AccountId, BalanceOf<T>>> {
+		sp_std::iter::successors(bag.head.and_then(<CandidateNodes<T>>::get), |node| {
+			node.next.as_ref().and_then(<CandidateNodes<T>>::get)
+		})
+		.map(|node| Bond { owner: node.id, amount: node.score })
+	}
+
+	/// Appends the candidate to its bag and stores its node.
+	fn put_in_bag(candidate: T::AccountId, score: BalanceOf<T>) {
+		let bag_upper = Self::notional_bag_for(score);
+		let mut bag =
+			<CandidateBags<T>>::get(bag_upper).unwrap_or(CandidateBag { head: None, tail: None });
+		let prev = bag.tail.replace(candidate.clone());
+		match &prev {
+			Some(tail) => <CandidateNodes<T>>::mutate(tail, |node| {
+				if let Some(node) = node {
+					node.next = Some(candidate.clone());
+				}
+			}),
+			None => bag.head = Some(candidate.clone()),
+		}
+		<CandidateBags<T>>::insert(bag_upper, bag);
+		<CandidateNodes<T>>::insert(
+			&candidate,
+			CandidateNode { id: candidate.clone(), prev, next: None, bag_upper, score },
+		);
+	}
+
+	/// Unlinks the node from its neighbours and its bag, the node itself is left untouched.
+	fn take_from_bag(node: &CandidateNode<T::AccountId, BalanceOf<T>>) {
+		if let Some(prev) = &node.prev {
+			<CandidateNodes<T>>::mutate(prev, |prev| {
+				if let Some(prev) = prev {
+					prev.next = node.next.clone();
+				}
+			});
+		}
+		if let Some(next) = &node.next {
+			<CandidateNodes<T>>::mutate(next, |next| {
+				if let Some(next) = next {
+					next.prev = node.prev.clone();
+				}
+			});
+		}
+		if let Some(mut bag) = <CandidateBags<T>>::get(node.bag_upper) {
+			if bag.head.as_ref() == Some(&node.id) {
+				bag.head = node.next.clone();
+			}
+			if bag.tail.as_ref() == Some(&node.id) {
+				bag.tail = node.prev.clone();
+			}
+			if bag.head.is_none() {
+				<CandidateBags<T>>::remove(node.bag_upper);
+			} else {
+				<CandidateBags<T>>::insert(node.bag_upper, bag);
+			}
+		}
+	}
+
+	/// Checks that the bags and nodes form consistent lists holding `count` candidates, each in
+	/// the bag matching its stake.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub(crate) fn do_try_state() -> Result<(), &'static str> {
+		let mut count = 0u32;
+		for bag_upper in Self::bag_uppers() {
+			let bag = match <CandidateBags<T>>::get(bag_upper) {
+				Some(bag) => bag,
+				None => continue,
+			};
+			let mut prev: Option<T::AccountId> = None;
+			let mut cursor = bag.head.clone();
+			while let Some(id) = cursor {
+				let node = <CandidateNodes<T>>::get(&id).ok_or("candidate bag links to no node")?;
+				frame_support::ensure!(node.prev == prev, "candidate node has a wrong prev link");
+				frame_support::ensure!(
+					node.bag_upper == bag_upper && Self::notional_bag_for(node.score) == bag_upper,
+					"candidate is not in the bag matching its stake"
+				);
+				count = count.saturating_add(1);
+				prev = Some(id);
+				cursor = node.next;
+			}
+			frame_support::ensure!(bag.tail == prev, "candidate bag has a wrong tail");
+		}
+		frame_support::ensure!(
+			count == Self::count() && <CandidateNodes<T>>::iter_keys().count() as u32 == count,
+			"candidate pool count does not match its nodes"
+		);
+		Ok(())
+	}
+}
@@ -34,6 +34,7 @@ use sp_std::{vec, vec::Vec};
 
 /// An action that can be performed upon a delegation
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum DelegationAction<Balance> {
 	Revoke(Balance),
 	Decrease(Balance),
@@ -90,7 +91,7 @@ impl<T: Config> Pallet<T> {
 
 		let bonded_amount = state.get_bond_amount(&collator).ok_or(<Error<T>>::DelegationDNE)?;
 		let now = <Round<T>>::get().current;
-		let when = now.saturating_add(T::RevokeDelegationDelay::get());
+		let when = now.saturating_add(<Pallet<T>>::revoke_delegation_delay());
 		scheduled_requests.push(ScheduledRequest {
 			delegator: delegator.clone(),
 			action: DelegationAction::Revoke(bonded_amount),
@@ -126,16 +127,17 @@ impl<T: Config> Pallet<T> {
 		let bonded_amount = state.get_bond_amount(&collator).ok_or(<Error<T>>::DelegationDNE)?;
 		ensure!(bonded_amount > decrease_amount, <Error<T>>::DelegatorBondBelowMin);
 		let new_amount: BalanceOf<T> = (bonded_amount - decrease_amount).into();
-		ensure!(new_amount >= T::MinDelegation::get(), <Error<T>>::DelegationBelowMin);
+		ensure!(new_amount >= <Pallet<T>>::min_delegation(), <Error<T>>::DelegationBelowMin);
 
 		// Net Total is total after pending orders are executed
 		let net_total = state.total().saturating_sub(state.less_total);
 		// Net Total is always >= MinDelegatorStk
-		let max_subtracted_amount = net_total.saturating_sub(T::MinDelegatorStk::get().into());
+		let max_subtracted_amount =
+			net_total.saturating_sub(<Pallet<T>>::min_delegator_stk().into());
 		ensure!(decrease_amount <= max_subtracted_amount, <Error<T>>::DelegatorBondBelowMin);
 
 		let now = <Round<T>>::get().current;
-		let when = now.saturating_add(T::RevokeDelegationDelay::get());
+		let when = now.saturating_add(<Pallet<T>>::revoke_delegation_delay());
 		scheduled_requests.push(ScheduledRequest {
 			delegator: delegator.clone(),
 			action: DelegationAction::Decrease(decrease_amount),
@@ -213,7 +215,8 @@ impl<T: Config> Pallet<T> {
 					true
 				} else {
 					ensure!(
-						state.total().saturating_sub(T::MinDelegatorStk::get().into()) >= amount,
+						state.total().saturating_sub(<Pallet<T>>::min_delegator_stk().into()) >=
+							amount,
 						<Error<T>>::DelegatorBondBelowMin
 					);
 					false
@@ -266,11 +269,11 @@ impl<T: Config> Pallet<T> {
 							state.total_sub_if::<T, _>(amount, |total| {
 								let new_total: BalanceOf<T> = total.into();
 								ensure!(
-									new_total >= T::MinDelegation::get(),
+									new_total >= <Pallet<T>>::min_delegation(),
 									<Error<T>>::DelegationBelowMin
 								);
 								ensure!(
-									new_total >= T::MinDelegatorStk::get(),
+									new_total >= <Pallet<T>>::min_delegator_stk(),
 									<Error<T>>::DelegatorBondBelowMin
 								);
 
@@ -319,7 +322,7 @@ impl<T: Config> Pallet<T> {
 		let mut state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
 		let mut updated_scheduled_requests = vec![];
 		let now = <Round<T>>::get().current;
-		let when = now.saturating_add(T::LeaveDelegatorsDelay::get());
+		let when = now.saturating_add(<Pallet<T>>::leave_delegators_delay());
 
 		// lazy migration for DelegatorStatus::Leaving
 		#[allow(deprecated)]
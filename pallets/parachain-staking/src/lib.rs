@@ -46,13 +46,17 @@
 //! `T::MaxDelegationsPerDelegator` collator candidates by calling `delegate`.
 //!
 //! To revoke a delegation, call `revoke_delegation` with the collator candidate's account.
+//! To unstake from a delegation without delay, call `instant_unstake`. A fee is charged on the
+//! unstaked amount and the amount unstaked this way per round is capped.
 //! To leave the set of delegators and revoke all delegations, call `leave_delegators`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod auto_compound;
+mod candidate_pool;
 mod delegation_requests;
 pub mod inflation;
+pub mod migrations;
 pub mod traits;
 pub mod types;
 pub mod weights;
@@ -70,6 +74,7 @@ pub use inflation::{InflationInfo, Range};
 use weights::WeightInfo;
 
 pub use auto_compound::{AutoCompoundConfig, AutoCompoundDelegations};
+pub use candidate_pool::{CandidateBag, CandidateNode, CandidatePool};
 pub use delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest};
 pub use pallet::*;
 pub use traits::*;
@@ -79,8 +84,8 @@ pub use RoundIndex;
 #[pallet]
 pub mod pallet {
 	use crate::{
+		candidate_pool::{CandidateBag, CandidateNode, CandidatePool},
 		delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest},
-		set::OrderedSet,
 		traits::*,
 		types::*,
 		AutoCompoundConfig, AutoCompoundDelegations, InflationInfo, Range, WeightInfo,
@@ -88,19 +93,23 @@ pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{
-			tokens::WithdrawReasons, Currency, Get, Imbalance, LockIdentifier, LockableCurrency,
-			ReservableCurrency,
+			tokens::WithdrawReasons, Currency, ExistenceRequirement, Get, LockIdentifier,
+			LockableCurrency, OnUnbalanced, ReservableCurrency,
 		},
 	};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::{
-		traits::{Saturating, Zero},
-		Perbill, Percent,
+		traits::{Saturating, UniqueSaturatedInto, Zero},
+		FixedPointNumber, FixedU128, Perbill, Percent,
 	};
 	use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	/// Pallet for parachain staking
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -108,6 +117,9 @@ pub mod pallet {
 	type RewardPoint = u32;
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
 
 	pub const COLLATOR_LOCK_ID: LockIdentifier = *b"stkngcol";
 	pub const DELEGATOR_LOCK_ID: LockIdentifier = *b"stkngdel";
@@ -126,28 +138,49 @@ pub mod pallet {
 		/// Minimum number of blocks per round
 		#[pallet::constant]
 		type MinBlocksPerRound: Get<u32>;
-		/// Number of rounds that candidates remain bonded before exit request is executable
+		/// Number of rounds that candidates remain bonded before exit request is executable,
+		/// default and minimum of the `LeaveCandidatesDelay` storage
 		#[pallet::constant]
 		type LeaveCandidatesDelay: Get<RoundIndex>;
-		/// Number of rounds candidate requests to decrease self-bond must wait to be executable
+		/// Maximum of the `LeaveCandidatesDelay` storage
+		#[pallet::constant]
+		type MaxLeaveCandidatesDelay: Get<RoundIndex>;
+		/// Number of rounds candidate requests to decrease self-bond must wait to be executable,
+		/// default and minimum of the `CandidateBondLessDelay` storage
 		#[pallet::constant]
 		type CandidateBondLessDelay: Get<RoundIndex>;
-		/// Number of rounds that delegators remain bonded before exit request is executable
+		/// Maximum of the `CandidateBondLessDelay` storage
+		#[pallet::constant]
+		type MaxCandidateBondLessDelay: Get<RoundIndex>;
+		/// Number of rounds that delegators remain bonded before exit request is executable,
+		/// default and minimum of the `LeaveDelegatorsDelay` storage
 		#[pallet::constant]
 		type LeaveDelegatorsDelay: Get<RoundIndex>;
-		/// Number of rounds that delegations remain bonded before revocation request is executable
+		/// Maximum of the `LeaveDelegatorsDelay` storage
+		#[pallet::constant]
+		type MaxLeaveDelegatorsDelay: Get<RoundIndex>;
+		/// Number of rounds that delegations remain bonded before revocation request is executable,
+		/// default and minimum of the `RevokeDelegationDelay` storage
 		#[pallet::constant]
 		type RevokeDelegationDelay: Get<RoundIndex>;
+		/// Maximum of the `RevokeDelegationDelay` storage
+		#[pallet::constant]
+		type MaxRevokeDelegationDelay: Get<RoundIndex>;
 		/// Number of rounds that delegation less requests must wait before executable
 		#[pallet::constant]
 		type DelegationBondLessDelay: Get<RoundIndex>;
+		/// Minimum number of rounds between two delegation transfers of a delegator
+		#[pallet::constant]
+		type DelegationTransferDelay: Get<RoundIndex>;
 		/// Number of rounds after which block authors are rewarded
 		#[pallet::constant]
 		type RewardPaymentDelay: Get<RoundIndex>;
 		/// Minimum number of selected candidates every round
 		#[pallet::constant]
 		type MinSelectedCandidates: Get<u32>;
-		/// Maximum top delegations counted per candidate
+		/// Maximum top delegations counted per candidate, default and maximum of the
+		/// `MaxTopDelegationsPerCandidate` storage. Lowering the storage doesn't move existing
+		/// top delegations to the bottom, it applies as the top delegations change.
 		#[pallet::constant]
 		type MaxTopDelegationsPerCandidate: Get<u32>;
 		/// Maximum bottom delegations (not counted) per candidate
@@ -156,18 +189,38 @@ pub mod pallet {
 		/// Maximum delegations per delegator
 		#[pallet::constant]
 		type MaxDelegationsPerDelegator: Get<u32>;
-		/// Minimum stake required for any candidate to be in `SelectedCandidates` for the round
+		/// Minimum stake required for any candidate to be in `SelectedCandidates` for the round,
+		/// default and minimum of the `MinCollatorStk` storage
 		#[pallet::constant]
 		type MinCollatorStk: Get<BalanceOf<Self>>;
-		/// Minimum stake required for any account to be a collator candidate
+		/// Maximum of the `MinCollatorStk` storage
+		#[pallet::constant]
+		type MaxMinCollatorStk: Get<BalanceOf<Self>>;
+		/// Minimum stake required for any account to be a collator candidate, default and
+		/// minimum of the `MinCandidateStk` storage
 		#[pallet::constant]
 		type MinCandidateStk: Get<BalanceOf<Self>>;
-		/// Minimum stake for any registered on-chain account to delegate
+		/// Maximum of the `MinCandidateStk` storage
+		#[pallet::constant]
+		type MaxMinCandidateStk: Get<BalanceOf<Self>>;
+		/// Minimum stake for any registered on-chain account to delegate, default and minimum of
+		/// the `MinDelegation` storage
 		#[pallet::constant]
 		type MinDelegation: Get<BalanceOf<Self>>;
-		/// Minimum stake for any registered on-chain account to be a delegator
+		/// Maximum of the `MinDelegation` storage
+		#[pallet::constant]
+		type MaxMinDelegation: Get<BalanceOf<Self>>;
+		/// Minimum stake for any registered on-chain account to be a delegator, default and
+		/// minimum of the `MinDelegatorStk` storage
 		#[pallet::constant]
 		type MinDelegatorStk: Get<BalanceOf<Self>>;
+		/// Maximum of the `MinDelegatorStk` storage
+		#[pallet::constant]
+		type MaxMinDelegatorStk: Get<BalanceOf<Self>>;
+		/// Upper thresholds of the bags of the candidate pool, in increasing order. Candidates
+		/// with more stake than the last threshold are held in a last bag.
+		#[pallet::constant]
+		type CandidateBagThresholds: Get<&'static [BalanceOf<Self>]>;
 		/// Get the current block author
 		type BlockAuthor: Get<Self::AccountId>;
 		/// Handler to notify the runtime when a collator is paid.
@@ -176,6 +229,18 @@ pub mod pallet {
 		/// Handler to distribute a collator's reward.
 		/// To use the default implementation of minting rewards, specify the type `()`.
 		type PayoutCollatorReward: PayoutCollatorReward<Self>;
+		/// Source of the funds paid out as staking rewards.
+		/// To mint rewards according to the deprecated `InflationConfig`, specify the type `()`.
+		type RewardSource: StakingRewardSource<Self>;
+		/// Handler for the fees charged on instant unstakes, e.g. the treasury.
+		/// To burn the fees, specify the type `()`.
+		type OnInstantUnstakeFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Maximum of the `InstantUnstakeFee` storage
+		#[pallet::constant]
+		type MaxInstantUnstakeFee: Get<Perbill>;
+		/// Maximum of the `InstantUnstakeCap` storage
+		#[pallet::constant]
+		type MaxInstantUnstakeCap: Get<BalanceOf<Self>>;
 		/// Handler to notify the runtime when a new round begin.
 		/// If you don't need it, you can specify the type `()`.
 		type OnNewRound: OnNewRound;
@@ -210,6 +275,7 @@ pub mod pallet {
 		AlreadyDelegatedCandidate,
 		InvalidSchedule,
 		CannotSetBelowMin,
+		CannotSetAboveMax,
 		RoundLengthMustBeGreaterThanTotalSelectedCollators,
 		NoWritingSameValue,
 		TooLowCandidateCountWeightHintJoinCandidates,
@@ -230,6 +296,13 @@ pub mod pallet {
 		TooLowDelegationCountToAutoCompound,
 		TooLowCandidateAutoCompoundingDelegationCountToAutoCompound,
 		TooLowCandidateAutoCompoundingDelegationCountToDelegate,
+		CannotTransferDelegationToSameCandidate,
+		TransferAmountExceedsDelegation,
+		DelegationTransferNotAllowedYet,
+		InstantUnstakeExceedsDelegation,
+		InstantUnstakeCapExceeded,
+		TooLowCandidateDelegationCountToTransferDelegation,
+		TooLowCandidateAutoCompoundingDelegationCountToTransferDelegation,
 	}
 
 	#[pallet::event]
@@ -394,6 +467,11 @@ pub mod pallet {
 		TotalSelectedSet { old: u32, new: u32 },
 		/// Set collator commission to this value.
 		CollatorCommissionSet { old: Perbill, new: Perbill },
+		/// Set a staking parameter to this value.
+		StakingParameterSet {
+			old: StakingParameter<BalanceOf<T>>,
+			new: StakingParameter<BalanceOf<T>>,
+		},
 		/// Set blocks per round
 		BlocksPerRoundSet {
 			current_round: RoundIndex,
@@ -408,6 +486,23 @@ pub mod pallet {
 		AutoCompoundSet { candidate: T::AccountId, delegator: T::AccountId, value: Percent },
 		/// Compounded a portion of rewards towards the delegation.
 		Compounded { candidate: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// Delegation moved, partially or entirely, to another candidate.
+		DelegationTransferred {
+			delegator: T::AccountId,
+			from_candidate: T::AccountId,
+			to_candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			in_top: bool,
+		},
+		/// Delegation unstaked without delay, the fee was charged on the unstaked amount.
+		InstantUnstaked {
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// The `RewardSource` failed to credit a reward, the amount stays with the source.
+		RewardCreditFailed { account: T::AccountId, amount: BalanceOf<T>, error: DispatchError },
 	}
 
 	#[pallet::hooks]
@@ -452,6 +547,11 @@ pub mod pallet {
 		fn on_finalize(_n: T::BlockNumber) {
 			Self::award_points_to_block_author();
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::storage]
@@ -464,6 +564,124 @@ pub mod pallet {
 	/// The total candidates selected every round
 	pub(crate) type TotalSelected<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	#[pallet::type_value]
+	pub(crate) fn DefaultMinCollatorStk<T: Config>() -> BalanceOf<T> {
+		T::MinCollatorStk::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn min_collator_stk)]
+	/// Minimum stake required for any candidate to be in `SelectedCandidates` for the round
+	pub(crate) type MinCollatorStk<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery, DefaultMinCollatorStk<T>>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultMinCandidateStk<T: Config>() -> BalanceOf<T> {
+		T::MinCandidateStk::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn min_candidate_stk)]
+	/// Minimum stake required for any account to be a collator candidate
+	pub(crate) type MinCandidateStk<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery, DefaultMinCandidateStk<T>>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultMinDelegation<T: Config>() -> BalanceOf<T> {
+		T::MinDelegation::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn min_delegation)]
+	/// Minimum stake for any registered on-chain account to delegate
+	pub(crate) type MinDelegation<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery, DefaultMinDelegation<T>>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultMinDelegatorStk<T: Config>() -> BalanceOf<T> {
+		T::MinDelegatorStk::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn min_delegator_stk)]
+	/// Minimum stake for any registered on-chain account to be a delegator
+	pub(crate) type MinDelegatorStk<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery, DefaultMinDelegatorStk<T>>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultLeaveCandidatesDelay<T: Config>() -> RoundIndex {
+		T::LeaveCandidatesDelay::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn leave_candidates_delay)]
+	/// Number of rounds that candidates remain bonded before exit request is executable
+	pub(crate) type LeaveCandidatesDelay<T: Config> =
+		StorageValue<_, RoundIndex, ValueQuery, DefaultLeaveCandidatesDelay<T>>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultCandidateBondLessDelay<T: Config>() -> RoundIndex {
+		T::CandidateBondLessDelay::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn candidate_bond_less_delay)]
+	/// Number of rounds candidate requests to decrease self-bond must wait to be executable
+	pub(crate) type CandidateBondLessDelay<T: Config> =
+		StorageValue<_, RoundIndex, ValueQuery, DefaultCandidateBondLessDelay<T>>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultLeaveDelegatorsDelay<T: Config>() -> RoundIndex {
+		T::LeaveDelegatorsDelay::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn leave_delegators_delay)]
+	/// Number of rounds that delegators remain bonded before exit request is executable
+	pub(crate) type LeaveDelegatorsDelay<T: Config> =
+		StorageValue<_, RoundIndex, ValueQuery, DefaultLeaveDelegatorsDelay<T>>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultRevokeDelegationDelay<T: Config>() -> RoundIndex {
+		T::RevokeDelegationDelay::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn revoke_delegation_delay)]
+	/// Number of rounds that delegations remain bonded before revocation or bond less request is
+	/// executable
+	pub(crate) type RevokeDelegationDelay<T: Config> =
+		StorageValue<_, RoundIndex, ValueQuery, DefaultRevokeDelegationDelay<T>>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultMaxTopDelegationsPerCandidate<T: Config>() -> u32 {
+		T::MaxTopDelegationsPerCandidate::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn max_top_delegations_per_candidate)]
+	/// Maximum top delegations counted per candidate. Once lowered, candidates holding more top
+	/// delegations keep them until they leave, bottom delegations are not promoted meanwhile
+	pub(crate) type MaxTopDelegationsPerCandidate<T: Config> =
+		StorageValue<_, u32, ValueQuery, DefaultMaxTopDelegationsPerCandidate<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn instant_unstake_fee)]
+	/// Fee charged on the amount unstaked through `instant_unstake`
+	pub(crate) type InstantUnstakeFee<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn instant_unstake_cap)]
+	/// Maximum amount unstaked through `instant_unstake` per round, instant unstakes are disabled
+	/// as long as it is zero
+	pub(crate) type InstantUnstakeCap<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn instant_unstaked)]
+	/// Amount unstaked through `instant_unstake` in the round, counted against the cap
+	pub(crate) type InstantUnstaked<T: Config> =
+		StorageValue<_, (RoundIndex, BalanceOf<T>), ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn parachain_bond_info)]
 	/// Parachain bond config info { account, percent_of_inflation }
@@ -514,6 +732,12 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Round in which a delegator last transferred a delegation, used to rate limit transfers.
+	#[pallet::storage]
+	#[pallet::getter(fn last_delegation_transfer)]
+	pub(crate) type LastDelegationTransfer<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RoundIndex, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn top_delegations)]
 	/// Top delegations for collator candidate
@@ -547,10 +771,24 @@ pub mod pallet {
 	pub(crate) type Total<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn candidate_pool)]
-	/// The pool of collator candidates, each with their total backing stake
-	pub(crate) type CandidatePool<T: Config> =
-		StorageValue<_, OrderedSet<Bond<T::AccountId, BalanceOf<T>>>, ValueQuery>;
+	/// Bags of the pool of active collator candidates, by upper threshold, see [CandidatePool]
+	pub(crate) type CandidateBags<T: Config> =
+		StorageMap<_, Twox64Concat, BalanceOf<T>, CandidateBag<T::AccountId>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Active collator candidates with their total backing stake, linked within their bag
+	pub(crate) type CandidateNodes<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		CandidateNode<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn candidate_pool_count)]
+	/// Number of active collator candidates
+	pub(crate) type CandidatePoolCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn at_stake)]
@@ -578,7 +816,8 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn inflation_config)]
-	/// Inflation configuration
+	/// Inflation configuration. Deprecated: only read when `RewardSource` is `()`, sources like
+	/// `RewardPot` derive the round issuance elsewhere.
 	pub type InflationConfig<T: Config> = StorageValue<_, InflationInfo<BalanceOf<T>>, ValueQuery>;
 
 	#[pallet::storage]
@@ -740,6 +979,8 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Set the expectations for total staked. These expectations determine the issuance for
 		/// the round according to logic in `fn compute_issuance`
+		///
+		/// Deprecated: has no effect unless `RewardSource` is `()`.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_staking_expectations())]
 		pub fn set_staking_expectations(
@@ -761,6 +1002,8 @@ pub mod pallet {
 		}
 
 		/// Set the annual inflation rate to derive per-round inflation
+		///
+		/// Deprecated: has no effect unless `RewardSource` is `()`.
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_inflation())]
 		pub fn set_inflation(
@@ -882,6 +1125,101 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Set a staking parameter, bounded by the matching `Config` constant and its `Max*`
+		/// counterpart
+		/// - minimum stakes are checked for the bonds made or decreased from now on
+		/// - delays apply to the requests scheduled from now on
+		/// - a lower maximum of top delegations applies as the top delegations of a candidate
+		///   change, the existing ones are not moved to the bottom
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_staking_parameter())]
+		pub fn set_staking_parameter(
+			origin: OriginFor<T>,
+			new: StakingParameter<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			let old = Self::staking_parameter(&new);
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			match new {
+				StakingParameter::MinCollatorStk(value) => {
+					ensure!(value >= T::MinCollatorStk::get(), Error::<T>::CannotSetBelowMin);
+					ensure!(value <= T::MaxMinCollatorStk::get(), Error::<T>::CannotSetAboveMax);
+					<MinCollatorStk<T>>::put(value);
+				},
+				StakingParameter::MinCandidateStk(value) => {
+					ensure!(value >= T::MinCandidateStk::get(), Error::<T>::CannotSetBelowMin);
+					ensure!(value <= T::MaxMinCandidateStk::get(), Error::<T>::CannotSetAboveMax);
+					<MinCandidateStk<T>>::put(value);
+				},
+				StakingParameter::MinDelegation(value) => {
+					ensure!(value >= T::MinDelegation::get(), Error::<T>::CannotSetBelowMin);
+					ensure!(value <= T::MaxMinDelegation::get(), Error::<T>::CannotSetAboveMax);
+					<MinDelegation<T>>::put(value);
+				},
+				StakingParameter::MinDelegatorStk(value) => {
+					ensure!(value >= T::MinDelegatorStk::get(), Error::<T>::CannotSetBelowMin);
+					ensure!(value <= T::MaxMinDelegatorStk::get(), Error::<T>::CannotSetAboveMax);
+					<MinDelegatorStk<T>>::put(value);
+				},
+				StakingParameter::LeaveCandidatesDelay(value) => {
+					ensure!(value >= T::LeaveCandidatesDelay::get(), Error::<T>::CannotSetBelowMin);
+					ensure!(
+						value <= T::MaxLeaveCandidatesDelay::get(),
+						Error::<T>::CannotSetAboveMax
+					);
+					<LeaveCandidatesDelay<T>>::put(value);
+				},
+				StakingParameter::CandidateBondLessDelay(value) => {
+					ensure!(
+						value >= T::CandidateBondLessDelay::get(),
+						Error::<T>::CannotSetBelowMin
+					);
+					ensure!(
+						value <= T::MaxCandidateBondLessDelay::get(),
+						Error::<T>::CannotSetAboveMax
+					);
+					<CandidateBondLessDelay<T>>::put(value);
+				},
+				StakingParameter::LeaveDelegatorsDelay(value) => {
+					ensure!(value >= T::LeaveDelegatorsDelay::get(), Error::<T>::CannotSetBelowMin);
+					ensure!(
+						value <= T::MaxLeaveDelegatorsDelay::get(),
+						Error::<T>::CannotSetAboveMax
+					);
+					<LeaveDelegatorsDelay<T>>::put(value);
+				},
+				StakingParameter::RevokeDelegationDelay(value) => {
+					ensure!(
+						value >= T::RevokeDelegationDelay::get(),
+						Error::<T>::CannotSetBelowMin
+					);
+					ensure!(
+						value <= T::MaxRevokeDelegationDelay::get(),
+						Error::<T>::CannotSetAboveMax
+					);
+					<RevokeDelegationDelay<T>>::put(value);
+				},
+				StakingParameter::MaxTopDelegationsPerCandidate(value) => {
+					ensure!(value > 0, Error::<T>::CannotSetBelowMin);
+					ensure!(
+						value <= T::MaxTopDelegationsPerCandidate::get(),
+						Error::<T>::CannotSetAboveMax
+					);
+					<MaxTopDelegationsPerCandidate<T>>::put(value);
+				},
+				StakingParameter::InstantUnstakeFee(value) => {
+					ensure!(value <= T::MaxInstantUnstakeFee::get(), Error::<T>::CannotSetAboveMax);
+					<InstantUnstakeFee<T>>::put(value);
+				},
+				StakingParameter::InstantUnstakeCap(value) => {
+					ensure!(value <= T::MaxInstantUnstakeCap::get(), Error::<T>::CannotSetAboveMax);
+					<InstantUnstakeCap<T>>::put(value);
+				},
+			}
+			Self::deposit_event(Event::StakingParameterSet { old, new });
+			Ok(().into())
+		}
+
 		/// Join the set of collator candidates
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::join_candidates(*candidate_count))]
@@ -893,17 +1231,12 @@ pub mod pallet {
 			let acc = ensure_signed(origin)?;
 			ensure!(!Self::is_candidate(&acc), Error::<T>::CandidateExists);
 			ensure!(!Self::is_delegator(&acc), Error::<T>::DelegatorExists);
-			ensure!(bond >= T::MinCandidateStk::get(), Error::<T>::CandidateBondBelowMin);
-			let mut candidates = <CandidatePool<T>>::get();
-			let old_count = candidates.0.len() as u32;
+			ensure!(bond >= <MinCandidateStk<T>>::get(), Error::<T>::CandidateBondBelowMin);
 			ensure!(
-				candidate_count >= old_count,
+				candidate_count >= CandidatePool::<T>::count(),
 				Error::<T>::TooLowCandidateCountWeightHintJoinCandidates
 			);
-			ensure!(
-				candidates.insert(Bond { owner: acc.clone(), amount: bond }),
-				Error::<T>::CandidateExists
-			);
+			ensure!(!CandidatePool::<T>::contains(&acc), Error::<T>::CandidateExists);
 			ensure!(
 				Self::get_collator_stakable_free_balance(&acc) >= bond,
 				Error::<T>::InsufficientBalance,
@@ -916,7 +1249,7 @@ pub mod pallet {
 			<TopDelegations<T>>::insert(&acc, empty_delegations.clone());
 			// insert empty bottom delegations
 			<BottomDelegations<T>>::insert(&acc, empty_delegations);
-			<CandidatePool<T>>::put(candidates);
+			CandidatePool::<T>::insert(acc.clone(), bond);
 			let new_total = <Total<T>>::get().saturating_add(bond);
 			<Total<T>>::put(new_total);
 			Self::deposit_event(Event::JoinedCollatorCandidates {
@@ -938,14 +1271,11 @@ pub mod pallet {
 			let collator = ensure_signed(origin)?;
 			let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
 			let (now, when) = state.schedule_leave::<T>()?;
-			let mut candidates = <CandidatePool<T>>::get();
 			ensure!(
-				candidate_count >= candidates.0.len() as u32,
+				candidate_count >= CandidatePool::<T>::count(),
 				Error::<T>::TooLowCandidateCountToLeaveCandidates
 			);
-			if candidates.remove(&Bond::from_owner(collator.clone())) {
-				<CandidatePool<T>>::put(candidates);
-			}
+			CandidatePool::<T>::remove(&collator);
 			<CandidateInfo<T>>::insert(&collator, state);
 			Self::deposit_event(Event::CandidateScheduledExit {
 				exit_allowed_round: now,
@@ -1050,16 +1380,14 @@ pub mod pallet {
 			let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
 			ensure!(state.is_leaving(), Error::<T>::CandidateNotLeaving);
 			state.go_online();
-			let mut candidates = <CandidatePool<T>>::get();
 			ensure!(
-				candidates.0.len() as u32 <= candidate_count,
+				CandidatePool::<T>::count() <= candidate_count,
 				Error::<T>::TooLowCandidateCountWeightHintCancelLeaveCandidates
 			);
 			ensure!(
-				candidates.insert(Bond { owner: collator.clone(), amount: state.total_counted }),
+				CandidatePool::<T>::insert(collator.clone(), state.total_counted),
 				Error::<T>::AlreadyActive
 			);
-			<CandidatePool<T>>::put(candidates);
 			<CandidateInfo<T>>::insert(&collator, state);
 			Self::deposit_event(Event::CancelledCandidateExit { candidate: collator });
 			Ok(().into())
@@ -1073,10 +1401,7 @@ pub mod pallet {
 			let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
 			ensure!(state.is_active(), Error::<T>::AlreadyOffline);
 			state.go_offline();
-			let mut candidates = <CandidatePool<T>>::get();
-			if candidates.remove(&Bond::from_owner(collator.clone())) {
-				<CandidatePool<T>>::put(candidates);
-			}
+			CandidatePool::<T>::remove(&collator);
 			<CandidateInfo<T>>::insert(&collator, state);
 			Self::deposit_event(Event::CandidateWentOffline { candidate: collator });
 			Ok(().into())
@@ -1091,12 +1416,10 @@ pub mod pallet {
 			ensure!(!state.is_active(), Error::<T>::AlreadyActive);
 			ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
 			state.go_online();
-			let mut candidates = <CandidatePool<T>>::get();
 			ensure!(
-				candidates.insert(Bond { owner: collator.clone(), amount: state.total_counted }),
+				CandidatePool::<T>::insert(collator.clone(), state.total_counted),
 				Error::<T>::AlreadyActive
 			);
-			<CandidatePool<T>>::put(candidates);
 			<CandidateInfo<T>>::insert(&collator, state);
 			Self::deposit_event(Event::CandidateBackOnline { candidate: collator });
 			Ok(().into())
@@ -1356,33 +1679,59 @@ pub mod pallet {
 			)
 		}
 
-		/// Hotfix to remove existing empty entries for candidates that have left.
-		#[pallet::call_index(28)]
-		#[pallet::weight(
-        T::DbWeight::get().reads_writes(2 * candidates.len() as u64, candidates.len() as u64)
-        )]
-		pub fn hotfix_remove_delegation_requests_exited_candidates(
+		/// Moves `amount` of the delegation towards `from_candidate` to `to_candidate`, without
+		/// the revoke or bond less delay. The delegation towards `to_candidate` is created or
+		/// increased and the total bonded by the delegator is unchanged. If `amount` is the whole
+		/// delegation, it is removed from `from_candidate` along with its auto-compound config.
+		/// A new delegation keeps the auto-compound value of the transferred one.
+		/// The delegation is counted for `to_candidate` from the next round on. Transfers are
+		/// allowed once every `DelegationTransferDelay` rounds, and not while a request is
+		/// pending for the transferred delegation.
+		// call index 28 belonged to the removed
+		// `hotfix_remove_delegation_requests_exited_candidates`
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_delegation(
+        *to_candidate_delegation_count,
+        *to_candidate_auto_compounding_delegation_count,
+        *from_candidate_delegation_count,
+        *from_candidate_auto_compounding_delegation_count,
+        ))]
+		pub fn transfer_delegation(
 			origin: OriginFor<T>,
-			candidates: Vec<T::AccountId>,
-		) -> DispatchResult {
-			ensure_signed(origin)?;
-			ensure!(candidates.len() < 100, <Error<T>>::InsufficientBalance);
-			for candidate in &candidates {
-				ensure!(
-					<CandidateInfo<T>>::get(&candidate).is_none(),
-					<Error<T>>::CandidateNotLeaving
-				);
-				ensure!(
-					<DelegationScheduledRequests<T>>::get(&candidate).is_empty(),
-					<Error<T>>::CandidateNotLeaving
-				);
-			}
-
-			for candidate in candidates {
-				<DelegationScheduledRequests<T>>::remove(candidate);
-			}
+			from_candidate: T::AccountId,
+			to_candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			to_candidate_delegation_count: u32,
+			to_candidate_auto_compounding_delegation_count: u32,
+			from_candidate_delegation_count: u32,
+			from_candidate_auto_compounding_delegation_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			Self::delegation_transfer(
+				delegator,
+				from_candidate,
+				to_candidate,
+				amount,
+				to_candidate_delegation_count,
+				to_candidate_auto_compounding_delegation_count,
+				from_candidate_delegation_count,
+				from_candidate_auto_compounding_delegation_count,
+			)
+		}
 
-			Ok(().into())
+		/// Unstake from a delegation without waiting `RevokeDelegationDelay` rounds, the
+		/// delegation is revoked if `amount` is all of it. `InstantUnstakeFee` of `amount` is
+		/// charged to the delegator and handed to `OnInstantUnstakeFee`. At most
+		/// `InstantUnstakeCap` can be unstaked this way per round, by all delegators.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::instant_unstake())]
+		pub fn instant_unstake(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			Self::delegation_instant_unstake(delegator, candidate, amount)
 		}
 	}
 
@@ -1407,7 +1756,9 @@ pub mod pallet {
 		pub fn is_selected_candidate(acc: &T::AccountId) -> bool {
 			<SelectedCandidates<T>>::get().binary_search(acc).is_ok()
 		}
-		/// Returns an account's free balance which is not locked in delegation staking
+		/// Returns an account's free balance which is not locked in delegation staking.
+		/// Balance locked by other pallets is included since locks overlap, so tokens still
+		/// locked by a vesting schedule can be delegated.
 		pub fn get_delegator_stakable_free_balance(acc: &T::AccountId) -> BalanceOf<T> {
 			let mut balance = T::Currency::free_balance(acc);
 			if let Some(state) = <DelegatorState<T>>::get(acc) {
@@ -1430,15 +1781,157 @@ pub mod pallet {
 		) -> Percent {
 			<AutoCompoundDelegations<T>>::auto_compound(candidate, delegator)
 		}
+		/// Overview of all the collator candidates, sorted by total counted stake.
+		pub fn candidate_summaries() -> Vec<CandidateSummary<T::AccountId, BalanceOf<T>>> {
+			let (selected, reward) = Self::expected_round_reward();
+			let commission = <CollatorCommission<T>>::get();
+			let last_round = <Round<T>>::get().current.saturating_sub(1);
+			let rounds_per_year = u128::from(crate::inflation::rounds_per_year::<T>());
+			let mut summaries: Vec<_> = <CandidateInfo<T>>::iter()
+				.map(|(candidate, info)| {
+					let estimated_apr = if selected.binary_search(&candidate).is_ok() {
+						let uncounted_stake =
+							Self::get_rewardable_delegators(&candidate).uncounted_stake;
+						let yearly_reward =
+							UniqueSaturatedInto::<u128>::unique_saturated_into(reward)
+								.saturating_mul(rounds_per_year);
+						let total: u128 = info
+							.total_counted
+							.saturating_sub(uncounted_stake)
+							.unique_saturated_into();
+						FixedU128::checked_from_rational(yearly_reward, total).unwrap_or_default()
+					} else {
+						FixedU128::zero()
+					};
+					CandidateSummary {
+						last_round_points: <AwardedPts<T>>::get(last_round, &candidate),
+						is_selected: Self::is_selected_candidate(&candidate),
+						is_active: info.is_active(),
+						candidate,
+						bond: info.bond,
+						total_counted: info.total_counted,
+						delegation_count: info.delegation_count,
+						commission,
+						estimated_apr,
+					}
+				})
+				.collect();
+			summaries.sort_by(|a, b| b.total_counted.cmp(&a.total_counted));
+			summaries
+		}
+		/// Overview of the delegations of a delegator with their pending requests and expected
+		/// rewards, `None` if the account isn't delegating.
+		pub fn delegator_summary(
+			delegator: T::AccountId,
+		) -> Option<DelegatorSummary<T::AccountId, BalanceOf<T>>> {
+			let state = <DelegatorState<T>>::get(&delegator)?;
+			let (selected, reward) = Self::expected_round_reward();
+			let now = <Round<T>>::get().current;
+			let delegations = state
+				.delegations
+				.0
+				.into_iter()
+				.map(|Bond { owner: candidate, amount }| {
+					let expected_reward = if selected.binary_search(&candidate).is_ok() {
+						let CountedDelegations { uncounted_stake, rewardable_delegations } =
+							Self::get_rewardable_delegators(&candidate);
+						let total = <CandidateInfo<T>>::get(&candidate)
+							.map(|info| info.total_counted.saturating_sub(uncounted_stake))
+							.unwrap_or_else(Zero::zero);
+						rewardable_delegations
+							.into_iter()
+							.find(|bond| bond.owner == delegator)
+							.map(|bond| Perbill::from_rational(bond.amount, total) * reward)
+							.unwrap_or_else(Zero::zero)
+					} else {
+						Zero::zero()
+					};
+					let pending_request = <DelegationScheduledRequests<T>>::get(&candidate)
+						.into_iter()
+						.find(|request| request.delegator == delegator)
+						.map(|request| PendingDelegationRequest {
+							action: request.action,
+							when_executable: request.when_executable,
+							rounds_until_executable: request.when_executable.saturating_sub(now),
+						});
+					DelegationSummary {
+						auto_compound: Self::delegation_auto_compound(&candidate, &delegator),
+						candidate,
+						amount,
+						expected_reward,
+						pending_request,
+					}
+				})
+				.collect();
+			Some(DelegatorSummary {
+				delegator,
+				total: state.total,
+				less_total: state.less_total,
+				delegations,
+			})
+		}
+		/// Candidates selected if the next round started now, and the reward each of them
+		/// shares pro rata of its counted stake, assuming they are all awarded the same points.
+		/// Mirrors `prepare_staking_payouts` and `pay_one_collator_reward`, with the issuance
+		/// projected by `RewardSource::expected_round_issuance`.
+		fn expected_round_reward() -> (Vec<T::AccountId>, BalanceOf<T>) {
+			let selected = Self::compute_top_candidates();
+			if selected.is_empty() {
+				return (selected, Zero::zero());
+			}
+			let staked = selected.iter().fold(BalanceOf::<T>::zero(), |staked, candidate| {
+				<CandidateInfo<T>>::get(candidate)
+					.map(|info| staked.saturating_add(info.total_counted))
+					.unwrap_or(staked)
+			});
+			let round_issuance = T::RewardSource::expected_round_issuance(staked);
+			let staking_reward = round_issuance
+				.saturating_sub(<ParachainBondInfo<T>>::get().percent * round_issuance);
+			let pct_due = Perbill::from_rational(1, selected.len() as u32);
+			let commission = pct_due * (<CollatorCommission<T>>::get() * round_issuance);
+			(selected, (pct_due * staking_reward).saturating_sub(commission))
+		}
 		/// Caller must ensure candidate is active before calling
 		pub(crate) fn update_active(candidate: T::AccountId, total: BalanceOf<T>) {
-			let mut candidates = <CandidatePool<T>>::get();
-			candidates.remove(&Bond::from_owner(candidate.clone()));
-			candidates.insert(Bond { owner: candidate, amount: total });
-			<CandidatePool<T>>::put(candidates);
+			CandidatePool::<T>::update(candidate, total);
+		}
+		/// Active candidates with their total backing stake, from the bags with the most stake
+		pub fn candidate_pool() -> Vec<Bond<T::AccountId, BalanceOf<T>>> {
+			CandidatePool::<T>::iter().collect()
+		}
+		/// Current value of the staking parameter of the same kind as `parameter`
+		pub fn staking_parameter(
+			parameter: &StakingParameter<BalanceOf<T>>,
+		) -> StakingParameter<BalanceOf<T>> {
+			match parameter {
+				StakingParameter::MinCollatorStk(_) =>
+					StakingParameter::MinCollatorStk(<MinCollatorStk<T>>::get()),
+				StakingParameter::MinCandidateStk(_) =>
+					StakingParameter::MinCandidateStk(<MinCandidateStk<T>>::get()),
+				StakingParameter::MinDelegation(_) =>
+					StakingParameter::MinDelegation(<MinDelegation<T>>::get()),
+				StakingParameter::MinDelegatorStk(_) =>
+					StakingParameter::MinDelegatorStk(<MinDelegatorStk<T>>::get()),
+				StakingParameter::LeaveCandidatesDelay(_) =>
+					StakingParameter::LeaveCandidatesDelay(<LeaveCandidatesDelay<T>>::get()),
+				StakingParameter::CandidateBondLessDelay(_) =>
+					StakingParameter::CandidateBondLessDelay(<CandidateBondLessDelay<T>>::get()),
+				StakingParameter::LeaveDelegatorsDelay(_) =>
+					StakingParameter::LeaveDelegatorsDelay(<LeaveDelegatorsDelay<T>>::get()),
+				StakingParameter::RevokeDelegationDelay(_) =>
+					StakingParameter::RevokeDelegationDelay(<RevokeDelegationDelay<T>>::get()),
+				StakingParameter::MaxTopDelegationsPerCandidate(_) =>
+					StakingParameter::MaxTopDelegationsPerCandidate(<MaxTopDelegationsPerCandidate<
+						T,
+					>>::get()),
+				StakingParameter::InstantUnstakeFee(_) =>
+					StakingParameter::InstantUnstakeFee(<InstantUnstakeFee<T>>::get()),
+				StakingParameter::InstantUnstakeCap(_) =>
+					StakingParameter::InstantUnstakeCap(<InstantUnstakeCap<T>>::get()),
+			}
 		}
 		/// Compute round issuance based on total staked for the given round
-		fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
+		pub(crate) fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
 			let config = <InflationConfig<T>>::get();
 			let round_issuance = crate::inflation::round_issuance_range::<T>(config.round);
 			// TODO: consider interpolation instead of bounded range
@@ -1483,19 +1976,19 @@ pub mod pallet {
 				return Weight::zero();
 			}
 			let total_staked = <Staked<T>>::take(round_to_payout);
-			let total_issuance = Self::compute_issuance(total_staked);
+			let total_issuance = T::RewardSource::round_issuance(total_staked);
 			let mut left_issuance = total_issuance;
 			// reserve portion of issuance for parachain bond account
 			let bond_config = <ParachainBondInfo<T>>::get();
 			let parachain_bond_reserve = bond_config.percent * total_issuance;
-			if let Ok(imb) =
-				T::Currency::deposit_into_existing(&bond_config.account, parachain_bond_reserve)
+			if let Some(reserved) =
+				Self::credit_reward(&bond_config.account, parachain_bond_reserve)
 			{
 				// update round issuance iff transfer succeeds
-				left_issuance = left_issuance.saturating_sub(imb.peek());
+				left_issuance = left_issuance.saturating_sub(reserved);
 				Self::deposit_event(Event::ReservedForParachainBond {
 					account: bond_config.account,
-					value: imb.peek(),
+					value: reserved,
 				});
 			}
 
@@ -1655,39 +2148,18 @@ pub mod pallet {
 				return vec![];
 			}
 
-			let mut candidates = <CandidatePool<T>>::get().0;
-
-			// If the number of candidates is greater than top_n, select the candidates with higher
-			// amount. Otherwise, return all the candidates.
-			if candidates.len() > top_n {
-				// Partially sort candidates such that element at index `top_n - 1` is sorted, and
-				// all the elements in the range 0..top_n are the top n elements.
-				candidates.select_nth_unstable_by(top_n - 1, |a, b| {
-					// Order by amount, then owner. The owner is needed to ensure a stable order
-					// when two accounts have the same amount.
-					a.amount.cmp(&b.amount).then_with(|| a.owner.cmp(&b.owner)).reverse()
-				});
-
-				let mut collators = candidates
-					.into_iter()
-					.take(top_n)
-					.filter(|x| x.amount >= T::MinCollatorStk::get())
-					.map(|x| x.owner)
-					.collect::<Vec<T::AccountId>>();
+			// Only the bags holding the top_n candidates with higher amount are read. Ties are
+			// ordered by owner to ensure a stable selection.
+			let mut collators = CandidatePool::<T>::top(top_n)
+				.into_iter()
+				.filter(|x| x.amount >= <MinCollatorStk<T>>::get())
+				.map(|x| x.owner)
+				.collect::<Vec<T::AccountId>>();
 
-				// Sort collators by AccountId
-				collators.sort();
+			// Sort collators by AccountId
+			collators.sort();
 
-				collators
-			} else {
-				// Return all candidates
-				// The candidates are already sorted by AccountId, so no need to sort again
-				candidates
-					.into_iter()
-					.filter(|x| x.amount >= T::MinCollatorStk::get())
-					.map(|x| x.owner)
-					.collect::<Vec<T::AccountId>>()
-			}
+			collators
 		}
 		/// Best as in most cumulatively supported in terms of stake
 		/// Returns [collator_count, delegation_count, total staked]
@@ -1721,7 +2193,8 @@ pub mod pallet {
 						total_exposed_amount: *snapshot_total,
 					})
 				}
-				let weight = T::WeightInfo::select_top_candidates(0, 0);
+				let weight = T::WeightInfo::select_top_candidates(0, 0)
+					.saturating_add(T::DbWeight::get().reads(CandidatePool::<T>::max_top_reads()));
 				return (weight, collator_count, delegation_count, total);
 			}
 
@@ -1769,7 +2242,9 @@ pub mod pallet {
 			<SelectedCandidates<T>>::put(collators);
 
 			let avg_delegator_count = delegation_count.checked_div(collator_count).unwrap_or(0);
-			let weight = T::WeightInfo::select_top_candidates(collator_count, avg_delegator_count);
+			// the candidate bags read to find the top candidates are not part of the benchmark
+			let weight = T::WeightInfo::select_top_candidates(collator_count, avg_delegator_count)
+				.saturating_add(T::DbWeight::get().reads(CandidatePool::<T>::max_top_reads()));
 			(weight, collator_count, delegation_count, total)
 		}
 
@@ -1829,26 +2304,273 @@ pub mod pallet {
 			state.increase_delegation::<T>(candidate.clone(), more)
 		}
 
-		/// Mint a specified reward amount to the beneficiary account. Emits the [Rewarded] event.
+		/// Moves a delegation between candidates, see [`Pallet::transfer_delegation`].
+		pub(crate) fn delegation_transfer(
+			delegator: T::AccountId,
+			from_candidate: T::AccountId,
+			to_candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			to_candidate_delegation_count_hint: u32,
+			to_candidate_auto_compounding_delegation_count_hint: u32,
+			from_candidate_delegation_count_hint: u32,
+			from_candidate_auto_compounding_delegation_count_hint: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				from_candidate != to_candidate,
+				Error::<T>::CannotTransferDelegationToSameCandidate
+			);
+			let now = <Round<T>>::get().current;
+			if let Some(last_transfer) = <LastDelegationTransfer<T>>::get(&delegator) {
+				ensure!(
+					now >= last_transfer.saturating_add(T::DelegationTransferDelay::get()),
+					Error::<T>::DelegationTransferNotAllowedYet
+				);
+			}
+			ensure!(
+				!Self::delegation_request_exists(&from_candidate, &delegator),
+				Error::<T>::PendingDelegationRequestAlreadyExists
+			);
+			ensure!(
+				!Self::delegation_request_revoke_exists(&to_candidate, &delegator),
+				Error::<T>::PendingDelegationRevoke
+			);
+
+			let mut state = <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+			let from_bond =
+				state.get_bond_amount(&from_candidate).ok_or(Error::<T>::DelegationDNE)?;
+			ensure!(!amount.is_zero(), Error::<T>::DelegationBelowMin);
+			ensure!(amount <= from_bond, Error::<T>::TransferAmountExceedsDelegation);
+			let remaining = from_bond.saturating_sub(amount);
+			let transfers_all = remaining.is_zero();
+			if !transfers_all {
+				ensure!(remaining >= <MinDelegation<T>>::get(), Error::<T>::DelegationBelowMin);
+			}
+			let to_bond = state.get_bond_amount(&to_candidate);
+			if to_bond.is_none() {
+				ensure!(amount >= <MinDelegation<T>>::get(), Error::<T>::DelegationBelowMin);
+				if !transfers_all {
+					ensure!(
+						(state.delegations.0.len() as u32) < T::MaxDelegationsPerDelegator::get(),
+						Error::<T>::ExceedMaxDelegationsPerDelegator
+					);
+				}
+			}
+
+			let mut from_state =
+				<CandidateInfo<T>>::get(&from_candidate).ok_or(Error::<T>::CandidateDNE)?;
+			let mut to_state =
+				<CandidateInfo<T>>::get(&to_candidate).ok_or(Error::<T>::CandidateDNE)?;
+			ensure!(
+				from_candidate_delegation_count_hint >= from_state.delegation_count,
+				Error::<T>::TooLowCandidateDelegationCountToTransferDelegation
+			);
+			ensure!(
+				to_candidate_delegation_count_hint >= to_state.delegation_count,
+				Error::<T>::TooLowCandidateDelegationCountToDelegate
+			);
+
+			let mut from_auto_compounding_state =
+				<AutoCompoundDelegations<T>>::get_storage(&from_candidate);
+			ensure!(
+				from_auto_compounding_state.len() <=
+					from_candidate_auto_compounding_delegation_count_hint,
+				Error::<T>::TooLowCandidateAutoCompoundingDelegationCountToTransferDelegation,
+			);
+			// a new delegation keeps the auto-compound value of the transferred one
+			let auto_compound = from_auto_compounding_state
+				.get_for_delegator(&delegator)
+				.unwrap_or_else(|| Percent::zero());
+			let compounds_to_candidate = to_bond.is_none() && !auto_compound.is_zero();
+			if compounds_to_candidate {
+				ensure!(
+					<AutoCompoundDelegations<T>>::get_storage(&to_candidate).len() <=
+						to_candidate_auto_compounding_delegation_count_hint,
+					Error::<T>::TooLowCandidateAutoCompoundingDelegationCountToDelegate,
+				);
+			}
+
+			// move the delegation out of the candidate's delegations
+			if transfers_all {
+				from_state.rm_delegation_if_exists::<T>(
+					&from_candidate,
+					delegator.clone(),
+					from_bond,
+				)?;
+			} else {
+				from_state.decrease_delegation::<T>(
+					&from_candidate,
+					delegator.clone(),
+					from_bond,
+					amount,
+				)?;
+			}
+
+			// and into the other candidate's delegations, the delegator's total is unchanged so
+			// the bond lock doesn't need to be adjusted
+			let in_top = if let Some(to_bond) = to_bond {
+				to_state.increase_delegation::<T>(
+					&to_candidate,
+					delegator.clone(),
+					to_bond,
+					amount,
+				)?
+			} else {
+				let (delegator_position, less_total_staked) = to_state.add_delegation::<T>(
+					&to_candidate,
+					Bond { owner: delegator.clone(), amount },
+				)?;
+				// only is_some if kicked the lowest bottom as a consequence of this delegation
+				if let Some(less) = less_total_staked {
+					<Total<T>>::put(<Total<T>>::get().saturating_sub(less));
+				}
+				matches!(delegator_position, DelegatorAdded::AddedToTop { .. })
+			};
+
+			state.delegations.0.retain(|bond| bond.owner != from_candidate);
+			if !transfers_all {
+				state
+					.delegations
+					.insert(Bond { owner: from_candidate.clone(), amount: remaining });
+			}
+			state.delegations.0.retain(|bond| bond.owner != to_candidate);
+			state.delegations.insert(Bond {
+				owner: to_candidate.clone(),
+				amount: to_bond.unwrap_or_else(Zero::zero).saturating_add(amount),
+			});
+
+			if transfers_all && from_auto_compounding_state.remove_for_delegator(&delegator) {
+				from_auto_compounding_state.set_storage(&from_candidate);
+			}
+			// read again, a delegation kicked from the bottom was removed from it meanwhile
+			if compounds_to_candidate {
+				let mut auto_compounding_state =
+					<AutoCompoundDelegations<T>>::get_storage(&to_candidate);
+				auto_compounding_state.set_for_delegator(delegator.clone(), auto_compound);
+				auto_compounding_state.set_storage(&to_candidate);
+			}
+
+			<CandidateInfo<T>>::insert(&from_candidate, from_state);
+			<CandidateInfo<T>>::insert(&to_candidate, to_state);
+			<DelegatorState<T>>::insert(&delegator, state);
+			<LastDelegationTransfer<T>>::insert(&delegator, now);
+			Self::deposit_event(Event::DelegationTransferred {
+				delegator,
+				from_candidate,
+				to_candidate,
+				amount,
+				in_top,
+			});
+
+			Ok(().into())
+		}
+
+		/// Unstakes from a delegation right away, see [`Pallet::instant_unstake`].
+		pub(crate) fn delegation_instant_unstake(
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				!Self::delegation_request_exists(&candidate, &delegator),
+				Error::<T>::PendingDelegationRequestAlreadyExists
+			);
+			let mut state = <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+			let bonded_amount =
+				state.get_bond_amount(&candidate).ok_or(Error::<T>::DelegationDNE)?;
+			ensure!(!amount.is_zero(), Error::<T>::DelegationBelowMin);
+			ensure!(amount <= bonded_amount, Error::<T>::InstantUnstakeExceedsDelegation);
+			let action = if amount == bonded_amount {
+				DelegationAction::Revoke(amount)
+			} else {
+				ensure!(
+					bonded_amount.saturating_sub(amount) >= <MinDelegation<T>>::get(),
+					Error::<T>::DelegationBelowMin
+				);
+				DelegationAction::Decrease(amount)
+			};
+
+			let now = <Round<T>>::get().current;
+			let (round, unstaked) = <InstantUnstaked<T>>::get();
+			let unstaked = if round == now { unstaked } else { Zero::zero() };
+			let unstaked = unstaked.saturating_add(amount);
+			ensure!(
+				unstaked <= <InstantUnstakeCap<T>>::get(),
+				Error::<T>::InstantUnstakeCapExceeded
+			);
+
+			// unstake as a request executable right away, so the same checks and updates apply
+			let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&candidate);
+			scheduled_requests.push(ScheduledRequest {
+				delegator: delegator.clone(),
+				when_executable: now,
+				action,
+			});
+			state.less_total = state.less_total.saturating_add(amount);
+			<DelegationScheduledRequests<T>>::insert(&candidate, scheduled_requests);
+			<DelegatorState<T>>::insert(&delegator, state);
+			Self::delegation_execute_scheduled_request(candidate.clone(), delegator.clone())?;
+
+			// the unstaked amount is no longer locked, so the fee can be withdrawn from it. Locks
+			// which don't restrict fees, like vesting, don't prevent it
+			let fee = <InstantUnstakeFee<T>>::get() * amount;
+			let imbalance = T::Currency::withdraw(
+				&delegator,
+				fee,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			T::OnInstantUnstakeFee::on_unbalanced(imbalance);
+
+			<InstantUnstaked<T>>::put((now, unstaked));
+			Self::deposit_event(Event::InstantUnstaked { delegator, candidate, amount, fee });
+			Ok(().into())
+		}
+
+		/// Credit an amount through the `RewardSource`. A failure is logged and reported with the
+		/// [RewardCreditFailed] event, the amount is not credited then.
+		fn credit_reward(who: &T::AccountId, amount: BalanceOf<T>) -> Option<BalanceOf<T>> {
+			match T::RewardSource::credit(who, amount) {
+				Ok(credited) => Some(credited),
+				Err(error) => {
+					log::error!(
+						target: "parachain-staking",
+						"failed to credit {:?} to {:?}: {:?}",
+						amount,
+						who,
+						error
+					);
+					Self::deposit_event(Event::RewardCreditFailed {
+						account: who.clone(),
+						amount,
+						error,
+					});
+					None
+				},
+			}
+		}
+
+		/// Mint a specified reward amount to the beneficiary account, using the
+		/// `RewardSource`. Emits the [Rewarded] event.
 		pub fn mint(amt: BalanceOf<T>, to: T::AccountId) {
-			if let Ok(amount_transferred) = T::Currency::deposit_into_existing(&to, amt) {
+			if let Some(amount_transferred) = Self::credit_reward(&to, amt) {
 				Self::deposit_event(Event::Rewarded {
 					account: to.clone(),
-					rewards: amount_transferred.peek(),
+					rewards: amount_transferred,
 				});
 			}
 		}
 
-		/// Mint a specified reward amount to the collator's account. Emits the [Rewarded] event.
+		/// Mint a specified reward amount to the collator's account, using the `RewardSource`.
+		/// Emits the [Rewarded] event.
 		pub fn mint_collator_reward(
 			_paid_for_round: RoundIndex,
 			collator_id: T::AccountId,
 			amt: BalanceOf<T>,
 		) -> Weight {
-			if let Ok(amount_transferred) = T::Currency::deposit_into_existing(&collator_id, amt) {
+			if let Some(amount_transferred) = Self::credit_reward(&collator_id, amt) {
 				Self::deposit_event(Event::Rewarded {
 					account: collator_id.clone(),
-					rewards: amount_transferred.peek(),
+					rewards: amount_transferred,
 				});
 			}
 			T::WeightInfo::mint_collator_reward()
@@ -1865,15 +2587,13 @@ pub mod pallet {
 			delegator: T::AccountId,
 		) -> Weight {
 			let mut weight = T::WeightInfo::mint_collator_reward();
-			if let Ok(amount_transferred) =
-				T::Currency::deposit_into_existing(&delegator, amt.clone())
-			{
+			if let Some(amount_transferred) = Self::credit_reward(&delegator, amt.clone()) {
 				Self::deposit_event(Event::Rewarded {
 					account: delegator.clone(),
-					rewards: amount_transferred.peek(),
+					rewards: amount_transferred,
 				});
 
-				let compound_amount = compound_percent.mul_ceil(amount_transferred.peek());
+				let compound_amount = compound_percent.mul_ceil(amount_transferred);
 				if compound_amount.is_zero() {
 					return weight;
 				}
@@ -1914,6 +2634,52 @@ pub mod pallet {
 			<AwardedPts<T>>::insert(now, author, score_plus_20);
 			<Points<T>>::mutate(now, |x| *x = x.saturating_add(20));
 		}
+
+		/// Checks the staking invariants:
+		/// * `TopDelegations` are sorted greatest to least for every candidate
+		/// * the cached delegation totals and `total_counted` match the stored delegations
+		/// * `Total` equals the sum of all candidate bonds and their delegations
+		/// * the active candidates are in the `CandidatePool` with their `total_counted`
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			let mut total = BalanceOf::<T>::zero();
+			for (candidate, info) in <CandidateInfo<T>>::iter() {
+				let top = <TopDelegations<T>>::get(&candidate)
+					.ok_or("candidate is missing its top delegations")?;
+				let bottom = <BottomDelegations<T>>::get(&candidate)
+					.ok_or("candidate is missing its bottom delegations")?;
+
+				ensure!(
+					top.delegations.windows(2).all(|pair| pair[0].amount >= pair[1].amount),
+					"top delegations are not sorted greatest to least"
+				);
+				let sum = |delegations: &Delegations<T::AccountId, BalanceOf<T>>| {
+					delegations
+						.delegations
+						.iter()
+						.fold(BalanceOf::<T>::zero(), |acc, bond| acc.saturating_add(bond.amount))
+				};
+				ensure!(sum(&top) == top.total, "top delegations total mismatch");
+				ensure!(sum(&bottom) == bottom.total, "bottom delegations total mismatch");
+				ensure!(
+					info.total_counted == info.bond.saturating_add(top.total),
+					"candidate total counted is not self bond plus top delegations"
+				);
+				ensure!(
+					CandidatePool::<T>::score(&candidate) ==
+						info.is_active().then_some(info.total_counted),
+					"active candidate is not in the candidate pool with its total counted"
+				);
+
+				total = total
+					.saturating_add(info.bond)
+					.saturating_add(top.total)
+					.saturating_add(bottom.total);
+			}
+			ensure!(total == <Total<T>>::get(), "Total does not match the sum of candidate bonds");
+
+			CandidatePool::<T>::do_try_state()
+		}
 	}
 
 	impl<T: Config> nimbus_primitives::CanAuthor<T::AccountId> for Pallet<T> {
@@ -0,0 +1,271 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Migrations
+//!
+//! Versioned storage migrations for the parachain staking pallet. Each migration checks the
+//! on-chain `StorageVersion` so it is a no-op when the runtime is upgraded more than once.
+
+use crate::{
+	set::OrderedSet, AwardedPts, BalanceOf, Bond, CandidateInfo, Config, DelayedPayouts,
+	DelegationScheduledRequests, Pallet, Points,
+};
+use frame_support::{
+	traits::{Currency, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Migration to storage version 1.
+///
+/// Removes the empty `DelegationScheduledRequests` entries left behind by candidates that have
+/// already exited. This replaces the former `hotfix_remove_delegation_requests_exited_candidates`
+/// call, which required someone to submit the stale keys by hand.
+pub struct RemoveEmptyDelegationRequestsOfExitedCandidates<T>(sp_std::marker::PhantomData<T>);
+
+#[cfg(feature = "try-runtime")]
+impl<T: Config> RemoveEmptyDelegationRequestsOfExitedCandidates<T> {
+	/// Returns the candidates whose scheduled requests entry should be removed.
+	fn stale_candidates() -> Vec<T::AccountId> {
+		<DelegationScheduledRequests<T>>::iter()
+			.filter(|(candidate, requests)| {
+				requests.is_empty() && !<CandidateInfo<T>>::contains_key(candidate)
+			})
+			.map(|(candidate, _)| candidate)
+			.collect()
+	}
+}
+
+impl<T: Config> OnRuntimeUpgrade for RemoveEmptyDelegationRequestsOfExitedCandidates<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 1 {
+			log::info!(
+				target: "parachain-staking",
+				"skipping migration to v1, on-chain storage version is {:?}",
+				on_chain_version
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut reads: u64 = 1;
+		let mut removed: u64 = 0;
+		for (candidate, requests) in <DelegationScheduledRequests<T>>::iter() {
+			reads = reads.saturating_add(2);
+			if requests.is_empty() && !<CandidateInfo<T>>::contains_key(&candidate) {
+				<DelegationScheduledRequests<T>>::remove(&candidate);
+				removed = removed.saturating_add(1);
+			}
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(
+			target: "parachain-staking",
+			"migrated to v1, removed {} stale delegation request entries",
+			removed
+		);
+
+		T::DbWeight::get().reads_writes(reads, removed.saturating_add(1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		use parity_scale_codec::Encode;
+
+		let stale = Self::stale_candidates().len() as u32;
+		log::info!(target: "parachain-staking", "{} stale delegation request entries found", stale);
+		Ok(stale.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() >= 1,
+			"storage version was not bumped to v1"
+		);
+		frame_support::ensure!(
+			Self::stale_candidates().is_empty(),
+			"stale delegation request entries remain after migration"
+		);
+		Ok(())
+	}
+}
+
+/// Migration to storage version 2.
+///
+/// Mints the rewards still due for the rounds already prepared for payout into `Pot`, for runtimes
+/// switching their `RewardSource` from minting to `RewardPot<Pot>`. Those rewards were computed
+/// from the `InflationConfig` and would otherwise be missing from the pot. Collators already paid
+/// for a round have their `AwardedPts` removed, so only the share of the remaining points is
+/// minted.
+pub struct FundRewardPotForDelayedPayouts<T, Pot>(sp_std::marker::PhantomData<(T, Pot)>);
+
+impl<T: Config, Pot> FundRewardPotForDelayedPayouts<T, Pot> {
+	/// Returns the rewards not yet paid out for the delayed payouts, and the number of reads.
+	fn unpaid_rewards() -> (BalanceOf<T>, u64) {
+		let mut reads: u64 = 0;
+		let mut unpaid = BalanceOf::<T>::zero();
+		for (round, payout) in <DelayedPayouts<T>>::iter() {
+			let total_points = <Points<T>>::get(round);
+			reads = reads.saturating_add(2);
+			// Same share as computed by `pay_one_collator_reward` for each collator
+			for points in <AwardedPts<T>>::iter_prefix_values(round) {
+				reads = reads.saturating_add(1);
+				let pct_due = Perbill::from_rational(points, total_points);
+				unpaid = unpaid.saturating_add(pct_due * payout.total_staking_reward);
+			}
+		}
+		(unpaid, reads)
+	}
+}
+
+impl<T: Config, Pot: Get<T::AccountId>> OnRuntimeUpgrade
+	for FundRewardPotForDelayedPayouts<T, Pot>
+{
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 2 {
+			log::info!(
+				target: "parachain-staking",
+				"skipping migration to v2, on-chain storage version is {:?}",
+				on_chain_version
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		let (outstanding, reads) = Self::unpaid_rewards();
+		drop(T::Currency::deposit_creating(&Pot::get(), outstanding));
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		log::info!(
+			target: "parachain-staking",
+			"migrated to v2, funded reward pot with {:?} for delayed payouts",
+			outstanding
+		);
+
+		T::DbWeight::get().reads_writes(reads.saturating_add(2), 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		use parity_scale_codec::Encode;
+
+		let (unpaid, _) = Self::unpaid_rewards();
+		Ok((T::Currency::free_balance(&Pot::get()), unpaid).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		use parity_scale_codec::Decode;
+
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() >= 2,
+			"storage version was not bumped to v2"
+		);
+		let (balance_before, unpaid) = <(BalanceOf<T>, BalanceOf<T>)>::decode(&mut &state[..])
+			.map_err(|_| "reward pot balance can't be decoded")?;
+		frame_support::ensure!(
+			T::Currency::free_balance(&Pot::get()) == balance_before.saturating_add(unpaid),
+			"reward pot was not funded with the unpaid rewards"
+		);
+		Ok(())
+	}
+}
+
+/// Migration to storage version 3.
+///
+/// Moves the candidates of the former `CandidatePool` ordered set into the bags-list
+/// [`CandidatePool`](crate::CandidatePool), whose bags are set by `CandidateBagThresholds`.
+pub struct MigrateCandidatePoolToBagsList<T>(sp_std::marker::PhantomData<T>);
+
+/// The former pool of candidates, read and written in full on every change.
+#[frame_support::storage_alias]
+pub(crate) type CandidatePool<T: Config> = StorageValue<
+	Pallet<T>,
+	OrderedSet<Bond<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
+	ValueQuery,
+>;
+
+impl<T: Config> OnRuntimeUpgrade for MigrateCandidatePoolToBagsList<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 3 {
+			log::info!(
+				target: "parachain-staking",
+				"skipping migration to v3, on-chain storage version is {:?}",
+				on_chain_version
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		let candidates = <CandidatePool<T>>::take().0;
+		let count = candidates.len() as u64;
+		for Bond { owner, amount } in candidates {
+			crate::CandidatePool::<T>::insert(owner, amount);
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+		log::info!(
+			target: "parachain-staking",
+			"migrated to v3, moved {} candidates to the candidate bags",
+			count
+		);
+
+		// each insertion reads and writes the candidate, the tail of its bag, the bag and the count
+		T::DbWeight::get().reads_writes(
+			count.saturating_mul(4).saturating_add(2),
+			count.saturating_mul(4).saturating_add(2),
+		)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		use parity_scale_codec::Encode;
+
+		Ok(<CandidatePool<T>>::get().0.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		use parity_scale_codec::Decode;
+
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() >= 3,
+			"storage version was not bumped to v3"
+		);
+		frame_support::ensure!(!<CandidatePool<T>>::exists(), "former candidate pool remains");
+		let candidates: Vec<Bond<T::AccountId, BalanceOf<T>>> = Decode::decode(&mut &state[..])
+			.map_err(|_| "former candidate pool can't be decoded")?;
+		frame_support::ensure!(
+			crate::CandidatePool::<T>::count() as usize == candidates.len(),
+			"candidate count does not match the former candidate pool"
+		);
+		for Bond { owner, amount } in candidates {
+			frame_support::ensure!(
+				crate::CandidatePool::<T>::score(&owner) == Some(amount),
+				"candidate is missing from the candidate bags"
+			);
+		}
+		Ok(())
+	}
+}
@@ -114,6 +114,7 @@ parameter_types! {
 	pub const LeaveDelegatorsDelay: u32 = 2;
 	pub const RevokeDelegationDelay: u32 = 2;
 	pub const DelegationBondLessDelay: u32 = 2;
+	pub const DelegationTransferDelay: u32 = 2;
 	pub const RewardPaymentDelay: u32 = 2;
 	pub const MinSelectedCandidates: u32 = GENESIS_NUM_SELECTED_CANDIDATES;
 	pub const MaxTopDelegationsPerCandidate: u32 = 4;
@@ -122,6 +123,14 @@ parameter_types! {
 	pub const MinCollatorStk: u128 = 10;
 	pub const MinDelegatorStk: u128 = 5;
 	pub const MinDelegation: u128 = 3;
+	pub const MaxDelay: u32 = 20;
+	pub const MaxMinCollatorStk: u128 = 100;
+	pub const MaxMinDelegatorStk: u128 = 50;
+	pub const MaxMinDelegation: u128 = 30;
+	pub const MaxInstantUnstakeFee: Perbill = Perbill::from_percent(50);
+	pub const MaxInstantUnstakeCap: u128 = 1_000;
+	pub const CandidateBagThresholds: &'static [u128] =
+		&[10, 20, 30, 40, 50, 60, 70, 80, 90, 100, 200, 500, 1_000];
 }
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -132,7 +141,12 @@ impl Config for Test {
 	type CandidateBondLessDelay = CandidateBondLessDelay;
 	type LeaveDelegatorsDelay = LeaveDelegatorsDelay;
 	type RevokeDelegationDelay = RevokeDelegationDelay;
+	type MaxLeaveCandidatesDelay = MaxDelay;
+	type MaxCandidateBondLessDelay = MaxDelay;
+	type MaxLeaveDelegatorsDelay = MaxDelay;
+	type MaxRevokeDelegationDelay = MaxDelay;
 	type DelegationBondLessDelay = DelegationBondLessDelay;
+	type DelegationTransferDelay = DelegationTransferDelay;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type MinSelectedCandidates = MinSelectedCandidates;
 	type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
@@ -142,9 +156,18 @@ impl Config for Test {
 	type MinCandidateStk = MinCollatorStk;
 	type MinDelegatorStk = MinDelegatorStk;
 	type MinDelegation = MinDelegation;
+	type MaxMinCollatorStk = MaxMinCollatorStk;
+	type MaxMinCandidateStk = MaxMinCollatorStk;
+	type MaxMinDelegatorStk = MaxMinDelegatorStk;
+	type MaxMinDelegation = MaxMinDelegation;
+	type CandidateBagThresholds = CandidateBagThresholds;
 	type BlockAuthor = BlockAuthor;
 	type OnCollatorPayout = ();
 	type PayoutCollatorReward = ();
+	type RewardSource = ();
+	type OnInstantUnstakeFee = ();
+	type MaxInstantUnstakeFee = MaxInstantUnstakeFee;
+	type MaxInstantUnstakeCap = MaxInstantUnstakeCap;
 	type OnNewRound = ();
 	type WeightInfo = ();
 }
@@ -28,15 +28,29 @@ use crate::{
 	assert_events_emitted, assert_events_emitted_match, assert_events_eq, assert_no_events,
 	auto_compound::{AutoCompoundConfig, AutoCompoundDelegations},
 	delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest},
+	migrations::{
+		CandidatePool as OldCandidatePool, FundRewardPotForDelayedPayouts,
+		MigrateCandidatePoolToBagsList, RemoveEmptyDelegationRequestsOfExitedCandidates,
+	},
 	mock::{
-		roll_blocks, roll_to, roll_to_round_begin, roll_to_round_end, set_author, Balances,
-		BlockNumber, ExtBuilder, ParachainStaking, RuntimeOrigin, Test,
+		roll_blocks, roll_to, roll_to_round_begin, roll_to_round_end, set_author, AccountId,
+		Balances, BlockNumber, ExtBuilder, ParachainStaking, RuntimeOrigin, Test,
+	},
+	set::OrderedSet,
+	AtStake, AwardedPts, Bond, CandidateBags, CandidatePool, CandidateSummary, CollatorStatus,
+	DelayedPayout, DelayedPayouts, DelegationScheduledRequests, DelegationSummary, Delegations,
+	DelegatorAdded, DelegatorState, DelegatorStatus, DelegatorSummary, Error, Event,
+	PendingDelegationRequest, Points, Range, RewardPot, StakingParameter, StakingRewardSource,
+	DELEGATOR_LOCK_ID,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		tokens::WithdrawReasons, Get, GetStorageVersion, LockableCurrency, OnRuntimeUpgrade,
+		StorageVersion,
 	},
-	AtStake, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded, DelegatorState,
-	DelegatorStatus, Error, Event, Range, DELEGATOR_LOCK_ID,
 };
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::{traits::Zero, DispatchError, ModuleError, Perbill, Percent};
+use sp_runtime::{traits::Zero, DispatchError, FixedU128, ModuleError, Perbill, Percent};
 
 // ~~ ROOT ~~
 
@@ -335,6 +349,265 @@ fn invalid_monetary_origin_fails() {
 	});
 }
 
+// SET STAKING PARAMETER
+
+#[test]
+fn set_staking_parameter_event_emits_correctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ParachainStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::MinDelegation(4)
+		));
+		assert_events_eq!(Event::StakingParameterSet {
+			old: StakingParameter::MinDelegation(3),
+			new: StakingParameter::MinDelegation(4),
+		});
+		assert_eq!(ParachainStaking::min_delegation(), 4);
+	});
+}
+
+#[test]
+fn set_staking_parameter_fails_if_not_monetary_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::signed(45),
+				StakingParameter::MinDelegation(4)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_staking_parameter_is_bounded_by_config() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MinCollatorStk(9)
+			),
+			Error::<Test>::CannotSetBelowMin
+		);
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::LeaveDelegatorsDelay(1)
+			),
+			Error::<Test>::CannotSetBelowMin
+		);
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MaxTopDelegationsPerCandidate(0)
+			),
+			Error::<Test>::CannotSetBelowMin
+		);
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MaxTopDelegationsPerCandidate(5)
+			),
+			Error::<Test>::CannotSetAboveMax
+		);
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MinCollatorStk(101)
+			),
+			Error::<Test>::CannotSetAboveMax
+		);
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::LeaveDelegatorsDelay(21)
+			),
+			Error::<Test>::CannotSetAboveMax
+		);
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeFee(Perbill::from_percent(51))
+			),
+			Error::<Test>::CannotSetAboveMax
+		);
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeCap(1_001)
+			),
+			Error::<Test>::CannotSetAboveMax
+		);
+		assert_noop!(
+			ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MinDelegatorStk(5)
+			),
+			Error::<Test>::NoWritingSameValue
+		);
+	});
+}
+
+#[test]
+fn raised_min_delegation_applies_to_new_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 30)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MinDelegation(15)
+			));
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MinDelegatorStk(15)
+			));
+			assert_noop!(
+				ParachainStaking::delegate(RuntimeOrigin::signed(3), 1, 10, 1, 0),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_ok!(ParachainStaking::delegate(RuntimeOrigin::signed(3), 1, 15, 1, 0));
+			// existing delegations are kept
+			assert_eq!(ParachainStaking::delegator_state(2).unwrap().total(), 10);
+		});
+}
+
+#[test]
+fn raised_min_candidate_stk_applies_to_new_candidates() {
+	ExtBuilder::default().with_balances(vec![(1, 30)]).build().execute_with(|| {
+		assert_ok!(ParachainStaking::set_staking_parameter(
+			RuntimeOrigin::root(),
+			StakingParameter::MinCandidateStk(20)
+		));
+		assert_noop!(
+			ParachainStaking::join_candidates(RuntimeOrigin::signed(1), 10u128, 0u32),
+			Error::<Test>::CandidateBondBelowMin
+		);
+		assert_ok!(ParachainStaking::join_candidates(RuntimeOrigin::signed(1), 20u128, 0u32));
+	});
+}
+
+#[test]
+fn raised_min_collator_stk_applies_to_selection() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30)])
+		.with_candidates(vec![(1, 30), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MinCollatorStk(25)
+			));
+			assert_eq!(ParachainStaking::compute_top_candidates(), vec![1]);
+		});
+}
+
+#[test]
+fn raised_revoke_delegation_delay_applies_to_new_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 15)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 15)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::RevokeDelegationDelay(5)
+			));
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			assert_eq!(
+				ParachainStaking::delegation_scheduled_requests(&1),
+				vec![ScheduledRequest {
+					delegator: 2,
+					when_executable: 6,
+					action: DelegationAction::Decrease(5),
+				}],
+			);
+		});
+}
+
+#[test]
+fn raised_leave_candidates_delay_applies_to_new_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30)])
+		.with_candidates(vec![(1, 30)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::LeaveCandidatesDelay(4)
+			));
+			assert_ok!(ParachainStaking::schedule_leave_candidates(RuntimeOrigin::signed(1), 1));
+			assert_eq!(
+				ParachainStaking::candidate_info(1).unwrap().status,
+				CollatorStatus::Leaving(5)
+			);
+		});
+}
+
+#[test]
+fn lowered_max_top_delegations_applies_as_top_delegations_change() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 20), (4, 1, 30), (5, 1, 40)])
+		.build()
+		.execute_with(|| {
+			let owners = |delegations: Option<Delegations<AccountId, u128>>| {
+				delegations
+					.unwrap()
+					.delegations
+					.into_iter()
+					.map(|d| d.owner)
+					.collect::<Vec<_>>()
+			};
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MaxTopDelegationsPerCandidate(2)
+			));
+
+			// the lowest top delegation is bumped to the bottom, the others are kept
+			assert_ok!(ParachainStaking::delegate(RuntimeOrigin::signed(6), 1, 50, 4, 0));
+			assert_eq!(owners(ParachainStaking::top_delegations(1)), vec![6, 5, 4, 3]);
+			assert_eq!(owners(ParachainStaking::bottom_delegations(1)), vec![2]);
+
+			// bottom delegations are not promoted while top delegations are above the maximum
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(5), 1));
+			roll_to(10);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(5),
+				5,
+				1
+			));
+			assert_eq!(owners(ParachainStaking::top_delegations(1)), vec![6, 4, 3]);
+			assert_eq!(owners(ParachainStaking::bottom_delegations(1)), vec![2]);
+			assert_ok!(ParachainStaking::do_try_state());
+
+			// once raised, bottom delegations are promoted as top delegations leave
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::MaxTopDelegationsPerCandidate(4)
+			));
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(4), 1));
+			roll_to(20);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(4),
+				4,
+				1
+			));
+			assert_eq!(owners(ParachainStaking::top_delegations(1)), vec![6, 3, 2]);
+			assert!(owners(ParachainStaking::bottom_delegations(1)).is_empty());
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().total_counted, 100);
+			assert_ok!(ParachainStaking::do_try_state());
+		});
+}
+
 // SET STAKING EXPECTATIONS
 
 #[test]
@@ -601,11 +874,11 @@ fn join_candidates_creates_candidate_state() {
 #[test]
 fn join_candidates_adds_to_candidate_pool() {
 	ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
-		assert!(ParachainStaking::candidate_pool().0.is_empty());
+		assert!(ParachainStaking::candidate_pool().is_empty());
 		assert_ok!(ParachainStaking::join_candidates(RuntimeOrigin::signed(1), 10u128, 0u32));
 		let candidate_pool = ParachainStaking::candidate_pool();
-		assert_eq!(candidate_pool.0[0].owner, 1);
-		assert_eq!(candidate_pool.0[0].amount, 10);
+		assert_eq!(candidate_pool[0].owner, 1);
+		assert_eq!(candidate_pool[0].amount, 10);
 	});
 }
 
@@ -728,9 +1001,9 @@ fn leave_candidates_removes_candidate_from_candidate_pool() {
 		.with_candidates(vec![(1, 10)])
 		.build()
 		.execute_with(|| {
-			assert_eq!(ParachainStaking::candidate_pool().0.len(), 1);
+			assert_eq!(ParachainStaking::candidate_pool().len(), 1);
 			assert_ok!(ParachainStaking::schedule_leave_candidates(RuntimeOrigin::signed(1), 1u32));
-			assert!(ParachainStaking::candidate_pool().0.is_empty());
+			assert!(ParachainStaking::candidate_pool().is_empty());
 		});
 }
 
@@ -998,8 +1271,8 @@ fn cancel_leave_candidates_adds_to_candidate_pool() {
 		.execute_with(|| {
 			assert_ok!(ParachainStaking::schedule_leave_candidates(RuntimeOrigin::signed(1), 1u32));
 			assert_ok!(ParachainStaking::cancel_leave_candidates(RuntimeOrigin::signed(1), 1));
-			assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-			assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 10);
+			assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+			assert_eq!(ParachainStaking::candidate_pool()[0].amount, 10);
 		});
 }
 
@@ -1024,9 +1297,9 @@ fn go_offline_removes_candidate_from_candidate_pool() {
 		.with_candidates(vec![(1, 20)])
 		.build()
 		.execute_with(|| {
-			assert_eq!(ParachainStaking::candidate_pool().0.len(), 1);
+			assert_eq!(ParachainStaking::candidate_pool().len(), 1);
 			assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(1)));
-			assert!(ParachainStaking::candidate_pool().0.is_empty());
+			assert!(ParachainStaking::candidate_pool().is_empty());
 		});
 }
 
@@ -1095,10 +1368,10 @@ fn go_online_adds_to_candidate_pool() {
 		.build()
 		.execute_with(|| {
 			assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(1)));
-			assert!(ParachainStaking::candidate_pool().0.is_empty());
+			assert!(ParachainStaking::candidate_pool().is_empty());
 			assert_ok!(ParachainStaking::go_online(RuntimeOrigin::signed(1)));
-			assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-			assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 20);
+			assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+			assert_eq!(ParachainStaking::candidate_pool()[0].amount, 20);
 		});
 }
 
@@ -1225,11 +1498,11 @@ fn candidate_bond_more_updates_candidate_pool() {
 		.with_candidates(vec![(1, 20)])
 		.build()
 		.execute_with(|| {
-			assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-			assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 20);
+			assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+			assert_eq!(ParachainStaking::candidate_pool()[0].amount, 20);
 			assert_ok!(ParachainStaking::candidate_bond_more(RuntimeOrigin::signed(1), 30));
-			assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-			assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 50);
+			assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+			assert_eq!(ParachainStaking::candidate_pool()[0].amount, 50);
 		});
 }
 
@@ -1413,16 +1686,16 @@ fn execute_candidate_bond_less_updates_candidate_pool() {
 		.with_candidates(vec![(1, 30)])
 		.build()
 		.execute_with(|| {
-			assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-			assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 30);
+			assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+			assert_eq!(ParachainStaking::candidate_pool()[0].amount, 30);
 			assert_ok!(ParachainStaking::schedule_candidate_bond_less(
 				RuntimeOrigin::signed(1),
 				10
 			));
 			roll_to(10);
 			assert_ok!(ParachainStaking::execute_candidate_bond_less(RuntimeOrigin::signed(1), 1));
-			assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-			assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 20);
+			assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+			assert_eq!(ParachainStaking::candidate_pool()[0].amount, 20);
 		});
 }
 
@@ -1515,6 +1788,27 @@ fn delegate_reserves_balance() {
 		});
 }
 
+#[test]
+fn delegate_with_vesting_locked_balance() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10)])
+		.with_candidates(vec![(1, 30)])
+		.build()
+		.execute_with(|| {
+			// Same restrictions as the lock set by pallet vesting on unvested funds.
+			Balances::set_lock(
+				*b"vesting ",
+				&2,
+				8,
+				WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE),
+			);
+			assert_eq!(ParachainStaking::get_delegator_stakable_free_balance(&2), 10);
+			assert_ok!(ParachainStaking::delegate(RuntimeOrigin::signed(2), 1, 10, 0, 0));
+			assert_eq!(ParachainStaking::get_delegator_stakable_free_balance(&2), 0);
+			assert_eq!(ParachainStaking::delegator_state(2).map(|state| state.total()), Some(10));
+		});
+}
+
 #[test]
 fn delegate_updates_delegator_state() {
 	ExtBuilder::default()
@@ -4468,7 +4762,7 @@ fn candidate_pool_updates_when_total_counted_changes() {
 		.execute_with(|| {
 			fn is_candidate_pool_bond(account: u64, bond: u128) {
 				let pool = ParachainStaking::candidate_pool();
-				for candidate in pool.0 {
+				for candidate in pool {
 					if candidate.owner == account {
 						assert_eq!(
 							candidate.amount, bond,
@@ -5648,12 +5942,13 @@ fn test_delegation_request_revoke_exists_returns_true_when_revoke_exists() {
 }
 
 #[test]
-fn test_hotfix_remove_delegation_requests_exited_candidates_cleans_up() {
+fn migration_to_v1_removes_empty_delegation_requests_of_exited_candidates() {
 	ExtBuilder::default()
 		.with_balances(vec![(1, 20)])
 		.with_candidates(vec![(1, 20)])
 		.build()
 		.execute_with(|| {
+			StorageVersion::new(0).put::<ParachainStaking>();
 			// invalid state
 			<DelegationScheduledRequests<Test>>::insert(
 				2,
@@ -5663,134 +5958,218 @@ fn test_hotfix_remove_delegation_requests_exited_candidates_cleans_up() {
 				3,
 				Vec::<ScheduledRequest<u64, u128>>::new(),
 			);
-			assert_ok!(ParachainStaking::hotfix_remove_delegation_requests_exited_candidates(
-				RuntimeOrigin::signed(1),
-				vec![2, 3, 4] // 4 does not exist, but is OK for idempotency
-			));
+
+			RemoveEmptyDelegationRequestsOfExitedCandidates::<Test>::on_runtime_upgrade();
 
 			assert!(!<DelegationScheduledRequests<Test>>::contains_key(2));
 			assert!(!<DelegationScheduledRequests<Test>>::contains_key(3));
+			assert_eq!(ParachainStaking::on_chain_storage_version(), 1);
 		});
 }
 
 #[test]
-fn test_hotfix_remove_delegation_requests_exited_candidates_cleans_up_only_specified_keys() {
+fn migration_to_v1_keeps_requests_of_active_candidates_and_non_empty_entries() {
 	ExtBuilder::default()
 		.with_balances(vec![(1, 20)])
 		.with_candidates(vec![(1, 20)])
 		.build()
 		.execute_with(|| {
-			// invalid state
+			StorageVersion::new(0).put::<ParachainStaking>();
 			<DelegationScheduledRequests<Test>>::insert(
-				2,
+				1,
 				Vec::<ScheduledRequest<u64, u128>>::new(),
 			);
 			<DelegationScheduledRequests<Test>>::insert(
 				3,
-				Vec::<ScheduledRequest<u64, u128>>::new(),
+				vec![ScheduledRequest {
+					delegator: 10,
+					when_executable: 1,
+					action: DelegationAction::Revoke(10),
+				}],
 			);
-			assert_ok!(ParachainStaking::hotfix_remove_delegation_requests_exited_candidates(
-				RuntimeOrigin::signed(1),
-				vec![2]
-			));
 
-			assert!(!<DelegationScheduledRequests<Test>>::contains_key(2));
+			RemoveEmptyDelegationRequestsOfExitedCandidates::<Test>::on_runtime_upgrade();
+
+			assert!(<DelegationScheduledRequests<Test>>::contains_key(1));
 			assert!(<DelegationScheduledRequests<Test>>::contains_key(3));
 		});
 }
 
 #[test]
-fn test_hotfix_remove_delegation_requests_exited_candidates_errors_when_requests_not_empty() {
+fn migration_to_v1_is_skipped_when_already_migrated() {
 	ExtBuilder::default()
 		.with_balances(vec![(1, 20)])
 		.with_candidates(vec![(1, 20)])
 		.build()
 		.execute_with(|| {
-			// invalid state
+			assert_eq!(ParachainStaking::on_chain_storage_version(), 3);
 			<DelegationScheduledRequests<Test>>::insert(
 				2,
 				Vec::<ScheduledRequest<u64, u128>>::new(),
 			);
-			<DelegationScheduledRequests<Test>>::insert(
-				3,
-				vec![ScheduledRequest {
-					delegator: 10,
-					when_executable: 1,
-					action: DelegationAction::Revoke(10),
-				}],
-			);
 
-			assert_noop!(
-				ParachainStaking::hotfix_remove_delegation_requests_exited_candidates(
-					RuntimeOrigin::signed(1),
-					vec![2, 3]
-				),
-				<Error<Test>>::CandidateNotLeaving,
-			);
+			RemoveEmptyDelegationRequestsOfExitedCandidates::<Test>::on_runtime_upgrade();
+
+			assert!(<DelegationScheduledRequests<Test>>::contains_key(2));
 		});
 }
 
 #[test]
-fn test_hotfix_remove_delegation_requests_exited_candidates_errors_when_candidate_not_exited() {
+fn migration_to_v2_funds_reward_pot_for_delayed_payouts() {
+	struct Pot;
+	impl Get<AccountId> for Pot {
+		fn get() -> AccountId {
+			7
+		}
+	}
+
 	ExtBuilder::default()
 		.with_balances(vec![(1, 20)])
 		.with_candidates(vec![(1, 20)])
 		.build()
 		.execute_with(|| {
-			// invalid state
-			<DelegationScheduledRequests<Test>>::insert(
-				1,
-				Vec::<ScheduledRequest<u64, u128>>::new(),
-			);
-			assert_noop!(
-				ParachainStaking::hotfix_remove_delegation_requests_exited_candidates(
-					RuntimeOrigin::signed(1),
-					vec![1]
-				),
-				<Error<Test>>::CandidateNotLeaving,
-			);
+			StorageVersion::new(1).put::<ParachainStaking>();
+			for (round, total_staking_reward) in [(1, 30), (2, 40)] {
+				<DelayedPayouts<Test>>::insert(
+					round,
+					DelayedPayout {
+						round_issuance: total_staking_reward + 10,
+						total_staking_reward,
+						collator_commission: Perbill::from_percent(20),
+					},
+				);
+			}
+			// Round 1 is half paid out, the collator with the other 10 points is still due
+			<Points<Test>>::insert(1, 20);
+			<AwardedPts<Test>>::insert(1, 1, 10);
+			<Points<Test>>::insert(2, 40);
+			<AwardedPts<Test>>::insert(2, 1, 20);
+			<AwardedPts<Test>>::insert(2, 2, 20);
+
+			FundRewardPotForDelayedPayouts::<Test, Pot>::on_runtime_upgrade();
+
+			assert_eq!(Balances::free_balance(&7), 55);
+			assert_eq!(ParachainStaking::on_chain_storage_version(), 2);
+
+			// Runs only once
+			FundRewardPotForDelayedPayouts::<Test, Pot>::on_runtime_upgrade();
+			assert_eq!(Balances::free_balance(&7), 55);
 		});
 }
 
 #[test]
-fn locking_zero_amount_is_ignored() {
-	use frame_support::traits::{LockableCurrency, WithdrawReasons};
-
-	// this test demonstrates the behavior of pallet Balance's `LockableCurrency` implementation of
-	// `set_locks()` when an amount of 0 is provided: it is a no-op
-
-	ExtBuilder::default().with_balances(vec![(1, 100)]).build().execute_with(|| {
-		assert_eq!(crate::mock::query_lock_amount(1, DELEGATOR_LOCK_ID), None);
+fn migration_to_v3_moves_candidate_pool_to_bags() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(2).put::<ParachainStaking>();
+		let mut candidates = OrderedSet::new();
+		for (owner, amount) in [(1, 15), (2, 45), (3, 42), (4, 600)] {
+			candidates.insert(Bond { owner, amount });
+		}
+		OldCandidatePool::<Test>::put(candidates);
 
-		Balances::set_lock(DELEGATOR_LOCK_ID, &1, 1, WithdrawReasons::all());
-		assert_eq!(crate::mock::query_lock_amount(1, DELEGATOR_LOCK_ID), Some(1));
+		MigrateCandidatePoolToBagsList::<Test>::on_runtime_upgrade();
 
-		Balances::set_lock(DELEGATOR_LOCK_ID, &1, 0, WithdrawReasons::all());
-		// Note that we tried to call `set_lock(0)` and it ignored it, we still have our lock
-		assert_eq!(crate::mock::query_lock_amount(1, DELEGATOR_LOCK_ID), Some(1));
+		assert!(!OldCandidatePool::<Test>::exists());
+		assert_eq!(ParachainStaking::candidate_pool_count(), 4);
+		assert_eq!(CandidatePool::<Test>::score(&2), Some(45));
+		assert_eq!(CandidatePool::<Test>::score(&4), Some(600));
+		assert_eq!(
+			ParachainStaking::candidate_pool(),
+			vec![
+				Bond { owner: 4, amount: 600 },
+				Bond { owner: 2, amount: 45 },
+				Bond { owner: 3, amount: 42 },
+				Bond { owner: 1, amount: 15 },
+			]
+		);
+		assert_eq!(ParachainStaking::on_chain_storage_version(), 3);
+		assert_ok!(ParachainStaking::do_try_state());
+
+		// Runs only once
+		OldCandidatePool::<Test>::put(OrderedSet::from(vec![Bond { owner: 5, amount: 20 }]));
+		MigrateCandidatePoolToBagsList::<Test>::on_runtime_upgrade();
+		assert_eq!(ParachainStaking::candidate_pool_count(), 4);
+		assert!(!CandidatePool::<Test>::contains(&5));
 	});
 }
 
 #[test]
-fn revoke_last_removes_lock() {
+fn try_state_holds_after_delegation_changes() {
 	ExtBuilder::default()
-		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
-		.with_candidates(vec![(1, 25), (2, 25)])
-		.with_delegations(vec![(3, 1, 30), (3, 2, 25)])
+		.with_balances(vec![(1, 30), (2, 30), (3, 30), (4, 30)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 20)])
 		.build()
 		.execute_with(|| {
-			assert_eq!(crate::mock::query_lock_amount(3, DELEGATOR_LOCK_ID), Some(55));
-
-			// schedule and remove one...
+			assert_ok!(ParachainStaking::do_try_state());
+			assert_ok!(ParachainStaking::delegate(RuntimeOrigin::signed(4), 1, 15, 2, 0));
+			assert_ok!(ParachainStaking::delegator_bond_more(RuntimeOrigin::signed(2), 1, 10));
 			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(3), 1));
-			roll_to_round_begin(3);
+			roll_to(30);
 			assert_ok!(ParachainStaking::execute_delegation_request(
 				RuntimeOrigin::signed(3),
 				3,
 				1
 			));
-			assert_eq!(crate::mock::query_lock_amount(3, DELEGATOR_LOCK_ID), Some(25));
-
+			assert_ok!(ParachainStaking::do_try_state());
+		});
+}
+
+#[test]
+fn try_state_detects_total_mismatch() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			crate::Total::<Test>::put(1);
+			assert_eq!(
+				ParachainStaking::do_try_state(),
+				Err("Total does not match the sum of candidate bonds")
+			);
+		});
+}
+
+#[test]
+fn locking_zero_amount_is_ignored() {
+	use frame_support::traits::{LockableCurrency, WithdrawReasons};
+
+	// this test demonstrates the behavior of pallet Balance's `LockableCurrency` implementation of
+	// `set_locks()` when an amount of 0 is provided: it is a no-op
+
+	ExtBuilder::default().with_balances(vec![(1, 100)]).build().execute_with(|| {
+		assert_eq!(crate::mock::query_lock_amount(1, DELEGATOR_LOCK_ID), None);
+
+		Balances::set_lock(DELEGATOR_LOCK_ID, &1, 1, WithdrawReasons::all());
+		assert_eq!(crate::mock::query_lock_amount(1, DELEGATOR_LOCK_ID), Some(1));
+
+		Balances::set_lock(DELEGATOR_LOCK_ID, &1, 0, WithdrawReasons::all());
+		// Note that we tried to call `set_lock(0)` and it ignored it, we still have our lock
+		assert_eq!(crate::mock::query_lock_amount(1, DELEGATOR_LOCK_ID), Some(1));
+	});
+}
+
+#[test]
+fn revoke_last_removes_lock() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 25), (2, 25)])
+		.with_delegations(vec![(3, 1, 30), (3, 2, 25)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(crate::mock::query_lock_amount(3, DELEGATOR_LOCK_ID), Some(55));
+
+			// schedule and remove one...
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(3), 1));
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(3),
+				3,
+				1
+			));
+			assert_eq!(crate::mock::query_lock_amount(3, DELEGATOR_LOCK_ID), Some(25));
+
 			// schedule and remove the other...
 			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(3), 2));
 			roll_to_round_begin(5);
@@ -6929,9 +7308,831 @@ fn test_compute_top_candidates_is_stable() {
 		.build()
 		.execute_with(|| {
 			// There are 6 candidates with equal amount, but only 5 can be selected
-			assert_eq!(ParachainStaking::candidate_pool().0.len(), 6);
+			assert_eq!(ParachainStaking::candidate_pool().len(), 6);
 			assert_eq!(ParachainStaking::total_selected(), 5);
 			// Returns the 5 candidates with greater AccountId, because they are iterated in reverse
 			assert_eq!(ParachainStaking::compute_top_candidates(), vec![2, 3, 4, 5, 6]);
 		});
 }
+
+#[test]
+fn reward_pot_pays_rewards_out_of_its_balance() {
+	struct Pot;
+	impl Get<AccountId> for Pot {
+		fn get() -> AccountId {
+			1
+		}
+	}
+	struct Inflow;
+	impl Get<u128> for Inflow {
+		fn get() -> u128 {
+			3
+		}
+	}
+	type Source = RewardPot<Pot, Inflow>;
+
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 10)])
+		.build()
+		.execute_with(|| {
+			// Everything above the existential deposit is distributed, regardless of the stake
+			assert_eq!(<Source as StakingRewardSource<Test>>::round_issuance(0), 99);
+			assert_eq!(<Source as StakingRewardSource<Test>>::round_issuance(1_000), 99);
+
+			assert_eq!(<Source as StakingRewardSource<Test>>::credit(&2, 40), Ok(40));
+			assert_eq!(Balances::free_balance(&1), 60);
+			assert_eq!(Balances::free_balance(&2), 50);
+			assert_eq!(<Source as StakingRewardSource<Test>>::round_issuance(0), 59);
+
+			// Pot is kept alive
+			assert!(<Source as StakingRewardSource<Test>>::credit(&2, 60).is_err());
+			assert_eq!(Balances::free_balance(&1), 60);
+
+			// Projections use the inflow over a round of 5 blocks, not the current balance
+			assert_eq!(<Source as StakingRewardSource<Test>>::expected_round_issuance(0), 15);
+		});
+}
+
+#[test]
+fn failed_reward_credit_is_reported() {
+	ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
+		// Minting into a non-existent account fails with the default source
+		ParachainStaking::mint(5, 9);
+		assert_events_eq!(Event::RewardCreditFailed {
+			account: 9,
+			amount: 5,
+			error: pallet_balances::Error::<Test>::DeadAccount.into(),
+		});
+		assert_eq!(Balances::free_balance(&9), 0);
+	});
+}
+
+// TRANSFER DELEGATION
+
+#[test]
+fn transfer_delegation_moves_the_whole_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 25)])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::transfer_delegation(
+				RuntimeOrigin::signed(3),
+				1,
+				2,
+				10,
+				0,
+				0,
+				1,
+				1
+			));
+			assert_events_emitted!(Event::DelegationTransferred {
+				delegator: 3,
+				from_candidate: 1,
+				to_candidate: 2,
+				amount: 10,
+				in_top: true,
+			});
+			let state = ParachainStaking::delegator_state(3).expect("delegator exists");
+			assert_eq!(state.get_bond_amount(&1), None);
+			assert_eq!(state.get_bond_amount(&2), Some(10));
+			assert_eq!(state.total(), 10);
+			let from = ParachainStaking::candidate_info(1).expect("candidate exists");
+			let to = ParachainStaking::candidate_info(2).expect("candidate exists");
+			assert_eq!((from.delegation_count, from.total_counted), (0, 30));
+			assert_eq!((to.delegation_count, to.total_counted), (1, 40));
+			assert_eq!(crate::mock::query_lock_amount(3, DELEGATOR_LOCK_ID), Some(10));
+			assert_eq!(ParachainStaking::total(), 70);
+			assert_ok!(ParachainStaking::do_try_state());
+		});
+}
+
+#[test]
+fn transfer_delegation_moves_part_of_the_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 25)])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_delegations(vec![(3, 1, 10), (3, 2, 5)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::transfer_delegation(
+				RuntimeOrigin::signed(3),
+				1,
+				2,
+				4,
+				1,
+				0,
+				1,
+				1
+			));
+			let state = ParachainStaking::delegator_state(3).expect("delegator exists");
+			assert_eq!(state.get_bond_amount(&1), Some(6));
+			assert_eq!(state.get_bond_amount(&2), Some(9));
+			assert_eq!(state.total(), 15);
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().total_counted, 36);
+			assert_eq!(ParachainStaking::candidate_info(2).unwrap().total_counted, 39);
+			assert_eq!(crate::mock::query_lock_amount(3, DELEGATOR_LOCK_ID), Some(15));
+			assert_ok!(ParachainStaking::do_try_state());
+		});
+}
+
+#[test]
+fn transfer_delegation_keeps_the_auto_compound_config() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 25)])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_auto_compounding_delegations(vec![(3, 1, 10, Percent::from_percent(50))])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::transfer_delegation(
+				RuntimeOrigin::signed(3),
+				1,
+				2,
+				10,
+				0,
+				0,
+				1,
+				1
+			));
+			assert!(ParachainStaking::auto_compounding_delegations(&1).is_empty());
+			assert_eq!(
+				vec![AutoCompoundConfig { delegator: 3, value: Percent::from_percent(50) }],
+				ParachainStaking::auto_compounding_delegations(&2),
+			);
+		});
+}
+
+#[test]
+fn transfer_delegation_fails_if_invalid_auto_compounding_hint() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 25), (4, 25)])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_auto_compounding_delegations(vec![
+			(3, 1, 10, Percent::from_percent(50)),
+			(4, 2, 10, Percent::from_percent(50)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::transfer_delegation(
+					RuntimeOrigin::signed(3),
+					1,
+					2,
+					10,
+					1,
+					0,
+					1,
+					1
+				),
+				Error::<Test>::TooLowCandidateAutoCompoundingDelegationCountToDelegate,
+			);
+		});
+}
+
+#[test]
+fn transfer_delegation_fails_if_invalid_from_candidate_hints() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 25), (4, 25)])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_auto_compounding_delegations(vec![
+			(3, 1, 10, Percent::from_percent(50)),
+			(4, 1, 10, Percent::from_percent(50)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::transfer_delegation(
+					RuntimeOrigin::signed(3),
+					1,
+					2,
+					10,
+					0,
+					1,
+					1,
+					2
+				),
+				Error::<Test>::TooLowCandidateDelegationCountToTransferDelegation,
+			);
+			assert_noop!(
+				ParachainStaking::transfer_delegation(
+					RuntimeOrigin::signed(3),
+					1,
+					2,
+					10,
+					0,
+					1,
+					2,
+					1
+				),
+				Error::<Test>::TooLowCandidateAutoCompoundingDelegationCountToTransferDelegation,
+			);
+		});
+}
+
+#[test]
+fn transfer_delegation_kicking_the_lowest_bottom_keeps_auto_compound_storage_consistent() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 30),
+			(2, 30),
+			(3, 30),
+			(4, 25),
+			(5, 25),
+			(6, 25),
+			(7, 25),
+			(8, 25),
+			(9, 25),
+			(10, 25),
+			(11, 25),
+		])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_auto_compounding_delegations(vec![
+			(3, 1, 30, Percent::from_percent(50)),
+			(4, 2, 10, Percent::from_percent(50)),
+			(5, 2, 10, Percent::from_percent(50)),
+			(6, 2, 10, Percent::from_percent(50)),
+			(7, 2, 10, Percent::from_percent(50)),
+			(8, 2, 10, Percent::from_percent(50)),
+			(9, 2, 10, Percent::from_percent(50)),
+			(10, 2, 10, Percent::from_percent(50)),
+			(11, 2, 10, Percent::from_percent(50)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::transfer_delegation(
+				RuntimeOrigin::signed(3),
+				1,
+				2,
+				30,
+				8,
+				8,
+				1,
+				1
+			));
+			assert_events_emitted!(Event::DelegationKicked {
+				delegator: 11,
+				candidate: 2,
+				unstaked_amount: 10,
+			});
+			let auto_compounding = ParachainStaking::auto_compounding_delegations(&2);
+			assert_eq!(auto_compounding.len(), 8);
+			assert!(!auto_compounding.iter().any(|d| d.delegator == 11));
+			assert!(auto_compounding.iter().any(|d| d.delegator == 3));
+		});
+}
+
+#[test]
+fn transfer_delegation_fails_to_the_same_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (3, 25)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::transfer_delegation(
+					RuntimeOrigin::signed(3),
+					1,
+					1,
+					5,
+					1,
+					0,
+					1,
+					1
+				),
+				Error::<Test>::CannotTransferDelegationToSameCandidate,
+			);
+		});
+}
+
+#[test]
+fn transfer_delegation_fails_if_amount_exceeds_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 25)])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::transfer_delegation(
+					RuntimeOrigin::signed(3),
+					1,
+					2,
+					11,
+					0,
+					0,
+					1,
+					1
+				),
+				Error::<Test>::TransferAmountExceedsDelegation,
+			);
+			assert_noop!(
+				ParachainStaking::transfer_delegation(
+					RuntimeOrigin::signed(3),
+					1,
+					2,
+					8,
+					0,
+					0,
+					1,
+					1
+				),
+				Error::<Test>::DelegationBelowMin,
+			);
+		});
+}
+
+#[test]
+fn transfer_delegation_fails_if_pending_request() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 25)])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(3),
+				1,
+				2
+			));
+			assert_noop!(
+				ParachainStaking::transfer_delegation(
+					RuntimeOrigin::signed(3),
+					1,
+					2,
+					5,
+					0,
+					0,
+					1,
+					1
+				),
+				Error::<Test>::PendingDelegationRequestAlreadyExists,
+			);
+		});
+}
+
+#[test]
+fn transfer_delegation_is_rate_limited() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 25)])
+		.with_candidates(vec![(1, 30), (2, 30)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::transfer_delegation(
+				RuntimeOrigin::signed(3),
+				1,
+				2,
+				5,
+				0,
+				0,
+				1,
+				1
+			));
+			assert_eq!(ParachainStaking::last_delegation_transfer(3), Some(1));
+			assert_noop!(
+				ParachainStaking::transfer_delegation(
+					RuntimeOrigin::signed(3),
+					2,
+					1,
+					5,
+					1,
+					0,
+					1,
+					1
+				),
+				Error::<Test>::DelegationTransferNotAllowedYet,
+			);
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::transfer_delegation(
+				RuntimeOrigin::signed(3),
+				2,
+				1,
+				5,
+				1,
+				0,
+				1,
+				1
+			));
+			assert_eq!(ParachainStaking::delegator_state(3).unwrap().get_bond_amount(&2), None);
+		});
+}
+
+// INSTANT UNSTAKE
+
+#[test]
+fn instant_unstake_revokes_the_whole_delegation_and_charges_the_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeFee(Perbill::from_percent(10))
+			));
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeCap(20)
+			));
+			let issuance = Balances::total_issuance();
+			assert_ok!(ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 10));
+			assert_events_emitted!(Event::DelegatorLeft { delegator: 2, unstaked_amount: 10 });
+			assert_events_emitted!(Event::InstantUnstaked {
+				delegator: 2,
+				candidate: 1,
+				amount: 10,
+				fee: 1,
+			});
+			assert!(ParachainStaking::delegator_state(2).is_none());
+			assert_eq!(crate::mock::query_lock_amount(2, DELEGATOR_LOCK_ID), None);
+			assert_eq!(Balances::free_balance(2), 19);
+			assert_eq!(Balances::total_issuance(), issuance - 1);
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().total_counted, 30);
+			assert_eq!(ParachainStaking::total(), 30);
+			assert_eq!(ParachainStaking::instant_unstaked(), (1, 10));
+			assert_ok!(ParachainStaking::do_try_state());
+		});
+}
+
+#[test]
+fn instant_unstake_charges_the_fee_from_vesting_locked_balance() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			// Restrictions of the lock set by pallet vesting on unvested funds
+			Balances::set_lock(
+				*b"vesting ",
+				&2,
+				10,
+				WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE,
+			);
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeFee(Perbill::from_percent(10))
+			));
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeCap(20)
+			));
+			assert_ok!(ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 10));
+			assert_events_emitted!(Event::InstantUnstaked {
+				delegator: 2,
+				candidate: 1,
+				amount: 10,
+				fee: 1,
+			});
+			assert_eq!(Balances::free_balance(2), 9);
+		});
+}
+
+#[test]
+fn instant_unstake_decreases_part_of_the_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeCap(20)
+			));
+			assert_ok!(ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 4));
+			assert_events_emitted!(Event::DelegationDecreased {
+				delegator: 2,
+				candidate: 1,
+				amount: 4,
+				in_top: true,
+			});
+			assert_events_emitted!(Event::InstantUnstaked {
+				delegator: 2,
+				candidate: 1,
+				amount: 4,
+				fee: 0,
+			});
+			let state = ParachainStaking::delegator_state(2).expect("delegator exists");
+			assert_eq!(state.get_bond_amount(&1), Some(6));
+			assert_eq!(state.less_total, 0);
+			assert!(ParachainStaking::delegation_scheduled_requests(&1).is_empty());
+			assert_eq!(crate::mock::query_lock_amount(2, DELEGATOR_LOCK_ID), Some(6));
+			assert_eq!(Balances::free_balance(2), 20);
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().total_counted, 36);
+			assert_ok!(ParachainStaking::do_try_state());
+		});
+}
+
+#[test]
+fn instant_unstake_is_disabled_by_default() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 4),
+				Error::<Test>::InstantUnstakeCapExceeded
+			);
+		});
+}
+
+#[test]
+fn instant_unstake_is_capped_per_round() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20), (3, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20), (3, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeCap(10)
+			));
+			assert_ok!(ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 6));
+			assert_noop!(
+				ParachainStaking::instant_unstake(RuntimeOrigin::signed(3), 1, 5),
+				Error::<Test>::InstantUnstakeCapExceeded
+			);
+			assert_ok!(ParachainStaking::instant_unstake(RuntimeOrigin::signed(3), 1, 4));
+			assert_eq!(ParachainStaking::instant_unstaked(), (1, 10));
+			roll_to_round_begin(2);
+			assert_ok!(ParachainStaking::instant_unstake(RuntimeOrigin::signed(3), 1, 10));
+			assert_eq!(ParachainStaking::instant_unstaked(), (2, 10));
+		});
+}
+
+#[test]
+fn instant_unstake_fails_if_pending_delegation_request() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeCap(20)
+			));
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(2), 1));
+			assert_noop!(
+				ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 10),
+				Error::<Test>::PendingDelegationRequestAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn instant_unstake_respects_delegation_amounts() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_staking_parameter(
+				RuntimeOrigin::root(),
+				StakingParameter::InstantUnstakeCap(20)
+			));
+			assert_noop!(
+				ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 0),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_noop!(
+				ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 11),
+				Error::<Test>::InstantUnstakeExceedsDelegation
+			);
+			assert_noop!(
+				ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 1, 8),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_noop!(
+				ParachainStaking::instant_unstake(RuntimeOrigin::signed(2), 2, 5),
+				Error::<Test>::DelegationDNE
+			);
+		});
+}
+
+// RUNTIME API
+
+#[test]
+fn candidate_summaries_estimate_delegator_apr() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 4_000), (2, 4_000), (3, 2_000)])
+		.with_candidates(vec![(1, 1_000), (2, 1_000)])
+		.with_delegations(vec![(3, 1, 250)])
+		.build()
+		.execute_with(|| {
+			// round issuance is 500, 150 is reserved for the parachain bond and each of the two
+			// candidates is due 175, including a commission of 50
+			let rounds_per_year = crate::inflation::rounds_per_year::<Test>() as u128;
+			let summaries = ParachainStaking::candidate_summaries();
+			assert_eq!(
+				summaries,
+				vec![
+					CandidateSummary {
+						candidate: 1,
+						bond: 1_000,
+						total_counted: 1_250,
+						delegation_count: 1,
+						commission: Perbill::from_percent(20),
+						is_active: true,
+						is_selected: true,
+						last_round_points: 0,
+						estimated_apr: FixedU128::from_rational(125 * rounds_per_year, 1_250),
+					},
+					CandidateSummary {
+						candidate: 2,
+						bond: 1_000,
+						total_counted: 1_000,
+						delegation_count: 0,
+						commission: Perbill::from_percent(20),
+						is_active: true,
+						is_selected: true,
+						last_round_points: 0,
+						estimated_apr: FixedU128::from_rational(125 * rounds_per_year, 1_000),
+					},
+				]
+			);
+		});
+}
+
+#[test]
+fn candidate_summaries_report_last_round_points() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 4_000), (2, 4_000)])
+		.with_candidates(vec![(1, 1_000), (2, 1_000)])
+		.build()
+		.execute_with(|| {
+			set_author(1, 1, 20);
+			roll_to_round_begin(2);
+			let points: Vec<_> = ParachainStaking::candidate_summaries()
+				.into_iter()
+				.map(|summary| (summary.candidate, summary.last_round_points))
+				.collect();
+			assert_eq!(points, vec![(1, 20), (2, 0)]);
+		});
+}
+
+#[test]
+fn candidate_summaries_skip_apr_of_unselected_candidates() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 4_000), (2, 4_000)])
+		.with_candidates(vec![(1, 1_000), (2, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(2)));
+			let summary = ParachainStaking::candidate_summaries()
+				.into_iter()
+				.find(|summary| summary.candidate == 2)
+				.expect("candidate exists");
+			assert!(!summary.is_active);
+			assert!(summary.is_selected);
+			assert!(summary.estimated_apr.is_zero());
+		});
+}
+
+#[test]
+fn delegator_summary_reports_expected_rewards_and_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 4_000), (2, 4_000), (3, 2_000)])
+		.with_candidates(vec![(1, 1_000), (2, 1_000)])
+		.with_auto_compounding_delegations(vec![
+			(3, 1, 250, Percent::from_percent(50)),
+			(3, 2, 250, Percent::zero()),
+		])
+		.build()
+		.execute_with(|| {
+			assert_eq!(ParachainStaking::delegator_summary(4), None);
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(3), 2));
+
+			// each candidate shares 125 pro rata of its counted stake, the revoked delegation
+			// isn't counted anymore
+			assert_eq!(
+				ParachainStaking::delegator_summary(3),
+				Some(DelegatorSummary {
+					delegator: 3,
+					total: 500,
+					less_total: 250,
+					delegations: vec![
+						DelegationSummary {
+							candidate: 1,
+							amount: 250,
+							auto_compound: Percent::from_percent(50),
+							expected_reward: 25,
+							pending_request: None,
+						},
+						DelegationSummary {
+							candidate: 2,
+							amount: 250,
+							auto_compound: Percent::zero(),
+							expected_reward: 0,
+							pending_request: Some(PendingDelegationRequest {
+								action: DelegationAction::Revoke(250),
+								when_executable: 3,
+								rounds_until_executable: 2,
+							}),
+						},
+					],
+				})
+			);
+
+			roll_to_round_begin(4);
+			let summary = ParachainStaking::delegator_summary(3).expect("delegator exists");
+			assert_eq!(
+				summary.delegations[1].pending_request.as_ref().unwrap().rounds_until_executable,
+				0
+			);
+		});
+}
+
+// CANDIDATE POOL
+
+#[test]
+fn candidate_moves_to_the_bag_matching_its_stake() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 15), (2, 25)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(CandidatePool::<Test>::notional_bag_for(15), 20);
+			assert_eq!(<CandidateBags<Test>>::get(20).unwrap().head, Some(1));
+			assert_eq!(<CandidateBags<Test>>::get(30).unwrap().head, Some(2));
+
+			assert_ok!(ParachainStaking::candidate_bond_more(RuntimeOrigin::signed(1), 20));
+			assert!(<CandidateBags<Test>>::get(20).is_none());
+			assert_eq!(CandidatePool::<Test>::score(&1), Some(35));
+			assert_eq!(<CandidateBags<Test>>::get(40).unwrap().head, Some(1));
+
+			// the delegation is counted in the bag of the candidate
+			assert_ok!(ParachainStaking::delegate(RuntimeOrigin::signed(3), 2, 20, 0, 0));
+			assert_eq!(CandidatePool::<Test>::score(&2), Some(45));
+			assert!(<CandidateBags<Test>>::get(30).is_none());
+			assert_eq!(
+				ParachainStaking::candidate_pool(),
+				vec![Bond { owner: 2, amount: 45 }, Bond { owner: 1, amount: 35 }]
+			);
+			assert_ok!(ParachainStaking::do_try_state());
+		});
+}
+
+#[test]
+fn candidate_beyond_the_last_threshold_is_in_the_top_bag() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 5_000)])
+		.with_candidates(vec![(1, 2_000)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(CandidatePool::<Test>::notional_bag_for(2_000), u128::MAX);
+			assert_eq!(<CandidateBags<Test>>::get(u128::MAX).unwrap().head, Some(1));
+			assert_ok!(ParachainStaking::do_try_state());
+		});
+}
+
+#[test]
+fn top_candidates_are_sorted_within_the_last_bag_read() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
+		.with_candidates(vec![(1, 52), (2, 58), (3, 55), (4, 55), (5, 80), (6, 20)])
+		.build()
+		.execute_with(|| {
+			// 1, 2, 3 and 4 share the same bag, in join order
+			assert_eq!(
+				CandidatePool::<Test>::top(3),
+				vec![
+					Bond { owner: 5, amount: 80 },
+					Bond { owner: 2, amount: 58 },
+					Bond { owner: 4, amount: 55 },
+				]
+			);
+			assert_eq!(CandidatePool::<Test>::top(10).len(), 6);
+			assert!(CandidatePool::<Test>::top(0).is_empty());
+			// 14 bags and 6 candidate nodes
+			assert_eq!(CandidatePool::<Test>::max_top_reads(), 20);
+		});
+}
+
+#[test]
+fn try_state_holds_after_candidates_leave_and_go_offline() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20)])
+		.build()
+		.execute_with(|| {
+			// 2 is in the middle of the bag
+			assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(2)));
+			assert_ok!(ParachainStaking::do_try_state());
+			assert_ok!(ParachainStaking::schedule_leave_candidates(RuntimeOrigin::signed(1), 4));
+			assert_ok!(ParachainStaking::do_try_state());
+			assert_ok!(ParachainStaking::go_online(RuntimeOrigin::signed(2)));
+			assert_ok!(ParachainStaking::do_try_state());
+			assert_eq!(ParachainStaking::candidate_pool_count(), 3);
+			assert_eq!(<CandidateBags<Test>>::get(20).unwrap().head, Some(3));
+			assert_eq!(<CandidateBags<Test>>::get(20).unwrap().tail, Some(2));
+		});
+}
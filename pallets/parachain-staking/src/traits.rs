@@ -18,7 +18,13 @@
 
 //! traits for parachain-staking
 
-use frame_support::pallet_prelude::Weight;
+use crate::BalanceOf;
+use frame_support::{
+	pallet_prelude::{DispatchError, Weight},
+	traits::{Currency, ExistenceRequirement, Get, Imbalance},
+};
+use sp_runtime::traits::Saturating;
+use sp_std::marker::PhantomData;
 
 pub trait OnCollatorPayout<AccountId, Balance> {
 	fn on_collator_payout(
@@ -66,3 +72,72 @@ impl<Runtime: crate::Config> PayoutCollatorReward<Runtime> for () {
 		crate::Pallet::<Runtime>::mint_collator_reward(for_round, collator_id, amount)
 	}
 }
+
+/// Provides the funds paid out as staking rewards.
+pub trait StakingRewardSource<Runtime: crate::Config> {
+	/// Amount distributed as rewards for a round, given the total amount staked in that round.
+	fn round_issuance(staked: BalanceOf<Runtime>) -> BalanceOf<Runtime>;
+
+	/// Amount expected to be distributed for a full round given the amount staked, used to
+	/// project rewards. Defaults to `round_issuance`.
+	fn expected_round_issuance(staked: BalanceOf<Runtime>) -> BalanceOf<Runtime> {
+		Self::round_issuance(staked)
+	}
+
+	/// Credits a reward to the given account, returns the credited amount.
+	fn credit(
+		who: &Runtime::AccountId,
+		amount: BalanceOf<Runtime>,
+	) -> Result<BalanceOf<Runtime>, DispatchError>;
+}
+
+/// Defines the default source of staking rewards. Rewards are minted according to the
+/// `InflationConfig` and deposited into existing accounts.
+impl<Runtime: crate::Config> StakingRewardSource<Runtime> for () {
+	fn round_issuance(staked: BalanceOf<Runtime>) -> BalanceOf<Runtime> {
+		crate::Pallet::<Runtime>::compute_issuance(staked)
+	}
+
+	fn credit(
+		who: &Runtime::AccountId,
+		amount: BalanceOf<Runtime>,
+	) -> Result<BalanceOf<Runtime>, DispatchError> {
+		Runtime::Currency::deposit_into_existing(who, amount).map(|imbalance| imbalance.peek())
+	}
+}
+
+/// Pays staking rewards out of the `Pot` account, which is funded by another pallet, e.g. as a
+/// beneficiary of the block reward. Nothing is minted and the `InflationConfig` is not used.
+///
+/// The whole pot balance above the existential deposit is distributed for every round. Payouts
+/// of a round have to complete before the next round is prepared, which holds as long as less
+/// collators are selected than there are blocks in a round.
+///
+/// `Inflow` is the amount the pot receives per block. Rewards are projected from it, since the
+/// pot balance depends on how far the current round's payouts went.
+pub struct RewardPot<Pot, Inflow>(PhantomData<(Pot, Inflow)>);
+
+impl<Runtime, Pot, Inflow> StakingRewardSource<Runtime> for RewardPot<Pot, Inflow>
+where
+	Runtime: crate::Config,
+	Pot: Get<Runtime::AccountId>,
+	Inflow: Get<BalanceOf<Runtime>>,
+{
+	fn round_issuance(_staked: BalanceOf<Runtime>) -> BalanceOf<Runtime> {
+		Runtime::Currency::free_balance(&Pot::get())
+			.saturating_sub(Runtime::Currency::minimum_balance())
+	}
+
+	fn expected_round_issuance(_staked: BalanceOf<Runtime>) -> BalanceOf<Runtime> {
+		let round_length = crate::Pallet::<Runtime>::round().length;
+		Inflow::get().saturating_mul(round_length.into())
+	}
+
+	fn credit(
+		who: &Runtime::AccountId,
+		amount: BalanceOf<Runtime>,
+	) -> Result<BalanceOf<Runtime>, DispatchError> {
+		Runtime::Currency::transfer(&Pot::get(), who, amount, ExistenceRequirement::KeepAlive)?;
+		Ok(amount)
+	}
+}
@@ -19,9 +19,9 @@
 //! Types for parachain-staking
 
 use crate::{
-	auto_compound::AutoCompoundDelegations, set::OrderedSet, BalanceOf, BottomDelegations,
-	CandidateInfo, Config, DelegatorState, Error, Event, Pallet, Round, RoundIndex, TopDelegations,
-	Total, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
+	auto_compound::AutoCompoundDelegations, delegation_requests::DelegationAction, set::OrderedSet,
+	BalanceOf, BottomDelegations, CandidateInfo, Config, DelegatorState, Error, Event, Pallet,
+	Round, RoundIndex, TopDelegations, Total, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
 };
 use frame_support::{
 	pallet_prelude::*,
@@ -30,7 +30,7 @@ use frame_support::{
 use parity_scale_codec::{Decode, Encode};
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Saturating, Zero},
-	Perbill, Percent, RuntimeDebug,
+	FixedU128, Perbill, Percent, RuntimeDebug,
 };
 use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap, prelude::*};
 
@@ -292,7 +292,8 @@ impl<AccountId, Balance: Copy + Ord + sp_std::ops::AddAssign + Zero + Saturating
 	/// Return the capacity status for top delegations
 	pub fn top_capacity<T: Config>(&self) -> CapacityStatus {
 		match &self.delegations {
-			x if x.len() as u32 >= T::MaxTopDelegationsPerCandidate::get() => CapacityStatus::Full,
+			x if x.len() as u32 >= <Pallet<T>>::max_top_delegations_per_candidate() =>
+				CapacityStatus::Full,
 			x if x.is_empty() => CapacityStatus::Empty,
 			_ => CapacityStatus::Partial,
 		}
@@ -386,7 +387,7 @@ impl<
 	pub fn schedule_leave<T: Config>(&mut self) -> Result<(RoundIndex, RoundIndex), DispatchError> {
 		ensure!(!self.is_leaving(), Error::<T>::CandidateAlreadyLeaving);
 		let now = <Round<T>>::get().current;
-		let when = now + T::LeaveCandidatesDelay::get();
+		let when = now + <Pallet<T>>::leave_candidates_delay();
 		self.status = CollatorStatus::Leaving(when);
 		Ok((now, when))
 	}
@@ -443,10 +444,10 @@ impl<
 		// ensure bond above min after decrease
 		ensure!(self.bond > less, Error::<T>::CandidateBondBelowMin);
 		ensure!(
-			self.bond - less >= T::MinCandidateStk::get().into(),
+			self.bond - less >= <Pallet<T>>::min_candidate_stk().into(),
 			Error::<T>::CandidateBondBelowMin
 		);
-		let when_executable = <Round<T>>::get().current + T::CandidateBondLessDelay::get();
+		let when_executable = <Round<T>>::get().current + <Pallet<T>>::candidate_bond_less_delay();
 		self.request = Some(CandidateBondLessRequest { amount: less, when_executable });
 		Ok(when_executable)
 	}
@@ -511,7 +512,12 @@ impl<
 		BalanceOf<T>: Into<Balance> + From<Balance>,
 	{
 		self.lowest_top_delegation_amount = top_delegations.lowest_delegation_amount().into();
-		self.top_capacity = top_delegations.top_capacity::<T>();
+		// top delegations are only partial once bottom delegations are empty, which may not be
+		// the case yet after `MaxTopDelegationsPerCandidate` is raised
+		self.top_capacity = match self.bottom_capacity {
+			CapacityStatus::Empty => top_delegations.top_capacity::<T>(),
+			_ => CapacityStatus::Full,
+		};
 		let old_total_counted = self.total_counted;
 		self.total_counted = self.bond.saturating_add(top_delegations.total.into());
 		// CandidatePool value for candidate always changes if top delegations total changes
@@ -591,8 +597,8 @@ impl<
 		let mut less_total_staked = None;
 		let mut top_delegations = <TopDelegations<T>>::get(candidate)
 			.expect("CandidateInfo existence => TopDelegations existence");
-		let max_top_delegations_per_candidate = T::MaxTopDelegationsPerCandidate::get();
-		if top_delegations.delegations.len() as u32 == max_top_delegations_per_candidate {
+		let max_top_delegations_per_candidate = <Pallet<T>>::max_top_delegations_per_candidate();
+		if top_delegations.delegations.len() as u32 >= max_top_delegations_per_candidate {
 			// pop lowest top delegation
 			let new_bottom_delegation = top_delegations.delegations.pop().expect("");
 			top_delegations.total =
@@ -745,8 +751,11 @@ impl<
 			.collect();
 		let actual_amount = actual_amount_option.ok_or(Error::<T>::DelegationDNE)?;
 		top_delegations.total = top_delegations.total.saturating_sub(actual_amount);
-		// if bottom nonempty => bump top bottom to top
-		if !matches!(self.bottom_capacity, CapacityStatus::Empty) {
+		// if bottom nonempty and top below capacity => bump top bottom to top
+		if !matches!(self.bottom_capacity, CapacityStatus::Empty) &&
+			(top_delegations.delegations.len() as u32) <
+				<Pallet<T>>::max_top_delegations_per_candidate()
+		{
 			let mut bottom_delegations =
 				<BottomDelegations<T>>::get(candidate).expect("bottom is nonempty as just checked");
 			// expect already stored greatest to least by bond amount
@@ -1641,3 +1650,107 @@ pub enum BondAdjust<Balance> {
 	Increase(Balance),
 	Decrease,
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Staking parameter set by `MonetaryGovernanceOrigin`, see [`Pallet::set_staking_parameter`].
+/// The value of the matching `Config` constant is the default and the lower bound of each
+/// parameter, except for `MaxTopDelegationsPerCandidate` where it is the upper bound, and the
+/// matching `Max*` constant is its upper bound. The instant unstake parameters have no default
+/// `Config` constant, both default to zero.
+pub enum StakingParameter<Balance> {
+	/// Minimum stake required for any candidate to be in `SelectedCandidates` for the round
+	MinCollatorStk(Balance),
+	/// Minimum stake required for any account to be a collator candidate
+	MinCandidateStk(Balance),
+	/// Minimum stake for any registered on-chain account to delegate
+	MinDelegation(Balance),
+	/// Minimum stake for any registered on-chain account to be a delegator
+	MinDelegatorStk(Balance),
+	/// Number of rounds that candidates remain bonded before exit request is executable
+	LeaveCandidatesDelay(RoundIndex),
+	/// Number of rounds candidate requests to decrease self-bond must wait to be executable
+	CandidateBondLessDelay(RoundIndex),
+	/// Number of rounds that delegators remain bonded before exit request is executable
+	LeaveDelegatorsDelay(RoundIndex),
+	/// Number of rounds that delegations remain bonded before revocation or bond less request
+	/// is executable
+	RevokeDelegationDelay(RoundIndex),
+	/// Maximum top delegations counted per candidate. When lowered, the existing top delegations
+	/// are kept and the new maximum applies as they change.
+	MaxTopDelegationsPerCandidate(u32),
+	/// Fee charged on the amount unstaked through `instant_unstake`
+	InstantUnstakeFee(Perbill),
+	/// Maximum amount unstaked through `instant_unstake` per round, by all delegators
+	InstantUnstakeCap(Balance),
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+/// Overview of a collator candidate, see [`Pallet::candidate_summaries`]
+pub struct CandidateSummary<AccountId, Balance> {
+	/// Account of the candidate
+	pub candidate: AccountId,
+	/// Self bond of the candidate
+	pub bond: Balance,
+	/// Self bond plus the top delegations
+	pub total_counted: Balance,
+	/// Number of delegations, top and bottom
+	pub delegation_count: u32,
+	/// Commission taken off the round rewards by the candidate
+	pub commission: Perbill,
+	/// Whether the candidate is online
+	pub is_active: bool,
+	/// Whether the candidate is collating in the current round
+	pub is_selected: bool,
+	/// Points awarded to the candidate in the previous round
+	pub last_round_points: u32,
+	/// Yearly reward of its delegators relative to their stake, zero if the candidate isn't
+	/// selected for the next round
+	pub estimated_apr: FixedU128,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+/// Request scheduled on a delegation, see [`DelegationSummary`]
+pub struct PendingDelegationRequest<Balance> {
+	/// Revoke or decrease of the delegation
+	pub action: DelegationAction<Balance>,
+	/// Round from which the request can be executed
+	pub when_executable: RoundIndex,
+	/// Rounds left before the request can be executed, zero if it already can
+	pub rounds_until_executable: RoundIndex,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+/// Overview of a delegation, see [`DelegatorSummary`]
+pub struct DelegationSummary<AccountId, Balance> {
+	/// Candidate supported by the delegation
+	pub candidate: AccountId,
+	/// Amount delegated
+	pub amount: Balance,
+	/// Percentage of the rewards auto-compounded
+	pub auto_compound: Percent,
+	/// Reward expected for the next round, assuming the selected candidates are evenly awarded
+	pub expected_reward: Balance,
+	/// Revoke or decrease scheduled on the delegation
+	pub pending_request: Option<PendingDelegationRequest<Balance>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+/// Overview of a delegator, see [`Pallet::delegator_summary`]
+pub struct DelegatorSummary<AccountId, Balance> {
+	/// Account of the delegator
+	pub delegator: AccountId,
+	/// Total amount delegated
+	pub total: Balance,
+	/// Sum of the pending revokes and decreases
+	pub less_total: Balance,
+	/// Delegations of the delegator
+	pub delegations: Vec<DelegationSummary<AccountId, Balance>>,
+}
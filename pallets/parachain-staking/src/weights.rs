@@ -62,6 +62,8 @@ pub trait WeightInfo {
 	#[rustfmt::skip]
 	fn set_parachain_bond_reserve_percent() -> Weight;
 	#[rustfmt::skip]
+	fn set_staking_parameter() -> Weight;
+	#[rustfmt::skip]
 	fn set_total_selected() -> Weight;
 	#[rustfmt::skip]
 	fn set_collator_commission() -> Weight;
@@ -125,6 +127,10 @@ pub trait WeightInfo {
 	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight;
 	#[rustfmt::skip]
 	fn mint_collator_reward() -> Weight;
+	#[rustfmt::skip]
+	fn transfer_delegation(x: u32, y: u32, z: u32, w: u32, ) -> Weight;
+	#[rustfmt::skip]
+	fn instant_unstake() -> Weight;
 }
 
 /// Weights for parachain_staking using the Substrate node and recommended hardware.
@@ -158,6 +164,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: ParachainStaking MinDelegation (r:1 w:1)
+	#[rustfmt::skip]
+	fn set_staking_parameter() -> Weight {
+		Weight::from_ref_time(27_315_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 	// Storage: ParachainStaking TotalSelected (r:1 w:1)
 	#[rustfmt::skip]
 	fn set_total_selected() -> Weight {
@@ -182,7 +195,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking DelegatorState (r:1 w:0)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:2 w:2)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking Total (r:1 w:1)
@@ -193,18 +208,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_ref_time(93_609_702 as u64)
 			// Standard Error: 1_049
 			.saturating_add(Weight::from_ref_time(156_227 as u64).saturating_mul(x as u64))
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	#[rustfmt::skip]
 	fn schedule_leave_candidates(x: u32, ) -> Weight {
 		Weight::from_ref_time(74_938_974 as u64)
 			// Standard Error: 1_813
 			.saturating_add(Weight::from_ref_time(136_560 as u64).saturating_mul(x as u64))
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
@@ -226,41 +243,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(x as u64)))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:2 w:2)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	#[rustfmt::skip]
 	fn cancel_leave_candidates(x: u32, ) -> Weight {
 		Weight::from_ref_time(70_048_572 as u64)
 			// Standard Error: 1_524
 			.saturating_add(Weight::from_ref_time(145_661 as u64).saturating_mul(x as u64))
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	#[rustfmt::skip]
 	fn go_offline() -> Weight {
 		Weight::from_ref_time(42_412_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:2 w:2)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	#[rustfmt::skip]
 	fn go_online() -> Weight {
 		Weight::from_ref_time(41_744_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	#[rustfmt::skip]
 	fn candidate_bond_more() -> Weight {
 		Weight::from_ref_time(68_900_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	#[rustfmt::skip]
@@ -273,12 +297,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: ParachainStaking Total (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	#[rustfmt::skip]
 	fn execute_candidate_bond_less() -> Weight {
 		Weight::from_ref_time(76_166_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	#[rustfmt::skip]
@@ -291,7 +316,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	#[rustfmt::skip]
@@ -301,8 +327,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_ref_time(239_400 as u64).saturating_mul(x as u64))
 			// Standard Error: 6_339
 			.saturating_add(Weight::from_ref_time(261_836 as u64).saturating_mul(y as u64))
-			.saturating_add(T::DbWeight::get().reads(7 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
@@ -316,7 +342,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
@@ -326,9 +353,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_ref_time(14_022_061 as u64)
 			// Standard Error: 30_972
 			.saturating_add(Weight::from_ref_time(29_549_278 as u64).saturating_mul(x as u64))
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(x as u64)))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
 			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(x as u64)))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
@@ -353,13 +380,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	#[rustfmt::skip]
 	fn delegator_bond_more() -> Weight {
 		Weight::from_ref_time(90_452_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(8 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
@@ -376,13 +404,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:0)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	#[rustfmt::skip]
 	fn execute_revoke_delegation() -> Weight {
 		Weight::from_ref_time(112_401_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(9 as u64))
-			.saturating_add(T::DbWeight::get().writes(8 as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
@@ -390,13 +419,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	#[rustfmt::skip]
 	fn execute_delegator_bond_less() -> Weight {
 		Weight::from_ref_time(97_377_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(8 as u64))
-			.saturating_add(T::DbWeight::get().writes(8 as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
@@ -436,7 +466,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_ref_time(233_812 as u64).saturating_mul(y as u64))
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 	}
-	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking CandidateBags (r:1 w:0)
+	// Storage: ParachainStaking CandidateNodes (r:1 w:0)
 	// Storage: ParachainStaking TotalSelected (r:1 w:0)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
@@ -451,7 +482,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_ref_time(24_788_127 as u64).saturating_mul(x as u64))
 			// Standard Error: 118_210
 			.saturating_add(Weight::from_ref_time(2_957_731 as u64).saturating_mul(y as u64))
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
 			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(x as u64)))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(x as u64)))
@@ -492,7 +523,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	// Storage: ParachainStaking BottomDelegations (r:1 w:1)
@@ -503,8 +535,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_ref_time(120_473 as u64).saturating_mul(x as u64))
 			// Standard Error: 5_811
 			.saturating_add(Weight::from_ref_time(80_638 as u64).saturating_mul(y as u64))
-			.saturating_add(T::DbWeight::get().reads(8 as u64))
-			.saturating_add(T::DbWeight::get().writes(8 as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: System Account (r:1 w:1)
 	#[rustfmt::skip]
@@ -513,6 +545,52 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking LastDelegationTransfer (r:1 w:1)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:2 w:0)
+	// Storage: ParachainStaking DelegatorState (r:2 w:2)
+	// Storage: ParachainStaking CandidateInfo (r:2 w:2)
+	// Storage: ParachainStaking AutoCompoundingDelegations (r:2 w:2)
+	// Storage: ParachainStaking TopDelegations (r:2 w:2)
+	// Storage: ParachainStaking BottomDelegations (r:2 w:2)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+	fn transfer_delegation(x: u32, y: u32, z: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(221_904_000 as u64)
+			// Standard Error: 6_104
+			.saturating_add(Weight::from_ref_time(241_092 as u64).saturating_mul(x as u64))
+			// Standard Error: 6_104
+			.saturating_add(Weight::from_ref_time(80_638 as u64).saturating_mul(y as u64))
+			// Standard Error: 6_104
+			.saturating_add(Weight::from_ref_time(241_092 as u64).saturating_mul(z as u64))
+			// Standard Error: 6_104
+			.saturating_add(Weight::from_ref_time(80_638 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads(22 as u64))
+			.saturating_add(T::DbWeight::get().writes(19 as u64))
+	}
+	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking InstantUnstaked (r:1 w:1)
+	// Storage: ParachainStaking InstantUnstakeCap (r:1 w:0)
+	// Storage: ParachainStaking InstantUnstakeFee (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:0)
+	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+	fn instant_unstake() -> Weight {
+		Weight::from_ref_time(138_614_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -545,6 +623,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: ParachainStaking MinDelegation (r:1 w:1)
+	#[rustfmt::skip]
+	fn set_staking_parameter() -> Weight {
+		Weight::from_ref_time(27_315_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 	// Storage: ParachainStaking TotalSelected (r:1 w:1)
 	#[rustfmt::skip]
 	fn set_total_selected() -> Weight {
@@ -569,7 +654,9 @@ impl WeightInfo for () {
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking DelegatorState (r:1 w:0)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:2 w:2)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking Total (r:1 w:1)
@@ -580,18 +667,20 @@ impl WeightInfo for () {
 		Weight::from_ref_time(93_609_702 as u64)
 			// Standard Error: 1_049
 			.saturating_add(Weight::from_ref_time(156_227 as u64).saturating_mul(x as u64))
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	#[rustfmt::skip]
 	fn schedule_leave_candidates(x: u32, ) -> Weight {
 		Weight::from_ref_time(74_938_974 as u64)
 			// Standard Error: 1_813
 			.saturating_add(Weight::from_ref_time(136_560 as u64).saturating_mul(x as u64))
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
@@ -613,41 +702,48 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(x as u64)))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:2 w:2)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	#[rustfmt::skip]
 	fn cancel_leave_candidates(x: u32, ) -> Weight {
 		Weight::from_ref_time(70_048_572 as u64)
 			// Standard Error: 1_524
 			.saturating_add(Weight::from_ref_time(145_661 as u64).saturating_mul(x as u64))
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	#[rustfmt::skip]
 	fn go_offline() -> Weight {
 		Weight::from_ref_time(42_412_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:2 w:2)
+	// Storage: ParachainStaking CandidateBags (r:1 w:1)
+	// Storage: ParachainStaking CandidatePoolCount (r:1 w:1)
 	#[rustfmt::skip]
 	fn go_online() -> Weight {
 		Weight::from_ref_time(41_744_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	#[rustfmt::skip]
 	fn candidate_bond_more() -> Weight {
 		Weight::from_ref_time(68_900_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	#[rustfmt::skip]
@@ -660,12 +756,13 @@ impl WeightInfo for () {
 	// Storage: ParachainStaking Total (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	#[rustfmt::skip]
 	fn execute_candidate_bond_less() -> Weight {
 		Weight::from_ref_time(76_166_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
 	}
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	#[rustfmt::skip]
@@ -678,7 +775,8 @@ impl WeightInfo for () {
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	#[rustfmt::skip]
@@ -688,8 +786,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_ref_time(239_400 as u64).saturating_mul(x as u64))
 			// Standard Error: 6_339
 			.saturating_add(Weight::from_ref_time(261_836 as u64).saturating_mul(y as u64))
-			.saturating_add(RocksDbWeight::get().reads(7 as u64))
-			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
@@ -703,7 +801,8 @@ impl WeightInfo for () {
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
@@ -713,9 +812,9 @@ impl WeightInfo for () {
 		Weight::from_ref_time(14_022_061 as u64)
 			// Standard Error: 30_972
 			.saturating_add(Weight::from_ref_time(29_549_278 as u64).saturating_mul(x as u64))
-			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(x as u64)))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(x as u64)))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
@@ -740,13 +839,14 @@ impl WeightInfo for () {
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	#[rustfmt::skip]
 	fn delegator_bond_more() -> Weight {
 		Weight::from_ref_time(90_452_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(8 as u64))
-			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
@@ -763,13 +863,14 @@ impl WeightInfo for () {
 	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:0)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	#[rustfmt::skip]
 	fn execute_revoke_delegation() -> Weight {
 		Weight::from_ref_time(112_401_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(9 as u64))
-			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
@@ -777,13 +878,14 @@ impl WeightInfo for () {
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	#[rustfmt::skip]
 	fn execute_delegator_bond_less() -> Weight {
 		Weight::from_ref_time(97_377_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(8 as u64))
-			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
@@ -823,7 +925,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_ref_time(233_812 as u64).saturating_mul(y as u64))
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 	}
-	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking CandidateBags (r:1 w:0)
+	// Storage: ParachainStaking CandidateNodes (r:1 w:0)
 	// Storage: ParachainStaking TotalSelected (r:1 w:0)
 	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
 	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
@@ -838,7 +941,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_ref_time(24_788_127 as u64).saturating_mul(x as u64))
 			// Standard Error: 118_210
 			.saturating_add(Weight::from_ref_time(2_957_731 as u64).saturating_mul(y as u64))
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
 			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(x as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(x as u64)))
@@ -879,7 +982,8 @@ impl WeightInfo for () {
 	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
 	// Storage: ParachainStaking TopDelegations (r:1 w:1)
-	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking Total (r:1 w:1)
 	// Storage: ParachainStaking BottomDelegations (r:1 w:1)
@@ -890,8 +994,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_ref_time(120_473 as u64).saturating_mul(x as u64))
 			// Standard Error: 5_811
 			.saturating_add(Weight::from_ref_time(80_638 as u64).saturating_mul(y as u64))
-			.saturating_add(RocksDbWeight::get().reads(8 as u64))
-			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
 	}
 	// Storage: System Account (r:1 w:1)
 	#[rustfmt::skip]
@@ -900,4 +1004,50 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking LastDelegationTransfer (r:1 w:1)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:2 w:0)
+	// Storage: ParachainStaking DelegatorState (r:2 w:2)
+	// Storage: ParachainStaking CandidateInfo (r:2 w:2)
+	// Storage: ParachainStaking AutoCompoundingDelegations (r:2 w:2)
+	// Storage: ParachainStaking TopDelegations (r:2 w:2)
+	// Storage: ParachainStaking BottomDelegations (r:2 w:2)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+	fn transfer_delegation(x: u32, y: u32, z: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(221_904_000 as u64)
+			// Standard Error: 6_104
+			.saturating_add(Weight::from_ref_time(241_092 as u64).saturating_mul(x as u64))
+			// Standard Error: 6_104
+			.saturating_add(Weight::from_ref_time(80_638 as u64).saturating_mul(y as u64))
+			// Standard Error: 6_104
+			.saturating_add(Weight::from_ref_time(241_092 as u64).saturating_mul(z as u64))
+			// Standard Error: 6_104
+			.saturating_add(Weight::from_ref_time(80_638 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads(22 as u64))
+			.saturating_add(RocksDbWeight::get().writes(19 as u64))
+	}
+	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking InstantUnstaked (r:1 w:1)
+	// Storage: ParachainStaking InstantUnstakeCap (r:1 w:0)
+	// Storage: ParachainStaking InstantUnstakeFee (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:0)
+	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	// Storage: ParachainStaking CandidateNodes (r:3 w:3)
+	// Storage: ParachainStaking CandidateBags (r:2 w:2)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+	fn instant_unstake() -> Weight {
+		Weight::from_ref_time(138_614_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(17 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
 }
@@ -0,0 +1,577 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Randomness Pallet
+//!
+//! - [`Config`]
+//!
+//! ## Overview
+//!
+//! Pallet that provides on-chain randomness produced by the block authors' VRF keys, replacing
+//! the collator-manipulable `RandomnessCollectiveFlip`.
+//!
+//! A mandatory inherent records the relay chain BABE randomness whenever the relay chain enters a
+//! new epoch, giving a per-epoch source of randomness that no collator can influence.
+//!
+//! Every block author includes a VRF pre-digest in the block header. The VRF input is the hash of
+//! the relay chain epoch randomness recorded by the parent block and the block number, so it
+//! cannot be chosen by the author, and the output is verified against the VRF key registered for
+//! the author's `NimbusId` in `VrfKeyLookup`. The verified output becomes the new local
+//! (per-block) randomness. Authors without a VRF key can't include a pre-digest, their blocks keep
+//! the local randomness of the parent block, as do the blocks authored before the first relay
+//! epoch randomness is recorded.
+//!
+//! Smart contracts request random words through the randomness precompile. A request reserves a
+//! deposit and a fee from the contract and names a future block (local VRF randomness) or relay
+//! epoch (BABE randomness). Once that randomness is recorded anyone can fulfill the request, which
+//! calls back the contract with the random words and pays the fulfiller's gas out of the fee.
+//! Requests that were not fulfilled in time can be purged by anyone, who is then paid the fee.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `set_babe_randomness_results` - mandatory inherent reading the relay chain epoch randomness
+//!
+//! ### Public Functions
+//!
+//! - `request_randomness` - registers a request and reserves its deposit and fee
+//! - `prepare_fulfillment` - removes a request that can be fulfilled and returns its random words
+//! - `finish_fulfillment` - releases the deposit and pays the fulfiller once the callback ran
+//! - `increase_request_fee` - raises the fee of a pending request
+//! - `execute_request_expiration` - purges an expired request, paying its fee to the caller
+//!
+//! ### Other
+//!
+//! - The pallet implements `frame_support::traits::Randomness` over the local randomness so it can
+//!   be used as the `RandomnessSource` of the author slot filter.
+//! - The VRF output of a block is verified and stored in `on_finalize`. While a block executes, the
+//!   stored output is still the one of its parent, which is exactly the value the next author
+//!   predicts its eligibility with.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod vrf;
+pub mod weights;
+pub use types::*;
+pub use weights::WeightInfo;
+
+/// Reads the BABE epoch data of the relay chain.
+pub trait GetBabeData<EpochIndex, Randomness> {
+	/// Index of the current relay chain epoch.
+	fn get_epoch_index() -> EpochIndex;
+	/// Randomness of the relay chain from one epoch ago.
+	fn get_epoch_randomness() -> Randomness;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement, Randomness, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+	use nimbus_primitives::NimbusId;
+	use session_keys_primitives::{InherentError, KeysLookup, VrfId, INHERENT_IDENTIFIER};
+	use sp_core::{hashing::blake2_256, H160, H256};
+	use sp_runtime::traits::{CheckedAdd, Convert, Hash, One, Saturating};
+	use sp_std::vec::Vec;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// A pending request of the pallet.
+	pub type RequestOf<T> = Request<BalanceOf<T>, RequestInfo<BlockNumberFor<T>>>;
+
+	/// Everything needed to fulfill a request, returned by `prepare_fulfillment`.
+	pub struct FulfillArgs<T: Config> {
+		/// The fulfilled request, already removed from storage
+		pub request: RequestOf<T>,
+		/// Deposit reserved for the request
+		pub deposit: BalanceOf<T>,
+		/// Random words passed to the `fulfillRandomWords` callback
+		pub randomness: Vec<[u8; 32]>,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Gets the VRF key registered for a block author.
+		type VrfKeyLookup: KeysLookup<NimbusId, VrfId>;
+
+		/// Gets the relay chain epoch index and randomness.
+		type BabeDataGetter: GetBabeData<u64, Option<Self::Hash>>;
+
+		/// Currency in which request deposits and fees are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Maps the EVM address of a requesting contract to its account.
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Deposit reserved for every request, returned once it is fulfilled or purged.
+		#[pallet::constant]
+		type Deposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of random words that can be requested at once.
+		#[pallet::constant]
+		type MaxRandomWords: Get<u8>;
+
+		/// Minimum number of blocks before a local randomness request can be fulfilled.
+		#[pallet::constant]
+		type MinBlockDelay: Get<Self::BlockNumber>;
+
+		/// Maximum number of blocks before a local randomness request can be fulfilled.
+		#[pallet::constant]
+		type MaxBlockDelay: Get<Self::BlockNumber>;
+
+		/// Number of blocks after which a local randomness request can be purged.
+		#[pallet::constant]
+		type BlockExpirationDelay: Get<Self::BlockNumber>;
+
+		/// Number of relay epochs after which a BABE randomness request can be purged.
+		#[pallet::constant]
+		type EpochExpirationDelay: Get<u64>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The relay chain entered a new epoch and its randomness was recorded.
+		NewEpochRandomness { epoch: u64, randomness: T::Hash },
+		/// A contract requested random words.
+		RandomnessRequested {
+			id: u64,
+			contract_address: H160,
+			fee: BalanceOf<T>,
+			num_words: u8,
+			request_type: RequestType<T::BlockNumber>,
+		},
+		/// A request was fulfilled and its deposit released.
+		RequestFulfilled { id: u64 },
+		/// The fee of a pending request was increased.
+		RequestFeeIncreased { id: u64, new_fee: BalanceOf<T> },
+		/// An expired request was purged and its fee paid to the caller.
+		RequestExpirationExecuted { id: u64 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No more request ids are available
+		RequestCounterOverflowed,
+		/// The fee of the request would overflow
+		RequestFeeOverflowed,
+		/// At least one random word must be requested
+		MustRequestAtLeastOneWord,
+		/// More words than `MaxRandomWords` were requested
+		CannotRequestMoreWordsThanMax,
+		/// The requested block is sooner than `MinBlockDelay` blocks from now
+		CannotRequestRandomnessBeforeMinDelay,
+		/// The requested block is later than `MaxBlockDelay` blocks from now
+		CannotRequestRandomnessAfterMaxDelay,
+		/// The requested relay epoch randomness may already be known
+		CannotRequestPastRandomness,
+		/// The request does not exist
+		RequestDNE,
+		/// The randomness of the request is not available yet
+		RequestCannotYetBeFulfilled,
+		/// Only the contract that made the request can increase its fee
+		OnlyRequesterCanIncreaseFee,
+		/// The request has not expired yet
+		RequestHasNotExpired,
+		/// No randomness result exists for the request
+		RandomnessResultDNE,
+		/// The randomness result of the request was not filled
+		RandomnessResultNotFilled,
+	}
+
+	/// VRF output of the most recent block.
+	#[pallet::storage]
+	#[pallet::getter(fn local_vrf_output)]
+	pub type LocalVrfOutput<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	/// Index of the last relay chain epoch seen by the parachain.
+	#[pallet::storage]
+	#[pallet::getter(fn relay_epoch)]
+	pub type RelayEpoch<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Relay chain randomness recorded at the start of `RelayEpoch`.
+	#[pallet::storage]
+	#[pallet::getter(fn epoch_randomness)]
+	pub type EpochRandomness<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	/// Number of requests made so far, the id of the next request.
+	#[pallet::storage]
+	#[pallet::getter(fn request_count)]
+	pub type RequestCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Pending randomness requests.
+	#[pallet::storage]
+	#[pallet::getter(fn requests)]
+	pub type Requests<T: Config> =
+		StorageMap<_, Twox64Concat, u64, RequestState<BalanceOf<T>, T::BlockNumber>>;
+
+	/// Randomness used to fulfill the pending requests, per randomness source.
+	#[pallet::storage]
+	#[pallet::getter(fn randomness_results)]
+	pub type RandomnessResults<T: Config> =
+		StorageMap<_, Twox64Concat, RequestType<T::BlockNumber>, RandomnessResult<T::Hash>>;
+
+	/// Ensures the mandatory inherent was included in the block.
+	#[pallet::storage]
+	pub(crate) type InherentIncluded<T: Config> = StorageValue<_, ()>;
+
+	/// VRF input of the current block, taken before the inherent records a new epoch randomness.
+	#[pallet::storage]
+	pub(crate) type VrfInput<T: Config> = StorageValue<_, T::Hash>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// The VRF output is verified in `on_finalize` against the input the author saw
+			if let Some(vrf_input) = Self::vrf_input(now) {
+				VrfInput::<T>::put(vrf_input);
+			}
+			T::WeightInfo::on_finalize()
+		}
+
+		fn on_finalize(now: BlockNumberFor<T>) {
+			assert!(
+				InherentIncluded::<T>::take().is_some(),
+				"Mandatory randomness inherent not included; InherentIncluded storage item is empty"
+			);
+			vrf::verify_and_set_output::<T>();
+
+			// Requests for this block are fulfilled with its VRF output
+			RandomnessResults::<T>::mutate_exists(RequestType::Local(now), |result| {
+				if let Some(result) = result {
+					result.randomness = LocalVrfOutput::<T>::get();
+				}
+			});
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Records the relay chain randomness when the relay chain enters a new epoch.
+		///
+		/// This inherent is mandatory and must be included in every block.
+		#[pallet::call_index(0)]
+		#[pallet::weight((T::WeightInfo::set_babe_randomness_results(), DispatchClass::Mandatory))]
+		pub fn set_babe_randomness_results(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			let relay_epoch = T::BabeDataGetter::get_epoch_index();
+			if relay_epoch > RelayEpoch::<T>::get() {
+				if let Some(randomness) = T::BabeDataGetter::get_epoch_randomness() {
+					EpochRandomness::<T>::put(randomness);
+					RandomnessResults::<T>::mutate_exists(
+						RequestType::BabeEpoch(relay_epoch),
+						|result| {
+							if let Some(result) = result {
+								result.randomness = Some(randomness);
+							}
+						},
+					);
+					Self::deposit_event(Event::NewEpochRandomness {
+						epoch: relay_epoch,
+						randomness,
+					});
+				}
+				RelayEpoch::<T>::put(relay_epoch);
+			}
+
+			InherentIncluded::<T>::put(());
+
+			Ok(Pays::No.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Registers a request made by `request.contract_address` and reserves its deposit and
+		/// fee from the contract. Returns the id of the request.
+		pub fn request_randomness(
+			request: Request<BalanceOf<T>, RequestType<T::BlockNumber>>,
+		) -> Result<u64, DispatchError> {
+			ensure!(request.num_words >= 1, Error::<T>::MustRequestAtLeastOneWord);
+			ensure!(
+				request.num_words <= T::MaxRandomWords::get(),
+				Error::<T>::CannotRequestMoreWordsThanMax
+			);
+
+			let info = match request.info {
+				RequestType::BabeEpoch(epoch) => {
+					// The randomness recorded in the next epoch is already being produced
+					ensure!(
+						epoch > Self::relay_epoch().saturating_add(1),
+						Error::<T>::CannotRequestPastRandomness
+					);
+					RequestInfo::BabeEpoch(
+						epoch,
+						epoch.saturating_add(T::EpochExpirationDelay::get()),
+					)
+				},
+				RequestType::Local(block) => {
+					let now = frame_system::Pallet::<T>::block_number();
+					ensure!(
+						block >= now.saturating_add(T::MinBlockDelay::get()),
+						Error::<T>::CannotRequestRandomnessBeforeMinDelay
+					);
+					ensure!(
+						block <= now.saturating_add(T::MaxBlockDelay::get()),
+						Error::<T>::CannotRequestRandomnessAfterMaxDelay
+					);
+					RequestInfo::Local(block, block.saturating_add(T::BlockExpirationDelay::get()))
+				},
+			};
+
+			let id = RequestCount::<T>::get();
+			let next_id = id.checked_add(1).ok_or(Error::<T>::RequestCounterOverflowed)?;
+
+			let deposit = T::Deposit::get();
+			let contract = T::AddressMapping::convert(request.contract_address);
+			T::Currency::reserve(&contract, deposit.saturating_add(request.fee))?;
+
+			RandomnessResults::<T>::mutate(request.info, |result| {
+				let result = result.get_or_insert_with(Default::default);
+				result.request_count = result.request_count.saturating_add(1);
+			});
+			RequestCount::<T>::put(next_id);
+			Requests::<T>::insert(
+				id,
+				RequestState {
+					request: Request {
+						refund_address: request.refund_address,
+						contract_address: request.contract_address,
+						fee: request.fee,
+						gas_limit: request.gas_limit,
+						num_words: request.num_words,
+						salt: request.salt,
+						info,
+					},
+					deposit,
+				},
+			);
+
+			Self::deposit_event(Event::RandomnessRequested {
+				id,
+				contract_address: request.contract_address,
+				fee: request.fee,
+				num_words: request.num_words,
+				request_type: request.info,
+			});
+			Ok(id)
+		}
+
+		/// Removes a request whose randomness is available and returns its random words.
+		///
+		/// The request is removed before the callback is executed so it cannot be fulfilled twice,
+		/// `finish_fulfillment` must be called with the returned arguments once the callback ran.
+		pub fn prepare_fulfillment(id: u64) -> Result<FulfillArgs<T>, DispatchError> {
+			let RequestState { request, deposit } =
+				Requests::<T>::get(id).ok_or(Error::<T>::RequestDNE)?;
+			ensure!(Self::can_be_fulfilled(&request.info), Error::<T>::RequestCannotYetBeFulfilled);
+
+			let request_type = request.info.request_type();
+			let randomness = RandomnessResults::<T>::get(request_type)
+				.ok_or(Error::<T>::RandomnessResultDNE)?
+				.randomness
+				.ok_or(Error::<T>::RandomnessResultNotFilled)?;
+			Self::remove_request(id, request_type);
+
+			let randomness = Self::random_words(randomness, request.salt, request.num_words);
+			Ok(FulfillArgs { request, deposit, randomness })
+		}
+
+		/// Releases the deposit of a fulfilled request, pays `cost` out of the fee to `caller` and
+		/// refunds the rest of the fee to the refund address of the request.
+		pub fn finish_fulfillment(
+			id: u64,
+			request: RequestOf<T>,
+			deposit: BalanceOf<T>,
+			caller: &T::AccountId,
+			cost: BalanceOf<T>,
+		) -> DispatchResult {
+			let contract = T::AddressMapping::convert(request.contract_address);
+			T::Currency::unreserve(&contract, deposit.saturating_add(request.fee));
+
+			let cost = cost.min(request.fee);
+			T::Currency::transfer(&contract, caller, cost, ExistenceRequirement::AllowDeath)?;
+
+			let refund_account = T::AddressMapping::convert(request.refund_address);
+			if refund_account != contract {
+				T::Currency::transfer(
+					&contract,
+					&refund_account,
+					request.fee.saturating_sub(cost),
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+
+			Self::deposit_event(Event::RequestFulfilled { id });
+			Ok(())
+		}
+
+		/// Increases the fee of a pending request. Only the contract that made the request can
+		/// increase its fee.
+		pub fn increase_request_fee(
+			caller: &H160,
+			id: u64,
+			fee_increase: BalanceOf<T>,
+		) -> DispatchResult {
+			Requests::<T>::try_mutate(id, |state| -> DispatchResult {
+				let state = state.as_mut().ok_or(Error::<T>::RequestDNE)?;
+				ensure!(
+					state.request.contract_address == *caller,
+					Error::<T>::OnlyRequesterCanIncreaseFee
+				);
+				let new_fee = state
+					.request
+					.fee
+					.checked_add(&fee_increase)
+					.ok_or(Error::<T>::RequestFeeOverflowed)?;
+
+				T::Currency::reserve(&T::AddressMapping::convert(*caller), fee_increase)?;
+				state.request.fee = new_fee;
+
+				Self::deposit_event(Event::RequestFeeIncreased { id, new_fee });
+				Ok(())
+			})
+		}
+
+		/// Purges an expired request. Its deposit is returned to the contract and its fee is paid
+		/// to `caller`.
+		pub fn execute_request_expiration(caller: &T::AccountId, id: u64) -> DispatchResult {
+			let RequestState { request, deposit } =
+				Requests::<T>::get(id).ok_or(Error::<T>::RequestDNE)?;
+			ensure!(Self::is_expired(&request.info), Error::<T>::RequestHasNotExpired);
+			Self::remove_request(id, request.info.request_type());
+
+			let contract = T::AddressMapping::convert(request.contract_address);
+			T::Currency::unreserve(&contract, deposit.saturating_add(request.fee));
+			T::Currency::transfer(
+				&contract,
+				caller,
+				request.fee,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::RequestExpirationExecuted { id });
+			Ok(())
+		}
+
+		/// VRF input of block `number`, derived from the relay chain epoch randomness recorded so
+		/// far. `None` until the first epoch randomness is recorded.
+		pub fn vrf_input(number: BlockNumberFor<T>) -> Option<T::Hash> {
+			EpochRandomness::<T>::get().map(|randomness| T::Hashing::hash_of(&(randomness, number)))
+		}
+
+		/// Whether the randomness a request is fulfilled with should be available.
+		pub fn can_be_fulfilled(info: &RequestInfo<T::BlockNumber>) -> bool {
+			match info {
+				RequestInfo::BabeEpoch(epoch, _) => Self::relay_epoch() >= *epoch,
+				// The VRF output of a block is only known once the block is finalized
+				RequestInfo::Local(block, _) => frame_system::Pallet::<T>::block_number() > *block,
+			}
+		}
+
+		/// Whether a request can be purged.
+		pub fn is_expired(info: &RequestInfo<T::BlockNumber>) -> bool {
+			match info {
+				RequestInfo::BabeEpoch(_, expires) => Self::relay_epoch() >= *expires,
+				RequestInfo::Local(_, expires) =>
+					frame_system::Pallet::<T>::block_number() >= *expires,
+			}
+		}
+
+		fn remove_request(id: u64, request_type: RequestType<T::BlockNumber>) {
+			Requests::<T>::remove(id);
+			RandomnessResults::<T>::mutate_exists(request_type, |result| {
+				if let Some(remaining) = result {
+					remaining.request_count = remaining.request_count.saturating_sub(1);
+					if remaining.request_count == 0 {
+						*result = None;
+					}
+				}
+			});
+		}
+
+		/// Derives `num_words` different random words from `randomness` and the request salt.
+		fn random_words(randomness: T::Hash, salt: H256, num_words: u8) -> Vec<[u8; 32]> {
+			(0..num_words)
+				.map(|index| blake2_256(&(randomness, salt, index).encode()))
+				.collect()
+		}
+	}
+
+	#[pallet::inherent]
+	impl<T: Config> ProvideInherent for Pallet<T> {
+		type Call = Call<T>;
+		type Error = InherentError;
+		const INHERENT_IDENTIFIER: InherentIdentifier = INHERENT_IDENTIFIER;
+
+		fn is_inherent_required(_: &InherentData) -> Result<Option<Self::Error>, Self::Error> {
+			// The inherent is required in every block
+			Ok(Some(InherentError::Other(sp_runtime::RuntimeString::Borrowed(
+				"Inherent required to set babe randomness results",
+			))))
+		}
+
+		fn create_inherent(_data: &InherentData) -> Option<Self::Call> {
+			Some(Call::set_babe_randomness_results {})
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			matches!(call, Call::set_babe_randomness_results {})
+		}
+	}
+
+	impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+		/// Mixes `subject` with the VRF output of the last block.
+		///
+		/// The returned block number is the block whose author produced the VRF output, that
+		/// author knew the randomness before it was revealed.
+		fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+			let vrf_output = LocalVrfOutput::<T>::get().unwrap_or_default();
+			let mut seed = Vec::with_capacity(subject.len() + vrf_output.as_ref().len());
+			seed.extend_from_slice(subject);
+			seed.extend_from_slice(vrf_output.as_ref());
+
+			(
+				T::Hashing::hash(&seed),
+				frame_system::Pallet::<T>::block_number().saturating_sub(One::one()),
+			)
+		}
+	}
+}
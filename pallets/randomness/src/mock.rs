@@ -0,0 +1,164 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_randomness, GetBabeData};
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU64, ConstU8, Everything},
+};
+use nimbus_primitives::NimbusId;
+use session_keys_primitives::{KeysLookup, VrfId};
+use sp_core::{H160, H256};
+use sp_io::TestExternalities;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
+};
+
+pub(crate) type AccountId = u64;
+pub(crate) type Balance = u128;
+pub(crate) type BlockNumber = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Randomness: pallet_randomness::{Pallet, Call, Storage, Event<T>, Inherent},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+/// Every author uses its nimbus key as VRF key, unless `VrfKeysRegistered` is unset.
+pub struct VrfKeysFromNimbusId;
+impl KeysLookup<NimbusId, VrfId> for VrfKeysFromNimbusId {
+	fn lookup_keys(author: &NimbusId) -> Option<VrfId> {
+		VrfKeysRegistered::get().then(|| author.clone().into())
+	}
+}
+
+parameter_types! {
+	pub static VrfKeysRegistered: bool = true;
+	pub static RelayEpochIndex: u64 = 0;
+	pub static RelayEpochRandomness: Option<H256> = None;
+}
+
+pub struct MockBabeDataGetter;
+impl GetBabeData<u64, Option<H256>> for MockBabeDataGetter {
+	fn get_epoch_index() -> u64 {
+		RelayEpochIndex::get()
+	}
+	fn get_epoch_randomness() -> Option<H256> {
+		RelayEpochRandomness::get()
+	}
+}
+
+/// Maps an EVM address to the account of its low 8 bytes.
+pub struct TruncatedAddressMapping;
+impl Convert<H160, AccountId> for TruncatedAddressMapping {
+	fn convert(address: H160) -> AccountId {
+		address.to_low_u64_be()
+	}
+}
+
+pub(crate) const ALICE: AccountId = 1;
+pub(crate) const BOB: AccountId = 2;
+pub(crate) const CONTRACT: AccountId = 10;
+pub(crate) const DEPOSIT: Balance = 10;
+
+/// EVM address of `account`.
+pub(crate) fn evm_address(account: AccountId) -> H160 {
+	H160::from_low_u64_be(account)
+}
+
+impl pallet_randomness::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type VrfKeyLookup = VrfKeysFromNimbusId;
+	type BabeDataGetter = MockBabeDataGetter;
+	type Currency = Balances;
+	type AddressMapping = TruncatedAddressMapping;
+	type Deposit = ConstU128<DEPOSIT>;
+	type MaxRandomWords = ConstU8<100>;
+	type MinBlockDelay = ConstU64<2>;
+	type MaxBlockDelay = ConstU64<20>;
+	type BlockExpirationDelay = ConstU64<50>;
+	type EpochExpirationDelay = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+pub(crate) fn new_test_ext() -> TestExternalities {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(ALICE, 100), (BOB, 100), (CONTRACT, 1_000)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = TestExternalities::from(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
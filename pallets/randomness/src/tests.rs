@@ -0,0 +1,465 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	mock::*, EpochRandomness, Error, Event, FulfillArgs, LocalVrfOutput, RandomnessResult,
+	RandomnessResults, RelayEpoch, Request, RequestInfo, RequestType, Requests,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnFinalize, OnInitialize, Randomness as RandomnessT},
+};
+use nimbus_primitives::{NimbusId, NIMBUS_ENGINE_ID};
+use parity_scale_codec::Encode;
+use session_keys_primitives::{
+	make_transcript, make_transcript_data, CompatibleDigestItem, PreDigest, VRF_ENGINE_ID,
+	VRF_INOUT_CONTEXT, VRF_KEY_ID,
+};
+use sp_consensus_vrf::schnorrkel::{PublicKey, VRFOutput, VRFProof};
+use sp_core::{hashing::blake2_256, H256};
+use sp_keystore::{testing::KeyStore, SyncCryptoStore};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	Digest, DigestItem, DispatchError,
+};
+
+/// Signs `vrf_input` with a fresh VRF key. Returns the digest of a block authored with that key
+/// together with the VRF output the runtime is expected to derive from it.
+fn author_digest(vrf_input: H256) -> (Digest, H256) {
+	let keystore = KeyStore::new();
+	let public = SyncCryptoStore::sr25519_generate_new(&keystore, VRF_KEY_ID, None).unwrap();
+	let signature = SyncCryptoStore::sr25519_vrf_sign(
+		&keystore,
+		VRF_KEY_ID,
+		&public,
+		make_transcript_data(vrf_input),
+	)
+	.unwrap()
+	.unwrap();
+	let (inout, _) = PublicKey::from_bytes(public.as_ref())
+		.unwrap()
+		.vrf_verify(make_transcript(vrf_input), &signature.output, &signature.proof)
+		.unwrap();
+
+	let nimbus_id: NimbusId = public.into();
+	let digest = Digest {
+		logs: vec![
+			DigestItem::PreRuntime(NIMBUS_ENGINE_ID, nimbus_id.encode()),
+			DigestItem::vrf_pre_digest(PreDigest {
+				vrf_output: VRFOutput(signature.output),
+				vrf_proof: VRFProof(signature.proof),
+			}),
+		],
+	};
+	(digest, H256(inout.make_bytes::<[u8; 32]>(VRF_INOUT_CONTEXT)))
+}
+
+/// Makes the inherent of the next block record the randomness of a new relay epoch.
+fn enter_relay_epoch(epoch: u64, randomness: H256) {
+	RelayEpochIndex::set(epoch);
+	RelayEpochRandomness::set(Some(randomness));
+}
+
+/// Executes block `number` with the given digest.
+fn run_block(number: BlockNumber, digest: Digest) {
+	System::initialize(&number, &H256::repeat_byte(number as u8), &digest);
+	Randomness::on_initialize(number);
+	assert_ok!(Randomness::set_babe_randomness_results(RuntimeOrigin::none()));
+	Randomness::on_finalize(number);
+}
+
+/// Executes the blocks up to `number`, every block signing its VRF input.
+fn run_to_block(number: BlockNumber) {
+	while System::block_number() < number {
+		let next = System::block_number() + 1;
+		let (digest, _) = author_digest(Randomness::vrf_input(next).unwrap());
+		run_block(next, digest);
+	}
+}
+
+/// Requests `num_words` words for `request_type` from `CONTRACT` with a fee of 20.
+fn request(request_type: RequestType<BlockNumber>, num_words: u8) -> Result<u64, DispatchError> {
+	Randomness::request_randomness(Request {
+		refund_address: evm_address(ALICE),
+		contract_address: evm_address(CONTRACT),
+		fee: 20,
+		gas_limit: 100_000,
+		num_words,
+		salt: H256::repeat_byte(3),
+		info: request_type,
+	})
+}
+
+#[test]
+fn first_block_seeds_vrf_output_with_parent_hash() {
+	new_test_ext().execute_with(|| {
+		run_block(1, Digest::default());
+
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(H256::repeat_byte(1)));
+	});
+}
+
+#[test]
+fn valid_vrf_pre_digest_sets_local_output() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+
+		let vrf_input = Randomness::vrf_input(2).unwrap();
+		assert_eq!(vrf_input, BlakeTwo256::hash_of(&(H256::repeat_byte(7), 2u64)));
+		let (digest, expected) = author_digest(vrf_input);
+		run_block(2, digest);
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(expected));
+
+		// Every block of the relay epoch signs a different input
+		let (digest, expected) = author_digest(Randomness::vrf_input(3).unwrap());
+		run_block(3, digest);
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(expected));
+	});
+}
+
+#[test]
+fn vrf_input_is_taken_before_new_epoch_randomness_is_recorded() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+
+		// The author signs the input of the parent state, the inherent then records the
+		// randomness of the new relay epoch
+		let (digest, expected) = author_digest(Randomness::vrf_input(2).unwrap());
+		enter_relay_epoch(2, H256::repeat_byte(8));
+		run_block(2, digest);
+		assert_eq!(EpochRandomness::<Test>::get(), Some(H256::repeat_byte(8)));
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(expected));
+
+		let vrf_input = Randomness::vrf_input(3).unwrap();
+		assert_eq!(vrf_input, BlakeTwo256::hash_of(&(H256::repeat_byte(8), 3u64)));
+		let (digest, expected) = author_digest(vrf_input);
+		run_block(3, digest);
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(expected));
+	});
+}
+
+#[test]
+fn blocks_before_first_epoch_randomness_keep_seeded_output() {
+	new_test_ext().execute_with(|| {
+		run_block(1, Digest::default());
+		assert_eq!(Randomness::vrf_input(2), None);
+
+		// There is no input to verify the pre-digest against
+		let (digest, _) = author_digest(H256::repeat_byte(1));
+		run_block(2, digest);
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(H256::repeat_byte(1)));
+	});
+}
+
+#[test]
+#[should_panic(expected = "VRF signature verification failed")]
+fn vrf_pre_digest_for_wrong_input_is_rejected() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+
+		let (digest, _) = author_digest(H256::repeat_byte(42));
+		run_block(2, digest);
+	});
+}
+
+#[test]
+fn block_without_author_keeps_parent_output() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+		run_block(2, Digest::default());
+
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(H256::repeat_byte(1)));
+	});
+}
+
+#[test]
+fn pre_digest_without_author_keeps_parent_output() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+
+		// Without a nimbus pre-digest no VRF key can be looked up for the author
+		let (mut digest, _) = author_digest(Randomness::vrf_input(2).unwrap());
+		digest.logs.remove(0);
+		run_block(2, digest);
+
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(H256::repeat_byte(1)));
+	});
+}
+
+#[test]
+fn author_without_registered_vrf_key_keeps_parent_output() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+
+		VrfKeysRegistered::set(false);
+		let (mut digest, _) = author_digest(Randomness::vrf_input(2).unwrap());
+		digest.logs.remove(1);
+		run_block(2, digest);
+
+		assert_eq!(LocalVrfOutput::<Test>::get(), Some(H256::repeat_byte(1)));
+	});
+}
+
+#[test]
+#[should_panic(
+	expected = "Block author with a registered VRF key must include a valid VRF pre-digest"
+)]
+fn registered_author_without_vrf_pre_digest_is_rejected() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+
+		// Dropping the pre-digest must not let the author keep the output of the parent
+		let (mut digest, _) = author_digest(Randomness::vrf_input(2).unwrap());
+		digest.logs.remove(1);
+		run_block(2, digest);
+	});
+}
+
+#[test]
+#[should_panic(
+	expected = "Block author with a registered VRF key must include a valid VRF pre-digest"
+)]
+fn registered_author_with_undecodable_vrf_pre_digest_is_rejected() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+
+		let (mut digest, _) = author_digest(Randomness::vrf_input(2).unwrap());
+		digest.logs[1] = DigestItem::PreRuntime(VRF_ENGINE_ID, vec![1, 2, 3]);
+		run_block(2, digest);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Mandatory randomness inherent not included")]
+fn missing_inherent_is_rejected() {
+	new_test_ext().execute_with(|| {
+		Randomness::on_finalize(1);
+	});
+}
+
+#[test]
+fn epoch_randomness_is_recorded_once_per_relay_epoch() {
+	new_test_ext().execute_with(|| {
+		RelayEpochIndex::set(1);
+		RelayEpochRandomness::set(Some(H256::repeat_byte(7)));
+		assert_ok!(Randomness::set_babe_randomness_results(RuntimeOrigin::none()));
+
+		assert_eq!(RelayEpoch::<Test>::get(), 1);
+		assert_eq!(EpochRandomness::<Test>::get(), Some(H256::repeat_byte(7)));
+		System::assert_last_event(RuntimeEvent::Randomness(Event::NewEpochRandomness {
+			epoch: 1,
+			randomness: H256::repeat_byte(7),
+		}));
+
+		// Randomness is only read when the relay chain enters a new epoch
+		RelayEpochRandomness::set(Some(H256::repeat_byte(8)));
+		assert_ok!(Randomness::set_babe_randomness_results(RuntimeOrigin::none()));
+		assert_eq!(EpochRandomness::<Test>::get(), Some(H256::repeat_byte(7)));
+
+		RelayEpochIndex::set(2);
+		assert_ok!(Randomness::set_babe_randomness_results(RuntimeOrigin::none()));
+		assert_eq!(RelayEpoch::<Test>::get(), 2);
+		assert_eq!(EpochRandomness::<Test>::get(), Some(H256::repeat_byte(8)));
+	});
+}
+
+#[test]
+fn random_mixes_subject_with_last_vrf_output() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+		System::set_block_number(2);
+
+		let (first, block_number) = <Randomness as RandomnessT<_, _>>::random(b"first");
+		let (second, _) = <Randomness as RandomnessT<_, _>>::random(b"second");
+		assert_ne!(first, second);
+		assert_eq!(block_number, 1);
+
+		// A new VRF output changes the randomness for the same subject
+		let (digest, _) = author_digest(Randomness::vrf_input(2).unwrap());
+		run_block(2, digest);
+		assert_ne!(<Randomness as RandomnessT<_, _>>::random(b"first").0, first);
+	});
+}
+
+#[test]
+fn request_reserves_deposit_and_fee() {
+	new_test_ext().execute_with(|| {
+		run_block(1, Digest::default());
+
+		assert_eq!(request(RequestType::Local(3), 2), Ok(0));
+		assert_eq!(request(RequestType::Local(3), 1), Ok(1));
+
+		assert_eq!(Balances::reserved_balance(CONTRACT), 2 * (DEPOSIT + 20));
+		assert_eq!(Randomness::request_count(), 2);
+		assert_eq!(Requests::<Test>::get(0).unwrap().request.info, RequestInfo::Local(3, 53));
+		assert_eq!(
+			RandomnessResults::<Test>::get(RequestType::Local(3)),
+			Some(RandomnessResult { randomness: None, request_count: 2 })
+		);
+		System::assert_last_event(RuntimeEvent::Randomness(Event::RandomnessRequested {
+			id: 1,
+			contract_address: evm_address(CONTRACT),
+			fee: 20,
+			num_words: 1,
+			request_type: RequestType::Local(3),
+		}));
+	});
+}
+
+#[test]
+fn invalid_requests_are_rejected() {
+	new_test_ext().execute_with(|| {
+		run_block(1, Digest::default());
+
+		assert_noop!(request(RequestType::Local(3), 0), Error::<Test>::MustRequestAtLeastOneWord);
+		assert_noop!(
+			request(RequestType::Local(3), 101),
+			Error::<Test>::CannotRequestMoreWordsThanMax
+		);
+		assert_noop!(
+			request(RequestType::Local(2), 1),
+			Error::<Test>::CannotRequestRandomnessBeforeMinDelay
+		);
+		assert_noop!(
+			request(RequestType::Local(22), 1),
+			Error::<Test>::CannotRequestRandomnessAfterMaxDelay
+		);
+		assert_noop!(
+			request(RequestType::BabeEpoch(1), 1),
+			Error::<Test>::CannotRequestPastRandomness
+		);
+	});
+}
+
+#[test]
+fn local_request_is_fulfilled_with_vrf_output_of_requested_block() {
+	new_test_ext().execute_with(|| {
+		enter_relay_epoch(1, H256::repeat_byte(7));
+		run_block(1, Digest::default());
+		assert_ok!(request(RequestType::Local(3), 2));
+
+		run_to_block(3);
+		assert_noop!(
+			Randomness::prepare_fulfillment(0),
+			Error::<Test>::RequestCannotYetBeFulfilled
+		);
+		let vrf_output = LocalVrfOutput::<Test>::get().unwrap();
+
+		run_to_block(4);
+		let FulfillArgs { request, deposit, randomness } =
+			Randomness::prepare_fulfillment(0).unwrap();
+		assert_eq!(
+			randomness,
+			vec![
+				blake2_256(&(vrf_output, H256::repeat_byte(3), 0u8).encode()),
+				blake2_256(&(vrf_output, H256::repeat_byte(3), 1u8).encode()),
+			]
+		);
+		// The request cannot be fulfilled twice
+		assert!(Requests::<Test>::get(0).is_none());
+		assert!(RandomnessResults::<Test>::get(RequestType::Local(3)).is_none());
+
+		assert_ok!(Randomness::finish_fulfillment(0, request, deposit, &BOB, 5));
+		assert_eq!(Balances::reserved_balance(CONTRACT), 0);
+		assert_eq!(Balances::free_balance(CONTRACT), 980);
+		assert_eq!(Balances::free_balance(BOB), 105);
+		assert_eq!(Balances::free_balance(ALICE), 115);
+		System::assert_last_event(RuntimeEvent::Randomness(Event::RequestFulfilled { id: 0 }));
+	});
+}
+
+#[test]
+fn babe_request_is_fulfilled_with_relay_epoch_randomness() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(request(RequestType::BabeEpoch(2), 1));
+
+		RelayEpochIndex::set(1);
+		RelayEpochRandomness::set(Some(H256::repeat_byte(7)));
+		assert_ok!(Randomness::set_babe_randomness_results(RuntimeOrigin::none()));
+		assert_noop!(
+			Randomness::prepare_fulfillment(0),
+			Error::<Test>::RequestCannotYetBeFulfilled
+		);
+
+		RelayEpochIndex::set(2);
+		RelayEpochRandomness::set(Some(H256::repeat_byte(8)));
+		assert_ok!(Randomness::set_babe_randomness_results(RuntimeOrigin::none()));
+		let FulfillArgs { randomness, .. } = Randomness::prepare_fulfillment(0).unwrap();
+		assert_eq!(
+			randomness,
+			vec![blake2_256(&(H256::repeat_byte(8), H256::repeat_byte(3), 0u8).encode())]
+		);
+	});
+}
+
+#[test]
+fn only_requester_can_increase_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(request(RequestType::BabeEpoch(2), 1));
+
+		assert_noop!(
+			Randomness::increase_request_fee(&evm_address(ALICE), 0, 5),
+			Error::<Test>::OnlyRequesterCanIncreaseFee
+		);
+		assert_noop!(
+			Randomness::increase_request_fee(&evm_address(CONTRACT), 1, 5),
+			Error::<Test>::RequestDNE
+		);
+
+		assert_ok!(Randomness::increase_request_fee(&evm_address(CONTRACT), 0, 5));
+		assert_eq!(Requests::<Test>::get(0).unwrap().request.fee, 25);
+		assert_eq!(Balances::reserved_balance(CONTRACT), DEPOSIT + 25);
+		System::assert_last_event(RuntimeEvent::Randomness(Event::RequestFeeIncreased {
+			id: 0,
+			new_fee: 25,
+		}));
+	});
+}
+
+#[test]
+fn expired_request_is_purged_and_fee_paid_to_caller() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(request(RequestType::BabeEpoch(2), 1));
+		assert_noop!(
+			Randomness::execute_request_expiration(&BOB, 0),
+			Error::<Test>::RequestHasNotExpired
+		);
+
+		RelayEpochIndex::set(7);
+		assert_ok!(Randomness::set_babe_randomness_results(RuntimeOrigin::none()));
+		assert_ok!(Randomness::execute_request_expiration(&BOB, 0));
+
+		assert!(Requests::<Test>::get(0).is_none());
+		assert!(RandomnessResults::<Test>::get(RequestType::BabeEpoch(2)).is_none());
+		assert_eq!(Balances::reserved_balance(CONTRACT), 0);
+		assert_eq!(Balances::free_balance(CONTRACT), 980);
+		assert_eq!(Balances::free_balance(BOB), 120);
+		System::assert_last_event(RuntimeEvent::Randomness(Event::RequestExpirationExecuted {
+			id: 0,
+		}));
+	});
+}
@@ -0,0 +1,93 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Types of the randomness requests made by smart contracts
+
+use frame_support::pallet_prelude::*;
+use sp_core::{H160, H256};
+
+/// The source of randomness a request is fulfilled with.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RequestType<BlockNumber> {
+	/// Relay chain randomness recorded when the parachain sees the given relay epoch.
+	BabeEpoch(u64),
+	/// VRF output of the given parachain block.
+	Local(BlockNumber),
+}
+
+/// When a request can be fulfilled and when it expires.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RequestInfo<BlockNumber> {
+	/// Relay epoch from which the request can be fulfilled, and relay epoch it expires at.
+	BabeEpoch(u64, u64),
+	/// Block after which the request can be fulfilled, and block it expires at.
+	Local(BlockNumber, BlockNumber),
+}
+
+impl<BlockNumber: Copy> RequestInfo<BlockNumber> {
+	/// The randomness result this request is fulfilled with.
+	pub fn request_type(&self) -> RequestType<BlockNumber> {
+		match self {
+			RequestInfo::BabeEpoch(epoch, _) => RequestType::BabeEpoch(*epoch),
+			RequestInfo::Local(block, _) => RequestType::Local(*block),
+		}
+	}
+}
+
+/// A randomness request made by a smart contract.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Request<Balance, Info> {
+	/// Address the unused part of the fee is refunded to
+	pub refund_address: H160,
+	/// Contract that made the request and receives the `fulfillRandomWords` callback
+	pub contract_address: H160,
+	/// Fee paid to the account fulfilling the request, used to refund its gas
+	pub fee: Balance,
+	/// Gas limit of the `fulfillRandomWords` callback
+	pub gas_limit: u64,
+	/// Number of random words requested
+	pub num_words: u8,
+	/// Salt mixed with the randomness so every request gets different words
+	pub salt: H256,
+	/// Randomness source, fulfillment and expiration of the request
+	pub info: Info,
+}
+
+/// A pending request with the deposit reserved for it.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RequestState<Balance, BlockNumber> {
+	/// The request
+	pub request: Request<Balance, RequestInfo<BlockNumber>>,
+	/// Deposit reserved from the contract, returned once the request is fulfilled or purged
+	pub deposit: Balance,
+}
+
+/// Randomness used to fulfill all the requests of one `RequestType`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RandomnessResult<Hash> {
+	/// The randomness, `None` until it is available
+	pub randomness: Option<Hash>,
+	/// Number of pending requests using this result, removed once it drops to zero
+	pub request_count: u64,
+}
+
+impl<Hash> Default for RandomnessResult<Hash> {
+	fn default() -> Self {
+		RandomnessResult { randomness: None, request_count: 0 }
+	}
+}
@@ -0,0 +1,89 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! VRF verification of the block author's pre-digest
+use crate::{Config, LocalVrfOutput, VrfInput};
+use nimbus_primitives::{NimbusId, NIMBUS_ENGINE_ID};
+use parity_scale_codec::Decode;
+use session_keys_primitives::{
+	make_transcript, KeysLookup, PreDigest, VRF_ENGINE_ID, VRF_INOUT_CONTEXT,
+};
+use sp_consensus_vrf::schnorrkel::{PublicKey, VRF_OUTPUT_LENGTH};
+use sp_core::ByteArray;
+
+/// Verifies the VRF pre-digest of the current block against the `VrfInput` taken in
+/// `on_initialize` and stores its output as the new `LocalVrfOutput`.
+///
+/// Blocks of authors without a registered VRF key keep the output of the parent. Panics if the
+/// author has a registered VRF key and the pre-digest is missing or invalid, which makes the block
+/// invalid.
+pub(crate) fn verify_and_set_output<T: Config>() {
+	let vrf_input = match VrfInput::<T>::take() {
+		Some(input) => input,
+		None => {
+			// No relay epoch randomness was recorded yet, so there is no input the author could
+			// have signed. Seed the local randomness with the parent hash until there is one.
+			if LocalVrfOutput::<T>::get().is_none() {
+				LocalVrfOutput::<T>::put(frame_system::Pallet::<T>::parent_hash());
+			}
+			return;
+		},
+	};
+
+	let mut pre_digest = None;
+	let mut author_vrf_id = None;
+	for (id, mut data) in frame_system::Pallet::<T>::digest()
+		.logs
+		.iter()
+		.filter_map(|log| log.as_pre_runtime())
+	{
+		if id == VRF_ENGINE_ID {
+			pre_digest = PreDigest::decode(&mut data).ok();
+		} else if id == NIMBUS_ENGINE_ID {
+			author_vrf_id = NimbusId::decode(&mut data)
+				.ok()
+				.and_then(|nimbus_id| T::VrfKeyLookup::lookup_keys(&nimbus_id));
+		}
+	}
+
+	let author_vrf_id = match author_vrf_id {
+		Some(author_vrf_id) => author_vrf_id,
+		None => {
+			log::warn!(
+				target: "randomness",
+				"No VRF key registered for the block author, keeping the VRF output of the parent block",
+			);
+			return;
+		},
+	};
+	// The input is known in advance, an author with a registered key must not be able to pick
+	// between the new output and the one of the parent by leaving out the pre-digest.
+	let PreDigest { vrf_output, vrf_proof } = pre_digest
+		.expect("Block author with a registered VRF key must include a valid VRF pre-digest");
+	let public = PublicKey::from_bytes(author_vrf_id.as_slice())
+		.expect("VrfId is a valid schnorrkel public key");
+
+	let (inout, _) = public
+		.vrf_verify(make_transcript(vrf_input), &vrf_output, &vrf_proof)
+		.expect("VRF signature verification failed");
+	let randomness = inout.make_bytes::<[u8; VRF_OUTPUT_LENGTH]>(VRF_INOUT_CONTEXT);
+	let randomness = T::Hash::decode(&mut &randomness[..])
+		.expect("VRF output bytes can be decoded into T::Hash");
+
+	LocalVrfOutput::<T>::put(randomness);
+}
@@ -0,0 +1,193 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for pallet_randomness
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_randomness.
+pub trait WeightInfo {
+	#[rustfmt::skip]
+	fn set_babe_randomness_results() -> Weight;
+	#[rustfmt::skip]
+	fn on_finalize() -> Weight;
+	#[rustfmt::skip]
+	fn request_randomness() -> Weight;
+	#[rustfmt::skip]
+	fn prepare_fulfillment() -> Weight;
+	#[rustfmt::skip]
+	fn finish_fulfillment() -> Weight;
+	#[rustfmt::skip]
+	fn increase_fee() -> Weight;
+	#[rustfmt::skip]
+	fn execute_request_expiration() -> Weight;
+}
+
+/// Weights for pallet_randomness using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: ParachainSystem RelayStateProof (r:1 w:0)
+	// Storage: Randomness RelayEpoch (r:1 w:1)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	// Storage: Randomness EpochRandomness (r:0 w:1)
+	// Storage: Randomness InherentIncluded (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_babe_randomness_results() -> Weight {
+		Weight::from_ref_time(26_910_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Randomness EpochRandomness (r:1 w:0)
+	// Storage: Randomness VrfInput (r:1 w:1)
+	// Storage: Randomness InherentIncluded (r:1 w:1)
+	// Storage: Randomness LocalVrfOutput (r:1 w:1)
+	// Storage: AuthorMapping NimbusLookup (r:1 w:0)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	#[rustfmt::skip]
+	fn on_finalize() -> Weight {
+		Weight::from_ref_time(66_480_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Randomness RelayEpoch (r:1 w:0)
+	// Storage: Randomness RequestCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	// Storage: Randomness Requests (r:0 w:1)
+	#[rustfmt::skip]
+	fn request_randomness() -> Weight {
+		Weight::from_ref_time(52_130_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Randomness Requests (r:1 w:1)
+	// Storage: Randomness RelayEpoch (r:1 w:0)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	#[rustfmt::skip]
+	fn prepare_fulfillment() -> Weight {
+		Weight::from_ref_time(41_760_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: System Account (r:3 w:3)
+	#[rustfmt::skip]
+	fn finish_fulfillment() -> Weight {
+		Weight::from_ref_time(58_940_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Randomness Requests (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+	fn increase_fee() -> Weight {
+		Weight::from_ref_time(35_270_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Randomness Requests (r:1 w:1)
+	// Storage: Randomness RelayEpoch (r:1 w:0)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+	fn execute_request_expiration() -> Weight {
+		Weight::from_ref_time(61_350_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: ParachainSystem RelayStateProof (r:1 w:0)
+	// Storage: Randomness RelayEpoch (r:1 w:1)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	// Storage: Randomness EpochRandomness (r:0 w:1)
+	// Storage: Randomness InherentIncluded (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_babe_randomness_results() -> Weight {
+		Weight::from_ref_time(26_910_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Randomness EpochRandomness (r:1 w:0)
+	// Storage: Randomness VrfInput (r:1 w:1)
+	// Storage: Randomness InherentIncluded (r:1 w:1)
+	// Storage: Randomness LocalVrfOutput (r:1 w:1)
+	// Storage: AuthorMapping NimbusLookup (r:1 w:0)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	#[rustfmt::skip]
+	fn on_finalize() -> Weight {
+		Weight::from_ref_time(66_480_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Randomness RelayEpoch (r:1 w:0)
+	// Storage: Randomness RequestCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	// Storage: Randomness Requests (r:0 w:1)
+	#[rustfmt::skip]
+	fn request_randomness() -> Weight {
+		Weight::from_ref_time(52_130_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Randomness Requests (r:1 w:1)
+	// Storage: Randomness RelayEpoch (r:1 w:0)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	#[rustfmt::skip]
+	fn prepare_fulfillment() -> Weight {
+		Weight::from_ref_time(41_760_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: System Account (r:3 w:3)
+	#[rustfmt::skip]
+	fn finish_fulfillment() -> Weight {
+		Weight::from_ref_time(58_940_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Randomness Requests (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+	fn increase_fee() -> Weight {
+		Weight::from_ref_time(35_270_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Randomness Requests (r:1 w:1)
+	// Storage: Randomness RelayEpoch (r:1 w:0)
+	// Storage: Randomness RandomnessResults (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+	fn execute_request_expiration() -> Weight {
+		Weight::from_ref_time(61_350_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @author The Diora Team
+/// @title Pallet AuthorMapping Interface
+/// @dev The interface through which solidity contracts will interact with pallet-author-mapping.
+/// Together with the parachain staking precompile, it allows a collator to be fully onboarded
+/// from an H160 wallet. NimbusIds and accounts are given as 32 bytes.
+/// @custom:address 0x000000000000000000000000000000000000080A
+interface AuthorMapping {
+    /// @dev Get the account id associated to a nimbus id
+    /// @custom:selector 6ed541d4
+    /// @param nimbusId The nimbus id to look up
+    /// @return The 32 bytes account id, zero if the nimbus id is not registered
+    function accountIdOf(bytes32 nimbusId) external view returns (bytes32);
+
+    /// @dev Get the nimbus id registered by an account
+    /// @custom:selector 14a37187
+    /// @param account The 32 bytes account id to look up
+    /// @return The nimbus id, zero if the account has no registered nimbus id
+    function nimbusIdOf(bytes32 account) external view returns (bytes32);
+
+    /// @dev Get the session keys associated to a nimbus id
+    /// @custom:selector 089b7a68
+    /// @param nimbusId The nimbus id to look up
+    /// @return The SCALE encoded keys, empty if the nimbus id is not registered
+    function keysOf(bytes32 nimbusId) external view returns (bytes memory);
+
+    /// @dev Register the caller's nimbus id, its VRF key defaults to the nimbus id.
+    /// A security deposit is reserved.
+    /// @custom:selector ef8b6cd8
+    /// @param nimbusId The nimbus id to register
+    function addAssociation(bytes32 nimbusId) external;
+
+    /// @dev Rotate the caller's nimbus id, its VRF key defaults to the new nimbus id.
+    /// No new security deposit is required.
+    /// @custom:selector 25a39da5
+    /// @param oldNimbusId The currently registered nimbus id
+    /// @param newNimbusId The new nimbus id
+    function updateAssociation(bytes32 oldNimbusId, bytes32 newNimbusId)
+        external;
+
+    /// @dev Clear the caller's association, the security deposit is returned.
+    /// @custom:selector 448b54d6
+    /// @param nimbusId The registered nimbus id
+    function clearAssociation(bytes32 nimbusId) external;
+
+    /// @dev Remove the caller's keys, the security deposit is returned.
+    /// @custom:selector a36fee17
+    function removeKeys() external;
+
+    /// @dev Set the caller's nimbus id and session keys at once, registering them if needed.
+    /// @custom:selector f1ec919c
+    /// @param keys The nimbus id followed by the SCALE encoded session keys (VRF key)
+    function setKeys(bytes memory keys) external;
+}
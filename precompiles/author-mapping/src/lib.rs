@@ -0,0 +1,163 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet author mapping through an evm precompile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use nimbus_primitives::NimbusId;
+use pallet_author_mapping::Call as AuthorMappingCall;
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{crypto::UncheckedFrom, sr25519, H256};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type AuthorMappingOf<Runtime> = pallet_author_mapping::Pallet<Runtime>;
+
+/// A precompile to wrap the functionality from pallet author mapping.
+///
+/// Together with the parachain staking precompile, it allows a collator to be fully onboarded
+/// from an H160 wallet. NimbusIds and accounts are given as 32 bytes.
+pub struct AuthorMappingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> AuthorMappingPrecompile<Runtime>
+where
+	Runtime: pallet_author_mapping::Config + pallet_evm::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<AuthorMappingCall<Runtime>>,
+{
+	// Storage Getters
+	#[precompile::public("accountIdOf(bytes32)")]
+	#[precompile::view]
+	fn account_id_of(handle: &mut impl PrecompileHandle, nimbus_id: H256) -> EvmResult<H256> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let nimbus_id = Self::h256_to_nimbus_id(nimbus_id);
+		let account_id = AuthorMappingOf::<Runtime>::account_id_of(&nimbus_id)
+			.map(|account_id| H256::from_slice(&account_id.encode()[..]))
+			.unwrap_or_default();
+
+		Ok(account_id)
+	}
+
+	#[precompile::public("nimbusIdOf(bytes32)")]
+	#[precompile::view]
+	fn nimbus_id_of(handle: &mut impl PrecompileHandle, account: H256) -> EvmResult<H256> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let account = Runtime::AccountId::from(account.0);
+		let nimbus_id = AuthorMappingOf::<Runtime>::nimbus_id_of(&account)
+			.map(|nimbus_id| H256::from_slice(&nimbus_id.encode()[..]))
+			.unwrap_or_default();
+
+		Ok(nimbus_id)
+	}
+
+	#[precompile::public("keysOf(bytes32)")]
+	#[precompile::view]
+	fn keys_of(handle: &mut impl PrecompileHandle, nimbus_id: H256) -> EvmResult<UnboundedBytes> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let nimbus_id = Self::h256_to_nimbus_id(nimbus_id);
+		let keys = AuthorMappingOf::<Runtime>::keys_of(&nimbus_id)
+			.map(|keys| keys.encode())
+			.unwrap_or_default();
+
+		Ok(keys.into())
+	}
+
+	// Runtime Methods (dispatchables)
+	#[precompile::public("addAssociation(bytes32)")]
+	fn add_association(handle: &mut impl PrecompileHandle, nimbus_id: H256) -> EvmResult {
+		let nimbus_id = Self::h256_to_nimbus_id(nimbus_id);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = AuthorMappingCall::<Runtime>::add_association { nimbus_id };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("updateAssociation(bytes32,bytes32)")]
+	fn update_association(
+		handle: &mut impl PrecompileHandle,
+		old_nimbus_id: H256,
+		new_nimbus_id: H256,
+	) -> EvmResult {
+		let old_nimbus_id = Self::h256_to_nimbus_id(old_nimbus_id);
+		let new_nimbus_id = Self::h256_to_nimbus_id(new_nimbus_id);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call =
+			AuthorMappingCall::<Runtime>::update_association { old_nimbus_id, new_nimbus_id };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("clearAssociation(bytes32)")]
+	fn clear_association(handle: &mut impl PrecompileHandle, nimbus_id: H256) -> EvmResult {
+		let nimbus_id = Self::h256_to_nimbus_id(nimbus_id);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = AuthorMappingCall::<Runtime>::clear_association { nimbus_id };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("removeKeys()")]
+	fn remove_keys(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = AuthorMappingCall::<Runtime>::remove_keys {};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("setKeys(bytes)")]
+	fn set_keys(handle: &mut impl PrecompileHandle, keys: UnboundedBytes) -> EvmResult {
+		let keys: Vec<u8> = keys.into();
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = AuthorMappingCall::<Runtime>::set_keys { keys };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	fn h256_to_nimbus_id(nimbus_id: H256) -> NimbusId {
+		sr25519::Public::unchecked_from(nimbus_id.0).into()
+	}
+}
@@ -0,0 +1,59 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Test utilities
+use crate::AuthorMappingPrecompile;
+
+use frame_support::traits::ConstU128;
+use nimbus_primitives::NimbusId;
+use precompile_utils::{precompile_set::*, testing::ExtBuilder};
+use sp_io::TestExternalities;
+
+precompile_utils::mock_runtime! {
+	precompiles: Precompiles<Runtime>,
+	pallets: {
+		AuthorMapping: pallet_author_mapping::{Pallet, Call, Storage, Event<T>},
+	},
+}
+
+impl pallet_author_mapping::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type DepositCurrency = Balances;
+	type DepositAmount = ConstU128<100>;
+	type Keys = NimbusId;
+	type WeightInfo = ();
+}
+
+/// Same checks as in the runtime.
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		PrecompileAt<
+			PrecompileAddress,
+			AuthorMappingPrecompile<R>,
+			(CallableByContract, CallableByPrecompile),
+		>,
+	),
+>;
+
+pub(crate) fn new_test_ext() -> TestExternalities {
+	// CHARLIE cannot afford the security deposit
+	ExtBuilder::<Runtime>::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000), (CHARLIE, 10)])
+		.build()
+}
@@ -0,0 +1,263 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use precompile_utils::testing::*;
+use sp_core::H160;
+
+type PCall = AuthorMappingPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Whether the reverted `output` is the dispatch error `error`.
+fn dispatch_error(output: &[u8], error: &str) -> bool {
+	let output = std::str::from_utf8(output).unwrap();
+	output.starts_with("Dispatched call failed with error: ") && output.contains(error)
+}
+
+fn nimbus_id(byte: u8) -> H256 {
+	H256::repeat_byte(byte)
+}
+
+/// NimbusId of the 32 bytes given to the precompile.
+fn nimbus_id_of(nimbus: H256) -> NimbusId {
+	AuthorMappingPrecompile::<Runtime>::h256_to_nimbus_id(nimbus)
+}
+
+fn account_h256(address: H160) -> H256 {
+	H256::from(<[u8; 32]>::from(account(address)))
+}
+
+fn associate(from: H160, nimbus: H256) {
+	precompiles()
+		.prepare_test(from, PRECOMPILE, PCall::add_association { nimbus_id: nimbus })
+		.expect_no_logs()
+		.execute_returns(vec![]);
+}
+
+/// Checks the views of the precompile for the association of `nimbus` with `owner`.
+fn assert_associated(nimbus: H256, owner: H160) {
+	precompiles()
+		.prepare_test(BOB, PRECOMPILE, PCall::account_id_of { nimbus_id: nimbus })
+		.expect_no_logs()
+		.execute_returns_encoded(account_h256(owner));
+
+	precompiles()
+		.prepare_test(BOB, PRECOMPILE, PCall::nimbus_id_of { account: account_h256(owner) })
+		.expect_no_logs()
+		.execute_returns_encoded(nimbus);
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["AuthorMappingInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn add_association_reserves_deposit_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		associate(ALICE, nimbus_id(1));
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 100);
+		assert_associated(nimbus_id(1), ALICE);
+
+		// Keys default to the NimbusId
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::keys_of { nimbus_id: nimbus_id(1) })
+			.expect_no_logs()
+			.execute_returns_encoded(UnboundedBytes::from(nimbus_id(1).0));
+	});
+}
+
+#[test]
+fn views_of_unknown_association_are_empty() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::account_id_of { nimbus_id: nimbus_id(1) })
+			.expect_no_logs()
+			.execute_returns_encoded(H256::zero());
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::nimbus_id_of { account: account_h256(ALICE) })
+			.expect_no_logs()
+			.execute_returns_encoded(H256::zero());
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::keys_of { nimbus_id: nimbus_id(1) })
+			.expect_no_logs()
+			.execute_returns_encoded(UnboundedBytes::from(Vec::<u8>::new()));
+	});
+}
+
+#[test]
+fn nimbus_id_cannot_be_associated_twice() {
+	new_test_ext().execute_with(|| {
+		associate(ALICE, nimbus_id(1));
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::add_association { nimbus_id: nimbus_id(1) })
+			.execute_reverts(|output| dispatch_error(output, "AlreadyAssociated"));
+
+		assert_eq!(Balances::reserved_balance(account(BOB)), 0);
+		assert_associated(nimbus_id(1), ALICE);
+	});
+}
+
+#[test]
+fn add_association_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::add_association { nimbus_id: nimbus_id(1) })
+			.execute_reverts(|output| dispatch_error(output, "CannotAffordSecurityDeposit"));
+	});
+}
+
+#[test]
+fn owner_updates_association() {
+	new_test_ext().execute_with(|| {
+		associate(ALICE, nimbus_id(1));
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::update_association {
+					old_nimbus_id: nimbus_id(1),
+					new_nimbus_id: nimbus_id(2),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		// No new deposit is taken
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 100);
+		assert_associated(nimbus_id(2), ALICE);
+		assert_eq!(AuthorMapping::account_id_of(&nimbus_id_of(nimbus_id(1))), None);
+	});
+}
+
+#[test]
+fn only_the_owner_updates_association() {
+	new_test_ext().execute_with(|| {
+		associate(ALICE, nimbus_id(1));
+
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::update_association {
+					old_nimbus_id: nimbus_id(1),
+					new_nimbus_id: nimbus_id(2),
+				},
+			)
+			.execute_reverts(|output| dispatch_error(output, "NotYourAssociation"));
+
+		assert_associated(nimbus_id(1), ALICE);
+	});
+}
+
+#[test]
+fn owner_clears_association() {
+	new_test_ext().execute_with(|| {
+		associate(ALICE, nimbus_id(1));
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::clear_association { nimbus_id: nimbus_id(1) })
+			.execute_reverts(|output| dispatch_error(output, "NotYourAssociation"));
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::clear_association { nimbus_id: nimbus_id(1) })
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+		assert_eq!(AuthorMapping::nimbus_id_of(&account(ALICE)), None);
+	});
+}
+
+#[test]
+fn remove_keys_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::remove_keys {})
+			.execute_reverts(|output| dispatch_error(output, "OldAuthorIdNotFound"));
+
+		associate(ALICE, nimbus_id(1));
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::remove_keys {})
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+		assert_eq!(AuthorMapping::account_id_of(&nimbus_id_of(nimbus_id(1))), None);
+	});
+}
+
+#[test]
+fn set_keys_registers_then_rotates() {
+	new_test_ext().execute_with(|| {
+		let keys = |nimbus: H256, keys: H256| [nimbus.0, keys.0].concat();
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::set_keys { keys: keys(nimbus_id(1), nimbus_id(3)).into() },
+			)
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 100);
+		assert_associated(nimbus_id(1), ALICE);
+		assert_eq!(
+			AuthorMapping::keys_of(&nimbus_id_of(nimbus_id(1))),
+			Some(nimbus_id_of(nimbus_id(3)))
+		);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::set_keys { keys: keys(nimbus_id(2), nimbus_id(4)).into() },
+			)
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 100);
+		assert_associated(nimbus_id(2), ALICE);
+		assert_eq!(
+			AuthorMapping::keys_of(&nimbus_id_of(nimbus_id(2))),
+			Some(nimbus_id_of(nimbus_id(4)))
+		);
+	});
+}
+
+#[test]
+fn set_keys_checks_size() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::set_keys { keys: vec![0x01; 33].into() })
+			.execute_reverts(|output| dispatch_error(output, "WrongKeySize"));
+	});
+}
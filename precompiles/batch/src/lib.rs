@@ -0,0 +1,240 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to batch multiple calls into a single transaction
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use evm::{ExitError, ExitReason, Transfer};
+use fp_evm::{Context, Log, PrecompileFailure, PrecompileHandle};
+use precompile_utils::{costs::call_cost, prelude::*};
+use sp_core::{ConstU32, H160, U256};
+use sp_std::{iter::repeat, marker::PhantomData, vec, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// How the batch reacts to a failing subcall.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+	/// Failing subcalls are skipped, the following ones are still executed.
+	BatchSome,
+	/// The batch stops at the first failing subcall but keeps the previous ones.
+	BatchSomeUntilFailure,
+	/// The whole batch is reverted if one subcall fails.
+	BatchAll,
+}
+
+/// Solidity selector of the `SubcallSucceeded` log.
+pub const LOG_SUBCALL_SUCCEEDED: [u8; 32] = keccak256!("SubcallSucceeded(uint256)");
+
+/// Solidity selector of the `SubcallFailed` log.
+pub const LOG_SUBCALL_FAILED: [u8; 32] = keccak256!("SubcallFailed(uint256)");
+
+/// Maximum size of the call data of a single subcall.
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+
+/// Maximum number of subcalls in a batch.
+pub const ARRAY_LIMIT: u32 = 2u32.pow(9);
+
+type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
+type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
+
+pub fn log_subcall_succeeded(address: impl Into<H160>, index: usize) -> Log {
+	log1(address, LOG_SUBCALL_SUCCEEDED, EvmDataWriter::new().write(U256::from(index)).build())
+}
+
+pub fn log_subcall_failed(address: impl Into<H160>, index: usize) -> Log {
+	log1(address, LOG_SUBCALL_FAILED, EvmDataWriter::new().write(U256::from(index)).build())
+}
+
+/// A precompile executing multiple calls on behalf of the caller.
+///
+/// Every subcall is made with the caller of the precompile as `msg.sender`, which allows an EOA
+/// to bundle actions such as an approval followed by a swap in a single transaction.
+pub struct BatchPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> BatchPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config,
+{
+	#[precompile::public("batchSome(address[],uint256[],bytes[],uint64[])")]
+	fn batch_some(
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult {
+		Self::inner_batch(Mode::BatchSome, handle, to, value, call_data, gas_limit)
+	}
+
+	#[precompile::public("batchSomeUntilFailure(address[],uint256[],bytes[],uint64[])")]
+	fn batch_some_until_failure(
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult {
+		Self::inner_batch(Mode::BatchSomeUntilFailure, handle, to, value, call_data, gas_limit)
+	}
+
+	#[precompile::public("batchAll(address[],uint256[],bytes[],uint64[])")]
+	fn batch_all(
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult {
+		Self::inner_batch(Mode::BatchAll, handle, to, value, call_data, gas_limit)
+	}
+
+	fn inner_batch(
+		mode: Mode,
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult {
+		// Missing values, call data and gas limits default to zero, empty and all remaining gas.
+		let addresses = Vec::from(to).into_iter().enumerate();
+		let values = Vec::from(value).into_iter().map(Some).chain(repeat(None));
+		let calls_data = Vec::from(call_data).into_iter().map(Some).chain(repeat(None));
+		let gas_limits = Vec::from(gas_limit)
+			.into_iter()
+			// A gas limit of 0 forwards all the remaining gas
+			.map(|limit| if limit == 0 { None } else { Some(limit) })
+			.chain(repeat(None));
+
+		// Cost of the log emitted after every subcall, it doesn't depend on the index.
+		let log_cost = log_subcall_failed(handle.code_address(), 0)
+			.compute_cost()
+			.map_err(|_| revert("Failed to compute log cost"))?;
+
+		for ((i, address), (value, (call_data, gas_limit))) in
+			addresses.zip(values.zip(calls_data.zip(gas_limits)))
+		{
+			let address = address.0;
+			let value = value.unwrap_or_else(U256::zero);
+			let call_data: Vec<u8> = call_data.unwrap_or_else(|| vec![].into()).into();
+
+			// The caller of the batch stays `msg.sender` of every subcall.
+			let sub_context =
+				Context { caller: handle.context().caller, address, apparent_value: value };
+
+			let transfer = if value.is_zero() {
+				None
+			} else {
+				Some(Transfer { source: handle.context().caller, target: address, value })
+			};
+
+			// Enough gas is kept aside to emit the log of the subcall. If there is not even enough
+			// gas for it the batch stops according to its mode.
+			let forwarded_gas = match (handle.remaining_gas().checked_sub(log_cost), mode) {
+				(Some(remaining), _) => remaining,
+				(None, Mode::BatchAll) =>
+					return Err(PrecompileFailure::Error { exit_status: ExitError::OutOfGas }),
+				(None, _) => return Ok(()),
+			};
+
+			// Cost of the subcall itself, paid by the batch precompile. It is recorded by
+			// `handle.call` like the `CALL` opcode records it, it only needs to be kept aside here.
+			let call_cost = call_cost(value, <Runtime as pallet_evm::Config>::config());
+
+			let forwarded_gas = match forwarded_gas.checked_sub(call_cost) {
+				Some(remaining) => remaining,
+				None => {
+					Self::record_subcall_failed(handle, i)?;
+					match mode {
+						Mode::BatchAll =>
+							return Err(PrecompileFailure::Error {
+								exit_status: ExitError::OutOfGas,
+							}),
+						Mode::BatchSomeUntilFailure => return Ok(()),
+						Mode::BatchSome => continue,
+					}
+				},
+			};
+
+			// A provided gas limit must be available in full.
+			let forwarded_gas = match gas_limit {
+				None => forwarded_gas,
+				Some(limit) if limit <= forwarded_gas => limit,
+				Some(_) => {
+					Self::record_subcall_failed(handle, i)?;
+					match mode {
+						Mode::BatchAll =>
+							return Err(PrecompileFailure::Error {
+								exit_status: ExitError::OutOfGas,
+							}),
+						Mode::BatchSomeUntilFailure => return Ok(()),
+						Mode::BatchSome => continue,
+					}
+				},
+			};
+
+			let (reason, output) =
+				handle.call(address, transfer, call_data, Some(forwarded_gas), false, &sub_context);
+
+			// The gas of the log was kept aside so recording it cannot run out of gas.
+			match reason {
+				ExitReason::Revert(_) | ExitReason::Error(_) =>
+					Self::record_subcall_failed(handle, i)?,
+				ExitReason::Succeed(_) => {
+					let log = log_subcall_succeeded(handle.code_address(), i);
+					handle.record_log_costs(&[&log])?;
+					log.record(handle)?;
+				},
+				ExitReason::Fatal(_) => (),
+			}
+
+			match (mode, reason) {
+				// Fatal errors always stop the whole execution.
+				(_, ExitReason::Fatal(exit_status)) =>
+					return Err(PrecompileFailure::Fatal { exit_status }),
+
+				// BatchAll reverts with the error of the failing subcall.
+				(Mode::BatchAll, ExitReason::Revert(exit_status)) =>
+					return Err(PrecompileFailure::Revert { exit_status, output }),
+				(Mode::BatchAll, ExitReason::Error(exit_status)) =>
+					return Err(PrecompileFailure::Error { exit_status }),
+
+				// BatchSomeUntilFailure stops at the failing subcall but the batch succeeds.
+				(Mode::BatchSomeUntilFailure, ExitReason::Revert(_) | ExitReason::Error(_)) =>
+					return Ok(()),
+
+				// Success, or failure ignored by BatchSome.
+				(_, _) => (),
+			}
+		}
+
+		Ok(())
+	}
+
+	fn record_subcall_failed(handle: &mut impl PrecompileHandle, index: usize) -> EvmResult {
+		let log = log_subcall_failed(handle.code_address(), index);
+		handle.record_log_costs(&[&log])?;
+		log.record(handle)
+	}
+}
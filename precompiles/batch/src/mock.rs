@@ -0,0 +1,40 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Test utilities
+use crate::BatchPrecompile;
+
+use precompile_utils::{precompile_set::*, testing::ExtBuilder};
+use sp_io::TestExternalities;
+
+precompile_utils::mock_runtime! {
+	precompiles: Precompiles<Runtime>,
+	pallets: {
+	},
+}
+
+/// Same checks as in the runtime: only callable by EOAs, subcalls are made on behalf of the
+/// caller.
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(PrecompileAt<PrecompileAddress, BatchPrecompile<R>, SubcallWithMaxNesting<2>>,),
+>;
+
+pub(crate) fn new_test_ext() -> TestExternalities {
+	ExtBuilder::<Runtime>::default().build()
+}
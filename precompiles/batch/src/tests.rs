@@ -0,0 +1,304 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use precompile_utils::testing::*;
+use std::{cell::RefCell, rc::Rc};
+
+type PCall = BatchPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Input of a batch call in the given mode.
+fn batch_input(
+	mode: Mode,
+	to: Vec<H160>,
+	value: Vec<u64>,
+	call_data: Vec<Vec<u8>>,
+	gas_limit: Vec<u64>,
+) -> Vec<u8> {
+	let to: BoundedVec<Address, GetArrayLimit> =
+		to.into_iter().map(Address).collect::<Vec<_>>().into();
+	let value: BoundedVec<U256, GetArrayLimit> =
+		value.into_iter().map(U256::from).collect::<Vec<_>>().into();
+	let call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit> =
+		call_data.into_iter().map(Into::into).collect::<Vec<_>>().into();
+	let gas_limit: BoundedVec<u64, GetArrayLimit> = gas_limit.into();
+
+	match mode {
+		Mode::BatchSome => PCall::batch_some { to, value, call_data, gas_limit },
+		Mode::BatchSomeUntilFailure =>
+			PCall::batch_some_until_failure { to, value, call_data, gas_limit },
+		Mode::BatchAll => PCall::batch_all { to, value, call_data, gas_limit },
+	}
+	.into()
+}
+
+/// Subcall handle recording every subcall, the subcalls to `reverting` revert.
+fn recording_subcalls(reverting: Option<H160>) -> (Rc<RefCell<Vec<Subcall>>>, impl SubcallTrait) {
+	let subcalls = Rc::new(RefCell::new(Vec::new()));
+	let recorded = subcalls.clone();
+
+	let handle = move |subcall: Subcall| {
+		let reverts = Some(subcall.address) == reverting;
+		recorded.borrow_mut().push(subcall);
+		if reverts {
+			SubcallOutput::revert()
+		} else {
+			SubcallOutput::succeed()
+		}
+	};
+
+	(subcalls, handle)
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["BatchInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn subcalls_are_made_on_behalf_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		let (subcalls, subcall_handle) = recording_subcalls(None);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(
+					Mode::BatchAll,
+					vec![BOB, CHARLIE],
+					vec![1, 2],
+					vec![vec![0x01], vec![0x02]],
+					vec![],
+				),
+			)
+			.with_subcall_handle(subcall_handle)
+			.expect_log(log_subcall_succeeded(PRECOMPILE, 0))
+			.expect_log(log_subcall_succeeded(PRECOMPILE, 1))
+			.execute_returns(vec![]);
+
+		let subcalls = subcalls.borrow();
+		assert_eq!(subcalls.len(), 2);
+		for (subcall, (target, value, input)) in
+			subcalls.iter().zip([(BOB, 1u64, vec![0x01]), (CHARLIE, 2, vec![0x02])])
+		{
+			assert_eq!(subcall.address, target);
+			assert_eq!(subcall.input, input);
+			assert!(!subcall.is_static);
+			assert_eq!(subcall.context.caller, ALICE);
+			assert_eq!(subcall.context.address, target);
+			assert_eq!(subcall.context.apparent_value, value.into());
+
+			let transfer = subcall.transfer.as_ref().expect("value is transferred");
+			assert_eq!(
+				(transfer.source, transfer.target, transfer.value),
+				(ALICE, target, value.into())
+			);
+		}
+	});
+}
+
+#[test]
+fn subcalls_are_charged_with_the_call_cost() {
+	new_test_ext().execute_with(|| {
+		let (_, subcall_handle) = recording_subcalls(None);
+		let log_cost = log_subcall_succeeded(PRECOMPILE, 0).compute_cost().unwrap();
+
+		// The value transfer surcharge is part of the cost
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(Mode::BatchAll, vec![BOB], vec![1], vec![], vec![]),
+			)
+			.with_subcall_handle(subcall_handle)
+			.expect_cost(call_cost(1.into(), &evm::Config::london()) + log_cost)
+			.expect_log(log_subcall_succeeded(PRECOMPILE, 0))
+			.execute_returns(vec![]);
+	});
+}
+
+#[test]
+fn missing_values_call_data_and_gas_limits_use_defaults() {
+	new_test_ext().execute_with(|| {
+		let (subcalls, subcall_handle) = recording_subcalls(None);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(
+					Mode::BatchAll,
+					vec![BOB, CHARLIE],
+					vec![1],
+					vec![vec![0x01]],
+					vec![50_000],
+				),
+			)
+			.with_subcall_handle(subcall_handle)
+			.with_target_gas(Some(1_000_000))
+			.execute_returns(vec![]);
+
+		let subcalls = subcalls.borrow();
+		assert_eq!(subcalls[0].target_gas, Some(50_000));
+		assert!(subcalls[0].transfer.is_some());
+
+		// No value, no call data and all the remaining gas
+		assert!(subcalls[1].transfer.is_none());
+		assert!(subcalls[1].input.is_empty());
+		assert!(subcalls[1].target_gas.unwrap() > 50_000);
+	});
+}
+
+#[test]
+fn batch_some_continues_after_a_failing_subcall() {
+	new_test_ext().execute_with(|| {
+		let (subcalls, subcall_handle) = recording_subcalls(Some(BOB));
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(Mode::BatchSome, vec![BOB, CHARLIE], vec![], vec![], vec![]),
+			)
+			.with_subcall_handle(subcall_handle)
+			.expect_log(log_subcall_failed(PRECOMPILE, 0))
+			.expect_log(log_subcall_succeeded(PRECOMPILE, 1))
+			.execute_returns(vec![]);
+
+		assert_eq!(subcalls.borrow().len(), 2);
+	});
+}
+
+#[test]
+fn batch_some_until_failure_stops_at_the_failing_subcall() {
+	new_test_ext().execute_with(|| {
+		let (subcalls, subcall_handle) = recording_subcalls(Some(BOB));
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(
+					Mode::BatchSomeUntilFailure,
+					vec![CHARLIE, BOB, CHARLIE],
+					vec![],
+					vec![],
+					vec![],
+				),
+			)
+			.with_subcall_handle(subcall_handle)
+			.expect_log(log_subcall_succeeded(PRECOMPILE, 0))
+			.expect_log(log_subcall_failed(PRECOMPILE, 1))
+			.execute_returns(vec![]);
+
+		assert_eq!(subcalls.borrow().len(), 2);
+	});
+}
+
+#[test]
+fn batch_all_reverts_if_a_subcall_fails() {
+	new_test_ext().execute_with(|| {
+		let (subcalls, subcall_handle) = recording_subcalls(Some(BOB));
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(Mode::BatchAll, vec![CHARLIE, BOB, CHARLIE], vec![], vec![], vec![]),
+			)
+			.with_subcall_handle(subcall_handle)
+			.execute_reverts(|_| true);
+
+		assert_eq!(subcalls.borrow().len(), 2);
+	});
+}
+
+#[test]
+fn gas_limit_above_remaining_gas_fails_the_subcall() {
+	new_test_ext().execute_with(|| {
+		let (subcalls, subcall_handle) = recording_subcalls(None);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(
+					Mode::BatchSome,
+					vec![BOB, CHARLIE],
+					vec![],
+					vec![],
+					vec![200_000, 10_000],
+				),
+			)
+			.with_subcall_handle(subcall_handle)
+			.with_target_gas(Some(100_000))
+			.expect_log(log_subcall_failed(PRECOMPILE, 0))
+			.expect_log(log_subcall_succeeded(PRECOMPILE, 1))
+			.execute_returns(vec![]);
+
+		let subcalls = subcalls.borrow();
+		assert_eq!(subcalls.len(), 1);
+		assert_eq!(subcalls[0].address, CHARLIE);
+	});
+}
+
+#[test]
+fn batch_all_fails_if_a_gas_limit_is_not_available() {
+	new_test_ext().execute_with(|| {
+		let (subcalls, subcall_handle) = recording_subcalls(None);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(Mode::BatchAll, vec![BOB], vec![], vec![], vec![200_000]),
+			)
+			.with_subcall_handle(subcall_handle)
+			.with_target_gas(Some(100_000))
+			.execute_error(ExitError::OutOfGas);
+
+		assert!(subcalls.borrow().is_empty());
+	});
+}
+
+#[test]
+fn not_callable_by_smart_contracts() {
+	new_test_ext().execute_with(|| {
+		pallet_evm::AccountCodes::<Runtime>::insert(ALICE, vec![0x60, 0x00]);
+		let (subcalls, subcall_handle) = recording_subcalls(None);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				batch_input(Mode::BatchAll, vec![BOB], vec![], vec![], vec![]),
+			)
+			.with_subcall_handle(subcall_handle)
+			.execute_reverts(|output| output == b"Function not callable by smart contracts");
+
+		assert!(subcalls.borrow().is_empty());
+	});
+}
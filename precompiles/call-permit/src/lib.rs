@@ -0,0 +1,233 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to dispatch calls authorized by EIP-712 signed permits
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use evm::{ExitReason, Transfer};
+use fp_evm::{Context, PrecompileFailure, PrecompileHandle};
+use frame_support::{
+	ensure,
+	storage::types::{StorageMap, ValueQuery},
+	traits::{ConstU32, Get, StorageInstance},
+	Blake2_128Concat,
+};
+use precompile_utils::{costs::call_cost, prelude::*};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Storage prefix for nonces.
+pub struct Nonces;
+
+impl StorageInstance for Nonces {
+	const STORAGE_PREFIX: &'static str = "Nonces";
+
+	fn pallet_prefix() -> &'static str {
+		"PrecompileCallPermit"
+	}
+}
+
+/// Nonce of every account, incremented by each dispatched permit.
+pub type NoncesStorage = StorageMap<Nonces, Blake2_128Concat, H160, U256, ValueQuery>;
+
+/// EIP-712 typehash of a call permit.
+pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+	"CallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP-712 typehash of the domain the permits are signed for.
+const PERMIT_DOMAIN: [u8; 32] = keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// Maximum size of the call data of a permit.
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+
+type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
+
+/// A precompile dispatching calls signed by their sender as EIP-712 permits.
+///
+/// Anyone can dispatch a permit and pay for its gas, the call is executed with the signer of the
+/// permit as `msg.sender`. This allows a relayer to pay the fees of users holding no balance,
+/// e.g. to delegate through the parachain staking precompile.
+pub struct CallPermitPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> CallPermitPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config + pallet_timestamp::Config,
+{
+	fn compute_domain_separator(address: H160) -> [u8; 32] {
+		let name: H256 = keccak_256(b"Call Permit Precompile").into();
+		let version: H256 = keccak256!("1").into();
+		let chain_id: U256 = <Runtime as pallet_evm::Config>::ChainId::get().into();
+
+		let domain_separator_inner = EvmDataWriter::new()
+			.write(H256::from(PERMIT_DOMAIN))
+			.write(name)
+			.write(version)
+			.write(chain_id)
+			.write(Address(address))
+			.build();
+
+		keccak_256(&domain_separator_inner)
+	}
+
+	/// Hash of the permit the sender must sign, as defined by EIP-712.
+	pub fn generate_permit(
+		address: H160,
+		from: H160,
+		to: H160,
+		value: U256,
+		data: Vec<u8>,
+		gas_limit: u64,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let domain_separator = Self::compute_domain_separator(address);
+
+		let permit_content = EvmDataWriter::new()
+			.write(H256::from(PERMIT_TYPEHASH))
+			.write(Address(from))
+			.write(Address(to))
+			.write(value)
+			// bytes are encoded as the keccak_256 of the content
+			.write(H256::from(keccak_256(&data)))
+			.write(gas_limit)
+			.write(nonce)
+			.write(deadline)
+			.build();
+		let permit_content = keccak_256(&permit_content);
+
+		let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+		pre_digest.extend_from_slice(b"\x19\x01");
+		pre_digest.extend_from_slice(&domain_separator);
+		pre_digest.extend_from_slice(&permit_content);
+		keccak_256(&pre_digest)
+	}
+
+	/// Cost of verifying a permit: the signature recovery and the nonce update.
+	pub fn dispatch_inherent_cost() -> u64 {
+		// cost of the ECRecover precompile
+		3_000 + RuntimeHelper::<Runtime>::db_write_gas_cost()
+	}
+
+	#[precompile::public(
+		"dispatch(address,address,uint256,bytes,uint64,uint256,uint8,bytes32,bytes32)"
+	)]
+	fn dispatch(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+		data: BoundedBytes<GetCallDataLimit>,
+		gas_limit: u64,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<UnboundedBytes> {
+		// The deadline is in seconds, the timestamp in milliseconds.
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let timestamp: u128 = pallet_timestamp::Pallet::<Runtime>::get().unique_saturated_into();
+		let timestamp = U256::from(timestamp / 1_000);
+
+		ensure!(deadline >= timestamp, revert("Permit expired"));
+
+		// The gas of the call must be available in full.
+		let call_cost = call_cost(value, <Runtime as pallet_evm::Config>::config());
+		let total_cost = gas_limit
+			.checked_add(call_cost)
+			.ok_or_else(|| revert("Call require too much gas (uint64 overflow)"))?;
+		if total_cost > handle.remaining_gas() {
+			return Err(revert("Gaslimit is too low to dispatch provided call"));
+		}
+
+		handle.record_cost(Self::dispatch_inherent_cost())?;
+
+		let from: H160 = from.into();
+		let to: H160 = to.into();
+		let data: Vec<u8> = data.into();
+
+		let nonce = NoncesStorage::get(from);
+		let permit = Self::generate_permit(
+			handle.context().address,
+			from,
+			to,
+			value,
+			data.clone(),
+			gas_limit,
+			nonce,
+			deadline,
+		);
+
+		let mut sig = [0u8; 65];
+		sig[0..32].copy_from_slice(r.as_bytes());
+		sig[32..64].copy_from_slice(s.as_bytes());
+		sig[64] = v;
+
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &permit)
+			.map_err(|_| revert("Invalid permit"))?;
+		let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+
+		ensure!(signer != H160::zero() && signer == from, revert("Invalid permit"));
+
+		NoncesStorage::insert(from, nonce + U256::one());
+
+		// The call is made on behalf of the signer of the permit.
+		let sub_context = Context { caller: from, address: to, apparent_value: value };
+		let transfer =
+			if value.is_zero() { None } else { Some(Transfer { source: from, target: to, value }) };
+
+		let (reason, output) =
+			handle.call(to, transfer, data, Some(gas_limit), false, &sub_context);
+		match reason {
+			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+			ExitReason::Revert(exit_status) =>
+				Err(PrecompileFailure::Revert { exit_status, output }),
+			ExitReason::Succeed(_) => Ok(output.into()),
+		}
+	}
+
+	#[precompile::public("nonces(address)")]
+	#[precompile::view]
+	fn nonces(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let nonce = NoncesStorage::get(H160::from(owner));
+
+		Ok(nonce)
+	}
+
+	#[precompile::public("DOMAIN_SEPARATOR()")]
+	#[precompile::view]
+	fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let domain_separator = Self::compute_domain_separator(handle.context().address).into();
+
+		Ok(domain_separator)
+	}
+}
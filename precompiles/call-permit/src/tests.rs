@@ -0,0 +1,350 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use libsecp256k1::{Message, PublicKey, SecretKey};
+use precompile_utils::testing::*;
+use std::{cell::RefCell, rc::Rc};
+
+type PCall = CallPermitPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Key of the account signing the permits.
+fn alice_key() -> SecretKey {
+	SecretKey::parse(&[0x11; 32]).unwrap()
+}
+
+/// EVM address controlled by `key`.
+fn address_of(key: &SecretKey) -> H160 {
+	let public = PublicKey::from_secret_key(key).serialize();
+	H160::from(H256::from_slice(&keccak_256(&public[1..])))
+}
+
+/// Signs `permit` with `key`, returning the `v`, `r` and `s` parts of the signature.
+fn sign(key: &SecretKey, permit: [u8; 32]) -> (u8, H256, H256) {
+	let (signature, recovery_id) = libsecp256k1::sign(&Message::parse(&permit), key);
+	let signature = signature.serialize();
+
+	(
+		recovery_id.serialize() + 27,
+		H256::from_slice(&signature[..32]),
+		H256::from_slice(&signature[32..]),
+	)
+}
+
+/// Permit for `from` to call `to` with the given value and data.
+struct Permit {
+	from: H160,
+	to: H160,
+	value: U256,
+	data: Vec<u8>,
+	gas_limit: u64,
+	deadline: U256,
+}
+
+impl Permit {
+	fn new(from: H160) -> Self {
+		Self {
+			from,
+			to: CHARLIE,
+			value: 0.into(),
+			data: vec![0x01, 0x02],
+			gas_limit: 100_000,
+			deadline: u64::MAX.into(),
+		}
+	}
+
+	/// Hash of the permit as signed for the precompile at `address` with `nonce`.
+	fn hash(&self, address: H160, nonce: U256) -> [u8; 32] {
+		CallPermitPrecompile::<Runtime>::generate_permit(
+			address,
+			self.from,
+			self.to,
+			self.value,
+			self.data.clone(),
+			self.gas_limit,
+			nonce,
+			self.deadline,
+		)
+	}
+
+	/// Input of a `dispatch` call with the provided signature.
+	fn dispatch(&self, (v, r, s): (u8, H256, H256)) -> Vec<u8> {
+		PCall::dispatch {
+			from: Address(self.from),
+			to: Address(self.to),
+			value: self.value,
+			data: self.data.clone().into(),
+			gas_limit: self.gas_limit,
+			deadline: self.deadline,
+			v,
+			r,
+			s,
+		}
+		.into()
+	}
+}
+
+/// Subcall handle recording every subcall and returning `output`.
+fn recording_subcalls(output: SubcallOutput) -> (Rc<RefCell<Vec<Subcall>>>, impl SubcallTrait) {
+	let subcalls = Rc::new(RefCell::new(Vec::new()));
+	let recorded = subcalls.clone();
+
+	let handle = move |subcall: Subcall| {
+		recorded.borrow_mut().push(subcall);
+		output.clone()
+	};
+
+	(subcalls, handle)
+}
+
+fn nonce_of(owner: H160) -> U256 {
+	NoncesStorage::get(owner)
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["CallPermitInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn valid_permit_is_dispatched_on_behalf_of_the_signer() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit { value: 5.into(), ..Permit::new(alice) };
+		let signature = sign(&alice_key(), permit.hash(PRECOMPILE, 0.into()));
+		let (subcalls, subcall_handle) = recording_subcalls(SubcallOutput {
+			output: b"done".to_vec(),
+			..SubcallOutput::succeed()
+		});
+
+		// Anyone can relay the permit
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.with_subcall_handle(subcall_handle)
+			.execute_returns_encoded(UnboundedBytes::from(b"done".to_vec()));
+
+		let subcalls = subcalls.borrow();
+		assert_eq!(subcalls.len(), 1);
+		assert_eq!(subcalls[0].address, CHARLIE);
+		assert_eq!(subcalls[0].input, vec![0x01, 0x02]);
+		assert_eq!(subcalls[0].target_gas, Some(100_000));
+		assert_eq!(subcalls[0].context.caller, alice);
+		assert_eq!(subcalls[0].context.apparent_value, 5.into());
+		let transfer = subcalls[0].transfer.as_ref().expect("value is transferred");
+		assert_eq!((transfer.source, transfer.target, transfer.value), (alice, CHARLIE, 5.into()));
+
+		assert_eq!(nonce_of(alice), 1.into());
+	});
+}
+
+#[test]
+fn permit_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit::new(alice);
+		let signature = sign(&alice_key(), permit.hash(PRECOMPILE, 0.into()));
+		let (subcalls, subcall_handle) = recording_subcalls(SubcallOutput::succeed());
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.with_subcall_handle(subcall_handle)
+			.execute_returns_encoded(UnboundedBytes::from(Vec::<u8>::new()));
+
+		// The nonce was used, the same signature no longer matches the permit
+		let (_, subcall_handle) = recording_subcalls(SubcallOutput::succeed());
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.with_subcall_handle(subcall_handle)
+			.execute_reverts(|output| output == b"Invalid permit");
+
+		assert_eq!(subcalls.borrow().len(), 1);
+		assert_eq!(nonce_of(alice), 1.into());
+	});
+}
+
+#[test]
+fn permit_signed_for_the_next_nonce_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit::new(alice);
+		let signature = sign(&alice_key(), permit.hash(PRECOMPILE, 1.into()));
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.execute_reverts(|output| output == b"Invalid permit");
+
+		assert_eq!(nonce_of(alice), 0.into());
+	});
+}
+
+#[test]
+fn permit_signed_by_another_account_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit::new(alice);
+		let other_key = SecretKey::parse(&[0x22; 32]).unwrap();
+		let signature = sign(&other_key, permit.hash(PRECOMPILE, 0.into()));
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.execute_reverts(|output| output == b"Invalid permit");
+
+		assert_eq!(nonce_of(alice), 0.into());
+	});
+}
+
+#[test]
+fn modified_permit_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit::new(alice);
+		let signature = sign(&alice_key(), permit.hash(PRECOMPILE, 0.into()));
+
+		// The relayer cannot change the value, the target, the data or the gas limit
+		for modified in [
+			Permit { value: 1.into(), ..Permit::new(alice) },
+			Permit { to: BOB, ..Permit::new(alice) },
+			Permit { data: vec![0x03], ..Permit::new(alice) },
+			Permit { gas_limit: 1, ..Permit::new(alice) },
+		] {
+			precompiles()
+				.prepare_test(BOB, PRECOMPILE, modified.dispatch(signature))
+				.execute_reverts(|output| output == b"Invalid permit");
+		}
+
+		assert_eq!(nonce_of(alice), 0.into());
+	});
+}
+
+#[test]
+fn permit_signed_for_another_verifying_contract_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit::new(alice);
+		let signature = sign(&alice_key(), permit.hash(BOB, 0.into()));
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.execute_reverts(|output| output == b"Invalid permit");
+	});
+}
+
+#[test]
+fn malformed_signature_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit::new(alice);
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch((0, H256::zero(), H256::zero())))
+			.execute_reverts(|output| output == b"Invalid permit");
+	});
+}
+
+#[test]
+fn expired_permit_is_rejected() {
+	new_test_ext().execute_with(|| {
+		// The timestamp is in milliseconds, the deadline in seconds
+		pallet_timestamp::Now::<Runtime>::put(10_000);
+		let alice = address_of(&alice_key());
+
+		let expired = Permit { deadline: 9.into(), ..Permit::new(alice) };
+		let signature = sign(&alice_key(), expired.hash(PRECOMPILE, 0.into()));
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, expired.dispatch(signature))
+			.execute_reverts(|output| output == b"Permit expired");
+
+		let permit = Permit { deadline: 10.into(), ..Permit::new(alice) };
+		let signature = sign(&alice_key(), permit.hash(PRECOMPILE, 0.into()));
+		let (_, subcall_handle) = recording_subcalls(SubcallOutput::succeed());
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.with_subcall_handle(subcall_handle)
+			.execute_returns_encoded(UnboundedBytes::from(Vec::<u8>::new()));
+	});
+}
+
+#[test]
+fn gas_limit_must_be_available_in_full() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit::new(alice);
+		let signature = sign(&alice_key(), permit.hash(PRECOMPILE, 0.into()));
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.with_target_gas(Some(50_000))
+			.execute_reverts(|output| output == b"Gaslimit is too low to dispatch provided call");
+
+		assert_eq!(nonce_of(alice), 0.into());
+	});
+}
+
+#[test]
+fn reverting_call_reverts_the_dispatch() {
+	new_test_ext().execute_with(|| {
+		let alice = address_of(&alice_key());
+		let permit = Permit::new(alice);
+		let signature = sign(&alice_key(), permit.hash(PRECOMPILE, 0.into()));
+		let (_, subcall_handle) = recording_subcalls(SubcallOutput {
+			output: b"reason".to_vec(),
+			..SubcallOutput::revert()
+		});
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, permit.dispatch(signature))
+			.with_subcall_handle(subcall_handle)
+			.execute_reverts(|output| output == b"reason");
+	});
+}
+
+#[test]
+fn views_return_the_nonce_and_domain_separator() {
+	new_test_ext().execute_with(|| {
+		NoncesStorage::insert(ALICE, U256::from(3));
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::nonces { owner: Address(ALICE) })
+			.with_static_call(true)
+			.execute_returns_encoded(U256::from(3));
+
+		let expected: H256 = keccak_256(
+			&EvmDataWriter::new()
+				.write(H256::from(keccak_256(
+					b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+				)))
+				.write(H256::from(keccak_256(b"Call Permit Precompile")))
+				.write(H256::from(keccak_256(b"1")))
+				.write(U256::zero())
+				.write(Address(PRECOMPILE))
+				.build(),
+		)
+		.into();
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::domain_separator {})
+			.with_static_call(true)
+			.execute_returns_encoded(expected);
+	});
+}
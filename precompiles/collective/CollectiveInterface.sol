@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @author The Diora Team
+/// @title Collective precompile
+/// @dev Allows to interact with a pallet_collective instance (Council or Technical Committee).
+/// Proposals are given as SCALE encoded runtime calls.
+/// @custom:address 0x0000000000000000000000000000000000000806 (Council)
+/// @custom:address 0x0000000000000000000000000000000000000807 (Technical Committee)
+interface Collective {
+    /// @dev Execute a proposal as a single member of the collective.
+    /// The sender must be a member of the collective.
+    /// This will NOT revert if the Substrate proposal is dispatched but fails !
+    ///
+    /// @param proposal SCALE-encoded Substrate call.
+    ///
+    /// @custom:selector 09c5eabe
+    function execute(bytes memory proposal) external;
+
+    /// @dev Make a proposal for a call.
+    /// The sender must be a member of the collective.
+    /// If the threshold is less than 2 then the proposal will be dispatched
+    /// directly from the group of one member of the collective.
+    ///
+    /// @param threshold Amount of members required to dispatch the proposal.
+    /// @param proposal SCALE-encoded Substrate call.
+    /// @return index Index of the new proposal. Meaningless if threshold < 2
+    ///
+    /// @custom:selector c57f3260
+    function propose(uint32 threshold, bytes memory proposal)
+        external
+        returns (uint32 index);
+
+    /// @dev Vote for a proposal.
+    /// The sender must be a member of the collective.
+    ///
+    /// @param proposalHash Hash of the proposal to vote for. Ensure the caller knows what they're
+    /// voting in case of front-running or reorgs.
+    /// @param proposalIndex Index of the proposal (returned by propose).
+    /// @param approve The vote itself, is the caller approving or not the proposal.
+    ///
+    /// @custom:selector 73e37688
+    function vote(
+        bytes32 proposalHash,
+        uint32 proposalIndex,
+        bool approve
+    ) external;
+
+    /// @dev Close a proposal.
+    /// Can be called by anyone once there is enough votes.
+    /// Reverts if called at a non appropriate time.
+    ///
+    /// @param proposalHash Hash of the proposal to close.
+    /// @param proposalIndex Index of the proposal.
+    /// @param proposalWeightBound Maximum amount of Substrate weight the proposal can use.
+    /// This call will revert if the proposal call would use more.
+    /// @param lengthBound Must be a value higher or equal to the length of the SCALE-encoded
+    /// proposal in bytes.
+    /// @return executed Was the proposal executed or removed?
+    ///
+    /// @custom:selector 638d9d47
+    function close(
+        bytes32 proposalHash,
+        uint32 proposalIndex,
+        uint64 proposalWeightBound,
+        uint32 lengthBound
+    ) external returns (bool executed);
+
+    /// @dev Compute the hash of a proposal.
+    ///
+    /// @param proposal SCALE-encoded Substrate call.
+    /// @return proposalHash Hash of the proposal.
+    ///
+    /// @custom:selector fc379417
+    function proposalHash(bytes memory proposal)
+        external
+        view
+        returns (bytes32 proposalHash);
+
+    /// @dev Get the hashes of active proposals.
+    ///
+    /// @return proposalsHash Hashes of active proposals.
+    ///
+    /// @custom:selector 55ef20e6
+    function proposals() external view returns (bytes32[] memory proposalsHash);
+
+    /// @dev Get the account ids of the members of the collective.
+    ///
+    /// @return members The 32 bytes account ids of the members of the collective
+    ///
+    /// @custom:selector bdd4d18d
+    function members() external view returns (bytes32[] memory members);
+
+    /// @dev Check if the given address is a member of the collective.
+    /// The address is mapped to its account id the same way as a caller of the precompile.
+    ///
+    /// @param account Address to check membership of
+    ///
+    /// @custom:selector a230c524
+    function isMember(address account) external view returns (bool);
+
+    /// @dev Get the prime account if any.
+    ///
+    /// @return prime The 32 bytes account id of the prime, zero if there is none
+    ///
+    /// @custom:selector c7ee005e
+    function prime() external view returns (bytes32);
+
+    /// @dev A proposal was executed.
+    /// @param proposalHash Hash of the executed proposal.
+    event Executed(bytes32 indexed proposalHash);
+
+    /// @dev A proposal was made.
+    /// @param who Address of the proposer.
+    /// @param proposalIndex Index of the proposal.
+    /// @param proposalHash Hash of the proposal.
+    /// @param threshold Amount of members required to dispatch the proposal.
+    event Proposed(
+        address indexed who,
+        uint32 indexed proposalIndex,
+        bytes32 indexed proposalHash,
+        uint32 threshold
+    );
+
+    /// @dev A member voted on a proposal.
+    /// @param who Address of the voter.
+    /// @param proposalHash Hash of the proposal.
+    /// @param voted Whether the vote approves the proposal.
+    event Voted(address indexed who, bytes32 indexed proposalHash, bool voted);
+
+    /// @dev A proposal was closed without being executed.
+    /// @param proposalHash Hash of the closed proposal.
+    event Closed(bytes32 indexed proposalHash);
+}
@@ -0,0 +1,318 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet_collective instances through the EVM
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{DecodeLimit, Encode};
+use fp_evm::{Log, PrecompileHandle};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, Pays, PostDispatchInfo},
+	traits::ConstU32,
+	weights::Weight,
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256};
+use sp_runtime::traits::Hash;
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the `Executed` log.
+pub const SELECTOR_LOG_EXECUTED: [u8; 32] = keccak256!("Executed(bytes32)");
+
+/// Solidity selector of the `Proposed` log.
+pub const SELECTOR_LOG_PROPOSED: [u8; 32] = keccak256!("Proposed(address,uint32,bytes32,uint32)");
+
+/// Solidity selector of the `Voted` log.
+pub const SELECTOR_LOG_VOTED: [u8; 32] = keccak256!("Voted(address,bytes32,bool)");
+
+/// Solidity selector of the `Closed` log.
+pub const SELECTOR_LOG_CLOSED: [u8; 32] = keccak256!("Closed(bytes32)");
+
+/// Maximum size of an encoded proposal.
+pub const PROPOSAL_LIMIT: u32 = 2u32.pow(16);
+
+/// Maximum nesting depth allowed when decoding a proposal.
+pub const PROPOSAL_DECODE_DEPTH_LIMIT: u32 = 8;
+
+type GetProposalLimit = ConstU32<PROPOSAL_LIMIT>;
+
+pub fn log_executed(address: impl Into<H160>, hash: H256) -> Log {
+	log2(address.into(), SELECTOR_LOG_EXECUTED, hash, Vec::new())
+}
+
+pub fn log_proposed(
+	address: impl Into<H160>,
+	who: impl Into<H160>,
+	index: u32,
+	hash: H256,
+	threshold: u32,
+) -> Log {
+	log4(
+		address.into(),
+		SELECTOR_LOG_PROPOSED,
+		who.into(),
+		H256::from_low_u64_be(index as u64),
+		hash,
+		EvmDataWriter::new().write::<u32>(threshold).build(),
+	)
+}
+
+pub fn log_voted(address: impl Into<H160>, who: impl Into<H160>, hash: H256, voted: bool) -> Log {
+	log3(
+		address.into(),
+		SELECTOR_LOG_VOTED,
+		who.into(),
+		hash,
+		EvmDataWriter::new().write::<bool>(voted).build(),
+	)
+}
+
+pub fn log_closed(address: impl Into<H160>, hash: H256) -> Log {
+	log2(address.into(), SELECTOR_LOG_CLOSED, hash, Vec::new())
+}
+
+/// A precompile to wrap the functionality of an instance of pallet_collective.
+///
+/// The same precompile is deployed once per instance, e.g. for the council and the technical
+/// committee. Proposals are given as SCALE encoded runtime calls.
+pub struct CollectivePrecompile<Runtime, Instance: 'static>(PhantomData<(Runtime, Instance)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Instance> CollectivePrecompile<Runtime, Instance>
+where
+	Instance: 'static,
+	Runtime: pallet_collective::Config<Instance> + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + DecodeLimit,
+	Runtime::RuntimeCall: From<pallet_collective::Call<Runtime, Instance>>,
+	<Runtime as pallet_collective::Config<Instance>>::Proposal: From<Runtime::RuntimeCall>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	H256: From<<Runtime as frame_system::Config>::Hash>
+		+ Into<<Runtime as frame_system::Config>::Hash>,
+{
+	#[precompile::public("execute(bytes)")]
+	fn execute(
+		handle: &mut impl PrecompileHandle,
+		proposal: BoundedBytes<GetProposalLimit>,
+	) -> EvmResult {
+		let proposal: Vec<_> = proposal.into();
+		let proposal_hash: H256 = hash::<Runtime>(&proposal);
+
+		let log = log_executed(handle.context().address, proposal_hash);
+		handle.record_log_costs(&[&log])?;
+
+		let proposal_length: u32 = proposal
+			.len()
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("uint32").in_field("length"))?;
+		let proposal = Self::decode_proposal(&proposal)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_collective::Call::<Runtime, Instance>::execute {
+			proposal: Box::new(proposal.into()),
+			length_bound: proposal_length,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("propose(uint32,bytes)")]
+	fn propose(
+		handle: &mut impl PrecompileHandle,
+		threshold: u32,
+		proposal: BoundedBytes<GetProposalLimit>,
+	) -> EvmResult<u32> {
+		// Threshold < 2 means the proposal has been directly executed, which emits a
+		// different log.
+		if threshold < 2 {
+			handle.record_log_costs_manual(2, 0)?;
+		} else {
+			handle.record_log_costs_manual(4, 32)?;
+		}
+
+		let proposal: Vec<_> = proposal.into();
+		let proposal_hash: H256 = hash::<Runtime>(&proposal);
+		let proposal_length: u32 = proposal
+			.len()
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("uint32").in_field("length"))?;
+		let proposal = Self::decode_proposal(&proposal)?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let proposal_index = pallet_collective::Pallet::<Runtime, Instance>::proposal_count();
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_collective::Call::<Runtime, Instance>::propose {
+			threshold,
+			proposal: Box::new(proposal.into()),
+			length_bound: proposal_length,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		let log = if threshold < 2 {
+			log_executed(handle.context().address, proposal_hash)
+		} else {
+			log_proposed(
+				handle.context().address,
+				handle.context().caller,
+				proposal_index,
+				proposal_hash,
+				threshold,
+			)
+		};
+
+		log.record(handle)?;
+
+		Ok(proposal_index)
+	}
+
+	#[precompile::public("vote(bytes32,uint32,bool)")]
+	fn vote(
+		handle: &mut impl PrecompileHandle,
+		proposal_hash: H256,
+		proposal_index: u32,
+		approve: bool,
+	) -> EvmResult {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_collective::Call::<Runtime, Instance>::vote {
+			proposal: proposal_hash.into(),
+			index: proposal_index,
+			approve,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log_voted(handle.context().address, handle.context().caller, proposal_hash, approve)
+			.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("close(bytes32,uint32,uint64,uint32)")]
+	fn close(
+		handle: &mut impl PrecompileHandle,
+		proposal_hash: H256,
+		proposal_index: u32,
+		proposal_weight_bound: u64,
+		length_bound: u32,
+	) -> EvmResult<bool> {
+		// Because the actual log cannot be built before dispatch, we manually record it first
+		// (`executed` and `closed` have the same cost).
+		handle.record_log_costs_manual(2, 0)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_collective::Call::<Runtime, Instance>::close {
+			proposal_hash: proposal_hash.into(),
+			index: proposal_index,
+			proposal_weight_bound: Weight::from_ref_time(proposal_weight_bound),
+			length_bound,
+		};
+
+		let post_dispatch_info =
+			RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		// The proposal was approved and executed if the caller pays the fee, a disapproved
+		// proposal is closed for free.
+		let (executed, log) = match post_dispatch_info.pays_fee {
+			Pays::Yes => (true, log_executed(handle.context().address, proposal_hash)),
+			Pays::No => (false, log_closed(handle.context().address, proposal_hash)),
+		};
+		log.record(handle)?;
+
+		Ok(executed)
+	}
+
+	#[precompile::public("proposalHash(bytes)")]
+	#[precompile::view]
+	fn proposal_hash(
+		_handle: &mut impl PrecompileHandle,
+		proposal: BoundedBytes<GetProposalLimit>,
+	) -> EvmResult<H256> {
+		let proposal: Vec<_> = proposal.into();
+
+		Ok(hash::<Runtime>(&proposal))
+	}
+
+	#[precompile::public("proposals()")]
+	#[precompile::view]
+	fn proposals(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<H256>> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let proposals = pallet_collective::Pallet::<Runtime, Instance>::proposals();
+
+		Ok(proposals.into_iter().map(|hash| hash.into()).collect())
+	}
+
+	#[precompile::public("members()")]
+	#[precompile::view]
+	fn members(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<H256>> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let members = pallet_collective::Pallet::<Runtime, Instance>::members();
+
+		Ok(members
+			.into_iter()
+			.map(|account_id| H256::from_slice(&account_id.encode()[..]))
+			.collect())
+	}
+
+	#[precompile::public("isMember(address)")]
+	#[precompile::view]
+	fn is_member(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<bool> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let account = Runtime::AddressMapping::into_account_id(account.into());
+
+		Ok(pallet_collective::Pallet::<Runtime, Instance>::is_member(&account))
+	}
+
+	#[precompile::public("prime()")]
+	#[precompile::view]
+	fn prime(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let prime = pallet_collective::Pallet::<Runtime, Instance>::prime()
+			.map(|account_id| H256::from_slice(&account_id.encode()[..]))
+			.unwrap_or_default();
+
+		Ok(prime)
+	}
+
+	fn decode_proposal(proposal: &[u8]) -> MayRevert<Runtime::RuntimeCall> {
+		Runtime::RuntimeCall::decode_with_depth_limit(PROPOSAL_DECODE_DEPTH_LIMIT, &mut &*proposal)
+			.map_err(|_| RevertReason::custom("Failed to decode proposal").in_field("proposal"))
+	}
+}
+
+/// Hash of an encoded proposal, as computed by pallet_collective.
+pub fn hash<Runtime>(data: &[u8]) -> H256
+where
+	Runtime: frame_system::Config,
+	H256: From<<Runtime as frame_system::Config>::Hash>,
+{
+	<Runtime as frame_system::Config>::Hashing::hash(data).into()
+}
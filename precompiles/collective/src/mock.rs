@@ -0,0 +1,69 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Test utilities
+use crate::CollectivePrecompile;
+
+use frame_support::traits::{ConstU32, ConstU64, GenesisBuild};
+use pallet_collective::Instance1;
+use precompile_utils::{precompile_set::*, testing::ExtBuilder};
+use sp_io::TestExternalities;
+
+precompile_utils::mock_runtime! {
+	precompiles: Precompiles<Runtime>,
+	pallets: {
+		Council: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
+	},
+}
+
+impl pallet_collective::Config<Instance1> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = ConstU64<10>;
+	type MaxProposals = ConstU32<10>;
+	type MaxMembers = ConstU32<10>;
+	type DefaultVote = pallet_collective::MoreThanMajorityThenPrimeDefaultVote;
+	type WeightInfo = ();
+}
+
+/// Same checks as in the runtime.
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		PrecompileAt<
+			PrecompileAddress,
+			CollectivePrecompile<R, Instance1>,
+			(CallableByContract, CallableByPrecompile),
+		>,
+	),
+>;
+
+pub(crate) fn new_test_ext() -> TestExternalities {
+	ExtBuilder::<Runtime>::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000), (DAVE, 1_000)])
+		.build_with(|storage| {
+			// DAVE is not a member of the council
+			pallet_collective::GenesisConfig::<Runtime, Instance1> {
+				members: vec![account(ALICE), account(BOB), account(CHARLIE)],
+				phantom: Default::default(),
+			}
+			.assimilate_storage(storage)
+			.unwrap();
+		})
+}
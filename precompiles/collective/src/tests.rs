@@ -0,0 +1,325 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use pallet_collective::{Event as CollectiveEvent, Instance1};
+use precompile_utils::testing::*;
+
+type PCall = CollectivePrecompileCall<Runtime, Instance1>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Whether the reverted `output` is the dispatch error `error`.
+fn dispatch_error(output: &[u8], error: &str) -> bool {
+	let output = std::str::from_utf8(output).unwrap();
+	output.starts_with("Dispatched call failed with error: ") && output.contains(error)
+}
+
+fn proposal() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1, 2, 3] })
+}
+
+fn collective_events() -> Vec<CollectiveEvent<Runtime, Instance1>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Council(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Submits `proposal()` from ALICE with the given threshold, returning its hash.
+fn propose(threshold: u32) -> H256 {
+	let encoded = proposal().encode();
+	let hash = hash::<Runtime>(&encoded);
+
+	precompiles()
+		.prepare_test(ALICE, PRECOMPILE, PCall::propose { threshold, proposal: encoded.into() })
+		.expect_log(log_proposed(PRECOMPILE, ALICE, 0, hash, threshold))
+		.execute_returns_encoded(0u32);
+
+	hash
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["CollectiveInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn member_executes_proposal_with_member_origin() {
+	new_test_ext().execute_with(|| {
+		let encoded = proposal().encode();
+		let hash = hash::<Runtime>(&encoded);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::execute { proposal: encoded.into() })
+			.expect_log(log_executed(PRECOMPILE, hash))
+			.execute_returns(vec![]);
+
+		assert!(collective_events().iter().any(|event| matches!(
+			event,
+			CollectiveEvent::MemberExecuted { proposal_hash, .. } if *proposal_hash == hash
+		)));
+	});
+}
+
+#[test]
+fn non_member_cannot_execute() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(DAVE, PRECOMPILE, PCall::execute { proposal: proposal().encode().into() })
+			.execute_reverts(|output| dispatch_error(output, "NotMember"));
+
+		assert!(collective_events().is_empty());
+	});
+}
+
+#[test]
+fn undecodable_proposal_is_rejected() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::execute { proposal: vec![0xff; 4].into() })
+			.execute_reverts(|output| output == b"proposal: Failed to decode proposal");
+	});
+}
+
+#[test]
+fn member_proposes() {
+	new_test_ext().execute_with(|| {
+		let hash = propose(2);
+
+		assert_eq!(pallet_collective::Pallet::<Runtime, Instance1>::proposals(), vec![hash]);
+		// The proposer votes for its own proposal
+		let voting = pallet_collective::Pallet::<Runtime, Instance1>::voting(hash).unwrap();
+		assert_eq!(voting.ayes, vec![account(ALICE)]);
+	});
+}
+
+#[test]
+fn proposal_with_threshold_of_one_is_executed_directly() {
+	new_test_ext().execute_with(|| {
+		let encoded = proposal().encode();
+		let hash = hash::<Runtime>(&encoded);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::propose { threshold: 1, proposal: encoded.into() },
+			)
+			.expect_log(log_executed(PRECOMPILE, hash))
+			.execute_returns_encoded(0u32);
+
+		assert!(pallet_collective::Pallet::<Runtime, Instance1>::proposals().is_empty());
+	});
+}
+
+#[test]
+fn non_member_cannot_propose() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				DAVE,
+				PRECOMPILE,
+				PCall::propose { threshold: 2, proposal: proposal().encode().into() },
+			)
+			.execute_reverts(|output| dispatch_error(output, "NotMember"));
+
+		assert!(pallet_collective::Pallet::<Runtime, Instance1>::proposals().is_empty());
+	});
+}
+
+#[test]
+fn member_votes_once() {
+	new_test_ext().execute_with(|| {
+		let hash = propose(2);
+
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::vote { proposal_hash: hash, proposal_index: 0, approve: false },
+			)
+			.expect_log(log_voted(PRECOMPILE, BOB, hash, false))
+			.execute_returns(vec![]);
+
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::vote { proposal_hash: hash, proposal_index: 0, approve: false },
+			)
+			.execute_reverts(|output| dispatch_error(output, "DuplicateVote"));
+
+		let voting = pallet_collective::Pallet::<Runtime, Instance1>::voting(hash).unwrap();
+		assert_eq!(voting.nays, vec![account(BOB)]);
+	});
+}
+
+#[test]
+fn non_member_cannot_vote() {
+	new_test_ext().execute_with(|| {
+		let hash = propose(2);
+
+		precompiles()
+			.prepare_test(
+				DAVE,
+				PRECOMPILE,
+				PCall::vote { proposal_hash: hash, proposal_index: 0, approve: true },
+			)
+			.execute_reverts(|output| dispatch_error(output, "NotMember"));
+	});
+}
+
+#[test]
+fn close_executes_approved_proposal() {
+	new_test_ext().execute_with(|| {
+		let hash = propose(2);
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::vote { proposal_hash: hash, proposal_index: 0, approve: true },
+			)
+			.execute_returns(vec![]);
+
+		// Anyone can close a decided proposal
+		precompiles()
+			.prepare_test(
+				DAVE,
+				PRECOMPILE,
+				PCall::close {
+					proposal_hash: hash,
+					proposal_index: 0,
+					proposal_weight_bound: proposal().get_dispatch_info().weight.ref_time(),
+					length_bound: proposal().encode().len() as u32,
+				},
+			)
+			.expect_log(log_executed(PRECOMPILE, hash))
+			.execute_returns_encoded(true);
+
+		assert!(pallet_collective::Pallet::<Runtime, Instance1>::proposals().is_empty());
+		assert!(collective_events().iter().any(|event| matches!(
+			event,
+			CollectiveEvent::Executed { proposal_hash, .. } if *proposal_hash == hash
+		)));
+	});
+}
+
+#[test]
+fn close_drops_disapproved_proposal() {
+	new_test_ext().execute_with(|| {
+		let hash = propose(3);
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::vote { proposal_hash: hash, proposal_index: 0, approve: false },
+			)
+			.execute_returns(vec![]);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::close {
+					proposal_hash: hash,
+					proposal_index: 0,
+					proposal_weight_bound: 0,
+					length_bound: proposal().encode().len() as u32,
+				},
+			)
+			.expect_log(log_closed(PRECOMPILE, hash))
+			.execute_returns_encoded(false);
+
+		assert!(pallet_collective::Pallet::<Runtime, Instance1>::proposals().is_empty());
+		assert!(collective_events().iter().any(|event| matches!(
+			event,
+			CollectiveEvent::Disapproved { proposal_hash } if *proposal_hash == hash
+		)));
+	});
+}
+
+#[test]
+fn undecided_proposal_cannot_be_closed() {
+	new_test_ext().execute_with(|| {
+		let hash = propose(3);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::close {
+					proposal_hash: hash,
+					proposal_index: 0,
+					proposal_weight_bound: 0,
+					length_bound: proposal().encode().len() as u32,
+				},
+			)
+			.execute_reverts(|output| dispatch_error(output, "TooEarly"));
+
+		assert_eq!(pallet_collective::Pallet::<Runtime, Instance1>::proposals(), vec![hash]);
+	});
+}
+
+#[test]
+fn views() {
+	new_test_ext().execute_with(|| {
+		let encoded = proposal().encode();
+		let hash = propose(2);
+		let member = |address| H256::from_slice(account(address).as_ref());
+
+		precompiles()
+			.prepare_test(DAVE, PRECOMPILE, PCall::proposal_hash { proposal: encoded.into() })
+			.expect_no_logs()
+			.execute_returns_encoded(hash);
+
+		precompiles()
+			.prepare_test(DAVE, PRECOMPILE, PCall::proposals {})
+			.expect_no_logs()
+			.execute_returns_encoded(vec![hash]);
+
+		precompiles()
+			.prepare_test(DAVE, PRECOMPILE, PCall::members {})
+			.expect_no_logs()
+			.execute_returns_encoded(vec![member(ALICE), member(BOB), member(CHARLIE)]);
+
+		precompiles()
+			.prepare_test(DAVE, PRECOMPILE, PCall::is_member { account: Address(BOB) })
+			.expect_no_logs()
+			.execute_returns_encoded(true);
+
+		precompiles()
+			.prepare_test(DAVE, PRECOMPILE, PCall::is_member { account: Address(DAVE) })
+			.expect_no_logs()
+			.execute_returns_encoded(false);
+
+		// No prime member is set
+		precompiles()
+			.prepare_test(DAVE, PRECOMPILE, PCall::prime {})
+			.expect_no_logs()
+			.execute_returns_encoded(H256::zero());
+	});
+}
@@ -8,79 +8,178 @@ pragma solidity >=0.8.0;
 /// wraps the pallet
 /// @custom:address 0x0000000000000000000000000000000000000801
 interface DappsStaking {
-
-    // Storage getters
-
-    /// @notice Read current era.
-    /// @return era: The current era
+    // --- Generated from the precompile implementation, do not edit ---
+    /// @dev Stake provided amount on the contract.
+    /// @custom:selector 6a7501cf
+    /// @param contractH160 The smart contract address used for staking
+    /// @param value The amount to stake
+    function bondAndStake(address contractH160, uint256 value) external;
+
+    /// @dev Claim one era of unclaimed dapp rewards for the specified contract and era.
+    /// @custom:selector a76885c8
+    /// @param contractH160 The smart contract address used for staking
+    /// @param era The era to be claimed
+    function claimDapp(address contractH160, uint32 era) external;
+
+    /// @dev Claim earned staker rewards for the oldest unclaimed era.
+    /// In order to claim multiple eras, this call has to be called multiple times.
+    /// Staker account is derived from the caller address.
+    /// @custom:selector 2ffe703c
+    /// @param contractH160 The smart contract address used for staking
+    function claimStaker(address contractH160) external;
+
+    /// @dev Unstake balance from the contract and unlock it right away, for a fee.
+    /// @custom:selector 7efcc987
+    /// @param contractH160 The smart contract address used for staking
+    /// @param value The amount to unstake
+    function instantUnstake(address contractH160, uint256 value) external;
+
+    /// @dev Transfer part or entire nomination from origin smart contract to target smart contract.
+    /// @custom:selector 57ceb851
+    /// @param originContractH160 The origin smart contract address
+    /// @param value The amount to transfer from origin to target
+    /// @param targetContractH160 The target smart contract address
+    function nominationTransfer(
+        address originContractH160,
+        uint256 value,
+        address targetContractH160
+    ) external;
+
+    /// @dev Read the staked amount from the era when the amount was last staked/unstaked.
+    /// @custom:selector af76b962
+    /// @param contractH160 The smart contract address used for staking
+    /// @return The most recent total staked amount on contract
+    function readContractStake(address contractH160) external view returns (uint128);
+
+    /// @dev Read current era.
+    /// @custom:selector 11553780
+    /// @return The current era
     function readCurrentEra() external view returns (uint32);
 
-    /// @notice Read unbonding period constant.
-    /// @return period: The unbonding period in eras
-    function readUnbondingPeriod() external view returns (uint32);
-
-    /// @notice Read Total network reward for the given era
-    /// @return reward: Total network reward for the given era
+    /// @dev Read total network reward for the given era.
+    /// @custom:selector aeb5eefd
+    /// @param era The era for which the reward is queried
+    /// @return Total network reward for the given era
     function readEraReward(uint32 era) external view returns (uint128);
 
-    /// @notice Read Total staked amount for the given era
-    /// @return staked: Total staked amount for the given era
+    /// @dev Read total staked amount for the given era.
+    /// @custom:selector 367d7466
+    /// @param era The era for which the staked amount is queried
+    /// @return Total staked amount for the given era
     function readEraStaked(uint32 era) external view returns (uint128);
 
-    /// @notice Read Staked amount for the staker
-    /// @param staker: The staker address in form of 20 or 32 hex bytes
-    /// @return amount: Staked amount by the staker
-    function readStakedAmount(bytes calldata staker) external view returns (uint128);
-
-    /// @notice Read Staked amount on a given contract for the staker
-    /// @param contract_id: The smart contract address used for staking
-    /// @param staker: The staker address in form of 20 or 32 hex bytes
-    /// @return amount: Staked amount by the staker
-    function readStakedAmountOnContract(address contract_id, bytes calldata staker) external view returns (uint128);
-
-    /// @notice Read the staked amount from the era when the amount was last staked/unstaked
-    /// @return total: The most recent total staked amount on contract
-    function readContractStake(address contract_id) external view returns (uint128);
+    /// @dev Read staked amount for the staker.
+    /// @custom:selector 09672b73
+    /// @param staker The staker address in form of 20 or 32 hex bytes
+    /// @return Staked amount by the staker
+    function readStakedAmount(bytes memory staker) external view returns (uint128);
+
+    /// @dev Read staked amount on a given contract for the staker.
+    /// @custom:selector abb25dba
+    /// @param contractH160 The smart contract address used for staking
+    /// @param staker The staker address in form of 20 or 32 hex bytes
+    /// @return Staked amount by the staker
+    function readStakedAmountOnContract(
+        address contractH160,
+        bytes memory staker
+    ) external view returns (uint128);
+
+    /// @dev Read unbonding period.
+    /// @custom:selector d89b8ac9
+    /// @return The unbonding period in eras
+    function readUnbondingPeriod() external view returns (uint32);
 
+    /// @dev Stop unbonding funds, latest unlocking chunks first, and stake them on the contract again.
+    /// @custom:selector da9870b8
+    /// @param contractH160 The smart contract address used for staking
+    /// @param value The amount to rebond and stake
+    function rebondAndStake(address contractH160, uint256 value) external;
+
+    /// @dev Set reward destination for staker rewards.
+    /// @custom:selector 08170573
+    /// @param rewardDestinationRaw The instruction on how the reward payout should be handled,
+    /// as a `RewardDestination`
+    function setRewardDestination(uint8 rewardDestinationRaw) external;
+
+    /// @dev Start unbonding process and unstake balance from the contract.
+    /// @custom:selector 0ad2fcca
+    /// @param contractH160 The smart contract address used for staking
+    /// @param value The amount to unstake
+    function unbondAndUnstake(address contractH160, uint256 value) external;
+
+    /// @dev Withdraw staked funds from an unregistered contract.
+    /// @custom:selector 09aff586
+    /// @param contractH160 The smart contract address used for staking
+    function withdrawFromUnregistered(address contractH160) external;
+
+    /// @dev Withdraw all funds that have completed the unbonding process.
+    /// @custom:selector 6e373bef
+    function withdrawUnbonded() external;
+    // --- End of generated functions ---
 
-    // Extrinsic calls
+    /// Instruction how to handle reward payout for staker.
+    /// `FreeBalance` - Reward will be paid out to the staker (free balance).
+    /// `StakeBalance` - Reward will be paid out to the staker and is immediately restaked (locked balance)
+    enum RewardDestination {FreeBalance, StakeBalance}
 
-    /// @notice Stake provided amount on the contract.
-    function bondAndStake(address, uint256) external;
+    /// @notice Emitted when a staker bonds and stakes on a contract.
+    /// @param staker: The address of the staker
+    /// @param smart_contract: The smart contract address used for staking
+    /// @param amount: The staked amount
+    event BondAndStake(address indexed staker, address indexed smart_contract, uint256 amount);
 
-    /// @notice Start unbonding process and unstake balance from the contract.
-    function unbondAndUnstake(address, uint256) external;
+    /// @notice Emitted when a staker unbonds and unstakes from a contract.
+    /// @param staker: The address of the staker
+    /// @param smart_contract: The smart contract address used for staking
+    /// @param amount: The unstaked amount
+    event UnbondAndUnstake(address indexed staker, address indexed smart_contract, uint256 amount);
 
-    /// @notice Withdraw all funds that have completed the unbonding process.
-    function withdrawUnbonded() external;
+    /// @notice Emitted when a staker withdraws its unbonded funds.
+    /// @param staker: The address of the staker
+    event WithdrawUnbonded(address indexed staker);
 
-    /// @notice Claim earned staker rewards for the oldest unclaimed era.
-    ///         In order to claim multiple eras, this call has to be called multiple times.
-    ///         Staker account is derived from the caller address.
+    /// @notice Emitted when the dapp rewards of a contract are claimed for an era.
+    /// @param caller: The address claiming the rewards
     /// @param smart_contract: The smart contract address used for staking
-    function claimStaker(address smart_contract) external;
+    /// @param era: The claimed era
+    event ClaimedDapp(address indexed caller, address indexed smart_contract, uint32 era);
 
-    /// @notice Claim one era of unclaimed dapp rewards for the specified contract and era.
+    /// @notice Emitted when a staker claims its rewards for the oldest unclaimed era.
+    /// @param staker: The address of the staker
     /// @param smart_contract: The smart contract address used for staking
-    /// @param era: The era to be claimed
-    function claimDapp(address smart_contract, uint32 era) external;
+    event ClaimedStaker(address indexed staker, address indexed smart_contract);
 
-    /// Instruction how to handle reward payout for staker.
-    /// `FreeBalance` - Reward will be paid out to the staker (free balance).
-    /// `StakeBalance` - Reward will be paid out to the staker and is immediately restaked (locked balance)
-    enum RewardDestination {FreeBalance, StakeBalance}
-
-    /// @notice Set reward destination for staker rewards
+    /// @notice Emitted when a staker sets its reward destination.
+    /// @param staker: The address of the staker
     /// @param reward_destination: The instruction on how the reward payout should be handled
-    function setRewardDestination(RewardDestination reward_destination) external;
+    event RewardDestinationSet(address indexed staker, uint8 reward_destination);
 
-    /// @notice Withdraw staked funds from an unregistered contract.
+    /// @notice Emitted when a staker withdraws its funds from an unregistered contract.
+    /// @param staker: The address of the staker
     /// @param smart_contract: The smart contract address used for staking
-    function withdrawFromUnregistered(address smart_contract) external;
+    event WithdrawnFromUnregistered(address indexed staker, address indexed smart_contract);
 
-    /// @notice Transfer part or entire nomination from origin smart contract to target smart contract
+    /// @notice Emitted when a staker transfers nomination from a contract to another.
+    /// @param staker: The address of the staker
     /// @param origin_smart_contract: The origin smart contract address
-    /// @param amount: The amount to transfer from origin to target
     /// @param target_smart_contract: The target smart contract address
-    function nominationTransfer(address origin_smart_contract, uint256 amount, address target_smart_contract) external;
-}
\ No newline at end of file
+    /// @param amount: The transferred amount
+    event NominationTransfer(
+        address indexed staker,
+        address indexed origin_smart_contract,
+        address indexed target_smart_contract,
+        uint256 amount
+    );
+
+    /// @notice Emitted when a staker unstakes from a contract without unbonding period.
+    /// @param staker: The address of the staker
+    /// @param smart_contract: The smart contract address used for staking
+    /// @param amount: The unstaked amount, before the fee
+    event InstantUnstake(address indexed staker, address indexed smart_contract, uint256 amount);
+
+    /// @notice Emitted when a staker stakes unbonding funds on a contract again.
+    /// @param staker: The address of the staker
+    /// @param smart_contract: The smart contract address used for staking
+    /// @param amount: The rebonded amount
+    event RebondAndStake(address indexed staker, address indexed smart_contract, uint256 amount);
+}
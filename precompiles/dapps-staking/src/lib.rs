@@ -21,10 +21,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use fp_evm::PrecompileHandle;
+use fp_evm::{Log, PrecompileHandle};
 use frame_support::{
 	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
-	traits::{Currency, Get},
+	traits::Currency,
 };
 use pallet_dapps_staking::RewardDestination;
 use pallet_evm::AddressMapping;
@@ -33,6 +33,9 @@ use sp_core::{ConstU32, H160, U256};
 use sp_runtime::{traits::Zero, SaturatedConversion, Saturating};
 use sp_std::{convert::TryInto, marker::PhantomData, vec::Vec};
 
+#[cfg(test)]
+mod tests;
+
 type BalanceOf<Runtime> = <<Runtime as pallet_dapps_staking::Config>::Currency as Currency<
 	<Runtime as frame_system::Config>::AccountId,
 >>::Balance;
@@ -46,9 +49,116 @@ pub enum Contract<A> {
 	Wasm(A),
 }
 
+/// Solidity selector of the `BondAndStake` log.
+pub const SELECTOR_LOG_BOND_AND_STAKE: [u8; 32] =
+	keccak256!("BondAndStake(address,address,uint256)");
+
+/// Solidity selector of the `UnbondAndUnstake` log.
+pub const SELECTOR_LOG_UNBOND_AND_UNSTAKE: [u8; 32] =
+	keccak256!("UnbondAndUnstake(address,address,uint256)");
+
+/// Solidity selector of the `InstantUnstake` log.
+pub const SELECTOR_LOG_INSTANT_UNSTAKE: [u8; 32] =
+	keccak256!("InstantUnstake(address,address,uint256)");
+
+/// Solidity selector of the `RebondAndStake` log.
+pub const SELECTOR_LOG_REBOND_AND_STAKE: [u8; 32] =
+	keccak256!("RebondAndStake(address,address,uint256)");
+
+/// Solidity selector of the `WithdrawUnbonded` log.
+pub const SELECTOR_LOG_WITHDRAW_UNBONDED: [u8; 32] = keccak256!("WithdrawUnbonded(address)");
+
+/// Solidity selector of the `ClaimedDapp` log.
+pub const SELECTOR_LOG_CLAIMED_DAPP: [u8; 32] = keccak256!("ClaimedDapp(address,address,uint32)");
+
+/// Solidity selector of the `ClaimedStaker` log.
+pub const SELECTOR_LOG_CLAIMED_STAKER: [u8; 32] = keccak256!("ClaimedStaker(address,address)");
+
+/// Solidity selector of the `RewardDestinationSet` log.
+pub const SELECTOR_LOG_REWARD_DESTINATION_SET: [u8; 32] =
+	keccak256!("RewardDestinationSet(address,uint8)");
+
+/// Solidity selector of the `WithdrawnFromUnregistered` log.
+pub const SELECTOR_LOG_WITHDRAWN_FROM_UNREGISTERED: [u8; 32] =
+	keccak256!("WithdrawnFromUnregistered(address,address)");
+
+/// Solidity selector of the `NominationTransfer` log.
+pub const SELECTOR_LOG_NOMINATION_TRANSFER: [u8; 32] =
+	keccak256!("NominationTransfer(address,address,address,uint256)");
+
+/// Log emitted when a staker stakes or unstakes an amount on a contract.
+pub fn log_stake(
+	address: impl Into<H160>,
+	selector: [u8; 32],
+	staker: H160,
+	contract: H160,
+	amount: U256,
+) -> Log {
+	log3(address.into(), selector, staker, contract, EvmDataWriter::new().write(amount).build())
+}
+
+/// Log emitted by the calls of a staker about one of its contracts.
+pub fn log_staker_contract(
+	address: impl Into<H160>,
+	selector: [u8; 32],
+	staker: H160,
+	contract: H160,
+) -> Log {
+	log3(address.into(), selector, staker, contract, Vec::new())
+}
+
+/// Log emitted when the unbonded chunks of a staker are withdrawn.
+pub fn log_withdraw_unbonded(address: impl Into<H160>, staker: H160) -> Log {
+	log2(address.into(), SELECTOR_LOG_WITHDRAW_UNBONDED, staker, Vec::new())
+}
+
+/// Log emitted when the dapp rewards of a contract are claimed for an era, by any caller.
+pub fn log_claimed_dapp(address: impl Into<H160>, caller: H160, contract: H160, era: u32) -> Log {
+	log3(
+		address.into(),
+		SELECTOR_LOG_CLAIMED_DAPP,
+		caller,
+		contract,
+		EvmDataWriter::new().write(era).build(),
+	)
+}
+
+/// Log emitted when a staker sets its reward destination.
+pub fn log_reward_destination_set(
+	address: impl Into<H160>,
+	staker: H160,
+	reward_destination: u8,
+) -> Log {
+	log2(
+		address.into(),
+		SELECTOR_LOG_REWARD_DESTINATION_SET,
+		staker,
+		EvmDataWriter::new().write(reward_destination).build(),
+	)
+}
+
+/// Log emitted when a staker moves some of its stake from one contract to another.
+pub fn log_nomination_transfer(
+	address: impl Into<H160>,
+	staker: H160,
+	origin_contract: H160,
+	target_contract: H160,
+	amount: U256,
+) -> Log {
+	log4(
+		address.into(),
+		SELECTOR_LOG_NOMINATION_TRANSFER,
+		staker,
+		origin_contract,
+		target_contract,
+		EvmDataWriter::new().write(amount).build(),
+	)
+}
+
 pub struct DappsStakingPrecompile<Runtime>(PhantomData<Runtime>);
 
 #[precompile_utils::precompile]
+#[precompile::solidity_interface("DappsStakingInterface.sol")]
 impl<Runtime> DappsStakingPrecompile<Runtime>
 where
 	Runtime: pallet_dapps_staking::Config + pallet_evm::Config,
@@ -59,6 +169,8 @@ where
 	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + EvmData,
 {
 	// Constants
+	/// Read current era.
+	/// @return The current era
 	#[precompile::public("readCurrentEra()")]
 	#[precompile::view]
 	fn read_current_era(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
@@ -71,17 +183,22 @@ where
 	}
 
 	// Storage Getters
+	/// Read unbonding period.
+	/// @return The unbonding period in eras
 	#[precompile::public("readUnbondingPeriod()")]
 	#[precompile::view]
 	fn read_unbonding_period(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
 		// Fetch info.
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
 
-		let unbonding_period = Runtime::UnbondingPeriod::get();
+		let unbonding_period = pallet_dapps_staking::UnbondingPeriod::<Runtime>::get();
 
 		Ok(unbonding_period)
 	}
 
+	/// Read total network reward for the given era.
+	/// @param era The era for which the reward is queried
+	/// @return Total network reward for the given era
 	#[precompile::public("readEraReward(uint32)")]
 	#[precompile::view]
 	fn read_era_reward(handle: &mut impl PrecompileHandle, era: u32) -> EvmResult<u128> {
@@ -95,6 +212,9 @@ where
 		Ok(reward.saturated_into())
 	}
 
+	/// Read total staked amount for the given era.
+	/// @param era The era for which the staked amount is queried
+	/// @return Total staked amount for the given era
 	#[precompile::public("readEraStaked(uint32)")]
 	#[precompile::view]
 	fn read_era_staked(handle: &mut impl PrecompileHandle, era: u32) -> EvmResult<u128> {
@@ -109,6 +229,9 @@ where
 		Ok(staked)
 	}
 
+	/// Read staked amount for the staker.
+	/// @param staker The staker address in form of 20 or 32 hex bytes
+	/// @return Staked amount by the staker
 	#[precompile::public("readStakedAmount(bytes)")]
 	#[precompile::view]
 	fn read_staked_amount(
@@ -129,6 +252,10 @@ where
 		Ok(ledger.locked.saturated_into())
 	}
 
+	/// Read staked amount on a given contract for the staker.
+	/// @param contractH160 The smart contract address used for staking
+	/// @param staker The staker address in form of 20 or 32 hex bytes
+	/// @return Staked amount by the staker
 	#[precompile::public("readStakedAmountOnContract(address,bytes)")]
 	#[precompile::view]
 	fn read_staked_amount_on_contract(
@@ -154,6 +281,9 @@ where
 		Ok(staked_amount.saturated_into())
 	}
 
+	/// Read the staked amount from the era when the amount was last staked/unstaked.
+	/// @param contractH160 The smart contract address used for staking
+	/// @return The most recent total staked amount on contract
 	#[precompile::public("readContractStake(address)")]
 	#[precompile::view]
 	fn read_contract_stake(
@@ -176,12 +306,24 @@ where
 		Ok(total)
 	}
 
+	/// Stake provided amount on the contract.
+	/// @param contractH160 The smart contract address used for staking
+	/// @param value The amount to stake
 	#[precompile::public("bondAndStake(address,uint256)")]
 	fn bond_and_stake(
 		handle: &mut impl PrecompileHandle,
 		contract_h160: Address,
 		value: U256,
 	) -> EvmResult {
+		let event = log_stake(
+			handle.context().address,
+			SELECTOR_LOG_BOND_AND_STAKE,
+			handle.context().caller,
+			contract_h160.0,
+			value,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let contract_id = Self::decode_smart_contract(contract_h160)?;
 		let value = Self::u256_to_amount(value).in_field("value")?;
 		log::trace!(target: "ds-precompile", "bond_and_stake {:?}, {:?}", contract_id, value);
@@ -192,17 +334,31 @@ where
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
 	// Runtime Methods (dispatchables)
 
+	/// Start unbonding process and unstake balance from the contract.
+	/// @param contractH160 The smart contract address used for staking
+	/// @param value The amount to unstake
 	#[precompile::public("unbondAndUnstake(address,uint256)")]
 	fn unbond_and_unstake(
 		handle: &mut impl PrecompileHandle,
 		contract_h160: Address,
 		value: U256,
 	) -> EvmResult {
+		let event = log_stake(
+			handle.context().address,
+			SELECTOR_LOG_UNBOND_AND_UNSTAKE,
+			handle.context().caller,
+			contract_h160.0,
+			value,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let contract_id = Self::decode_smart_contract(contract_h160)?;
 		let value = Self::u256_to_amount(value).in_field("value")?;
 
@@ -214,26 +370,126 @@ where
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Unstake balance from the contract and unlock it right away, for a fee.
+	/// @param contractH160 The smart contract address used for staking
+	/// @param value The amount to unstake
+	#[precompile::public("instantUnstake(address,uint256)")]
+	fn instant_unstake(
+		handle: &mut impl PrecompileHandle,
+		contract_h160: Address,
+		value: U256,
+	) -> EvmResult {
+		let event = log_stake(
+			handle.context().address,
+			SELECTOR_LOG_INSTANT_UNSTAKE,
+			handle.context().caller,
+			contract_h160.0,
+			value,
+		);
+		handle.record_log_costs(&[&event])?;
+
+		let contract_id = Self::decode_smart_contract(contract_h160)?;
+		let value = Self::u256_to_amount(value).in_field("value")?;
+
+		log::trace!(target: "ds-precompile", "instant_unstake {:?}, {:?}", contract_id, value);
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_dapps_staking::Call::<Runtime>::instant_unstake { contract_id, value };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		event.record(handle)?;
+
+		Ok(())
+	}
+
+	/// Stop unbonding funds, latest unlocking chunks first, and stake them on the contract again.
+	/// @param contractH160 The smart contract address used for staking
+	/// @param value The amount to rebond and stake
+	#[precompile::public("rebondAndStake(address,uint256)")]
+	fn rebond_and_stake(
+		handle: &mut impl PrecompileHandle,
+		contract_h160: Address,
+		value: U256,
+	) -> EvmResult {
+		// the logged amount is the one actually rebonded, the log costs the same for any amount
+		handle.record_log_costs(&[&log_stake(
+			handle.context().address,
+			SELECTOR_LOG_REBOND_AND_STAKE,
+			handle.context().caller,
+			contract_h160.0,
+			value,
+		)])?;
+
+		let contract_id = Self::decode_smart_contract(contract_h160)?;
+		let value = Self::u256_to_amount(value).in_field("value")?;
+
+		log::trace!(target: "ds-precompile", "rebond_and_stake {:?}, {:?}", contract_id, value);
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let unbonding_before =
+			pallet_dapps_staking::Ledger::<Runtime>::get(&origin).unbonding_amount();
+		let call = pallet_dapps_staking::Call::<Runtime>::rebond_and_stake { contract_id, value };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin.clone()).into(), call)?;
+
+		let rebonded = unbonding_before.saturating_sub(
+			pallet_dapps_staking::Ledger::<Runtime>::get(&origin).unbonding_amount(),
+		);
+		log_stake(
+			handle.context().address,
+			SELECTOR_LOG_REBOND_AND_STAKE,
+			handle.context().caller,
+			contract_h160.0,
+			rebonded.into(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	/// Withdraw all funds that have completed the unbonding process.
 	#[precompile::public("withdrawUnbonded()")]
 	fn withdraw_unbonded(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let event = log_withdraw_unbonded(handle.context().address, handle.context().caller);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call = pallet_dapps_staking::Call::<Runtime>::withdraw_unbonded {};
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Claim one era of unclaimed dapp rewards for the specified contract and era.
+	/// @param contractH160 The smart contract address used for staking
+	/// @param era The era to be claimed
 	#[precompile::public("claimDapp(address,uint32)")]
 	fn claim_dapp(
 		handle: &mut impl PrecompileHandle,
 		contract_h160: Address,
 		era: u32,
 	) -> EvmResult {
+		let event = log_claimed_dapp(
+			handle.context().address,
+			handle.context().caller,
+			contract_h160.0,
+			era,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let contract_id = Self::decode_smart_contract(contract_h160)?;
 
 		log::trace!(target: "ds-precompile", "claim_dapp {:?}, era {:?}", contract_id, era);
@@ -244,11 +500,25 @@ where
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Claim earned staker rewards for the oldest unclaimed era.
+	/// In order to claim multiple eras, this call has to be called multiple times.
+	/// Staker account is derived from the caller address.
+	/// @param contractH160 The smart contract address used for staking
 	#[precompile::public("claimStaker(address)")]
 	fn claim_staker(handle: &mut impl PrecompileHandle, contract_h160: Address) -> EvmResult {
+		let event = log_staker_contract(
+			handle.context().address,
+			SELECTOR_LOG_CLAIMED_STAKER,
+			handle.context().caller,
+			contract_h160.0,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let contract_id = Self::decode_smart_contract(contract_h160)?;
 		log::trace!(target: "ds-precompile", "claim_staker {:?}", contract_id);
 
@@ -258,14 +528,26 @@ where
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Set reward destination for staker rewards.
+	/// @param rewardDestinationRaw The instruction on how the reward payout should be handled,
+	/// as a `RewardDestination`
 	#[precompile::public("setRewardDestination(uint8)")]
 	fn set_reward_destination(
 		handle: &mut impl PrecompileHandle,
 		reward_destination_raw: u8,
 	) -> EvmResult {
+		let event = log_reward_destination_set(
+			handle.context().address,
+			handle.context().caller,
+			reward_destination_raw,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Transform raw value into dapps staking enum
 		let reward_destination = if reward_destination_raw == 0 {
 			RewardDestination::FreeBalance
@@ -284,14 +566,26 @@ where
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Withdraw staked funds from an unregistered contract.
+	/// @param contractH160 The smart contract address used for staking
 	#[precompile::public("withdrawFromUnregistered(address)")]
 	fn withdraw_from_unregistered(
 		handle: &mut impl PrecompileHandle,
 		contract_h160: Address,
 	) -> EvmResult {
+		let event = log_staker_contract(
+			handle.context().address,
+			SELECTOR_LOG_WITHDRAWN_FROM_UNREGISTERED,
+			handle.context().caller,
+			contract_h160.0,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let contract_id = Self::decode_smart_contract(contract_h160)?;
 		log::trace!(target: "ds-precompile", "withdraw_from_unregistered {:?}", contract_id);
@@ -303,10 +597,15 @@ where
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
-	/// Claim rewards for the contract in the dapps-staking pallet
+	/// Transfer part or entire nomination from origin smart contract to target smart contract.
+	/// @param originContractH160 The origin smart contract address
+	/// @param value The amount to transfer from origin to target
+	/// @param targetContractH160 The target smart contract address
 	#[precompile::public("nominationTransfer(address,uint256,address)")]
 	fn nomination_transfer(
 		handle: &mut impl PrecompileHandle,
@@ -314,6 +613,15 @@ where
 		value: U256,
 		target_contract_h160: Address,
 	) -> EvmResult {
+		let event = log_nomination_transfer(
+			handle.context().address,
+			handle.context().caller,
+			origin_contract_h160.0,
+			target_contract_h160.0,
+			value,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin_contract_id = Self::decode_smart_contract(origin_contract_h160)?;
 		let value = Self::u256_to_amount(value).in_field("value")?;
@@ -330,6 +638,8 @@ where
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
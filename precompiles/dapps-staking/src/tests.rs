@@ -0,0 +1,119 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use sha3::{Digest, Keccak256};
+use sp_core::H256;
+
+const PRECOMPILE: H160 = H160([0x08; 20]);
+const STAKER: H160 = H160([0xAA; 20]);
+const CONTRACT: H160 = H160([0xBB; 20]);
+const TARGET_CONTRACT: H160 = H160([0xCC; 20]);
+
+/// Canonical signatures of the events declared in the Solidity interface.
+fn interface_event_signatures() -> Vec<String> {
+	let interface = include_str!("../DappsStakingInterface.sol");
+
+	interface
+		.split("event ")
+		.skip(1)
+		.map(|declaration| {
+			let declaration: String = declaration.split(");").next().unwrap().into();
+			let (name, params) = declaration.split_once('(').unwrap();
+			let types: Vec<_> = params
+				.split(',')
+				.map(|param| param.split_whitespace().next().unwrap())
+				.collect();
+			format!("{}({})", name.trim(), types.join(","))
+		})
+		.collect()
+}
+
+#[test]
+fn interface_events_match_log_selectors() {
+	let selectors = [
+		SELECTOR_LOG_BOND_AND_STAKE,
+		SELECTOR_LOG_UNBOND_AND_UNSTAKE,
+		SELECTOR_LOG_WITHDRAW_UNBONDED,
+		SELECTOR_LOG_CLAIMED_DAPP,
+		SELECTOR_LOG_CLAIMED_STAKER,
+		SELECTOR_LOG_REWARD_DESTINATION_SET,
+		SELECTOR_LOG_WITHDRAWN_FROM_UNREGISTERED,
+		SELECTOR_LOG_NOMINATION_TRANSFER,
+		SELECTOR_LOG_INSTANT_UNSTAKE,
+		SELECTOR_LOG_REBOND_AND_STAKE,
+	];
+
+	let signatures = interface_event_signatures();
+	assert_eq!(signatures.len(), selectors.len());
+
+	for signature in signatures {
+		let selector: [u8; 32] = Keccak256::digest(signature.as_bytes()).into();
+		assert!(selectors.contains(&selector), "no log selector for event {signature}");
+	}
+}
+
+#[test]
+fn stake_log_indexes_staker_and_contract() {
+	let log =
+		log_stake(PRECOMPILE, SELECTOR_LOG_BOND_AND_STAKE, STAKER, CONTRACT, U256::from(1_000u32));
+
+	assert_eq!(log.address, PRECOMPILE);
+	assert_eq!(log.topics, vec![H256(SELECTOR_LOG_BOND_AND_STAKE), STAKER.into(), CONTRACT.into()]);
+	assert_eq!(log.data, EvmDataWriter::new().write(U256::from(1_000u32)).build());
+}
+
+#[test]
+fn withdraw_unbonded_log_has_no_data() {
+	let log = log_withdraw_unbonded(PRECOMPILE, STAKER);
+
+	assert_eq!(log.topics, vec![H256(SELECTOR_LOG_WITHDRAW_UNBONDED), STAKER.into()]);
+	assert!(log.data.is_empty());
+}
+
+#[test]
+fn claimed_dapp_log_encodes_the_era() {
+	let log = log_claimed_dapp(PRECOMPILE, STAKER, CONTRACT, 42);
+
+	assert_eq!(log.topics, vec![H256(SELECTOR_LOG_CLAIMED_DAPP), STAKER.into(), CONTRACT.into()]);
+	assert_eq!(U256::from_big_endian(&log.data), U256::from(42u32));
+}
+
+#[test]
+fn reward_destination_set_log_encodes_the_destination() {
+	let log = log_reward_destination_set(PRECOMPILE, STAKER, 1);
+
+	assert_eq!(log.topics, vec![H256(SELECTOR_LOG_REWARD_DESTINATION_SET), STAKER.into()]);
+	assert_eq!(U256::from_big_endian(&log.data), U256::one());
+}
+
+#[test]
+fn nomination_transfer_log_indexes_both_contracts() {
+	let log = log_nomination_transfer(PRECOMPILE, STAKER, CONTRACT, TARGET_CONTRACT, 500u32.into());
+
+	assert_eq!(
+		log.topics,
+		vec![
+			H256(SELECTOR_LOG_NOMINATION_TRANSFER),
+			STAKER.into(),
+			CONTRACT.into(),
+			TARGET_CONTRACT.into()
+		]
+	);
+	assert_eq!(log.data, EvmDataWriter::new().write(U256::from(500u32)).build());
+}
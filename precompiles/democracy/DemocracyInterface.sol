@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @author The Diora Team
+/// @title Pallet Democracy Interface
+/// @dev The interface through which solidity contracts will interact with pallet-democracy.
+/// Accounts other than the caller are given as 32 bytes account ids.
+/// @custom:address 0x0000000000000000000000000000000000000805
+interface Democracy {
+    /// @dev Get The total number of public proposals past and present
+    /// @custom:selector 31305462
+    /// @return The total number of public proposals past and present
+    function publicPropCount() external view returns (uint256);
+
+    /// @dev Get details about all public porposals.
+    /// @custom:selector 4767142d
+    /// @param propIndex The index of the proposal you are interested in
+    /// @return The amount of tokens locked behind the proposal
+    function depositOf(uint256 propIndex) external view returns (uint256);
+
+    /// @dev Get the index of the lowest unbaked referendum
+    /// @custom:selector d49dccf0
+    /// @return The lowest referendum index representing an unbaked referendum.
+    function lowestUnbaked() external view returns (uint256);
+
+    /// @dev Get the details about an ongoing referendum.
+    /// @custom:selector f033b7cd
+    /// @param refIndex The index of the referendum you are interested in
+    /// @return end The block at which the referendum ends
+    /// @return proposalHash The hash of the proposal under vote
+    /// @return threshold The voting threshold. 0 = SuperMajorityApprove,
+    /// 1 = SuperMajorityAgainst, 2 = SimpleMajority
+    /// @return delay The number of blocks between the end of the vote and its enactment
+    /// @return ayes The total amount of aye votes, weighted by conviction
+    /// @return nays The total amount of nay votes, weighted by conviction
+    /// @return turnout The total amount of tokens voting, without conviction
+    function ongoingReferendumInfo(uint32 refIndex)
+        external
+        view
+        returns (
+            uint256 end,
+            bytes32 proposalHash,
+            uint8 threshold,
+            uint256 delay,
+            uint256 ayes,
+            uint256 nays,
+            uint256 turnout
+        );
+
+    /// @dev Get the details about a finished referendum.
+    /// @custom:selector c75abcce
+    /// @param refIndex The index of the referendum you are interested in
+    /// @return approved Whether the referendum passed
+    /// @return end The block at which the referendum ended
+    function finishedReferendumInfo(uint32 refIndex)
+        external
+        view
+        returns (bool approved, uint256 end);
+
+    /// @dev Make a new proposal
+    /// The preimage of the proposal must have been noted through the preimage precompile.
+    /// @custom:selector 7824e7d1
+    /// @param proposalHash The hash of the proposal you are making
+    /// @param value The number of tokens to be locked behind this proposal.
+    function propose(bytes32 proposalHash, uint256 value) external;
+
+    /// @dev Signal agreement with a proposal
+    /// No amount is necessary here. Seconds are always for the same amount that the original
+    /// proposer locked.
+    /// @custom:selector 7896dfe3
+    /// @param propIndex index of the proposal you are seconding
+    function second(uint256 propIndex) external;
+
+    /// @dev Vote in a referendum.
+    /// @custom:selector 6cd18b0d
+    /// @param refIndex index of the referendum you are voting in
+    /// @param aye true is a vote to enact the proposal; false is a vote to keep the status quo.
+    /// @param voteAmount The number of tokens you are willing to lock if you get your way
+    /// @param conviction How strongly you want to vote. Higher conviction means longer lock time.
+    /// This must be an integer in the range 0 to 6
+    function standardVote(
+        uint256 refIndex,
+        bool aye,
+        uint256 voteAmount,
+        uint256 conviction
+    ) external;
+
+    /// @dev Remove a vote for a referendum.
+    /// @custom:selector 3f68fde4
+    /// @param refIndex index of the referendum whose vote is removed
+    function removeVote(uint256 refIndex) external;
+
+    /// @dev Delegate voting power to another account.
+    /// The balance delegated is locked for as long as it is delegated, and thereafter for the
+    /// time appropriate for the conviction's lock period.
+    /// @custom:selector 9c2f4aa9
+    /// @param representative The account to whom the vote shall be delegated.
+    /// @param conviction The conviction with which you are delegating. This conviction is used for
+    /// _all_ delegated votes.
+    /// @param amount The number of tokens whose voting power shall be delegated.
+    function delegate(
+        bytes32 representative,
+        uint256 conviction,
+        uint256 amount
+    ) external;
+
+    /// @dev Undelegate voting power
+    /// @custom:selector 1eef225c
+    function unDelegate() external;
+
+    /// @dev Unlock tokens that have an expired lock.
+    /// @custom:selector ec9b5b3a
+    /// @param target The account whose tokens should be unlocked. This may be any account.
+    function unlock(bytes32 target) external;
+
+    /// @dev A motion has been proposed by a public account.
+    /// @param proposalIndex The index of the proposal.
+    /// @param deposit The deposit locked behind the proposal.
+    event Proposed(uint32 indexed proposalIndex, uint256 deposit);
+
+    /// @dev An account has seconded a proposal.
+    /// @param proposalIndex The index of the proposal.
+    /// @param seconder The address of the seconder.
+    event Seconded(uint32 indexed proposalIndex, address seconder);
+
+    /// @dev An account made a standard vote.
+    /// @param referendumIndex The index of the referendum.
+    /// @param voter The address of the voter.
+    /// @param aye Is it a vote for or against the referendum.
+    /// @param voteAmount The amount used to vote.
+    /// @param conviction The conviction of the vote.
+    event StandardVote(
+        uint32 indexed referendumIndex,
+        address voter,
+        bool aye,
+        uint256 voteAmount,
+        uint8 conviction
+    );
+
+    /// @dev An account removed its vote from a referendum.
+    /// @param referendumIndex The index of the referendum.
+    /// @param voter The address of the voter.
+    event VoteRemoved(uint32 indexed referendumIndex, address voter);
+
+    /// @dev An account delegated some voting power to another account
+    /// @param who The address of the delegator.
+    /// @param target The account receiving the voting power.
+    event Delegated(address indexed who, bytes32 target);
+
+    /// @dev An account undelegated some voting power.
+    /// @param who The address of the delegator.
+    event Undelegated(address indexed who);
+}
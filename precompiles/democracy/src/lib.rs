@@ -0,0 +1,357 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet democracy through an evm precompile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::{Bounded, Currency, QueryPreimage},
+};
+use pallet_democracy::{
+	AccountVote, Call as DemocracyCall, Conviction, ReferendumInfo, Vote, VoteThreshold,
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_runtime::traits::StaticLookup;
+use sp_std::{convert::TryInto, marker::PhantomData};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_democracy::Config>::Currency as Currency<
+	<Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+type DemocracyOf<Runtime> = pallet_democracy::Pallet<Runtime>;
+
+/// Solidity selector of the `Proposed` log.
+pub const SELECTOR_LOG_PROPOSED: [u8; 32] = keccak256!("Proposed(uint32,uint256)");
+
+/// Solidity selector of the `Seconded` log.
+pub const SELECTOR_LOG_SECONDED: [u8; 32] = keccak256!("Seconded(uint32,address)");
+
+/// Solidity selector of the `StandardVote` log.
+pub const SELECTOR_LOG_STANDARD_VOTE: [u8; 32] =
+	keccak256!("StandardVote(uint32,address,bool,uint256,uint8)");
+
+/// Solidity selector of the `VoteRemoved` log.
+pub const SELECTOR_LOG_VOTE_REMOVED: [u8; 32] = keccak256!("VoteRemoved(uint32,address)");
+
+/// Solidity selector of the `Delegated` log.
+pub const SELECTOR_LOG_DELEGATED: [u8; 32] = keccak256!("Delegated(address,bytes32)");
+
+/// Solidity selector of the `Undelegated` log.
+pub const SELECTOR_LOG_UNDELEGATED: [u8; 32] = keccak256!("Undelegated(address)");
+
+/// A precompile to wrap the functionality from pallet democracy.
+///
+/// Proposals are submitted by the hash of a preimage previously noted through the preimage
+/// precompile, accounts other than the caller are given as 32 bytes account ids.
+pub struct DemocracyPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> DemocracyPrecompile<Runtime>
+where
+	Runtime: pallet_democracy::Config + pallet_evm::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::BlockNumber: Into<U256>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<DemocracyCall<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	#[precompile::public("publicPropCount()")]
+	#[precompile::view]
+	fn public_prop_count(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let prop_count = DemocracyOf::<Runtime>::public_prop_count();
+
+		Ok(prop_count.into())
+	}
+
+	#[precompile::public("depositOf(uint256)")]
+	#[precompile::view]
+	fn deposit_of(handle: &mut impl PrecompileHandle, prop_index: U256) -> EvmResult<U256> {
+		let prop_index: u32 = prop_index
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("uint32").in_field("propIndex"))?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let deposit = DemocracyOf::<Runtime>::deposit_of(prop_index)
+			.ok_or_else(|| revert("No such proposal in pallet democracy"))?
+			.1;
+
+		Ok(deposit.into())
+	}
+
+	#[precompile::public("lowestUnbaked()")]
+	#[precompile::view]
+	fn lowest_unbaked(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let lowest_unbaked = DemocracyOf::<Runtime>::lowest_unbaked();
+
+		Ok(lowest_unbaked.into())
+	}
+
+	#[precompile::public("ongoingReferendumInfo(uint32)")]
+	#[precompile::view]
+	fn ongoing_referendum_info(
+		handle: &mut impl PrecompileHandle,
+		ref_index: u32,
+	) -> EvmResult<(U256, H256, u8, U256, U256, U256, U256)> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let ref_status = match DemocracyOf::<Runtime>::referendum_info(ref_index) {
+			Some(ReferendumInfo::Ongoing(ref_status)) => ref_status,
+			Some(ReferendumInfo::Finished { .. }) => return Err(revert("Referendum is finished")),
+			None => return Err(revert("Unknown referendum")),
+		};
+
+		let threshold = match ref_status.threshold {
+			VoteThreshold::SuperMajorityApprove => 0,
+			VoteThreshold::SuperMajorityAgainst => 1,
+			VoteThreshold::SimpleMajority => 2,
+		};
+
+		Ok((
+			ref_status.end.into(),
+			ref_status.proposal.hash(),
+			threshold,
+			ref_status.delay.into(),
+			ref_status.tally.ayes.into(),
+			ref_status.tally.nays.into(),
+			ref_status.tally.turnout.into(),
+		))
+	}
+
+	#[precompile::public("finishedReferendumInfo(uint32)")]
+	#[precompile::view]
+	fn finished_referendum_info(
+		handle: &mut impl PrecompileHandle,
+		ref_index: u32,
+	) -> EvmResult<(bool, U256)> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		match DemocracyOf::<Runtime>::referendum_info(ref_index) {
+			Some(ReferendumInfo::Finished { approved, end }) => Ok((approved, end.into())),
+			Some(ReferendumInfo::Ongoing(_)) => Err(revert("Referendum is ongoing")),
+			None => Err(revert("Unknown referendum")),
+		}
+	}
+
+	#[precompile::public("propose(bytes32,uint256)")]
+	fn propose(handle: &mut impl PrecompileHandle, proposal_hash: H256, value: U256) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let prop_count = DemocracyOf::<Runtime>::public_prop_count();
+
+		let value = Self::u256_to_amount(value).in_field("value")?;
+
+		// The preimage must have been noted beforehand, its length bounds the proposal.
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let proposal_len = <Runtime as pallet_democracy::Config>::Preimages::len(&proposal_hash)
+			.ok_or_else(|| {
+				RevertReason::custom("Failure in preimage fetch").in_field("proposalHash")
+			})?;
+		let proposal = Bounded::Lookup { hash: proposal_hash, len: proposal_len };
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = DemocracyCall::<Runtime>::propose { proposal, value };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_PROPOSED,
+			H256::from_low_u64_be(prop_count as u64),
+			EvmDataWriter::new().write::<U256>(value.into()).build(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("second(uint256)")]
+	fn second(handle: &mut impl PrecompileHandle, prop_index: U256) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let prop_index: u32 = prop_index
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("uint32").in_field("propIndex"))?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = DemocracyCall::<Runtime>::second { proposal: prop_index };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_SECONDED,
+			H256::from_low_u64_be(prop_index as u64),
+			EvmDataWriter::new().write::<Address>(handle.context().caller.into()).build(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("standardVote(uint256,bool,uint256,uint256)")]
+	fn standard_vote(
+		handle: &mut impl PrecompileHandle,
+		ref_index: U256,
+		aye: bool,
+		vote_amount: U256,
+		conviction: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32 * 4)?;
+
+		let ref_index: u32 = ref_index
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("uint32").in_field("refIndex"))?;
+		let balance = Self::u256_to_amount(vote_amount).in_field("voteAmount")?;
+		let conviction = Self::u256_to_conviction(conviction)?;
+
+		let vote = AccountVote::Standard { vote: Vote { aye, conviction }, balance };
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = DemocracyCall::<Runtime>::vote { ref_index, vote };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_STANDARD_VOTE,
+			H256::from_low_u64_be(ref_index as u64),
+			EvmDataWriter::new()
+				.write::<Address>(handle.context().caller.into())
+				.write::<bool>(aye)
+				.write::<U256>(vote_amount)
+				.write::<u8>(conviction.into())
+				.build(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("removeVote(uint256)")]
+	fn remove_vote(handle: &mut impl PrecompileHandle, ref_index: U256) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let ref_index: u32 = ref_index
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("uint32").in_field("refIndex"))?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = DemocracyCall::<Runtime>::remove_vote { index: ref_index };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_VOTE_REMOVED,
+			H256::from_low_u64_be(ref_index as u64),
+			EvmDataWriter::new().write::<Address>(handle.context().caller.into()).build(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("delegate(bytes32,uint256,uint256)")]
+	fn delegate(
+		handle: &mut impl PrecompileHandle,
+		representative: H256,
+		conviction: U256,
+		amount: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let conviction = Self::u256_to_conviction(conviction)?;
+		let balance = Self::u256_to_amount(amount).in_field("amount")?;
+
+		let to = Runtime::AccountId::from(representative.0);
+		let to = <Runtime as frame_system::Config>::Lookup::unlookup(to);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = DemocracyCall::<Runtime>::delegate { to, conviction, balance };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_DELEGATED,
+			handle.context().caller,
+			EvmDataWriter::new().write::<H256>(representative).build(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("unDelegate()")]
+	fn un_delegate(handle: &mut impl PrecompileHandle) -> EvmResult {
+		handle.record_log_costs_manual(2, 0)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = DemocracyCall::<Runtime>::undelegate {};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(handle.context().address, SELECTOR_LOG_UNDELEGATED, handle.context().caller, [])
+			.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("unlock(bytes32)")]
+	fn unlock(handle: &mut impl PrecompileHandle, target: H256) -> EvmResult {
+		let target = Runtime::AccountId::from(target.0);
+		let target = <Runtime as frame_system::Config>::Lookup::unlookup(target);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = DemocracyCall::<Runtime>::unlock { target };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+
+	fn u256_to_conviction(value: U256) -> MayRevert<Conviction> {
+		let value: u8 = value.try_into().map_err(|_| {
+			RevertReason::custom("Must be an integer between 0 and 6 included")
+				.in_field("conviction")
+		})?;
+
+		Conviction::try_from(value).map_err(|_| {
+			RevertReason::custom("Must be an integer between 0 and 6 included")
+				.in_field("conviction")
+		})
+	}
+}
@@ -0,0 +1,340 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use codec::Encode;
+use frame_support::traits::StorePreimage;
+use pallet_democracy::{Voting, VotingOf};
+use precompile_utils::testing::*;
+use sp_core::H160;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+type PCall = DemocracyPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Whether the reverted `output` is the dispatch error `error`.
+fn dispatch_error(output: &[u8], error: &str) -> bool {
+	let output = std::str::from_utf8(output).unwrap();
+	output.starts_with("Dispatched call failed with error: ") && output.contains(error)
+}
+
+fn proposal() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![1, 2, 3] })
+}
+
+/// Notes the preimage of `proposal()` and returns its hash.
+fn note_proposal() -> H256 {
+	let encoded = proposal().encode();
+	Preimage::note_preimage(RuntimeOrigin::signed(account(ALICE)), encoded.clone()).unwrap();
+
+	BlakeTwo256::hash(&encoded)
+}
+
+/// Starts a referendum on `proposal()` without going through the launch period.
+fn start_referendum() -> H256 {
+	let proposal = <Preimage as StorePreimage>::bound(proposal()).unwrap();
+	let hash = proposal.hash();
+	Democracy::internal_start_referendum(proposal, VoteThreshold::SuperMajorityApprove, 0);
+
+	hash
+}
+
+fn vote(from: H160, aye: bool, vote_amount: u128, conviction: u8) {
+	precompiles()
+		.prepare_test(
+			from,
+			PRECOMPILE,
+			PCall::standard_vote {
+				ref_index: 0.into(),
+				aye,
+				vote_amount: vote_amount.into(),
+				conviction: conviction.into(),
+			},
+		)
+		.expect_log(log2(
+			PRECOMPILE,
+			SELECTOR_LOG_STANDARD_VOTE,
+			H256::zero(),
+			EvmDataWriter::new()
+				.write(Address(from))
+				.write(aye)
+				.write(U256::from(vote_amount))
+				.write(conviction)
+				.build(),
+		))
+		.execute_returns(vec![]);
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["DemocracyInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn propose_noted_preimage() {
+	new_test_ext().execute_with(|| {
+		let hash = note_proposal();
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::propose { proposal_hash: hash, value: 10.into() })
+			.expect_log(log2(
+				PRECOMPILE,
+				SELECTOR_LOG_PROPOSED,
+				H256::zero(),
+				EvmDataWriter::new().write(U256::from(10)).build(),
+			))
+			.execute_returns(vec![]);
+
+		assert_eq!(Balances::reserved_balance(account(BOB)), 10);
+
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::public_prop_count {})
+			.expect_no_logs()
+			.execute_returns_encoded(U256::one());
+
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::deposit_of { prop_index: 0.into() })
+			.expect_no_logs()
+			.execute_returns_encoded(U256::from(10));
+	});
+}
+
+#[test]
+fn propose_requires_noted_preimage() {
+	new_test_ext().execute_with(|| {
+		let hash = BlakeTwo256::hash(&proposal().encode());
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::propose { proposal_hash: hash, value: 10.into() })
+			.execute_reverts(|output| output == b"proposalHash: Failure in preimage fetch");
+
+		assert_eq!(Democracy::public_prop_count(), 0);
+	});
+}
+
+#[test]
+fn propose_requires_minimum_deposit() {
+	new_test_ext().execute_with(|| {
+		let hash = note_proposal();
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::propose { proposal_hash: hash, value: 9.into() })
+			.execute_reverts(|output| dispatch_error(output, "ValueLow"));
+
+		assert_eq!(Balances::reserved_balance(account(BOB)), 0);
+	});
+}
+
+#[test]
+fn second_reserves_deposit_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		let hash = note_proposal();
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::propose { proposal_hash: hash, value: 10.into() })
+			.execute_returns(vec![]);
+
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::second { prop_index: 0.into() })
+			.expect_log(log2(
+				PRECOMPILE,
+				SELECTOR_LOG_SECONDED,
+				H256::zero(),
+				EvmDataWriter::new().write(Address(CHARLIE)).build(),
+			))
+			.execute_returns(vec![]);
+
+		assert_eq!(Balances::reserved_balance(account(CHARLIE)), 10);
+		assert_eq!(
+			Democracy::deposit_of(0).unwrap().0.into_inner(),
+			vec![account(BOB), account(CHARLIE)]
+		);
+	});
+}
+
+#[test]
+fn unknown_proposal_has_no_deposit() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::deposit_of { prop_index: 0.into() })
+			.execute_reverts(|output| output == b"No such proposal in pallet democracy");
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::second { prop_index: 0.into() })
+			.execute_reverts(|output| dispatch_error(output, "ProposalMissing"));
+	});
+}
+
+#[test]
+fn standard_vote_is_tallied() {
+	new_test_ext().execute_with(|| {
+		let hash = start_referendum();
+
+		vote(ALICE, true, 100, 1);
+		vote(BOB, false, 50, 0);
+
+		// Without conviction, a vote only counts for a tenth of the balance
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::ongoing_referendum_info { ref_index: 0 })
+			.expect_no_logs()
+			.execute_returns_encoded((
+				U256::from(11),
+				hash,
+				0u8,
+				U256::zero(),
+				U256::from(100),
+				U256::from(5),
+				U256::from(150),
+			));
+
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::lowest_unbaked {})
+			.expect_no_logs()
+			.execute_returns_encoded(U256::zero());
+	});
+}
+
+#[test]
+fn standard_vote_rejects_invalid_conviction() {
+	new_test_ext().execute_with(|| {
+		start_referendum();
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::standard_vote {
+					ref_index: 0.into(),
+					aye: true,
+					vote_amount: 100.into(),
+					conviction: 7.into(),
+				},
+			)
+			.execute_reverts(|output| {
+				output == b"conviction: Must be an integer between 0 and 6 included"
+			});
+	});
+}
+
+#[test]
+fn standard_vote_cannot_exceed_balance() {
+	new_test_ext().execute_with(|| {
+		start_referendum();
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::standard_vote {
+					ref_index: 0.into(),
+					aye: true,
+					vote_amount: 1_001.into(),
+					conviction: 1.into(),
+				},
+			)
+			.execute_reverts(|output| dispatch_error(output, "InsufficientFunds"));
+	});
+}
+
+#[test]
+fn remove_vote_clears_tally() {
+	new_test_ext().execute_with(|| {
+		start_referendum();
+		vote(ALICE, true, 100, 1);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::remove_vote { ref_index: 0.into() })
+			.expect_log(log2(
+				PRECOMPILE,
+				SELECTOR_LOG_VOTE_REMOVED,
+				H256::zero(),
+				EvmDataWriter::new().write(Address(ALICE)).build(),
+			))
+			.execute_returns(vec![]);
+
+		match Democracy::referendum_info(0) {
+			Some(ReferendumInfo::Ongoing(status)) => assert_eq!(status.tally.turnout, 0),
+			_ => panic!("referendum should be ongoing"),
+		}
+	});
+}
+
+#[test]
+fn referendum_info_views_check_status() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::ongoing_referendum_info { ref_index: 0 })
+			.execute_reverts(|output| output == b"Unknown referendum");
+
+		start_referendum();
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::finished_referendum_info { ref_index: 0 })
+			.execute_reverts(|output| output == b"Referendum is ongoing");
+	});
+}
+
+#[test]
+fn delegate_and_undelegate() {
+	new_test_ext().execute_with(|| {
+		let representative = H256::from(<[u8; 32]>::from(account(BOB)));
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::delegate { representative, conviction: 1.into(), amount: 100.into() },
+			)
+			.expect_log(log2(
+				PRECOMPILE,
+				SELECTOR_LOG_DELEGATED,
+				ALICE,
+				EvmDataWriter::new().write(representative).build(),
+			))
+			.execute_returns(vec![]);
+
+		match VotingOf::<Runtime>::get(account(ALICE)) {
+			Voting::Delegating { target, balance, .. } => {
+				assert_eq!(target, account(BOB));
+				assert_eq!(balance, 100);
+			},
+			_ => panic!("ALICE should be delegating"),
+		}
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::un_delegate {})
+			.expect_log(log2(PRECOMPILE, SELECTOR_LOG_UNDELEGATED, ALICE, []))
+			.execute_returns(vec![]);
+
+		assert!(matches!(VotingOf::<Runtime>::get(account(ALICE)), Voting::Direct { .. }));
+	});
+}
+
+#[test]
+fn undelegate_requires_delegation() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::un_delegate {})
+			.execute_reverts(|output| dispatch_error(output, "NotDelegating"));
+	});
+}
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @author The Diora Team
+/// @title Pallet Identity Interface
+/// @dev The interface through which solidity contracts will interact with pallet-identity.
+/// The identity of the caller is the one of its mapped Substrate account. Other accounts
+/// (sub-accounts, registrars, judged targets) are given as 32 bytes account ids.
+/// @custom:address 0x0000000000000000000000000000000000000809
+interface Identity {
+    /// @dev Identity data. Only raw data of at most 32 bytes can be set.
+    /// Hashed data set from Substrate is returned as its 32 bytes hash.
+    struct Data {
+        /// Is `true` if it contains data.
+        bool hasData;
+        /// The contained value.
+        bytes value;
+    }
+
+    /// @dev Additional field of an identity.
+    struct Additional {
+        /// The key of the field.
+        Data key;
+        /// The value of the field.
+        Data value;
+    }
+
+    /// @dev Information of an identity.
+    struct IdentityInfo {
+        /// Additional fields of the identity that are not catered for with the struct's
+        /// explicit fields.
+        Additional[] additional;
+        /// A reasonable display name for the controller of the account.
+        Data display;
+        /// The full legal name in the local jurisdiction of the entity.
+        Data legal;
+        /// A representative website held by the controller of the account.
+        Data web;
+        /// The Riot/Matrix handle held by the controller of the account.
+        Data riot;
+        /// The email address of the controller of the account.
+        Data email;
+        /// Is `true` if `pgpFingerprint` is set.
+        bool hasPgpFingerprint;
+        /// The 20 bytes PGP/GPG public key fingerprint of the controller of the account.
+        bytes pgpFingerprint;
+        /// A graphic image representing the controller of the account.
+        Data image;
+        /// The Twitter identity.
+        Data twitter;
+    }
+
+    /// @dev Judgement of a registrar. Exactly one of the flags is set.
+    struct Judgement {
+        /// The default value; no opinion is held.
+        bool isUnknown;
+        /// No judgement is yet in place, but a deposit is reserved as payment for providing one.
+        bool isFeePaid;
+        /// The deposit reserved for providing a judgement.
+        uint256 feePaidDeposit;
+        /// The data appears to be reasonably acceptable in terms of its accuracy.
+        bool isReasonable;
+        /// The target is known directly by the registrar and the registrar can fully attest to
+        /// the data being accurate.
+        bool isKnownGood;
+        /// The data was once good but is currently out of date.
+        bool isOutOfDate;
+        /// The data is imprecise or of sufficiently low-quality to be problematic.
+        bool isLowQuality;
+        /// The data is erroneous.
+        bool isErroneous;
+    }
+
+    /// @dev Judgement given by a registrar.
+    struct JudgementInfo {
+        /// The index of the registrar.
+        uint32 registrarIndex;
+        /// The judgement.
+        Judgement judgement;
+    }
+
+    /// @dev Registered identity of an account.
+    struct Registration {
+        /// Is `false` if the account has no identity.
+        bool isValid;
+        /// Judgements from the registrars on this identity.
+        JudgementInfo[] judgements;
+        /// Amount held on deposit for this information.
+        uint256 deposit;
+        /// Information on the identity.
+        IdentityInfo info;
+    }
+
+    /// @dev Sub-accounts of an account.
+    struct SubsOf {
+        /// The deposit reserved for the sub-accounts.
+        uint256 deposit;
+        /// The 32 bytes account ids of the sub-accounts.
+        bytes32[] accounts;
+    }
+
+    /// @dev Super account of a sub-account.
+    struct SuperOf {
+        /// Is `false` if the account is not a sub-account.
+        bool isValid;
+        /// The 32 bytes account id of the super account.
+        bytes32 account;
+        /// The name of the sub-account.
+        Data data;
+    }
+
+    /// @dev Registrar providing judgements.
+    struct Registrar {
+        /// Is `false` if the registrar was removed.
+        bool isValid;
+        /// The index of the registrar.
+        uint32 index;
+        /// The 32 bytes account id of the registrar.
+        bytes32 account;
+        /// Amount required to be given to the registrar for them to provide judgement.
+        uint256 fee;
+        /// Bitflags of the identity fields the registrar cares about.
+        uint64 fields;
+    }
+
+    /// @dev Sub-account with its name.
+    struct SubAccount {
+        /// The 32 bytes account id of the sub-account.
+        bytes32 account;
+        /// The name of the sub-account.
+        Data data;
+    }
+
+    /// @dev Set the identity of the caller, replacing any previous one and resetting its
+    /// judgements. A deposit is reserved for it.
+    /// @custom:selector 7e08b4cb
+    /// @param info The identity information
+    function setIdentity(IdentityInfo memory info) external;
+
+    /// @dev Set the sub-accounts of the caller, replacing the previous ones.
+    /// The caller must have an identity, a deposit is reserved for each sub-account.
+    /// @custom:selector 32ca728b
+    /// @param subs The sub-accounts with their names
+    function setSubs(SubAccount[] memory subs) external;
+
+    /// @dev Clear the identity of the caller with its sub-accounts, the deposits are returned.
+    /// @custom:selector 7a6a10c7
+    function clearIdentity() external;
+
+    /// @dev Request a judgement from a registrar, paying them a fee.
+    /// @custom:selector d523ceb9
+    /// @param regIndex The index of the registrar
+    /// @param maxFee The maximum fee the caller is willing to pay
+    function requestJudgement(uint32 regIndex, uint256 maxFee) external;
+
+    /// @dev Cancel a previous judgement request, the fee is returned.
+    /// @custom:selector c79934a5
+    /// @param regIndex The index of the registrar
+    function cancelRequest(uint32 regIndex) external;
+
+    /// @dev Provide a judgement on an identity, the caller must be the registrar.
+    /// @custom:selector c6bc0d0d
+    /// @param regIndex The index of the registrar
+    /// @param target The 32 bytes account id of the judged account
+    /// @param judgement The judgement, which cannot be `FeePaid`
+    /// @param identity The hash of the judged identity information
+    function provideJudgement(
+        uint32 regIndex,
+        bytes32 target,
+        Judgement memory judgement,
+        bytes32 identity
+    ) external;
+
+    /// @dev Get the identity of an account.
+    /// @custom:selector f0eb5e54
+    /// @param who The address of the account
+    /// @return The registered identity, `isValid` is false if there is none
+    function identity(address who) external view returns (Registration memory);
+
+    /// @dev Get the super account of a sub-account.
+    /// @custom:selector c18110d6
+    /// @param who The address of the sub-account
+    /// @return The super account, `isValid` is false if the account is not a sub-account
+    function superOf(address who) external view returns (SuperOf memory);
+
+    /// @dev Get the sub-accounts of an account.
+    /// @custom:selector 3f08986b
+    /// @param who The address of the account
+    /// @return The sub-accounts and their deposit
+    function subsOf(address who) external view returns (SubsOf memory);
+
+    /// @dev Get the registrars.
+    /// @custom:selector e88e512e
+    /// @return The registrars, removed ones have `isValid` set to false
+    function registrars() external view returns (Registrar[] memory);
+
+    /// @dev An identity was set.
+    /// @param who The address of the account
+    event IdentitySet(address indexed who);
+
+    /// @dev An identity was cleared.
+    /// @param who The address of the account
+    event IdentityCleared(address indexed who);
+
+    /// @dev A judgement was requested.
+    /// @param who The address of the account
+    /// @param registrarIndex The index of the registrar
+    event JudgementRequested(address indexed who, uint32 registrarIndex);
+
+    /// @dev A judgement request was cancelled.
+    /// @param who The address of the account
+    /// @param registrarIndex The index of the registrar
+    event JudgementUnrequested(address indexed who, uint32 registrarIndex);
+
+    /// @dev A judgement was given by a registrar.
+    /// @param target The 32 bytes account id of the judged account
+    /// @param registrarIndex The index of the registrar
+    event JudgementGiven(bytes32 indexed target, uint32 registrarIndex);
+}
@@ -0,0 +1,602 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet_identity through the EVM
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::{ConstU32, Currency},
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_runtime::traits::StaticLookup;
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_identity::Config>::Currency as Currency<
+	<Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+type MaxAdditionalFieldsOf<Runtime> = <Runtime as pallet_identity::Config>::MaxAdditionalFields;
+
+/// Solidity selector of the `IdentitySet` log.
+pub const SELECTOR_LOG_IDENTITY_SET: [u8; 32] = keccak256!("IdentitySet(address)");
+
+/// Solidity selector of the `IdentityCleared` log.
+pub const SELECTOR_LOG_IDENTITY_CLEARED: [u8; 32] = keccak256!("IdentityCleared(address)");
+
+/// Solidity selector of the `JudgementRequested` log.
+pub const SELECTOR_LOG_JUDGEMENT_REQUESTED: [u8; 32] =
+	keccak256!("JudgementRequested(address,uint32)");
+
+/// Solidity selector of the `JudgementUnrequested` log.
+pub const SELECTOR_LOG_JUDGEMENT_UNREQUESTED: [u8; 32] =
+	keccak256!("JudgementUnrequested(address,uint32)");
+
+/// Solidity selector of the `JudgementGiven` log.
+pub const SELECTOR_LOG_JUDGEMENT_GIVEN: [u8; 32] = keccak256!("JudgementGiven(bytes32,uint32)");
+
+/// Maximum size of raw identity data, as enforced by `pallet_identity::Data`.
+pub const RAW_DATA_LIMIT: u32 = 32;
+
+/// Size of a PGP fingerprint.
+pub const PGP_FINGERPRINT_SIZE: u32 = 20;
+
+/// Maximum amount of additional fields parsed from the input. The limit of the runtime is
+/// enforced when converting them for the pallet.
+pub const ADDITIONAL_FIELDS_LIMIT: u32 = 100;
+
+/// Maximum amount of sub-accounts parsed from the input. The limit of the runtime is enforced
+/// by the pallet.
+pub const SUB_ACCOUNTS_LIMIT: u32 = 100;
+
+type GetRawDataLimit = ConstU32<RAW_DATA_LIMIT>;
+type GetPgpFingerprintSize = ConstU32<PGP_FINGERPRINT_SIZE>;
+type GetAdditionalFieldsLimit = ConstU32<ADDITIONAL_FIELDS_LIMIT>;
+type GetSubAccountsLimit = ConstU32<SUB_ACCOUNTS_LIMIT>;
+
+/// Identity data, only raw data can be set from the EVM.
+/// Hashed data set from Substrate is returned as its 32 bytes hash.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct Data {
+	has_data: bool,
+	value: BoundedBytes<GetRawDataLimit>,
+}
+
+/// Additional field of an identity.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct Additional {
+	key: Data,
+	value: Data,
+}
+
+/// Information of an identity, mirroring `pallet_identity::IdentityInfo`.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct IdentityInfo<FieldLimit> {
+	additional: BoundedVec<Additional, FieldLimit>,
+	display: Data,
+	legal: Data,
+	web: Data,
+	riot: Data,
+	email: Data,
+	has_pgp_fingerprint: bool,
+	pgp_fingerprint: BoundedBytes<GetPgpFingerprintSize>,
+	image: Data,
+	twitter: Data,
+}
+
+/// Judgement of a registrar, exactly one of the flags is set.
+#[derive(Clone, Debug, Default, Eq, PartialEq, EvmData)]
+pub struct Judgement {
+	is_unknown: bool,
+	is_fee_paid: bool,
+	fee_paid_deposit: U256,
+	is_reasonable: bool,
+	is_known_good: bool,
+	is_out_of_date: bool,
+	is_low_quality: bool,
+	is_erroneous: bool,
+}
+
+/// Judgement given by a registrar.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct JudgementInfo {
+	registrar_index: u32,
+	judgement: Judgement,
+}
+
+/// Registered identity of an account, `is_valid` is false if the account has no identity.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct Registration<FieldLimit> {
+	is_valid: bool,
+	judgements: Vec<JudgementInfo>,
+	deposit: U256,
+	info: IdentityInfo<FieldLimit>,
+}
+
+/// Sub-accounts of an account and the deposit reserved for them.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct SubsOf {
+	deposit: U256,
+	accounts: Vec<H256>,
+}
+
+/// Super account of a sub-account, `is_valid` is false if the account is not a sub-account.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct SuperOf {
+	is_valid: bool,
+	account: H256,
+	data: Data,
+}
+
+/// Registrar providing judgements, `is_valid` is false if the registrar was removed.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct Registrar {
+	is_valid: bool,
+	index: u32,
+	account: H256,
+	fee: U256,
+	fields: u64,
+}
+
+/// Sub-account with its name, as given to `setSubs`.
+#[derive(Clone, Debug, Eq, PartialEq, EvmData)]
+pub struct SubAccount {
+	account: H256,
+	data: Data,
+}
+
+/// A precompile to wrap the functionality from pallet_identity.
+///
+/// The identity of an EVM account is the one of its mapped Substrate account, other accounts
+/// (sub-accounts, registrars, judged targets) are given as 32 bytes account ids.
+pub struct IdentityPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> IdentityPrecompile<Runtime>
+where
+	Runtime: pallet_identity::Config + pallet_evm::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::Hash: From<H256>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_identity::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	#[precompile::public("setIdentity((((bool,bytes),(bool,bytes))[],(bool,bytes),(bool,bytes),(bool,bytes),(bool,bytes),(bool,bytes),bool,bytes,(bool,bytes),(bool,bytes)))")]
+	fn set_identity(
+		handle: &mut impl PrecompileHandle,
+		info: IdentityInfo<GetAdditionalFieldsLimit>,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 0)?;
+
+		let info = Self::identity_to_input(info)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity::Call::<Runtime>::set_identity { info: Box::new(info) };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(handle.context().address, SELECTOR_LOG_IDENTITY_SET, handle.context().caller, [])
+			.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("setSubs((bytes32,(bool,bytes))[])")]
+	fn set_subs(
+		handle: &mut impl PrecompileHandle,
+		subs: BoundedVec<SubAccount, GetSubAccountsLimit>,
+	) -> EvmResult {
+		let subs: Vec<_> = subs.into();
+		let mut call_subs = Vec::with_capacity(subs.len());
+		for (i, SubAccount { account, data }) in subs.into_iter().enumerate() {
+			let data = Self::data_to_input(data).in_field("data").in_array(i).in_field("subs")?;
+			call_subs.push((Runtime::AccountId::from(account.0), data));
+		}
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity::Call::<Runtime>::set_subs { subs: call_subs };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("clearIdentity()")]
+	fn clear_identity(handle: &mut impl PrecompileHandle) -> EvmResult {
+		handle.record_log_costs_manual(2, 0)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity::Call::<Runtime>::clear_identity {};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(handle.context().address, SELECTOR_LOG_IDENTITY_CLEARED, handle.context().caller, [])
+			.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("requestJudgement(uint32,uint256)")]
+	fn request_judgement(
+		handle: &mut impl PrecompileHandle,
+		reg_index: u32,
+		max_fee: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let max_fee = Self::u256_to_amount(max_fee).in_field("maxFee")?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity::Call::<Runtime>::request_judgement { reg_index, max_fee };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_JUDGEMENT_REQUESTED,
+			handle.context().caller,
+			EvmDataWriter::new().write::<u32>(reg_index).build(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("cancelRequest(uint32)")]
+	fn cancel_request(handle: &mut impl PrecompileHandle, reg_index: u32) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity::Call::<Runtime>::cancel_request { reg_index };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_JUDGEMENT_UNREQUESTED,
+			handle.context().caller,
+			EvmDataWriter::new().write::<u32>(reg_index).build(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public(
+		"provideJudgement(uint32,bytes32,(bool,bool,uint256,bool,bool,bool,bool,bool),bytes32)"
+	)]
+	fn provide_judgement(
+		handle: &mut impl PrecompileHandle,
+		reg_index: u32,
+		target: H256,
+		judgement: Judgement,
+		identity: H256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let judgement = Self::judgement_to_input(judgement).in_field("judgement")?;
+		let target_account = Runtime::AccountId::from(target.0);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity::Call::<Runtime>::provide_judgement {
+			reg_index,
+			target: <Runtime as frame_system::Config>::Lookup::unlookup(target_account),
+			judgement,
+			identity: identity.into(),
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_JUDGEMENT_GIVEN,
+			target,
+			EvmDataWriter::new().write::<u32>(reg_index).build(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("identity(address)")]
+	#[precompile::view]
+	fn identity(
+		handle: &mut impl PrecompileHandle,
+		who: Address,
+	) -> EvmResult<Registration<MaxAdditionalFieldsOf<Runtime>>> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+		let registration = match pallet_identity::Pallet::<Runtime>::identity(who) {
+			Some(registration) => registration,
+			None =>
+				return Ok(Registration {
+					is_valid: false,
+					judgements: Vec::new(),
+					deposit: U256::zero(),
+					info: Self::empty_identity_output(),
+				}),
+		};
+
+		let judgements = registration
+			.judgements
+			.into_iter()
+			.map(|(registrar_index, judgement)| JudgementInfo {
+				registrar_index,
+				judgement: Self::judgement_to_output(judgement),
+			})
+			.collect();
+
+		Ok(Registration {
+			is_valid: true,
+			judgements,
+			deposit: registration.deposit.into(),
+			info: Self::identity_to_output(registration.info),
+		})
+	}
+
+	#[precompile::public("superOf(address)")]
+	#[precompile::view]
+	fn super_of(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<SuperOf> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+		let super_of = match pallet_identity::Pallet::<Runtime>::super_of(who) {
+			Some((account, data)) => SuperOf {
+				is_valid: true,
+				account: Self::account_to_output(&account),
+				data: Self::data_to_output(data),
+			},
+			None => SuperOf {
+				is_valid: false,
+				account: H256::zero(),
+				data: Self::data_to_output(pallet_identity::Data::None),
+			},
+		};
+
+		Ok(super_of)
+	}
+
+	#[precompile::public("subsOf(address)")]
+	#[precompile::view]
+	fn subs_of(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<SubsOf> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+		let (deposit, accounts) = pallet_identity::Pallet::<Runtime>::subs_of(who);
+
+		Ok(SubsOf {
+			deposit: deposit.into(),
+			accounts: accounts.iter().map(Self::account_to_output).collect(),
+		})
+	}
+
+	#[precompile::public("registrars()")]
+	#[precompile::view]
+	fn registrars(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Registrar>> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let registrars = pallet_identity::Pallet::<Runtime>::registrars()
+			.into_iter()
+			.enumerate()
+			.map(|(index, registrar)| match registrar {
+				Some(registrar) => Registrar {
+					is_valid: true,
+					index: index as u32,
+					account: Self::account_to_output(&registrar.account),
+					fee: registrar.fee.into(),
+					fields: registrar.fields.0.bits(),
+				},
+				None => Registrar {
+					is_valid: false,
+					index: index as u32,
+					account: H256::zero(),
+					fee: U256::zero(),
+					fields: 0,
+				},
+			})
+			.collect();
+
+		Ok(registrars)
+	}
+
+	fn identity_to_input(
+		info: IdentityInfo<GetAdditionalFieldsLimit>,
+	) -> MayRevert<pallet_identity::IdentityInfo<MaxAdditionalFieldsOf<Runtime>>> {
+		let additional: Vec<_> = info.additional.into();
+		let mut additional_fields = Vec::with_capacity(additional.len());
+		for (i, Additional { key, value }) in additional.into_iter().enumerate() {
+			let key =
+				Self::data_to_input(key).in_field("key").in_array(i).in_field("additional")?;
+			let value = Self::data_to_input(value)
+				.in_field("value")
+				.in_array(i)
+				.in_field("additional")?;
+			additional_fields.push((key, value));
+		}
+		let additional = additional_fields.try_into().map_err(|_| {
+			RevertReason::value_is_too_large("additional fields").in_field("additional")
+		})?;
+
+		let pgp_fingerprint = if info.has_pgp_fingerprint {
+			let fingerprint: Vec<u8> = info.pgp_fingerprint.into();
+			let fingerprint: [u8; PGP_FINGERPRINT_SIZE as usize] =
+				fingerprint.try_into().map_err(|_| {
+					RevertReason::custom("PGP fingerprint must be 20 bytes long")
+						.in_field("pgpFingerprint")
+				})?;
+			Some(fingerprint)
+		} else {
+			None
+		};
+
+		Ok(pallet_identity::IdentityInfo {
+			additional,
+			display: Self::data_to_input(info.display).in_field("display")?,
+			legal: Self::data_to_input(info.legal).in_field("legal")?,
+			web: Self::data_to_input(info.web).in_field("web")?,
+			riot: Self::data_to_input(info.riot).in_field("riot")?,
+			email: Self::data_to_input(info.email).in_field("email")?,
+			pgp_fingerprint,
+			image: Self::data_to_input(info.image).in_field("image")?,
+			twitter: Self::data_to_input(info.twitter).in_field("twitter")?,
+		})
+	}
+
+	fn identity_to_output(
+		info: pallet_identity::IdentityInfo<MaxAdditionalFieldsOf<Runtime>>,
+	) -> IdentityInfo<MaxAdditionalFieldsOf<Runtime>> {
+		let additional: Vec<_> = info
+			.additional
+			.into_iter()
+			.map(|(key, value)| Additional {
+				key: Self::data_to_output(key),
+				value: Self::data_to_output(value),
+			})
+			.collect();
+
+		IdentityInfo {
+			additional: additional.into(),
+			display: Self::data_to_output(info.display),
+			legal: Self::data_to_output(info.legal),
+			web: Self::data_to_output(info.web),
+			riot: Self::data_to_output(info.riot),
+			email: Self::data_to_output(info.email),
+			has_pgp_fingerprint: info.pgp_fingerprint.is_some(),
+			pgp_fingerprint: info.pgp_fingerprint.map(|f| f.to_vec()).unwrap_or_default().into(),
+			image: Self::data_to_output(info.image),
+			twitter: Self::data_to_output(info.twitter),
+		}
+	}
+
+	fn empty_identity_output() -> IdentityInfo<MaxAdditionalFieldsOf<Runtime>> {
+		IdentityInfo {
+			additional: Vec::new().into(),
+			display: Self::data_to_output(pallet_identity::Data::None),
+			legal: Self::data_to_output(pallet_identity::Data::None),
+			web: Self::data_to_output(pallet_identity::Data::None),
+			riot: Self::data_to_output(pallet_identity::Data::None),
+			email: Self::data_to_output(pallet_identity::Data::None),
+			has_pgp_fingerprint: false,
+			pgp_fingerprint: Vec::new().into(),
+			image: Self::data_to_output(pallet_identity::Data::None),
+			twitter: Self::data_to_output(pallet_identity::Data::None),
+		}
+	}
+
+	fn data_to_input(data: Data) -> MayRevert<pallet_identity::Data> {
+		if !data.has_data {
+			return Ok(pallet_identity::Data::None);
+		}
+
+		let value: Vec<u8> = data.value.into();
+		let value = value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("raw data").in_field("value"))?;
+
+		Ok(pallet_identity::Data::Raw(value))
+	}
+
+	fn data_to_output(data: pallet_identity::Data) -> Data {
+		match data {
+			pallet_identity::Data::None => Data { has_data: false, value: Vec::new().into() },
+			pallet_identity::Data::Raw(value) =>
+				Data { has_data: true, value: value.into_inner().into() },
+			pallet_identity::Data::BlakeTwo256(hash) |
+			pallet_identity::Data::Sha256(hash) |
+			pallet_identity::Data::Keccak256(hash) |
+			pallet_identity::Data::ShaThree256(hash) => Data { has_data: true, value: hash.into() },
+		}
+	}
+
+	fn judgement_to_input(
+		judgement: Judgement,
+	) -> MayRevert<pallet_identity::Judgement<BalanceOf<Runtime>>> {
+		let flags = [
+			judgement.is_unknown,
+			judgement.is_fee_paid,
+			judgement.is_reasonable,
+			judgement.is_known_good,
+			judgement.is_out_of_date,
+			judgement.is_low_quality,
+			judgement.is_erroneous,
+		];
+		if flags.iter().filter(|flag| **flag).count() != 1 {
+			return Err(RevertReason::custom("Exactly one judgement flag must be set").into());
+		}
+
+		let judgement = if judgement.is_unknown {
+			pallet_identity::Judgement::Unknown
+		} else if judgement.is_fee_paid {
+			let deposit =
+				Self::u256_to_amount(judgement.fee_paid_deposit).in_field("feePaidDeposit")?;
+			pallet_identity::Judgement::FeePaid(deposit)
+		} else if judgement.is_reasonable {
+			pallet_identity::Judgement::Reasonable
+		} else if judgement.is_known_good {
+			pallet_identity::Judgement::KnownGood
+		} else if judgement.is_out_of_date {
+			pallet_identity::Judgement::OutOfDate
+		} else if judgement.is_low_quality {
+			pallet_identity::Judgement::LowQuality
+		} else {
+			pallet_identity::Judgement::Erroneous
+		};
+
+		Ok(judgement)
+	}
+
+	fn judgement_to_output(judgement: pallet_identity::Judgement<BalanceOf<Runtime>>) -> Judgement {
+		let mut output = Judgement::default();
+		match judgement {
+			pallet_identity::Judgement::Unknown => output.is_unknown = true,
+			pallet_identity::Judgement::FeePaid(deposit) => {
+				output.is_fee_paid = true;
+				output.fee_paid_deposit = deposit.into();
+			},
+			pallet_identity::Judgement::Reasonable => output.is_reasonable = true,
+			pallet_identity::Judgement::KnownGood => output.is_known_good = true,
+			pallet_identity::Judgement::OutOfDate => output.is_out_of_date = true,
+			pallet_identity::Judgement::LowQuality => output.is_low_quality = true,
+			pallet_identity::Judgement::Erroneous => output.is_erroneous = true,
+		}
+
+		output
+	}
+
+	fn account_to_output(account: &Runtime::AccountId) -> H256 {
+		H256::from_slice(&account.encode()[..])
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+}
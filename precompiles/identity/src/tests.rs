@@ -0,0 +1,390 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use precompile_utils::testing::*;
+use sp_core::H160;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+type PCall = IdentityPrecompileCall<Runtime>;
+type OutputFieldLimit = MaxAdditionalFieldsOf<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Whether the reverted `output` is the dispatch error `error`.
+fn dispatch_error(output: &[u8], error: &str) -> bool {
+	let output = std::str::from_utf8(output).unwrap();
+	output.starts_with("Dispatched call failed with error: ") && output.contains(error)
+}
+
+fn data(value: &[u8]) -> Data {
+	Data { has_data: true, value: value.into() }
+}
+
+fn no_data() -> Data {
+	Data { has_data: false, value: Vec::new().into() }
+}
+
+/// Identity with a display name and the given additional fields.
+fn info<L>(display: &[u8], additional: Vec<Additional>) -> IdentityInfo<L> {
+	IdentityInfo {
+		additional: additional.into(),
+		display: data(display),
+		legal: no_data(),
+		web: no_data(),
+		riot: no_data(),
+		email: no_data(),
+		has_pgp_fingerprint: false,
+		pgp_fingerprint: Vec::new().into(),
+		image: no_data(),
+		twitter: no_data(),
+	}
+}
+
+fn account_h256(address: H160) -> H256 {
+	H256::from(<[u8; 32]>::from(account(address)))
+}
+
+fn set_identity(from: H160, info: IdentityInfo<GetAdditionalFieldsLimit>) {
+	precompiles()
+		.prepare_test(from, PRECOMPILE, PCall::set_identity { info })
+		.expect_log(log2(PRECOMPILE, SELECTOR_LOG_IDENTITY_SET, from, []))
+		.execute_returns(vec![]);
+}
+
+/// Registers CHARLIE as registrar 0, charging `fee` for judgements.
+fn add_registrar(fee: Balance) {
+	Identity::add_registrar(RuntimeOrigin::root(), account(CHARLIE)).unwrap();
+	Identity::set_fee(RuntimeOrigin::signed(account(CHARLIE)), 0, fee).unwrap();
+}
+
+fn request_judgement(from: H160, max_fee: Balance) {
+	precompiles()
+		.prepare_test(
+			from,
+			PRECOMPILE,
+			PCall::request_judgement { reg_index: 0, max_fee: max_fee.into() },
+		)
+		.expect_log(log2(
+			PRECOMPILE,
+			SELECTOR_LOG_JUDGEMENT_REQUESTED,
+			from,
+			EvmDataWriter::new().write(0u32).build(),
+		))
+		.execute_returns(vec![]);
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["IdentityInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn set_identity_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		let additional = vec![Additional { key: data(b"discord"), value: data(b"alice#0001") }];
+		set_identity(ALICE, info(b"alice", additional.clone()));
+
+		// Basic deposit of 10 and 1 per additional field
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 11);
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::identity { who: Address(ALICE) })
+			.expect_no_logs()
+			.execute_returns_encoded(Registration::<OutputFieldLimit> {
+				is_valid: true,
+				judgements: Vec::new(),
+				deposit: 11.into(),
+				info: info(b"alice", additional),
+			});
+	});
+}
+
+#[test]
+fn identity_of_unknown_account_is_not_valid() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::identity { who: Address(BOB) })
+			.expect_no_logs()
+			.execute_returns_encoded(Registration::<OutputFieldLimit> {
+				is_valid: false,
+				judgements: Vec::new(),
+				deposit: 0.into(),
+				info: IdentityInfo { display: no_data(), ..info(b"", Vec::new()) },
+			});
+	});
+}
+
+#[test]
+fn set_identity_rejects_too_many_additional_fields() {
+	new_test_ext().execute_with(|| {
+		let field = Additional { key: data(b"key"), value: data(b"value") };
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::set_identity { info: info(b"alice", vec![field; 3]) },
+			)
+			.execute_reverts(|output| {
+				output == b"additional: Value is too large for additional fields"
+			});
+
+		assert!(Identity::identity(account(ALICE)).is_none());
+	});
+}
+
+#[test]
+fn set_identity_rejects_invalid_pgp_fingerprint() {
+	new_test_ext().execute_with(|| {
+		let info = IdentityInfo {
+			has_pgp_fingerprint: true,
+			pgp_fingerprint: vec![0x01; 19].into(),
+			..info(b"alice", Vec::new())
+		};
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::set_identity { info })
+			.execute_reverts(|output| {
+				output == b"pgpFingerprint: PGP fingerprint must be 20 bytes long"
+			});
+	});
+}
+
+#[test]
+fn clear_identity_unreserves_deposit() {
+	new_test_ext().execute_with(|| {
+		set_identity(ALICE, info(b"alice", Vec::new()));
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::clear_identity {})
+			.expect_log(log2(PRECOMPILE, SELECTOR_LOG_IDENTITY_CLEARED, ALICE, []))
+			.execute_returns(vec![]);
+
+		assert!(Identity::identity(account(ALICE)).is_none());
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::clear_identity {})
+			.execute_reverts(|output| dispatch_error(output, "NotNamed"));
+	});
+}
+
+#[test]
+fn set_subs_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		let sub = SubAccount { account: account_h256(BOB), data: data(b"bob") };
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::set_subs { subs: vec![sub.clone()].into() })
+			.execute_reverts(|output| dispatch_error(output, "NoIdentity"));
+
+		set_identity(ALICE, info(b"alice", Vec::new()));
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::set_subs { subs: vec![sub].into() })
+			.execute_returns(vec![]);
+
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::subs_of { who: Address(ALICE) })
+			.expect_no_logs()
+			.execute_returns_encoded(SubsOf {
+				deposit: 5.into(),
+				accounts: vec![account_h256(BOB)],
+			});
+
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::super_of { who: Address(BOB) })
+			.expect_no_logs()
+			.execute_returns_encoded(SuperOf {
+				is_valid: true,
+				account: account_h256(ALICE),
+				data: data(b"bob"),
+			});
+
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, PCall::super_of { who: Address(CHARLIE) })
+			.expect_no_logs()
+			.execute_returns_encoded(SuperOf {
+				is_valid: false,
+				account: H256::zero(),
+				data: no_data(),
+			});
+	});
+}
+
+#[test]
+fn registrar_provides_requested_judgement() {
+	new_test_ext().execute_with(|| {
+		add_registrar(5);
+		set_identity(ALICE, info(b"alice", Vec::new()));
+		request_judgement(ALICE, 5);
+
+		// The fee is reserved until the judgement is given
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 15);
+
+		let identity = BlakeTwo256::hash_of(&Identity::identity(account(ALICE)).unwrap().info);
+		precompiles()
+			.prepare_test(
+				CHARLIE,
+				PRECOMPILE,
+				PCall::provide_judgement {
+					reg_index: 0,
+					target: account_h256(ALICE),
+					judgement: Judgement { is_known_good: true, ..Default::default() },
+					identity,
+				},
+			)
+			.expect_log(log2(
+				PRECOMPILE,
+				SELECTOR_LOG_JUDGEMENT_GIVEN,
+				account_h256(ALICE),
+				EvmDataWriter::new().write(0u32).build(),
+			))
+			.execute_returns(vec![]);
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 10);
+		assert_eq!(Balances::free_balance(account(CHARLIE)), 1_005);
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::identity { who: Address(ALICE) })
+			.expect_no_logs()
+			.execute_returns_encoded(Registration::<OutputFieldLimit> {
+				is_valid: true,
+				judgements: vec![JudgementInfo {
+					registrar_index: 0,
+					judgement: Judgement { is_known_good: true, ..Default::default() },
+				}],
+				deposit: 10.into(),
+				info: info(b"alice", Vec::new()),
+			});
+	});
+}
+
+#[test]
+fn only_the_registrar_provides_judgement() {
+	new_test_ext().execute_with(|| {
+		add_registrar(5);
+		set_identity(ALICE, info(b"alice", Vec::new()));
+		request_judgement(ALICE, 5);
+
+		let identity = BlakeTwo256::hash_of(&Identity::identity(account(ALICE)).unwrap().info);
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::provide_judgement {
+					reg_index: 0,
+					target: account_h256(ALICE),
+					judgement: Judgement { is_known_good: true, ..Default::default() },
+					identity,
+				},
+			)
+			.execute_reverts(|output| dispatch_error(output, "InvalidIndex"));
+	});
+}
+
+#[test]
+fn judgement_must_have_exactly_one_flag() {
+	new_test_ext().execute_with(|| {
+		add_registrar(5);
+		set_identity(ALICE, info(b"alice", Vec::new()));
+
+		precompiles()
+			.prepare_test(
+				CHARLIE,
+				PRECOMPILE,
+				PCall::provide_judgement {
+					reg_index: 0,
+					target: account_h256(ALICE),
+					judgement: Judgement {
+						is_known_good: true,
+						is_reasonable: true,
+						..Default::default()
+					},
+					identity: H256::zero(),
+				},
+			)
+			.execute_reverts(|output| {
+				output == b"judgement: Exactly one judgement flag must be set"
+			});
+	});
+}
+
+#[test]
+fn request_judgement_respects_max_fee() {
+	new_test_ext().execute_with(|| {
+		add_registrar(5);
+		set_identity(ALICE, info(b"alice", Vec::new()));
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::request_judgement { reg_index: 0, max_fee: 4.into() },
+			)
+			.execute_reverts(|output| dispatch_error(output, "FeeChanged"));
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 10);
+	});
+}
+
+#[test]
+fn cancel_request_unreserves_fee() {
+	new_test_ext().execute_with(|| {
+		add_registrar(5);
+		set_identity(ALICE, info(b"alice", Vec::new()));
+		request_judgement(ALICE, 5);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::cancel_request { reg_index: 0 })
+			.expect_log(log2(
+				PRECOMPILE,
+				SELECTOR_LOG_JUDGEMENT_UNREQUESTED,
+				ALICE,
+				EvmDataWriter::new().write(0u32).build(),
+			))
+			.execute_returns(vec![]);
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 10);
+		assert!(Identity::identity(account(ALICE)).unwrap().judgements.is_empty());
+	});
+}
+
+#[test]
+fn registrars_view() {
+	new_test_ext().execute_with(|| {
+		add_registrar(5);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::registrars {})
+			.expect_no_logs()
+			.execute_returns_encoded(vec![Registrar {
+				is_valid: true,
+				index: 0,
+				account: account_h256(CHARLIE),
+				fee: 5.into(),
+				fields: 0,
+			}]);
+	});
+}
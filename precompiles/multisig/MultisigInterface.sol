@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @author The Diora Team
+/// @title Pallet Multisig Interface
+/// @dev The interface through which solidity contracts will interact with pallet-multisig.
+/// Signatories are given as 32 bytes and don't need to be sorted. Operations are approved by
+/// the hash of their call, the call is dispatched with the `as_multi` extrinsic of pallet-multisig.
+/// @custom:address 0x000000000000000000000000000000000000080D
+interface Multisig {
+    /// @dev Block height and extrinsic index of the first approval of a multisig operation.
+    /// A zero height means that the caller is the first to approve the operation.
+    struct Timepoint {
+        uint32 height;
+        uint32 index;
+    }
+
+    // --- Generated from the precompile implementation, do not edit ---
+    /// @dev Approve a call from a multisig account by its hash, without dispatching it.
+    /// A deposit is reserved by the first approval. Once enough approvals are recorded, the call
+    /// is dispatched by any signatory with the `as_multi` extrinsic.
+    /// @custom:selector beae3126
+    /// @param threshold The number of approvals required to dispatch the call
+    /// @param otherSignatories The signatories of the multisig account except the caller
+    /// @param timepoint The timepoint of the first approval, zero if the caller is the first
+    /// @param callHash The blake2-256 hash of the SCALE encoded runtime call
+    /// @param maxWeight Unused, kept for compatibility with the pallet
+    function approveAsMulti(
+        uint16 threshold,
+        bytes32[] memory otherSignatories,
+        Timepoint memory timepoint,
+        bytes32 callHash,
+        uint64 maxWeight
+    ) external;
+
+    /// @dev Cancel a multisig operation created by the caller, its deposit is returned.
+    /// @custom:selector cc52e9c5
+    /// @param threshold The number of approvals required to dispatch the call
+    /// @param otherSignatories The signatories of the multisig account except the caller
+    /// @param timepoint The timepoint of the first approval
+    /// @param callHash The blake2-256 hash of the SCALE encoded runtime call
+    function cancelAsMulti(
+        uint16 threshold,
+        bytes32[] memory otherSignatories,
+        Timepoint memory timepoint,
+        bytes32 callHash
+    ) external;
+
+    /// @dev Get the account controlled by a set of signatories
+    /// @custom:selector 6afda93f
+    /// @param signatories All the signatories of the multisig account
+    /// @param threshold The number of approvals required to dispatch a call
+    /// @return The 32 bytes multisig account id
+    function multisigAccount(
+        bytes32[] memory signatories,
+        uint16 threshold
+    ) external view returns (bytes32);
+    // --- End of generated functions ---
+}
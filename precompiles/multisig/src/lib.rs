@@ -0,0 +1,176 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet multisig through an evm precompile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::ConstU32,
+	weights::Weight,
+};
+use pallet_evm::AddressMapping;
+use pallet_multisig::Call as MultisigCall;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of signatories of a multisig account.
+pub const SIGNATORIES_LIMIT: u32 = 100;
+
+type GetSignatoriesLimit = ConstU32<SIGNATORIES_LIMIT>;
+
+type RuntimeCallOf<Runtime> = <Runtime as frame_system::Config>::RuntimeCall;
+
+type MultisigOf<Runtime> = pallet_multisig::Pallet<Runtime>;
+
+/// Block height and extrinsic index of the first approval of a multisig operation.
+/// A zero height means that the caller is the first to approve the operation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EvmData)]
+pub struct Timepoint {
+	pub height: u32,
+	pub index: u32,
+}
+
+/// A precompile to wrap the functionality from pallet multisig.
+///
+/// Signatories are given as 32 bytes account ids, they don't need to be sorted. Timepoints are
+/// given as the height and index of the extrinsic that created the multisig operation, a zero
+/// height means that the caller is the first to approve it.
+///
+/// Operations are approved by the hash of their call, the call itself is dispatched with the
+/// `as_multi` extrinsic of pallet multisig: dispatching runtime calls from the EVM would let smart
+/// contracts and other precompiles dispatch any call on behalf of the caller.
+pub struct MultisigPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+#[precompile::solidity_interface("MultisigInterface.sol")]
+impl<Runtime> MultisigPrecompile<Runtime>
+where
+	Runtime: pallet_multisig::Config + pallet_evm::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::BlockNumber: From<u32>,
+	RuntimeCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<RuntimeCallOf<Runtime> as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	RuntimeCallOf<Runtime>: From<MultisigCall<Runtime>>,
+{
+	/// Get the account controlled by a set of signatories
+	/// @param signatories All the signatories of the multisig account
+	/// @param threshold The number of approvals required to dispatch a call
+	/// @return The 32 bytes multisig account id
+	#[precompile::public("multisigAccount(bytes32[],uint16)")]
+	#[precompile::view]
+	fn multisig_account(
+		_handle: &mut impl PrecompileHandle,
+		signatories: BoundedVec<H256, GetSignatoriesLimit>,
+		threshold: u16,
+	) -> EvmResult<H256> {
+		let signatories = Self::sorted_accounts(signatories);
+		let account = MultisigOf::<Runtime>::multi_account_id(&signatories, threshold);
+
+		Ok(H256::from_slice(&account.encode()[..]))
+	}
+
+	/// Approve a call from a multisig account by its hash, without dispatching it.
+	/// A deposit is reserved by the first approval. Once enough approvals are recorded, the call
+	/// is dispatched by any signatory with the `as_multi` extrinsic.
+	/// @param threshold The number of approvals required to dispatch the call
+	/// @param otherSignatories The signatories of the multisig account except the caller
+	/// @param timepoint The timepoint of the first approval, zero if the caller is the first
+	/// @param callHash The blake2-256 hash of the SCALE encoded runtime call
+	/// @param maxWeight Unused, kept for compatibility with the pallet
+	#[precompile::public("approveAsMulti(uint16,bytes32[],(uint32,uint32),bytes32,uint64)")]
+	fn approve_as_multi(
+		handle: &mut impl PrecompileHandle,
+		threshold: u16,
+		other_signatories: BoundedVec<H256, GetSignatoriesLimit>,
+		timepoint: Timepoint,
+		call_hash: H256,
+		max_weight: u64,
+	) -> EvmResult {
+		let other_signatories = Self::sorted_accounts(other_signatories);
+		let maybe_timepoint = Self::to_timepoint(timepoint);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = MultisigCall::<Runtime>::approve_as_multi {
+			threshold,
+			other_signatories,
+			maybe_timepoint,
+			call_hash: call_hash.0,
+			max_weight: Weight::from_ref_time(max_weight),
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	/// Cancel a multisig operation created by the caller, its deposit is returned.
+	/// @param threshold The number of approvals required to dispatch the call
+	/// @param otherSignatories The signatories of the multisig account except the caller
+	/// @param timepoint The timepoint of the first approval
+	/// @param callHash The blake2-256 hash of the SCALE encoded runtime call
+	#[precompile::public("cancelAsMulti(uint16,bytes32[],(uint32,uint32),bytes32)")]
+	fn cancel_as_multi(
+		handle: &mut impl PrecompileHandle,
+		threshold: u16,
+		other_signatories: BoundedVec<H256, GetSignatoriesLimit>,
+		timepoint: Timepoint,
+		call_hash: H256,
+	) -> EvmResult {
+		let other_signatories = Self::sorted_accounts(other_signatories);
+		let timepoint = Self::to_timepoint(timepoint)
+			.ok_or_else(|| RevertReason::custom("Timepoint is required").in_field("timepoint"))?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = MultisigCall::<Runtime>::cancel_as_multi {
+			threshold,
+			other_signatories,
+			timepoint,
+			call_hash: call_hash.0,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	fn sorted_accounts(accounts: BoundedVec<H256, GetSignatoriesLimit>) -> Vec<Runtime::AccountId> {
+		let mut accounts: Vec<_> = Vec::from(accounts)
+			.into_iter()
+			.map(|account| Runtime::AccountId::from(account.0))
+			.collect();
+		accounts.sort();
+
+		accounts
+	}
+
+	fn to_timepoint(
+		Timepoint { height, index }: Timepoint,
+	) -> Option<pallet_multisig::Timepoint<Runtime::BlockNumber>> {
+		(height != 0).then(|| pallet_multisig::Timepoint { height: height.into(), index })
+	}
+}
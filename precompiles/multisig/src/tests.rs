@@ -0,0 +1,268 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use frame_support::{assert_ok, traits::Currency};
+use precompile_utils::testing::*;
+use sp_core::H160;
+
+type PCall = MultisigPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Whether the reverted `output` is the dispatch error `error`.
+fn dispatch_error(output: &[u8], error: &str) -> bool {
+	let output = std::str::from_utf8(output).unwrap();
+	output.starts_with("Dispatched call failed with error: ") && output.contains(error)
+}
+
+fn account_h256(address: H160) -> H256 {
+	H256::from(<[u8; 32]>::from(account(address)))
+}
+
+fn signatories(addresses: &[H160]) -> BoundedVec<H256, GetSignatoriesLimit> {
+	addresses.iter().copied().map(account_h256).collect::<Vec<_>>().into()
+}
+
+/// Multisig account of `addresses`, funded with 1_000.
+fn funded_multisig(addresses: &[H160], threshold: u16) -> AccountId {
+	let mut accounts: Vec<_> = addresses.iter().copied().map(account).collect();
+	accounts.sort();
+	let multisig = Multisig::multi_account_id(&accounts, threshold);
+	Balances::make_free_balance_be(&multisig, 1_000);
+
+	multisig
+}
+
+fn transfer(to: H160, value: Balance) -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer { dest: account(to), value })
+}
+
+fn call_hash(call: &RuntimeCall) -> H256 {
+	H256(sp_io::hashing::blake2_256(&call.encode()))
+}
+
+fn timepoint(height: u32, index: u32) -> Timepoint {
+	Timepoint { height, index }
+}
+
+/// Approval of `caller` for the 2 out of 3 multisig operation of ALICE, BOB and CHARLIE.
+fn approve(caller: H160, call: &RuntimeCall, timepoint: Timepoint) -> PCall {
+	let other_signatories: Vec<_> = [ALICE, BOB, CHARLIE]
+		.into_iter()
+		.filter(|signatory| *signatory != caller)
+		.collect();
+
+	PCall::approve_as_multi {
+		threshold: 2,
+		other_signatories: signatories(&other_signatories),
+		timepoint,
+		call_hash: call_hash(call),
+		max_weight: 0,
+	}
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["MultisigInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn multisig_account_does_not_depend_on_signatories_order() {
+	new_test_ext().execute_with(|| {
+		let multisig = funded_multisig(&[ALICE, BOB, CHARLIE], 2);
+
+		for order in [[ALICE, BOB, CHARLIE], [CHARLIE, ALICE, BOB]] {
+			precompiles()
+				.prepare_test(
+					ALICE,
+					PRECOMPILE,
+					PCall::multisig_account { signatories: signatories(&order), threshold: 2 },
+				)
+				.expect_no_logs()
+				.execute_returns_encoded(H256::from(<[u8; 32]>::from(multisig.clone())));
+		}
+	});
+}
+
+#[test]
+fn calls_cannot_be_dispatched_through_the_precompile() {
+	for signature in [
+		"asMultiThreshold1(bytes32[],bytes)",
+		"asMulti(uint16,bytes32[],(uint32,uint32),bytes,uint64)",
+	] {
+		let hash = sp_io::hashing::keccak_256(signature.as_bytes());
+		let selector = u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]);
+		assert!(!PCall::supports_selector(selector));
+	}
+}
+
+#[test]
+fn caller_cannot_be_one_of_the_other_signatories() {
+	new_test_ext().execute_with(|| {
+		let call = transfer(CHARLIE, 100);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::approve_as_multi {
+					threshold: 2,
+					other_signatories: signatories(&[ALICE, BOB]),
+					timepoint: timepoint(0, 0),
+					call_hash: call_hash(&call),
+					max_weight: 0,
+				},
+			)
+			.execute_reverts(|output| dispatch_error(output, "SenderInSignatories"));
+	});
+}
+
+#[test]
+fn approve_as_multi_reserves_deposit_of_the_first_approval() {
+	new_test_ext().execute_with(|| {
+		let multisig = funded_multisig(&[ALICE, BOB, CHARLIE], 2);
+		let call = transfer(CHARLIE, 100);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, approve(ALICE, &call, timepoint(0, 0)))
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		// Base deposit of 10 and 1 per approval needed
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 12);
+		assert!(pallet_multisig::Multisigs::<Runtime>::contains_key(&multisig, call_hash(&call).0));
+	});
+}
+
+#[test]
+fn approve_as_multi_checks_timepoint_of_the_operation() {
+	new_test_ext().execute_with(|| {
+		let call = transfer(CHARLIE, 100);
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, approve(ALICE, &call, timepoint(0, 0)))
+			.execute_returns(vec![]);
+
+		for (wrong_timepoint, error) in
+			[(timepoint(0, 0), "NoTimepoint"), (timepoint(1, 1), "WrongTimepoint")]
+		{
+			precompiles()
+				.prepare_test(BOB, PRECOMPILE, approve(BOB, &call, wrong_timepoint))
+				.execute_reverts(|output| dispatch_error(output, error));
+		}
+	});
+}
+
+#[test]
+fn approvals_are_dispatched_with_the_multisig_extrinsic() {
+	new_test_ext().execute_with(|| {
+		let multisig = funded_multisig(&[ALICE, BOB, CHARLIE], 2);
+		let call = transfer(CHARLIE, 100);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, approve(ALICE, &call, timepoint(0, 0)))
+			.execute_returns(vec![]);
+		// The threshold is reached but the precompile never dispatches the call
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, approve(BOB, &call, timepoint(1, 0)))
+			.expect_no_logs()
+			.execute_returns(vec![]);
+		assert_eq!(Balances::free_balance(&multisig), 1_000);
+
+		let mut other_signatories = vec![account(ALICE), account(BOB)];
+		other_signatories.sort();
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(account(CHARLIE)),
+			2,
+			other_signatories,
+			Some(pallet_multisig::Timepoint { height: 1, index: 0 }),
+			Box::new(call.clone()),
+			call.get_dispatch_info().weight,
+		));
+
+		assert!(!pallet_multisig::Multisigs::<Runtime>::contains_key(
+			&multisig,
+			call_hash(&call).0
+		));
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+		assert_eq!(Balances::free_balance(&multisig), 900);
+		assert_eq!(Balances::free_balance(account(CHARLIE)), 1_100);
+	});
+}
+
+#[test]
+fn only_the_depositor_cancels_operation() {
+	new_test_ext().execute_with(|| {
+		let multisig = funded_multisig(&[ALICE, BOB, CHARLIE], 2);
+		let call = transfer(CHARLIE, 100);
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, approve(ALICE, &call, timepoint(0, 0)))
+			.execute_returns(vec![]);
+
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::cancel_as_multi {
+					threshold: 2,
+					other_signatories: signatories(&[ALICE, CHARLIE]),
+					timepoint: timepoint(1, 0),
+					call_hash: call_hash(&call),
+				},
+			)
+			.execute_reverts(|output| dispatch_error(output, "NotOwner"));
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::cancel_as_multi {
+					threshold: 2,
+					other_signatories: signatories(&[BOB, CHARLIE]),
+					timepoint: timepoint(0, 0),
+					call_hash: call_hash(&call),
+				},
+			)
+			.execute_reverts(|output| output == b"timepoint: Timepoint is required");
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::cancel_as_multi {
+					threshold: 2,
+					other_signatories: signatories(&[BOB, CHARLIE]),
+					timepoint: timepoint(1, 0),
+					call_hash: call_hash(&call),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert!(!pallet_multisig::Multisigs::<Runtime>::contains_key(
+			&multisig,
+			call_hash(&call).0
+		));
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+	});
+}
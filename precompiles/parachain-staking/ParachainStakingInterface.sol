@@ -8,152 +8,74 @@ pragma solidity >=0.8.0;
 /// wraps the pallet
 /// @custom:address 0x0000000000000000000000000000000000000800
 interface ParachainStaking {
-    /// @dev Check whether the specified address is currently a staking delegator
-    /// @custom:selector fd8ab482
-    /// @param The delegator address in form of 20 or 32 hex bytes
-    /// @return A boolean confirming whether the address is a delegator
-    function isDelegator(bytes memory delegator) external view returns (bool);
-
-    /// @dev Check whether the specified address is currently a collator candidate
-    /// @custom:selector d51b9e93
-    /// @param The candidate address in form of 32 hex bytes
-    /// @return A boolean confirming whether the address is a collator candidate
-    function isCandidate(bytes32 candidate) external view returns (bool);
-
-    /// @dev Check whether the specifies address is currently a part of the active set
-    /// @custom:selector 740d7d2a
-    /// @param The candidate address in form of 32 hex bytes
-    /// @return A boolean confirming whether the address is a part of the active set
-    function isSelectedCandidate(bytes32 candidate)
-    external
-    view
-    returns (bool);
-
-    /// @dev Total points awarded to all collators in a particular round
-    /// @custom:selector 9799b4e7
-    /// @param round the round for which we are querying the points total
-    /// @return The total points awarded to all collators in the round
-    function points(uint32 round) external view returns (uint256);
-
+    // --- Generated from the precompile implementation, do not edit ---
     /// @dev Total points awarded to a specific collator in a particular round.
-    /// A value of `0` may signify that no blocks were produced or that the storage for that round has been removed
-    /// @custom:selector bfea66ac
-    /// @param round the round for which we are querying the awarded points
+    /// A value of `0` may signify that no blocks were produced or that the storage for that
+    /// round has been removed.
+    /// @custom:selector 7bb52d11
+    /// @param round The round for which we are querying the awarded points
     /// @param candidate The candidate to whom the points are awarded
     /// @return The total points awarded to the collator for the provided round
-    function awardedPoints(uint32 round, bytes32 candidate)
-    external
-    view
-    returns (uint32);
+    function awardedPoints(uint32 round, bytes32 candidate) external view returns (uint32);
 
-    /// @dev The amount delegated in support of the candidate by the delegator
-    /// @custom:selector a73e51bc
-    /// @param delegator Who made this delegation
-    /// @param candidate The candidate for which the delegation is in support of
-    /// @return The amount of the delegation in support of the candidate by the delegator
-    function delegationAmount(bytes memory delegator, bytes32 candidate)
-    external
-    view
-    returns (uint256);
+    /// @dev Cancel pending candidate bond request.
+    /// @custom:selector b5ad5f07
+    function cancelCandidateBondLess() external;
 
-    /// @dev Whether the delegation is in the top delegations
-    /// @custom:selector 91cc8657
-    /// @param delegator Who made this delegation
-    /// @param candidate The candidate for which the delegation is in support of
-    /// @return If delegation is in top delegations (is counted)
-    function isInTopDelegations(bytes memory delegator, bytes32 candidate)
-    external
-    view
-    returns (bool);
+    /// @dev Cancel pending delegation request (already made in support of input by caller).
+    /// @custom:selector 1048a9c0
+    /// @param candidate The address of the candidate
+    function cancelDelegationRequest(bytes32 candidate) external;
 
-    /// @dev Get the minimum delegation amount
-    /// @custom:selector 02985992
-    /// @return The minimum delegation amount
-    function minDelegation() external view returns (uint256);
+    /// @dev Cancel request to leave the set of collator candidates.
+    /// @custom:selector 28492b22
+    /// @param candidateCount The number of candidates in the CandidatePool
+    function cancelLeaveCandidates(uint32 candidateCount) external;
+
+    /// @dev Cancel request to leave the set of delegators.
+    /// @custom:selector f7421284
+    /// @notice DEPRECATED use batch util with cancelDelegationRequest for all delegations
+    function cancelLeaveDelegators() external;
+
+    /// @dev Get the CandidateAutoCompoundingDelegationCount weight hint.
+    /// @custom:selector 5bbcd751
+    /// @param candidate The address for which we are querying the auto compounding delegation
+    /// count
+    /// @return The number of auto compounding delegations
+    function candidateAutoCompoundingDelegationCount(
+        bytes32 candidate
+    ) external view returns (uint32);
 
-    /// @dev Get the CandidateCount weight hint
+    /// @dev Request to bond more for collator candidates.
+    /// @custom:selector a52c8643
+    /// @param more The additional amount self-bonded
+    function candidateBondMore(uint256 more) external;
+
+    /// @dev Get the CandidateCount weight hint.
     /// @custom:selector a9a981a3
     /// @return The CandidateCount weight hint
-    function candidateCount() external view returns (uint256);
+    function candidateCount() external view returns (uint32);
 
-    /// @dev Get the current round number
-    /// @custom:selector 146ca531
-    /// @return The current round number
-    function round() external view returns (uint256);
-
-    /// @dev Get the CandidateDelegationCount weight hint
-    /// @custom:selector 2ec087eb
+    /// @dev Get the CandidateDelegationCount weight hint.
+    /// @custom:selector 8aee59d4
     /// @param candidate The address for which we are querying the nomination count
     /// @return The number of nominations backing the collator
-    function candidateDelegationCount(bytes32 candidate)
-    external
-    view
-    returns (uint32);
-
-    /// @dev Get the CandidateAutoCompoundingDelegationCount weight hint
-    /// @custom:selector 905f0806
-    /// @param candidate The address for which we are querying the auto compounding
-    ///     delegation count
-    /// @return The number of auto compounding delegations
-    function candidateAutoCompoundingDelegationCount(bytes32 candidate)
-    external
-    view
-    returns (uint32);
+    function candidateDelegationCount(bytes32 candidate) external view returns (uint32);
 
-    /// @dev Get the DelegatorDelegationCount weight hint
-    /// @custom:selector 067ec822
-    /// @param delegator The address for which we are querying the delegation count
-    /// @return The number of delegations made by the delegator
-    function delegatorDelegationCount(bytes memory delegator)
-    external
-    view
-    returns (uint256);
-
-    /// @dev Get the selected candidates for the current round
-    /// @custom:selector bcf868a6
-    /// @return The selected candidate accounts
-    function selectedCandidates() external view returns (bytes32[] memory);
+    /// @dev Whether there exists a pending exit for candidate.
+    /// @custom:selector 89485bd5
+    /// @param candidate The candidate for which the exit request was made
+    /// @return Whether a pending exit request exists for the candidate
+    function candidateExitIsPending(bytes32 candidate) external view returns (bool);
 
-    /// @dev Whether there exists a pending request for a delegation made by a delegator
-    /// @custom:selector 3b16def8
-    /// @param delegator the delegator that made the delegation
-    /// @param candidate the candidate for which the delegation was made
-    /// @return Whether a pending request exists for such delegation
-    function delegationRequestIsPending(bytes memory delegator, bytes32 candidate)
-    external
-    view
-    returns (bool);
-
-    /// @dev Whether there exists a pending exit for candidate
-    /// @custom:selector 43443682
-    /// @param candidate the candidate for which the exit request was made
-    /// @return Whether a pending request exists for such delegation
-    function candidateExitIsPending(bytes32 candidate)
-    external
-    view
-    returns (bool);
-
-    /// @dev Whether there exists a pending bond less request made by a candidate
-    /// @custom:selector d0deec11
-    /// @param candidate the candidate which made the request
+    /// @dev Whether there exists a pending bond less request made by a candidate.
+    /// @custom:selector 30d1a8eb
+    /// @param candidate The candidate which made the request
     /// @return Whether a pending bond less request was made by the candidate
-    function candidateRequestIsPending(bytes32 candidate)
-    external
-    view
-    returns (bool);
-
-    /// @dev Returns the percent value of auto-compound set for a delegation
-    /// @custom:selector b4d4c7fd
-    /// @param delegator the delegator that made the delegation
-    /// @param candidate the candidate for which the delegation was made
-    /// @return Percent of rewarded amount that is auto-compounded on each payout
-    function delegationAutoCompound(bytes memory delegator, bytes32 candidate)
-    external
-    view
-    returns (uint8);
+    function candidateRequestIsPending(bytes32 candidate) external view returns (bool);
 
-    /// @dev Make a delegation in support of a collator candidate
-    /// @custom:selector 829f5ee3
+    /// @dev Make a delegation in support of a collator candidate.
+    /// @custom:selector 77da73b5
     /// @param candidate The address of the supported collator candidate
     /// @param amount The amount bonded in support of the collator candidate
     /// @param candidateDelegationCount The number of delegations in support of the candidate
@@ -165,8 +87,8 @@ interface ParachainStaking {
         uint32 delegatorDelegationCount
     ) external;
 
-    /// @dev Make a delegation in support of a collator candidate
-    /// @custom:selector 4b8bc9bf
+    /// @dev Make a delegation in support of a collator candidate, with auto-compounding rewards.
+    /// @custom:selector 61b2d247
     /// @param candidate The address of the supported collator candidate
     /// @param amount The amount bonded in support of the collator candidate
     /// @param autoCompound The percent of reward that should be auto-compounded
@@ -183,14 +105,68 @@ interface ParachainStaking {
         uint32 delegatorDelegationCount
     ) external;
 
-    /// @notice DEPRECATED use batch util with scheduleRevokeDelegation for all delegations
-    /// @dev Request to leave the set of delegators
-    /// @custom:selector f939dadb
-    function scheduleLeaveDelegators() external;
+    /// @dev The amount delegated in support of the candidate by the delegator.
+    /// @custom:selector 177c8556
+    /// @param delegator Who made this delegation
+    /// @param candidate The candidate for which the delegation is in support of
+    /// @return The amount of the delegation in support of the candidate by the delegator
+    function delegationAmount(
+        bytes memory delegator,
+        bytes32 candidate
+    ) external view returns (uint256);
+
+    /// @dev Returns the percent value of auto-compound set for a delegation.
+    /// @custom:selector 8c78993b
+    /// @param delegator The delegator that made the delegation
+    /// @param candidate The candidate for which the delegation was made
+    /// @return Percent of rewarded amount that is auto-compounded on each payout
+    function delegationAutoCompound(
+        bytes memory delegator,
+        bytes32 candidate
+    ) external view returns (uint8);
+
+    /// @dev Whether there exists a pending request for a delegation made by a delegator.
+    /// @custom:selector f0628985
+    /// @param delegator The delegator that made the delegation
+    /// @param candidate The candidate for which the delegation was made
+    /// @return Whether a pending request exists for such delegation
+    function delegationRequestIsPending(
+        bytes memory delegator,
+        bytes32 candidate
+    ) external view returns (bool);
+
+    /// @dev Bond more for delegators with respect to a specific collator candidate.
+    /// @custom:selector 5bd015e0
+    /// @param candidate The address of the collator candidate for which delegation shall increase
+    /// @param more The amount by which the delegation is increased
+    function delegatorBondMore(bytes32 candidate, uint256 more) external;
+
+    /// @dev Get the DelegatorDelegationCount weight hint.
+    /// @custom:selector 52cb2a2d
+    /// @param delegator The address for which we are querying the delegation count
+    /// @return The number of delegations made by the delegator
+    function delegatorDelegationCount(bytes memory delegator) external view returns (uint32);
+
+    /// @dev Execute pending candidate bond request.
+    /// @custom:selector e0c41737
+    /// @param candidate The address for the candidate for which the request will be executed
+    function executeCandidateBondLess(bytes32 candidate) external;
 
+    /// @dev Execute pending delegation request (if exists && is due).
+    /// @custom:selector 0a6f1b83
+    /// @param delegator The address of the delegator
+    /// @param candidate The address of the candidate
+    function executeDelegationRequest(bytes memory delegator, bytes32 candidate) external;
+
+    /// @dev Execute due request to leave the set of collator candidates.
+    /// @custom:selector c42777e3
+    /// @param candidate The candidate address for which the pending exit request will be executed
+    /// @param candidateCount The number of delegations for the candidate to be revoked
+    function executeLeaveCandidates(bytes32 candidate, uint32 candidateCount) external;
+
+    /// @dev Execute request to leave the set of delegators and revoke all delegations.
+    /// @custom:selector 0fd71530
     /// @notice DEPRECATED use batch util with executeDelegationRequest for all delegations
-    /// @dev Execute request to leave the set of delegators and revoke all delegations
-    /// @custom:selector fb1e2bf9
     /// @param delegator The leaving delegator
     /// @param delegatorDelegationCount The number of active delegations to be revoked by delegator
     function executeLeaveDelegators(
@@ -198,71 +174,282 @@ interface ParachainStaking {
         uint32 delegatorDelegationCount
     ) external;
 
-    /// @notice DEPRECATED use batch util with cancelDelegationRequest for all delegations
-    /// @dev Cancel request to leave the set of delegators
-    /// @custom:selector f7421284
-    function cancelLeaveDelegators() external;
+    /// @dev Fetch the total staked towards a candidate.
+    /// @custom:selector c3c02a96
+    /// @param candidate Address of the candidate
+    /// @return Total amount of stake
+    function getCandidateTotalCounted(bytes32 candidate) external view returns (uint256);
 
-    /// @dev Request to revoke an existing delegation
-    /// @custom:selector 1a1c740c
-    /// @param candidate The address of the collator candidate which will no longer be supported
-    function scheduleRevokeDelegation(bytes32 candidate) external;
+    /// @dev Fetch the total staked amount of a delegator, regardless of the candidate.
+    /// @custom:selector c09a9226
+    /// @param delegator Address of the delegator
+    /// @return Total amount of stake
+    function getDelegatorTotalStaked(bytes memory delegator) external view returns (uint256);
 
-    /// @dev Bond more for delegators with respect to a specific collator candidate
-    /// @custom:selector 0465135b
-    /// @param candidate The address of the collator candidate for which delegation shall increase
-    /// @param more The amount by which the delegation is increased
-    function delegatorBondMore(bytes32 candidate, uint256 more) external;
+    /// @dev Temporarily leave the set of collator candidates without unbonding.
+    /// @custom:selector a6485ccd
+    function goOffline() external;
+
+    /// @dev Rejoin the set of collator candidates if previously had called `goOffline`.
+    /// @custom:selector 6e5b676b
+    function goOnline() external;
+
+    /// @dev Unstake from a delegation without waiting for the revoke delay, for a fee.
+    /// @custom:selector 2c05ff08
+    /// @param candidate The address of the collator candidate the stake is unstaked from
+    /// @param amount The amount unstaked, the delegation is revoked if it is all of it
+    function instantUnstake(bytes32 candidate, uint256 amount) external;
+
+    /// @dev Check whether the specified address is currently a collator candidate.
+    /// @custom:selector b89694c6
+    /// @param candidate The candidate address in form of 32 hex bytes
+    /// @return A boolean confirming whether the address is a collator candidate
+    function isCandidate(bytes32 candidate) external view returns (bool);
+
+    /// @dev Check whether the specified address is currently a staking delegator.
+    /// @custom:selector 34ef9dce
+    /// @param delegator The delegator address in form of 20 or 32 hex bytes
+    /// @return A boolean confirming whether the address is a delegator
+    function isDelegator(bytes memory delegator) external view returns (bool);
+
+    /// @dev Whether the delegation is in the top delegations.
+    /// @custom:selector b9f4e893
+    /// @param delegator Who made this delegation
+    /// @param candidate The candidate for which the delegation is in support of
+    /// @return If delegation is in top delegations (is counted)
+    function isInTopDelegations(
+        bytes memory delegator,
+        bytes32 candidate
+    ) external view returns (bool);
+
+    /// @dev Check whether the specified address is currently a part of the active set.
+    /// @custom:selector d026dbf5
+    /// @param candidate The candidate address in form of 32 hex bytes
+    /// @return A boolean confirming whether the address is a part of the active set
+    function isSelectedCandidate(bytes32 candidate) external view returns (bool);
+
+    /// @dev Join the set of collator candidates.
+    /// @custom:selector 36ab24bf
+    /// @param amount The amount self-bonded by the caller to become a collator candidate
+    /// @param candidateCount The number of candidates in the CandidatePool
+    function joinCandidates(uint256 amount, uint32 candidateCount) external;
+
+    /// @dev Get the minimum delegation amount.
+    /// @custom:selector 02985992
+    /// @return The minimum delegation amount
+    function minDelegation() external view returns (uint128);
+
+    /// @dev Total points awarded to all collators in a particular round.
+    /// @custom:selector 63fa9a87
+    /// @param round The round for which we are querying the points total
+    /// @return The total points awarded to all collators in the round
+    function points(uint32 round) external view returns (uint32);
+
+    /// @dev Get the current round number.
+    /// @custom:selector 146ca531
+    /// @return The current round number
+    function round() external view returns (uint32);
+
+    /// @dev Request to bond less for collator candidates.
+    /// @custom:selector 60744ae0
+    /// @param less The amount to be subtracted from self-bond and unreserved
+    function scheduleCandidateBondLess(uint256 less) external;
 
-    /// @dev Request to bond less for delegators with respect to a specific collator candidate
-    /// @custom:selector c172fd2b
+    /// @dev Request to bond less for delegators with respect to a specific collator candidate.
+    /// @custom:selector 67faedf9
     /// @param candidate The address of the collator candidate for which delegation shall decrease
     /// @param less The amount by which the delegation is decreased (upon execution)
-    function scheduleDelegatorBondLess(bytes32 candidate, uint256 less)
-    external;
+    function scheduleDelegatorBondLess(bytes32 candidate, uint256 less) external;
 
-    /// @dev Execute pending delegation request (if exists && is due)
-    /// @custom:selector e98c8abe
-    /// @param delegator The address of the delegator
-    /// @param candidate The address of the candidate
-    function executeDelegationRequest(bytes memory delegator, bytes32 candidate)
-    external;
+    /// @dev Request to leave the set of collator candidates.
+    /// @custom:selector 440bdf71
+    /// @param candidateCount The number of candidates in the CandidatePool
+    function scheduleLeaveCandidates(uint32 candidateCount) external;
 
-    /// @dev Cancel pending delegation request (already made in support of input by caller)
-    /// @custom:selector c90eee83
-    /// @param candidate The address of the candidate
-    function cancelDelegationRequest(bytes32 candidate) external;
+    /// @dev Request to leave the set of delegators.
+    /// @custom:selector f939dadb
+    /// @notice DEPRECATED use batch util with scheduleRevokeDelegation for all delegations
+    function scheduleLeaveDelegators() external;
+
+    /// @dev Request to revoke an existing delegation.
+    /// @custom:selector 98803c17
+    /// @param candidate The address of the collator candidate which will no longer be supported
+    function scheduleRevokeDelegation(bytes32 candidate) external;
+
+    /// @dev Get the selected candidates for the current round.
+    /// @custom:selector bcf868a6
+    /// @return The selected candidate accounts
+    function selectedCandidates() external view returns (bytes32[] memory);
 
-    /// @dev Sets an auto-compound value for a delegation
-    /// @custom:selector faa1786f
+    /// @dev Sets an auto-compound value for a delegation.
+    /// @custom:selector 448b2004
     /// @param candidate The address of the supported collator candidate
     /// @param value The percent of reward that should be auto-compounded
-    /// @param candidateAutoCompoundingDelegationCount The number of auto-compounding delegations
-    /// in support of the candidate
-    /// @param delegatorDelegationCount The number of existing delegations by the caller
+    /// @param candidateAutoCompoundingDelegationCountHint The number of auto-compounding
+    /// delegations in support of the candidate
+    /// @param delegationCountHint The number of existing delegations by the caller
     function setAutoCompound(
         bytes32 candidate,
         uint8 value,
-        uint32 candidateAutoCompoundingDelegationCount,
-        uint32 delegatorDelegationCount
+        uint32 candidateAutoCompoundingDelegationCountHint,
+        uint32 delegationCountHint
     ) external;
 
-    /// @dev Fetch the total staked amount of a delegator, regardless of the
-    /// candidate.
-    /// @custom:selector e6861713
-    /// @param delegator Address of the delegator.
-    /// @return Total amount of stake.
-    function getDelegatorTotalStaked(bytes memory delegator)
-    external
-    view
-    returns (uint256);
+    /// @dev Move (part of) a delegation from one collator candidate to another.
+    /// @custom:selector 21e58ee3
+    /// @param fromCandidate The address of the collator candidate the stake is moved from
+    /// @param toCandidate The address of the collator candidate the stake is moved to
+    /// @param amount The amount of stake moved
+    /// @param toCandidateDelegationCount The number of existing delegations in support of the
+    /// destination candidate
+    /// @param toCandidateAutoCompoundingDelegationCount The number of auto-compounding
+    /// delegations in support of the destination candidate
+    /// @param fromCandidateDelegationCount The number of existing delegations in support of the
+    /// source candidate
+    /// @param fromCandidateAutoCompoundingDelegationCount The number of auto-compounding
+    /// delegations in support of the source candidate
+    function transferDelegation(
+        bytes32 fromCandidate,
+        bytes32 toCandidate,
+        uint256 amount,
+        uint32 toCandidateDelegationCount,
+        uint32 toCandidateAutoCompoundingDelegationCount,
+        uint32 fromCandidateDelegationCount,
+        uint32 fromCandidateAutoCompoundingDelegationCount
+    ) external;
+    // --- End of generated functions ---
 
-    /// @dev Fetch the total staked towards a candidate.
-    /// @custom:selector bc5a1043
+    /// @dev A candidate joined the candidate pool.
+    /// @param candidate Address of the candidate.
+    /// @param amount Amount bonded by the candidate.
+    event JoinedCandidates(address indexed candidate, uint256 amount);
+
+    /// @dev A candidate scheduled to leave the candidate pool.
+    /// @param candidate Address of the candidate.
+    event ScheduledLeaveCandidates(address indexed candidate);
+
+    /// @dev The exit of a candidate was executed.
+    /// @param caller Address executing the request.
+    /// @param candidate Account id of the candidate.
+    event ExecutedLeaveCandidates(address indexed caller, bytes32 indexed candidate);
+
+    /// @dev A candidate cancelled its exit.
+    /// @param candidate Address of the candidate.
+    event CancelledLeaveCandidates(address indexed candidate);
+
+    /// @dev A candidate went offline.
+    /// @param candidate Address of the candidate.
+    event WentOffline(address indexed candidate);
+
+    /// @dev A candidate went back online.
     /// @param candidate Address of the candidate.
-    /// @return Total amount of stake.
-    function getCandidateTotalCounted(bytes32 candidate)
-    external
-    view
-    returns (uint256);
+    event WentOnline(address indexed candidate);
+
+    /// @dev A candidate increased its bond.
+    /// @param candidate Address of the candidate.
+    /// @param amount Amount added to the bond.
+    event CandidateBondedMore(address indexed candidate, uint256 amount);
+
+    /// @dev A candidate scheduled a decrease of its bond.
+    /// @param candidate Address of the candidate.
+    /// @param amount Amount to remove from the bond.
+    event ScheduledCandidateBondLess(address indexed candidate, uint256 amount);
+
+    /// @dev The bond decrease of a candidate was executed.
+    /// @param caller Address executing the request.
+    /// @param candidate Account id of the candidate.
+    event ExecutedCandidateBondLess(address indexed caller, bytes32 indexed candidate);
+
+    /// @dev A candidate cancelled the decrease of its bond.
+    /// @param candidate Address of the candidate.
+    event CancelledCandidateBondLess(address indexed candidate);
+
+    /// @dev A delegation was made.
+    /// @param delegator Address of the delegator.
+    /// @param candidate Account id of the candidate.
+    /// @param amount Amount delegated.
+    /// @param autoCompound Percentage of the rewards auto-compounded.
+    event Delegated(
+        address indexed delegator,
+        bytes32 indexed candidate,
+        uint256 amount,
+        uint8 autoCompound
+    );
+
+    /// @dev A delegator scheduled to revoke all its delegations.
+    /// @param delegator Address of the delegator.
+    event ScheduledLeaveDelegators(address indexed delegator);
+
+    /// @dev The exit of a delegator was executed.
+    /// @param caller Address executing the request.
+    /// @param delegator Account id of the delegator.
+    event ExecutedLeaveDelegators(address indexed caller, bytes32 indexed delegator);
+
+    /// @dev A delegator cancelled its exit.
+    /// @param delegator Address of the delegator.
+    event CancelledLeaveDelegators(address indexed delegator);
+
+    /// @dev A delegator scheduled to revoke a delegation.
+    /// @param delegator Address of the delegator.
+    /// @param candidate Account id of the candidate.
+    event ScheduledRevokeDelegation(address indexed delegator, bytes32 indexed candidate);
+
+    /// @dev A delegator increased the bond of a delegation.
+    /// @param delegator Address of the delegator.
+    /// @param candidate Account id of the candidate.
+    /// @param amount Amount added to the delegation.
+    event DelegatorBondedMore(
+        address indexed delegator,
+        bytes32 indexed candidate,
+        uint256 amount
+    );
+
+    /// @dev A delegator scheduled a decrease of the bond of a delegation.
+    /// @param delegator Address of the delegator.
+    /// @param candidate Account id of the candidate.
+    /// @param amount Amount to remove from the delegation.
+    event ScheduledDelegatorBondLess(
+        address indexed delegator,
+        bytes32 indexed candidate,
+        uint256 amount
+    );
+
+    /// @dev A delegation request was executed.
+    /// @param caller Address executing the request.
+    /// @param delegator Account id of the delegator.
+    /// @param candidate Account id of the candidate.
+    event ExecutedDelegationRequest(
+        address indexed caller,
+        bytes32 indexed delegator,
+        bytes32 indexed candidate
+    );
+
+    /// @dev A delegator cancelled a delegation request.
+    /// @param delegator Address of the delegator.
+    /// @param candidate Account id of the candidate.
+    event CancelledDelegationRequest(address indexed delegator, bytes32 indexed candidate);
+
+    /// @dev A delegator set the auto-compounding percentage of a delegation.
+    /// @param delegator Address of the delegator.
+    /// @param candidate Account id of the candidate.
+    /// @param value Percentage of the rewards auto-compounded.
+    event AutoCompoundSet(address indexed delegator, bytes32 indexed candidate, uint8 value);
+
+    /// @dev A delegator moved stake from one candidate to another.
+    /// @param delegator Address of the delegator.
+    /// @param fromCandidate Account id of the candidate the stake is moved from.
+    /// @param toCandidate Account id of the candidate the stake is moved to.
+    /// @param amount Amount of stake moved.
+    event DelegationTransferred(
+        address indexed delegator,
+        bytes32 indexed fromCandidate,
+        bytes32 indexed toCandidate,
+        uint256 amount
+    );
+
+    /// @dev A delegator unstaked from a delegation without delay.
+    /// @param delegator Address of the delegator.
+    /// @param candidate Account id of the candidate.
+    /// @param amount Amount unstaked, before the fee.
+    event InstantUnstaked(address indexed delegator, bytes32 indexed candidate, uint256 amount);
 }
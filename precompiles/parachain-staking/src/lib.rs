@@ -21,21 +21,228 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Encode;
-use fp_evm::PrecompileHandle;
+use fp_evm::{Log, PrecompileHandle};
 use frame_support::{
 	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
 	sp_runtime::Percent,
-	traits::{Currency, Get},
+	traits::Currency,
 };
 use pallet_evm::AddressMapping;
 use precompile_utils::prelude::*;
-use sp_core::{ConstU32, H256, U256};
+use sp_core::{ConstU32, H160, H256, U256};
 use sp_std::{convert::TryInto, marker::PhantomData, vec::Vec};
 
+#[cfg(test)]
+mod tests;
+
 type BalanceOf<Runtime> = <<Runtime as pallet_parachain_staking::Config>::Currency as Currency<
 	<Runtime as frame_system::Config>::AccountId,
 >>::Balance;
 
+/// Solidity selector of the `JoinedCandidates` log.
+pub const SELECTOR_LOG_JOINED_CANDIDATES: [u8; 32] =
+	keccak256!("JoinedCandidates(address,uint256)");
+
+/// Solidity selector of the `ScheduledLeaveCandidates` log.
+pub const SELECTOR_LOG_SCHEDULED_LEAVE_CANDIDATES: [u8; 32] =
+	keccak256!("ScheduledLeaveCandidates(address)");
+
+/// Solidity selector of the `ExecutedLeaveCandidates` log.
+pub const SELECTOR_LOG_EXECUTED_LEAVE_CANDIDATES: [u8; 32] =
+	keccak256!("ExecutedLeaveCandidates(address,bytes32)");
+
+/// Solidity selector of the `CancelledLeaveCandidates` log.
+pub const SELECTOR_LOG_CANCELLED_LEAVE_CANDIDATES: [u8; 32] =
+	keccak256!("CancelledLeaveCandidates(address)");
+
+/// Solidity selector of the `WentOffline` log.
+pub const SELECTOR_LOG_WENT_OFFLINE: [u8; 32] = keccak256!("WentOffline(address)");
+
+/// Solidity selector of the `WentOnline` log.
+pub const SELECTOR_LOG_WENT_ONLINE: [u8; 32] = keccak256!("WentOnline(address)");
+
+/// Solidity selector of the `CandidateBondedMore` log.
+pub const SELECTOR_LOG_CANDIDATE_BONDED_MORE: [u8; 32] =
+	keccak256!("CandidateBondedMore(address,uint256)");
+
+/// Solidity selector of the `ScheduledCandidateBondLess` log.
+pub const SELECTOR_LOG_SCHEDULED_CANDIDATE_BOND_LESS: [u8; 32] =
+	keccak256!("ScheduledCandidateBondLess(address,uint256)");
+
+/// Solidity selector of the `ExecutedCandidateBondLess` log.
+pub const SELECTOR_LOG_EXECUTED_CANDIDATE_BOND_LESS: [u8; 32] =
+	keccak256!("ExecutedCandidateBondLess(address,bytes32)");
+
+/// Solidity selector of the `CancelledCandidateBondLess` log.
+pub const SELECTOR_LOG_CANCELLED_CANDIDATE_BOND_LESS: [u8; 32] =
+	keccak256!("CancelledCandidateBondLess(address)");
+
+/// Solidity selector of the `Delegated` log.
+pub const SELECTOR_LOG_DELEGATED: [u8; 32] = keccak256!("Delegated(address,bytes32,uint256,uint8)");
+
+/// Solidity selector of the `ScheduledLeaveDelegators` log.
+pub const SELECTOR_LOG_SCHEDULED_LEAVE_DELEGATORS: [u8; 32] =
+	keccak256!("ScheduledLeaveDelegators(address)");
+
+/// Solidity selector of the `ExecutedLeaveDelegators` log.
+pub const SELECTOR_LOG_EXECUTED_LEAVE_DELEGATORS: [u8; 32] =
+	keccak256!("ExecutedLeaveDelegators(address,bytes32)");
+
+/// Solidity selector of the `CancelledLeaveDelegators` log.
+pub const SELECTOR_LOG_CANCELLED_LEAVE_DELEGATORS: [u8; 32] =
+	keccak256!("CancelledLeaveDelegators(address)");
+
+/// Solidity selector of the `ScheduledRevokeDelegation` log.
+pub const SELECTOR_LOG_SCHEDULED_REVOKE_DELEGATION: [u8; 32] =
+	keccak256!("ScheduledRevokeDelegation(address,bytes32)");
+
+/// Solidity selector of the `DelegatorBondedMore` log.
+pub const SELECTOR_LOG_DELEGATOR_BONDED_MORE: [u8; 32] =
+	keccak256!("DelegatorBondedMore(address,bytes32,uint256)");
+
+/// Solidity selector of the `ScheduledDelegatorBondLess` log.
+pub const SELECTOR_LOG_SCHEDULED_DELEGATOR_BOND_LESS: [u8; 32] =
+	keccak256!("ScheduledDelegatorBondLess(address,bytes32,uint256)");
+
+/// Solidity selector of the `ExecutedDelegationRequest` log.
+pub const SELECTOR_LOG_EXECUTED_DELEGATION_REQUEST: [u8; 32] =
+	keccak256!("ExecutedDelegationRequest(address,bytes32,bytes32)");
+
+/// Solidity selector of the `CancelledDelegationRequest` log.
+pub const SELECTOR_LOG_CANCELLED_DELEGATION_REQUEST: [u8; 32] =
+	keccak256!("CancelledDelegationRequest(address,bytes32)");
+
+/// Solidity selector of the `AutoCompoundSet` log.
+pub const SELECTOR_LOG_AUTO_COMPOUND_SET: [u8; 32] =
+	keccak256!("AutoCompoundSet(address,bytes32,uint8)");
+
+/// Solidity selector of the `DelegationTransferred` log.
+pub const SELECTOR_LOG_DELEGATION_TRANSFERRED: [u8; 32] =
+	keccak256!("DelegationTransferred(address,bytes32,bytes32,uint256)");
+
+/// Solidity selector of the `InstantUnstaked` log.
+pub const SELECTOR_LOG_INSTANT_UNSTAKED: [u8; 32] =
+	keccak256!("InstantUnstaked(address,bytes32,uint256)");
+
+/// Log emitted by the calls of a candidate which don't involve an amount.
+pub fn log_candidate(address: impl Into<H160>, selector: [u8; 32], candidate: H160) -> Log {
+	log2(address.into(), selector, candidate, Vec::new())
+}
+
+/// Log emitted by the calls of a candidate bonding or unbonding an amount.
+pub fn log_candidate_amount(
+	address: impl Into<H160>,
+	selector: [u8; 32],
+	candidate: H160,
+	amount: U256,
+) -> Log {
+	log2(address.into(), selector, candidate, EvmDataWriter::new().write(amount).build())
+}
+
+/// Log emitted when a request is executed on behalf of an account, by any caller.
+pub fn log_executed(
+	address: impl Into<H160>,
+	selector: [u8; 32],
+	caller: H160,
+	account: H256,
+) -> Log {
+	log3(address.into(), selector, caller, account, Vec::new())
+}
+
+/// Log emitted by the calls of a delegator which don't involve an amount.
+pub fn log_delegator(address: impl Into<H160>, selector: [u8; 32], delegator: H160) -> Log {
+	log2(address.into(), selector, delegator, Vec::new())
+}
+
+/// Log emitted by the calls of a delegator about one of its delegations.
+pub fn log_delegation(
+	address: impl Into<H160>,
+	selector: [u8; 32],
+	delegator: H160,
+	candidate: H256,
+) -> Log {
+	log3(address.into(), selector, delegator, candidate, Vec::new())
+}
+
+/// Log emitted by the calls of a delegator bonding or unbonding an amount on a delegation.
+pub fn log_delegation_amount(
+	address: impl Into<H160>,
+	selector: [u8; 32],
+	delegator: H160,
+	candidate: H256,
+	amount: U256,
+) -> Log {
+	log3(address.into(), selector, delegator, candidate, EvmDataWriter::new().write(amount).build())
+}
+
+/// Log emitted when a delegation is made.
+pub fn log_delegated(
+	address: impl Into<H160>,
+	delegator: H160,
+	candidate: H256,
+	amount: U256,
+	auto_compound: u8,
+) -> Log {
+	log3(
+		address.into(),
+		SELECTOR_LOG_DELEGATED,
+		delegator,
+		candidate,
+		EvmDataWriter::new().write(amount).write(auto_compound).build(),
+	)
+}
+
+/// Log emitted when the auto-compounding percentage of a delegation is set.
+pub fn log_auto_compound_set(
+	address: impl Into<H160>,
+	delegator: H160,
+	candidate: H256,
+	value: u8,
+) -> Log {
+	log3(
+		address.into(),
+		SELECTOR_LOG_AUTO_COMPOUND_SET,
+		delegator,
+		candidate,
+		EvmDataWriter::new().write(value).build(),
+	)
+}
+
+/// Log emitted when stake is moved from one delegation to another.
+pub fn log_delegation_transferred(
+	address: impl Into<H160>,
+	delegator: H160,
+	from_candidate: H256,
+	to_candidate: H256,
+	amount: U256,
+) -> Log {
+	log4(
+		address.into(),
+		SELECTOR_LOG_DELEGATION_TRANSFERRED,
+		delegator,
+		from_candidate,
+		to_candidate,
+		EvmDataWriter::new().write(amount).build(),
+	)
+}
+
+/// Log emitted when a delegation request is executed, by any caller.
+pub fn log_executed_delegation_request(
+	address: impl Into<H160>,
+	caller: H160,
+	delegator: H256,
+	candidate: H256,
+) -> Log {
+	log4(
+		address.into(),
+		SELECTOR_LOG_EXECUTED_DELEGATION_REQUEST,
+		caller,
+		delegator,
+		candidate,
+		Vec::new(),
+	)
+}
+
 /// A precompile to wrap the functionality from parachain_staking.
 ///
 /// EXAMPLE USECASE:
@@ -46,6 +253,7 @@ type BalanceOf<Runtime> = <<Runtime as pallet_parachain_staking::Config>::Curren
 pub struct ParachainStakingPrecompile<Runtime>(PhantomData<Runtime>);
 
 #[precompile_utils::precompile]
+#[precompile::solidity_interface("ParachainStakingInterface.sol")]
 impl<Runtime> ParachainStakingPrecompile<Runtime>
 where
 	Runtime: pallet_parachain_staking::Config + pallet_evm::Config,
@@ -56,16 +264,15 @@ where
 	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + EvmData,
 {
 	// Constants
+	/// Get the minimum delegation amount.
+	/// @return The minimum delegation amount
 	#[precompile::public("minDelegation()")]
 	#[precompile::public("min_delegation()")]
 	#[precompile::view]
 	fn min_delegation(handle: &mut impl PrecompileHandle) -> EvmResult<u128> {
 		// Fetch info.
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
-		let min_nomination: u128 =
-			<<Runtime as pallet_parachain_staking::Config>::MinDelegation as Get<
-				BalanceOf<Runtime>,
-			>>::get()
+		let min_nomination: u128 = <pallet_parachain_staking::Pallet<Runtime>>::min_delegation()
 			.try_into()
 			.map_err(|_| revert("Amount is too large for provided balance type"))?;
 
@@ -73,6 +280,9 @@ where
 	}
 
 	// Storage Getters
+	/// Total points awarded to all collators in a particular round.
+	/// @param round The round for which we are querying the points total
+	/// @return The total points awarded to all collators in the round
 	#[precompile::public("points(uint32)")]
 	#[precompile::view]
 	fn points(handle: &mut impl PrecompileHandle, round: u32) -> EvmResult<u32> {
@@ -83,7 +293,12 @@ where
 		Ok(points)
 	}
 
-	/// Points for each collator per round
+	/// Total points awarded to a specific collator in a particular round.
+	/// A value of `0` may signify that no blocks were produced or that the storage for that
+	/// round has been removed.
+	/// @param round The round for which we are querying the awarded points
+	/// @param candidate The candidate to whom the points are awarded
+	/// @return The total points awarded to the collator for the provided round
 	#[precompile::public("awardedPoints(uint32,bytes32)")]
 	#[precompile::view]
 	fn awarded_points(
@@ -99,6 +314,8 @@ where
 		Ok(points)
 	}
 
+	/// Get the CandidateCount weight hint.
+	/// @return The CandidateCount weight hint
 	#[precompile::public("candidateCount()")]
 	#[precompile::public("candidate_count()")]
 	#[precompile::view]
@@ -106,12 +323,14 @@ where
 		// Fetch info.
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
 		let candidate_count: u32 =
-			<pallet_parachain_staking::Pallet<Runtime>>::candidate_pool().0.len() as u32;
+			<pallet_parachain_staking::Pallet<Runtime>>::candidate_pool_count();
 
 		// Build output.
 		Ok(candidate_count)
 	}
 
+	/// Get the current round number.
+	/// @return The current round number
 	#[precompile::public("round()")]
 	#[precompile::view]
 	fn round(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
@@ -123,6 +342,9 @@ where
 	}
 
 	// Query candidate delegation_count
+	/// Get the CandidateDelegationCount weight hint.
+	/// @param candidate The address for which we are querying the nomination count
+	/// @return The number of nominations backing the collator
 	#[precompile::public("candidateDelegationCount(bytes32)")]
 	#[precompile::public("candidate_delegation_count(bytes32)")]
 	#[precompile::view]
@@ -158,6 +380,10 @@ where
 	}
 
 	// Query candidate auto_compounding_delegations_count
+	/// Get the CandidateAutoCompoundingDelegationCount weight hint.
+	/// @param candidate The address for which we are querying the auto compounding delegation
+	/// count
+	/// @return The number of auto compounding delegations
 	#[precompile::public("candidateAutoCompoundingDelegationCount(bytes32)")]
 	#[precompile::view]
 	fn candidate_auto_compounding_delegation_count(
@@ -177,6 +403,9 @@ where
 
 	// Query delegator delegate count
 	// 20 / 32 bytes
+	/// Get the DelegatorDelegationCount weight hint.
+	/// @param delegator The address for which we are querying the delegation count
+	/// @return The number of delegations made by the delegator
 	#[precompile::public("delegatorDelegationCount(bytes)")]
 	#[precompile::public("delegator_delegation_count(bytes)")]
 	#[precompile::view]
@@ -213,6 +442,8 @@ where
 	}
 
 	// Query all candidates
+	/// Get the selected candidates for the current round.
+	/// @return The selected candidate accounts
 	#[precompile::public("selectedCandidates()")]
 	#[precompile::public("selected_candidates()")]
 	#[precompile::view]
@@ -229,6 +460,10 @@ where
 	}
 
 	// Query delegator delegate amount for candidate
+	/// The amount delegated in support of the candidate by the delegator.
+	/// @param delegator Who made this delegation
+	/// @param candidate The candidate for which the delegation is in support of
+	/// @return The amount of the delegation in support of the candidate by the delegator
 	#[precompile::public("delegationAmount(bytes,bytes32)")]
 	#[precompile::view]
 	fn delegation_amount(
@@ -249,6 +484,10 @@ where
 	}
 
 	// Role Verifiers
+	/// Whether the delegation is in the top delegations.
+	/// @param delegator Who made this delegation
+	/// @param candidate The candidate for which the delegation is in support of
+	/// @return If delegation is in top delegations (is counted)
 	#[precompile::public("isInTopDelegations(bytes,bytes32)")]
 	#[precompile::view]
 	fn is_in_top_delegations(
@@ -272,6 +511,9 @@ where
 	}
 
 	// Query is_delegator
+	/// Check whether the specified address is currently a staking delegator.
+	/// @param delegator The delegator address in form of 20 or 32 hex bytes
+	/// @return A boolean confirming whether the address is a delegator
 	#[precompile::public("isDelegator(bytes)")]
 	#[precompile::public("is_delegator(bytes)")]
 	#[precompile::view]
@@ -289,6 +531,9 @@ where
 		Ok(is_delegator)
 	}
 
+	/// Check whether the specified address is currently a collator candidate.
+	/// @param candidate The candidate address in form of 32 hex bytes
+	/// @return A boolean confirming whether the address is a collator candidate
 	#[precompile::public("isCandidate(bytes32)")]
 	#[precompile::public("is_candidate(bytes32)")]
 	#[precompile::view]
@@ -302,6 +547,9 @@ where
 		Ok(is_candidate)
 	}
 
+	/// Check whether the specified address is currently a part of the active set.
+	/// @param candidate The candidate address in form of 32 hex bytes
+	/// @return A boolean confirming whether the address is a part of the active set
 	#[precompile::public("isSelectedCandidate(bytes32)")]
 	#[precompile::public("is_selected_candidate(bytes32)")]
 	#[precompile::view]
@@ -318,6 +566,10 @@ where
 		Ok(is_selected)
 	}
 
+	/// Whether there exists a pending request for a delegation made by a delegator.
+	/// @param delegator The delegator that made the delegation
+	/// @param candidate The candidate for which the delegation was made
+	/// @return Whether a pending request exists for such delegation
 	#[precompile::public("delegationRequestIsPending(bytes,bytes32)")]
 	#[precompile::public("delegation_request_is_pending(bytes,bytes32)")]
 	#[precompile::view]
@@ -341,6 +593,9 @@ where
 		Ok(pending)
 	}
 
+	/// Whether there exists a pending exit for candidate.
+	/// @param candidate The candidate for which the exit request was made
+	/// @return Whether a pending exit request exists for the candidate
 	#[precompile::public("candidateExitIsPending(bytes32)")]
 	#[precompile::public("candidate_exit_is_pending(bytes32)")]
 	#[precompile::view]
@@ -371,6 +626,9 @@ where
 		Ok(pending)
 	}
 
+	/// Whether there exists a pending bond less request made by a candidate.
+	/// @param candidate The candidate which made the request
+	/// @return Whether a pending bond less request was made by the candidate
 	#[precompile::public("candidateRequestIsPending(bytes32)")]
 	#[precompile::public("candidate_request_is_pending(bytes32)")]
 	#[precompile::view]
@@ -400,6 +658,10 @@ where
 		Ok(pending)
 	}
 
+	/// Returns the percent value of auto-compound set for a delegation.
+	/// @param delegator The delegator that made the delegation
+	/// @param candidate The candidate for which the delegation was made
+	/// @return Percent of rewarded amount that is auto-compounded on each payout
 	#[precompile::public("delegationAutoCompound(bytes,bytes32)")]
 	#[precompile::view]
 	fn delegation_auto_compound(
@@ -422,6 +684,9 @@ where
 
 	// Runtime Methods (dispatchables)
 
+	/// Join the set of collator candidates.
+	/// @param amount The amount self-bonded by the caller to become a collator candidate
+	/// @param candidateCount The number of candidates in the CandidatePool
 	#[precompile::public("joinCandidates(uint256,uint32)")]
 	#[precompile::public("join_candidates(uint256,uint32)")]
 	fn join_candidates(
@@ -429,6 +694,14 @@ where
 		amount: U256,
 		candidate_count: u32,
 	) -> EvmResult {
+		let event = log_candidate_amount(
+			handle.context().address,
+			SELECTOR_LOG_JOINED_CANDIDATES,
+			handle.context().caller,
+			amount,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let amount = Self::u256_to_amount(amount).in_field("amount")?;
 
 		// Build call with origin.
@@ -441,15 +714,26 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Request to leave the set of collator candidates.
+	/// @param candidateCount The number of candidates in the CandidatePool
 	#[precompile::public("scheduleLeaveCandidates(uint32)")]
 	#[precompile::public("schedule_leave_candidates(uint32)")]
 	fn schedule_leave_candidates(
 		handle: &mut impl PrecompileHandle,
 		candidate_count: u32,
 	) -> EvmResult {
+		let event = log_candidate(
+			handle.context().address,
+			SELECTOR_LOG_SCHEDULED_LEAVE_CANDIDATES,
+			handle.context().caller,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call = pallet_parachain_staking::Call::<Runtime>::schedule_leave_candidates {
@@ -459,9 +743,14 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Execute due request to leave the set of collator candidates.
+	/// @param candidate The candidate address for which the pending exit request will be executed
+	/// @param candidateCount The number of delegations for the candidate to be revoked
 	#[precompile::public("executeLeaveCandidates(bytes32,uint32)")]
 	#[precompile::public("execute_leave_candidates(bytes32,uint32)")]
 	fn execute_leave_candidates(
@@ -469,6 +758,14 @@ where
 		candidate: H256,
 		candidate_count: u32,
 	) -> EvmResult {
+		let event = log_executed(
+			handle.context().address,
+			SELECTOR_LOG_EXECUTED_LEAVE_CANDIDATES,
+			handle.context().caller,
+			candidate,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let candidate = Runtime::AccountId::from(candidate.0);
 
 		// Build call with origin.
@@ -481,15 +778,26 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Cancel request to leave the set of collator candidates.
+	/// @param candidateCount The number of candidates in the CandidatePool
 	#[precompile::public("cancelLeaveCandidates(uint32)")]
 	#[precompile::public("cancel_leave_candidates(uint32)")]
 	fn cancel_leave_candidates(
 		handle: &mut impl PrecompileHandle,
 		candidate_count: u32,
 	) -> EvmResult {
+		let event = log_candidate(
+			handle.context().address,
+			SELECTOR_LOG_CANCELLED_LEAVE_CANDIDATES,
+			handle.context().caller,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call =
@@ -498,12 +806,22 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Temporarily leave the set of collator candidates without unbonding.
 	#[precompile::public("goOffline()")]
 	#[precompile::public("go_offline()")]
 	fn go_offline(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let event = log_candidate(
+			handle.context().address,
+			SELECTOR_LOG_WENT_OFFLINE,
+			handle.context().caller,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call = pallet_parachain_staking::Call::<Runtime>::go_offline {};
@@ -511,12 +829,22 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Rejoin the set of collator candidates if previously had called `goOffline`.
 	#[precompile::public("goOnline()")]
 	#[precompile::public("go_online()")]
 	fn go_online(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let event = log_candidate(
+			handle.context().address,
+			SELECTOR_LOG_WENT_ONLINE,
+			handle.context().caller,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call = pallet_parachain_staking::Call::<Runtime>::go_online {};
@@ -524,12 +852,24 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Request to bond more for collator candidates.
+	/// @param more The additional amount self-bonded
 	#[precompile::public("candidateBondMore(uint256)")]
 	#[precompile::public("candidate_bond_more(uint256)")]
 	fn candidate_bond_more(handle: &mut impl PrecompileHandle, more: U256) -> EvmResult {
+		let event = log_candidate_amount(
+			handle.context().address,
+			SELECTOR_LOG_CANDIDATE_BONDED_MORE,
+			handle.context().caller,
+			more,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let more = Self::u256_to_amount(more).in_field("more")?;
 
 		// Build call with origin.
@@ -539,12 +879,24 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Request to bond less for collator candidates.
+	/// @param less The amount to be subtracted from self-bond and unreserved
 	#[precompile::public("scheduleCandidateBondLess(uint256)")]
 	#[precompile::public("schedule_candidate_bond_less(uint256)")]
 	fn schedule_candidate_bond_less(handle: &mut impl PrecompileHandle, less: U256) -> EvmResult {
+		let event = log_candidate_amount(
+			handle.context().address,
+			SELECTOR_LOG_SCHEDULED_CANDIDATE_BOND_LESS,
+			handle.context().caller,
+			less,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let less = Self::u256_to_amount(less).in_field("less")?;
 
 		// Build call with origin.
@@ -554,15 +906,27 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Execute pending candidate bond request.
+	/// @param candidate The address for the candidate for which the request will be executed
 	#[precompile::public("executeCandidateBondLess(bytes32)")]
 	#[precompile::public("execute_candidate_bond_less(bytes32)")]
 	fn execute_candidate_bond_less(
 		handle: &mut impl PrecompileHandle,
 		candidate: H256,
 	) -> EvmResult {
+		let event = log_executed(
+			handle.context().address,
+			SELECTOR_LOG_EXECUTED_CANDIDATE_BOND_LESS,
+			handle.context().caller,
+			candidate,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let candidate = Runtime::AccountId::from(candidate.0);
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
@@ -572,12 +936,22 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Cancel pending candidate bond request.
 	#[precompile::public("cancelCandidateBondLess()")]
 	#[precompile::public("cancel_candidate_bond_less()")]
 	fn cancel_candidate_bond_less(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let event = log_candidate(
+			handle.context().address,
+			SELECTOR_LOG_CANCELLED_CANDIDATE_BOND_LESS,
+			handle.context().caller,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call = pallet_parachain_staking::Call::<Runtime>::cancel_candidate_bond_less {};
@@ -585,9 +959,16 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Make a delegation in support of a collator candidate.
+	/// @param candidate The address of the supported collator candidate
+	/// @param amount The amount bonded in support of the collator candidate
+	/// @param candidateDelegationCount The number of delegations in support of the candidate
+	/// @param delegatorDelegationCount The number of existing delegations by the caller
 	#[precompile::public("delegate(bytes32,uint256,uint32,uint32)")]
 	fn delegate(
 		handle: &mut impl PrecompileHandle,
@@ -596,6 +977,10 @@ where
 		candidate_delegation_count: u32,
 		delegator_delegation_count: u32,
 	) -> EvmResult {
+		let event =
+			log_delegated(handle.context().address, handle.context().caller, candidate, amount, 0);
+		handle.record_log_costs(&[&event])?;
+
 		let amount = Self::u256_to_amount(amount).in_field("amount")?;
 		let candidate = Runtime::AccountId::from(candidate.0);
 
@@ -611,9 +996,19 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Make a delegation in support of a collator candidate, with auto-compounding rewards.
+	/// @param candidate The address of the supported collator candidate
+	/// @param amount The amount bonded in support of the collator candidate
+	/// @param autoCompound The percent of reward that should be auto-compounded
+	/// @param candidateDelegationCount The number of delegations in support of the candidate
+	/// @param candidateAutoCompoundingDelegationCount The number of auto-compounding delegations
+	/// in support of the candidate
+	/// @param delegatorDelegationCount The number of existing delegations by the caller
 	#[precompile::public("delegateWithAutoCompound(bytes32,uint256,uint8,uint32,uint32,uint32)")]
 	fn delegate_with_auto_compound(
 		handle: &mut impl PrecompileHandle,
@@ -630,6 +1025,15 @@ where
 				.into());
 		}
 
+		let event = log_delegated(
+			handle.context().address,
+			handle.context().caller,
+			candidate,
+			amount,
+			auto_compound,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let amount = Self::u256_to_amount(amount).in_field("amount")?;
 		let auto_compound = Percent::from_percent(auto_compound);
 
@@ -648,13 +1052,23 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
-	/// Deprecated in favor of batch util
+	/// Request to leave the set of delegators.
+	/// @notice DEPRECATED use batch util with scheduleRevokeDelegation for all delegations
 	#[precompile::public("scheduleLeaveDelegators()")]
 	#[precompile::public("schedule_leave_delegators()")]
 	fn schedule_leave_delegators(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let event = log_delegator(
+			handle.context().address,
+			SELECTOR_LOG_SCHEDULED_LEAVE_DELEGATORS,
+			handle.context().caller,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call = pallet_parachain_staking::Call::<Runtime>::schedule_leave_delegators {};
@@ -662,10 +1076,15 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
-	/// Deprecated in favor of batch util
+	/// Execute request to leave the set of delegators and revoke all delegations.
+	/// @notice DEPRECATED use batch util with executeDelegationRequest for all delegations
+	/// @param delegator The leaving delegator
+	/// @param delegatorDelegationCount The number of active delegations to be revoked by delegator
 	#[precompile::public("executeLeaveDelegators(bytes,uint32)")]
 	#[precompile::public("execute_leave_delegators(bytes,uint32)")]
 	fn execute_leave_delegators(
@@ -675,6 +1094,15 @@ where
 	) -> EvmResult {
 		let delegator_vec: Vec<u8> = Vec::<u8>::from(delegator);
 		let delegator = Self::parse_input_address(delegator_vec)?;
+
+		let event = log_executed(
+			handle.context().address,
+			SELECTOR_LOG_EXECUTED_LEAVE_DELEGATORS,
+			handle.context().caller,
+			H256::from_slice(&delegator.encode()[..]),
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call = pallet_parachain_staking::Call::<Runtime>::execute_leave_delegators {
@@ -685,13 +1113,23 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
-	/// Deprecated in favor of batch util
+	/// Cancel request to leave the set of delegators.
+	/// @notice DEPRECATED use batch util with cancelDelegationRequest for all delegations
 	#[precompile::public("cancelLeaveDelegators()")]
 	#[precompile::public("cancel_leave_delegators()")]
 	fn cancel_leave_delegators(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let event = log_delegator(
+			handle.context().address,
+			SELECTOR_LOG_CANCELLED_LEAVE_DELEGATORS,
+			handle.context().caller,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let call = pallet_parachain_staking::Call::<Runtime>::cancel_leave_delegators {};
@@ -699,15 +1137,27 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Request to revoke an existing delegation.
+	/// @param candidate The address of the collator candidate which will no longer be supported
 	#[precompile::public("scheduleRevokeDelegation(bytes32)")]
 	#[precompile::public("schedule_revoke_delegation(bytes32)")]
 	fn schedule_revoke_delegation(
 		handle: &mut impl PrecompileHandle,
 		candidate: H256,
 	) -> EvmResult {
+		let event = log_delegation(
+			handle.context().address,
+			SELECTOR_LOG_SCHEDULED_REVOKE_DELEGATION,
+			handle.context().caller,
+			candidate,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let candidate = Runtime::AccountId::from(candidate.0);
 
 		// Build call with origin.
@@ -719,9 +1169,14 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Bond more for delegators with respect to a specific collator candidate.
+	/// @param candidate The address of the collator candidate for which delegation shall increase
+	/// @param more The amount by which the delegation is increased
 	#[precompile::public("delegatorBondMore(bytes32,uint256)")]
 	#[precompile::public("delegator_bond_more(bytes32,uint256)")]
 	fn delegator_bond_more(
@@ -729,6 +1184,15 @@ where
 		candidate: H256,
 		more: U256,
 	) -> EvmResult {
+		let event = log_delegation_amount(
+			handle.context().address,
+			SELECTOR_LOG_DELEGATOR_BONDED_MORE,
+			handle.context().caller,
+			candidate,
+			more,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let candidate = Runtime::AccountId::from(candidate.0);
 		let more = Self::u256_to_amount(more).in_field("more")?;
 
@@ -740,9 +1204,14 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Request to bond less for delegators with respect to a specific collator candidate.
+	/// @param candidate The address of the collator candidate for which delegation shall decrease
+	/// @param less The amount by which the delegation is decreased (upon execution)
 	#[precompile::public("scheduleDelegatorBondLess(bytes32,uint256)")]
 	#[precompile::public("schedule_delegator_bond_less(bytes32,uint256)")]
 	fn schedule_delegator_bond_less(
@@ -750,6 +1219,15 @@ where
 		candidate: H256,
 		less: U256,
 	) -> EvmResult {
+		let event = log_delegation_amount(
+			handle.context().address,
+			SELECTOR_LOG_SCHEDULED_DELEGATOR_BOND_LESS,
+			handle.context().caller,
+			candidate,
+			less,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let candidate = Runtime::AccountId::from(candidate.0);
 		let less = Self::u256_to_amount(less).in_field("less")?;
 
@@ -763,9 +1241,14 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Execute pending delegation request (if exists && is due).
+	/// @param delegator The address of the delegator
+	/// @param candidate The address of the candidate
 	#[precompile::public("executeDelegationRequest(bytes,bytes32)")]
 	#[precompile::public("execute_delegation_request(bytes,bytes32)")]
 	fn execute_delegation_request(
@@ -775,6 +1258,15 @@ where
 	) -> EvmResult {
 		let delegator_vec: Vec<u8> = Vec::<u8>::from(delegator);
 		let delegator = Self::parse_input_address(delegator_vec)?;
+
+		let event = log_executed_delegation_request(
+			handle.context().address,
+			handle.context().caller,
+			H256::from_slice(&delegator.encode()[..]),
+			candidate,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let candidate = Runtime::AccountId::from(candidate.0);
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
@@ -786,12 +1278,24 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Cancel pending delegation request (already made in support of input by caller).
+	/// @param candidate The address of the candidate
 	#[precompile::public("cancelDelegationRequest(bytes32)")]
 	#[precompile::public("cancel_delegation_request(bytes32)")]
 	fn cancel_delegation_request(handle: &mut impl PrecompileHandle, candidate: H256) -> EvmResult {
+		let event = log_delegation(
+			handle.context().address,
+			SELECTOR_LOG_CANCELLED_DELEGATION_REQUEST,
+			handle.context().caller,
+			candidate,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let candidate = Runtime::AccountId::from(candidate.0);
 		// Build call with origin.
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
@@ -801,9 +1305,17 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Sets an auto-compound value for a delegation.
+	/// @param candidate The address of the supported collator candidate
+	/// @param value The percent of reward that should be auto-compounded
+	/// @param candidateAutoCompoundingDelegationCountHint The number of auto-compounding
+	/// delegations in support of the candidate
+	/// @param delegationCountHint The number of existing delegations by the caller
 	#[precompile::public("setAutoCompound(bytes32,uint8,uint32,uint32)")]
 	fn set_auto_compound(
 		handle: &mut impl PrecompileHandle,
@@ -818,6 +1330,14 @@ where
 				.into());
 		}
 
+		let event = log_auto_compound_set(
+			handle.context().address,
+			handle.context().caller,
+			candidate,
+			value,
+		);
+		handle.record_log_costs(&[&event])?;
+
 		let value = Percent::from_percent(value);
 		let candidate = Runtime::AccountId::from(candidate.0);
 		// Build call with origin.
@@ -832,9 +1352,103 @@ where
 		// Dispatch call (if enough gas).
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
+		event.record(handle)?;
+
+		Ok(())
+	}
+
+	/// Move (part of) a delegation from one collator candidate to another.
+	/// @param fromCandidate The address of the collator candidate the stake is moved from
+	/// @param toCandidate The address of the collator candidate the stake is moved to
+	/// @param amount The amount of stake moved
+	/// @param toCandidateDelegationCount The number of existing delegations in support of the
+	/// destination candidate
+	/// @param toCandidateAutoCompoundingDelegationCount The number of auto-compounding
+	/// delegations in support of the destination candidate
+	/// @param fromCandidateDelegationCount The number of existing delegations in support of the
+	/// source candidate
+	/// @param fromCandidateAutoCompoundingDelegationCount The number of auto-compounding
+	/// delegations in support of the source candidate
+	#[precompile::public("transferDelegation(bytes32,bytes32,uint256,uint32,uint32,uint32,uint32)")]
+	fn transfer_delegation(
+		handle: &mut impl PrecompileHandle,
+		from_candidate: H256,
+		to_candidate: H256,
+		amount: U256,
+		to_candidate_delegation_count: u32,
+		to_candidate_auto_compounding_delegation_count: u32,
+		from_candidate_delegation_count: u32,
+		from_candidate_auto_compounding_delegation_count: u32,
+	) -> EvmResult {
+		let event = log_delegation_transferred(
+			handle.context().address,
+			handle.context().caller,
+			from_candidate,
+			to_candidate,
+			amount,
+		);
+		handle.record_log_costs(&[&event])?;
+
+		let from_candidate = Runtime::AccountId::from(from_candidate.0);
+		let to_candidate = Runtime::AccountId::from(to_candidate.0);
+		let amount = Self::u256_to_amount(amount).in_field("amount")?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::transfer_delegation {
+			from_candidate,
+			to_candidate,
+			amount,
+			to_candidate_delegation_count,
+			to_candidate_auto_compounding_delegation_count,
+			from_candidate_delegation_count,
+			from_candidate_auto_compounding_delegation_count,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		event.record(handle)?;
+
+		Ok(())
+	}
+
+	/// Unstake from a delegation without waiting for the revoke delay, for a fee.
+	/// @param candidate The address of the collator candidate the stake is unstaked from
+	/// @param amount The amount unstaked, the delegation is revoked if it is all of it
+	#[precompile::public("instantUnstake(bytes32,uint256)")]
+	fn instant_unstake(
+		handle: &mut impl PrecompileHandle,
+		candidate: H256,
+		amount: U256,
+	) -> EvmResult {
+		let event = log_delegation_amount(
+			handle.context().address,
+			SELECTOR_LOG_INSTANT_UNSTAKED,
+			handle.context().caller,
+			candidate,
+			amount,
+		);
+		handle.record_log_costs(&[&event])?;
+
+		let candidate = Runtime::AccountId::from(candidate.0);
+		let amount = Self::u256_to_amount(amount).in_field("amount")?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::instant_unstake { candidate, amount };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		event.record(handle)?;
+
 		Ok(())
 	}
 
+	/// Fetch the total staked amount of a delegator, regardless of the candidate.
+	/// @param delegator Address of the delegator
+	/// @return Total amount of stake
 	#[precompile::public("getDelegatorTotalStaked(bytes)")]
 	#[precompile::view]
 	fn get_delegator_total_staked(
@@ -853,6 +1467,9 @@ where
 		Ok(amount.into())
 	}
 
+	/// Fetch the total staked towards a candidate.
+	/// @param candidate Address of the candidate
+	/// @return Total amount of stake
 	#[precompile::public("getCandidateTotalCounted(bytes32)")]
 	#[precompile::view]
 	fn get_candidate_total_counted(
@@ -0,0 +1,161 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use sha3::{Digest, Keccak256};
+
+const PRECOMPILE: H160 = H160([0x08; 20]);
+const CALLER: H160 = H160([0xAA; 20]);
+const CANDIDATE: H256 = H256([0xBB; 32]);
+const DELEGATOR: H256 = H256([0xCC; 32]);
+
+/// Canonical signatures of the events declared in the Solidity interface.
+fn interface_event_signatures() -> Vec<String> {
+	let interface = include_str!("../ParachainStakingInterface.sol");
+
+	interface
+		.split("event ")
+		.skip(1)
+		.map(|declaration| {
+			let declaration: String = declaration.split(");").next().unwrap().into();
+			let (name, params) = declaration.split_once('(').unwrap();
+			let types: Vec<_> = params
+				.split(',')
+				.map(|param| param.split_whitespace().next().unwrap())
+				.collect();
+			format!("{}({})", name.trim(), types.join(","))
+		})
+		.collect()
+}
+
+#[test]
+fn interface_events_match_log_selectors() {
+	let selectors = [
+		SELECTOR_LOG_JOINED_CANDIDATES,
+		SELECTOR_LOG_SCHEDULED_LEAVE_CANDIDATES,
+		SELECTOR_LOG_EXECUTED_LEAVE_CANDIDATES,
+		SELECTOR_LOG_CANCELLED_LEAVE_CANDIDATES,
+		SELECTOR_LOG_WENT_OFFLINE,
+		SELECTOR_LOG_WENT_ONLINE,
+		SELECTOR_LOG_CANDIDATE_BONDED_MORE,
+		SELECTOR_LOG_SCHEDULED_CANDIDATE_BOND_LESS,
+		SELECTOR_LOG_EXECUTED_CANDIDATE_BOND_LESS,
+		SELECTOR_LOG_CANCELLED_CANDIDATE_BOND_LESS,
+		SELECTOR_LOG_DELEGATED,
+		SELECTOR_LOG_SCHEDULED_LEAVE_DELEGATORS,
+		SELECTOR_LOG_EXECUTED_LEAVE_DELEGATORS,
+		SELECTOR_LOG_CANCELLED_LEAVE_DELEGATORS,
+		SELECTOR_LOG_SCHEDULED_REVOKE_DELEGATION,
+		SELECTOR_LOG_DELEGATOR_BONDED_MORE,
+		SELECTOR_LOG_SCHEDULED_DELEGATOR_BOND_LESS,
+		SELECTOR_LOG_EXECUTED_DELEGATION_REQUEST,
+		SELECTOR_LOG_CANCELLED_DELEGATION_REQUEST,
+		SELECTOR_LOG_AUTO_COMPOUND_SET,
+		SELECTOR_LOG_DELEGATION_TRANSFERRED,
+		SELECTOR_LOG_INSTANT_UNSTAKED,
+	];
+
+	let signatures = interface_event_signatures();
+	assert_eq!(signatures.len(), selectors.len());
+
+	for signature in signatures {
+		let selector: [u8; 32] = Keccak256::digest(signature.as_bytes()).into();
+		assert!(selectors.contains(&selector), "no log selector for event {signature}");
+	}
+}
+
+#[test]
+fn candidate_log_only_indexes_the_candidate() {
+	let log = log_candidate(PRECOMPILE, SELECTOR_LOG_WENT_OFFLINE, CALLER);
+
+	assert_eq!(log.address, PRECOMPILE);
+	assert_eq!(log.topics, vec![H256(SELECTOR_LOG_WENT_OFFLINE), CALLER.into()]);
+	assert!(log.data.is_empty());
+}
+
+#[test]
+fn candidate_amount_log_encodes_the_amount() {
+	let log = log_candidate_amount(
+		PRECOMPILE,
+		SELECTOR_LOG_JOINED_CANDIDATES,
+		CALLER,
+		U256::from(1_000u32),
+	);
+
+	assert_eq!(log.topics, vec![H256(SELECTOR_LOG_JOINED_CANDIDATES), CALLER.into()]);
+	assert_eq!(log.data, EvmDataWriter::new().write(U256::from(1_000u32)).build());
+}
+
+#[test]
+fn address_topics_are_left_padded() {
+	let log = log_delegator(PRECOMPILE, SELECTOR_LOG_SCHEDULED_LEAVE_DELEGATORS, CALLER);
+
+	assert_eq!(&log.topics[1][..12], &[0u8; 12]);
+	assert_eq!(&log.topics[1][12..], CALLER.as_bytes());
+}
+
+#[test]
+fn delegation_amount_log_indexes_delegator_and_candidate() {
+	let log = log_delegation_amount(
+		PRECOMPILE,
+		SELECTOR_LOG_DELEGATOR_BONDED_MORE,
+		CALLER,
+		CANDIDATE,
+		U256::from(500u32),
+	);
+
+	assert_eq!(
+		log.topics,
+		vec![H256(SELECTOR_LOG_DELEGATOR_BONDED_MORE), CALLER.into(), CANDIDATE]
+	);
+	assert_eq!(log.data, EvmDataWriter::new().write(U256::from(500u32)).build());
+}
+
+#[test]
+fn delegated_log_encodes_amount_and_auto_compound() {
+	let log = log_delegated(PRECOMPILE, CALLER, CANDIDATE, U256::from(500u32), 50);
+
+	assert_eq!(log.topics, vec![H256(SELECTOR_LOG_DELEGATED), CALLER.into(), CANDIDATE]);
+	assert_eq!(log.data.len(), 64);
+	assert_eq!(U256::from_big_endian(&log.data[..32]), U256::from(500u32));
+	assert_eq!(U256::from_big_endian(&log.data[32..]), U256::from(50u32));
+}
+
+#[test]
+fn executed_delegation_request_log_indexes_all_accounts() {
+	let log = log_executed_delegation_request(PRECOMPILE, CALLER, DELEGATOR, CANDIDATE);
+
+	assert_eq!(
+		log.topics,
+		vec![H256(SELECTOR_LOG_EXECUTED_DELEGATION_REQUEST), CALLER.into(), DELEGATOR, CANDIDATE]
+	);
+	assert!(log.data.is_empty());
+}
+
+#[test]
+fn delegation_transferred_log_indexes_both_candidates() {
+	let to_candidate = H256([0xDD; 32]);
+	let log =
+		log_delegation_transferred(PRECOMPILE, CALLER, CANDIDATE, to_candidate, U256::from(500u32));
+
+	assert_eq!(
+		log.topics,
+		vec![H256(SELECTOR_LOG_DELEGATION_TRANSFERRED), CALLER.into(), CANDIDATE, to_candidate]
+	);
+	assert_eq!(log.data, EvmDataWriter::new().write(U256::from(500u32)).build());
+}
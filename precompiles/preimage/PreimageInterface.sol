@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @author The Diora Team
+/// @title Pallet Preimage Interface
+/// @dev The interface through which solidity contracts will interact with pallet-preimage.
+/// A noted preimage can then be proposed by hash through the democracy precompile.
+/// @custom:address 0x0000000000000000000000000000000000000808
+interface Preimage {
+    /// @dev Register a Preimage on-chain.
+    /// @custom:selector cb00f603
+    /// @param encodedProposal The preimage to be registered on-chain
+    /// @return preimageHash The hash of the preimage
+    function notePreimage(bytes memory encodedProposal)
+        external
+        returns (bytes32 preimageHash);
+
+    /// @dev Clear an unrequested preimage from storage.
+    /// @custom:selector 02e71b45
+    /// @param hash The preimage to be cleared from storage
+    function unnotePreimage(bytes32 hash) external;
+
+    /// @dev A Preimage was registered on-chain.
+    /// @param hash The computed hash of the preimage
+    event PreimageNoted(bytes32 indexed hash);
+
+    /// @dev A Preimage was un-registered on-chain.
+    /// @param hash The hash of the preimage that was un-registered
+    event PreimageUnnoted(bytes32 indexed hash);
+}
@@ -0,0 +1,101 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to note and unnote preimages through the EVM
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::ConstU32,
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_runtime::traits::Hash;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the `PreimageNoted` log.
+pub const SELECTOR_LOG_PREIMAGE_NOTED: [u8; 32] = keccak256!("PreimageNoted(bytes32)");
+
+/// Solidity selector of the `PreimageUnnoted` log.
+pub const SELECTOR_LOG_PREIMAGE_UNNOTED: [u8; 32] = keccak256!("PreimageUnnoted(bytes32)");
+
+/// Maximum size of a noted preimage.
+pub const ENCODED_PROPOSAL_SIZE_LIMIT: u32 = 2u32.pow(16);
+
+type GetEncodedProposalSizeLimit = ConstU32<ENCODED_PROPOSAL_SIZE_LIMIT>;
+
+/// A precompile to wrap the functionality from pallet_preimage.
+///
+/// Noting a preimage is the first step of a democracy proposal, which is then submitted by hash.
+pub struct PreimagePrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> PreimagePrecompile<Runtime>
+where
+	Runtime: pallet_preimage::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_preimage::Call<Runtime>>,
+	Runtime::Hash: From<H256> + Into<H256>,
+{
+	/// Register a preimage on-chain and return its hash.
+	#[precompile::public("notePreimage(bytes)")]
+	fn note_preimage(
+		handle: &mut impl PrecompileHandle,
+		encoded_proposal: BoundedBytes<GetEncodedProposalSizeLimit>,
+	) -> EvmResult<H256> {
+		handle.record_log_costs_manual(2, 0)?;
+
+		let bytes: Vec<u8> = encoded_proposal.into();
+		let hash: H256 = <Runtime as frame_system::Config>::Hashing::hash(&bytes).into();
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_preimage::Call::<Runtime>::note_preimage { bytes };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(handle.context().address, SELECTOR_LOG_PREIMAGE_NOTED, hash, Vec::new())
+			.record(handle)?;
+
+		Ok(hash)
+	}
+
+	/// Clear an unrequested preimage from the runtime storage.
+	#[precompile::public("unnotePreimage(bytes32)")]
+	fn unnote_preimage(handle: &mut impl PrecompileHandle, hash: H256) -> EvmResult {
+		handle.record_log_costs_manual(2, 0)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_preimage::Call::<Runtime>::unnote_preimage { hash: hash.into() };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(handle.context().address, SELECTOR_LOG_PREIMAGE_UNNOTED, hash, Vec::new())
+			.record(handle)?;
+
+		Ok(())
+	}
+}
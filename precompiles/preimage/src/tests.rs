@@ -0,0 +1,137 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use frame_support::traits::QueryPreimage;
+use precompile_utils::testing::*;
+use sp_core::H160;
+use sp_runtime::traits::BlakeTwo256;
+
+type PCall = PreimagePrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Whether the reverted `output` is the dispatch error `error`.
+fn dispatch_error(output: &[u8], error: &str) -> bool {
+	let output = std::str::from_utf8(output).unwrap();
+	output.starts_with("Dispatched call failed with error: ") && output.contains(error)
+}
+
+fn note(from: H160, bytes: Vec<u8>) {
+	let hash = BlakeTwo256::hash(&bytes);
+
+	precompiles()
+		.prepare_test(from, PRECOMPILE, PCall::note_preimage { encoded_proposal: bytes.into() })
+		.expect_log(log2(PRECOMPILE, SELECTOR_LOG_PREIMAGE_NOTED, hash, Vec::new()))
+		.execute_returns_encoded(hash);
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["PreimageInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn note_preimage_reserves_deposit_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		note(ALICE, vec![1, 2, 3]);
+
+		let hash = BlakeTwo256::hash(&[1, 2, 3]);
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash), Some(3));
+		// Base deposit of 10 and 1 per byte
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 13);
+		assert_eq!(Balances::reserved_balance(account(BOB)), 0);
+	});
+}
+
+#[test]
+fn preimage_cannot_be_noted_twice() {
+	new_test_ext().execute_with(|| {
+		note(ALICE, vec![1, 2, 3]);
+
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::note_preimage { encoded_proposal: vec![1, 2, 3].into() },
+			)
+			.execute_reverts(|output| dispatch_error(output, "AlreadyNoted"));
+
+		assert_eq!(Balances::reserved_balance(account(BOB)), 0);
+	});
+}
+
+#[test]
+fn preimage_larger_than_the_limit_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let bytes = vec![0u8; ENCODED_PROPOSAL_SIZE_LIMIT as usize + 1];
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::note_preimage { encoded_proposal: bytes.into() },
+			)
+			.execute_reverts(|output| output.ends_with(b"Value is too large for length"));
+	});
+}
+
+#[test]
+fn unnote_preimage_unreserves_deposit() {
+	new_test_ext().execute_with(|| {
+		note(ALICE, vec![1, 2, 3]);
+		let hash = BlakeTwo256::hash(&[1, 2, 3]);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::unnote_preimage { hash })
+			.expect_log(log2(PRECOMPILE, SELECTOR_LOG_PREIMAGE_UNNOTED, hash, Vec::new()))
+			.execute_returns(vec![]);
+
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash), None);
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+	});
+}
+
+#[test]
+fn only_the_depositor_can_unnote_preimage() {
+	new_test_ext().execute_with(|| {
+		note(ALICE, vec![1, 2, 3]);
+		let hash = BlakeTwo256::hash(&[1, 2, 3]);
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::unnote_preimage { hash })
+			.execute_reverts(|output| dispatch_error(output, "NotAuthorized"));
+
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash), Some(3));
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 13);
+	});
+}
+
+#[test]
+fn unknown_preimage_cannot_be_unnoted() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::unnote_preimage { hash: H256::repeat_byte(1) })
+			.execute_reverts(|output| dispatch_error(output, "NotNoted"));
+	});
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @author The Diora Team
+/// @title Pallet Proxy Interface
+/// @dev The interface through which solidity contracts will interact with pallet-proxy.
+/// Accounts are given as 32 bytes and proxy types as their index in the runtime:
+/// Any = 0, NonTransfer = 1, Governance = 2, Staking = 3, CancelProxy = 4, AuthorMapping = 5.
+/// Smart contracts can only call `proxy` and `isProxy`, other precompiles only `isProxy`.
+/// @custom:address 0x000000000000000000000000000000000000080B
+interface Proxy {
+    // --- Generated from the precompile implementation, do not edit ---
+    /// @dev Register a proxy account for the caller that is able to make calls on its behalf.
+    /// A deposit is reserved for every proxy.
+    /// @custom:selector 0cadeda5
+    /// @param delegate The 32 bytes account that will make calls on behalf of the caller
+    /// @param proxyType The permissions allowed for this proxy account
+    /// @param delay The announcement period required before the proxy can make calls
+    function addProxy(bytes32 delegate, uint8 proxyType, uint32 delay) external;
+
+    /// @dev Check if an account is a registered proxy of another one
+    /// @custom:selector a9be1055
+    /// @param real The 32 bytes account that registered the proxy
+    /// @param delegate The 32 bytes proxy account
+    /// @param proxyType The permissions of the proxy account
+    /// @param delay The announcement period of the proxy account
+    /// @return True if the proxy is registered with these parameters
+    function isProxy(
+        bytes32 real,
+        bytes32 delegate,
+        uint8 proxyType,
+        uint32 delay
+    ) external view returns (bool);
+
+    /// @dev Make an EVM call on behalf of an account the caller is a proxy for.
+    /// The call must be allowed by the proxy type and the proxy must not require announcements.
+    /// The value sent with the call is paid by the real account.
+    /// @custom:selector 0d3cff86
+    /// @param real The account the caller is a proxy for, it must not be a smart contract
+    /// @param callTo The address to call on behalf of the real account
+    /// @param callData The data of the call
+    function proxy(address real, address callTo, bytes memory callData) external payable;
+
+    /// @dev Unregister all the proxy accounts of the caller, their deposits are returned.
+    /// @custom:selector 14a5b5fa
+    function removeProxies() external;
+
+    /// @dev Unregister a proxy account of the caller, its deposit is returned.
+    /// @custom:selector cc4b0a1b
+    /// @param delegate The 32 bytes account to unregister
+    /// @param proxyType The permissions of the proxy account
+    /// @param delay The announcement period of the proxy account
+    function removeProxy(bytes32 delegate, uint8 proxyType, uint32 delay) external;
+    // --- End of generated functions ---
+}
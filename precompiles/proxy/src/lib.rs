@@ -0,0 +1,324 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet proxy through an evm precompile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Decode;
+use evm::{ExitReason, Transfer};
+use fp_evm::{Context, PrecompileFailure, PrecompileHandle};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	ensure,
+	traits::ConstU32,
+};
+use pallet_evm::AddressMapping;
+use pallet_proxy::Call as ProxyCall;
+use precompile_utils::{
+	data::String,
+	precompile_set::{get_address_type, AddressType, SelectorFilter},
+	prelude::*,
+};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{StaticLookup, Zero};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum size of the call data of a subcall made through a proxy.
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+
+type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
+
+type RuntimeCallOf<Runtime> = <Runtime as frame_system::Config>::RuntimeCall;
+
+type ProxyTypeOf<Runtime> = <Runtime as pallet_proxy::Config>::ProxyType;
+
+type ProxyOf<Runtime> = pallet_proxy::Pallet<Runtime>;
+
+type BalanceOf<Runtime> = <Runtime as pallet_balances::Config>::Balance;
+
+/// EVM subcall made by a proxy on behalf of the account it is a proxy for.
+pub struct EvmSubCall {
+	pub to: Address,
+	pub value: U256,
+	pub call_data: BoundedBytes<GetCallDataLimit>,
+}
+
+/// Filter of the EVM subcalls a proxy is allowed to make, implemented by the `ProxyType` of
+/// pallet proxy.
+///
+/// The `InstanceFilter` of the proxy type only applies to runtime calls, the EVM subcalls of the
+/// `proxy` function are checked against this filter instead.
+pub trait EvmProxyCallFilter: Sized + Send + Sync {
+	/// Whether this proxy type allows `call`. `recipient_has_code` is true when the recipient is
+	/// a smart contract.
+	fn is_evm_proxy_call_allowed(&self, _call: &EvmSubCall, _recipient_has_code: bool) -> bool {
+		false
+	}
+}
+
+/// Only `isProxy` can be called.
+pub struct OnlyIsProxy<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> SelectorFilter for OnlyIsProxy<Runtime>
+where
+	Runtime: pallet_proxy::Config + pallet_evm::Config + pallet_balances::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::BlockNumber: From<u32>,
+	RuntimeCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<RuntimeCallOf<Runtime> as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	RuntimeCallOf<Runtime>: From<ProxyCall<Runtime>> + From<pallet_balances::Call<Runtime>>,
+	ProxyTypeOf<Runtime>: EvmProxyCallFilter,
+	BalanceOf<Runtime>: TryFrom<U256>,
+{
+	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
+		match selector {
+			None => false,
+			Some(selector) =>
+				ProxyPrecompileCall::<Runtime>::is_proxy_selectors().contains(&selector),
+		}
+	}
+
+	fn description() -> String {
+		"Allowed for all callers only for selector 'isProxy'".into()
+	}
+}
+
+/// Only `isProxy` and `proxy` can be called.
+pub struct OnlyIsProxyAndProxy<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> SelectorFilter for OnlyIsProxyAndProxy<Runtime>
+where
+	Runtime: pallet_proxy::Config + pallet_evm::Config + pallet_balances::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::BlockNumber: From<u32>,
+	RuntimeCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<RuntimeCallOf<Runtime> as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	RuntimeCallOf<Runtime>: From<ProxyCall<Runtime>> + From<pallet_balances::Call<Runtime>>,
+	ProxyTypeOf<Runtime>: EvmProxyCallFilter,
+	BalanceOf<Runtime>: TryFrom<U256>,
+{
+	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
+		match selector {
+			None => false,
+			Some(selector) =>
+				ProxyPrecompileCall::<Runtime>::is_proxy_selectors().contains(&selector) ||
+					ProxyPrecompileCall::<Runtime>::proxy_selectors().contains(&selector),
+		}
+	}
+
+	fn description() -> String {
+		"Allowed for all callers only for selectors 'isProxy', 'proxy'".into()
+	}
+}
+
+/// A precompile to wrap the functionality from pallet proxy.
+///
+/// Accounts are given as 32 bytes account ids and proxy types as the index of the runtime
+/// `ProxyType` variant. A proxy makes EVM subcalls on behalf of the account it is a proxy for,
+/// runtime calls are not dispatched through this precompile.
+pub struct ProxyPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+#[precompile::solidity_interface("ProxyInterface.sol")]
+impl<Runtime> ProxyPrecompile<Runtime>
+where
+	Runtime: pallet_proxy::Config + pallet_evm::Config + pallet_balances::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::BlockNumber: From<u32>,
+	RuntimeCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<RuntimeCallOf<Runtime> as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	RuntimeCallOf<Runtime>: From<ProxyCall<Runtime>> + From<pallet_balances::Call<Runtime>>,
+	ProxyTypeOf<Runtime>: EvmProxyCallFilter,
+	BalanceOf<Runtime>: TryFrom<U256>,
+{
+	/// Register a proxy account for the caller that is able to make calls on its behalf.
+	/// A deposit is reserved for every proxy.
+	/// @param delegate The 32 bytes account that will make calls on behalf of the caller
+	/// @param proxyType The permissions allowed for this proxy account
+	/// @param delay The announcement period required before the proxy can make calls
+	#[precompile::public("addProxy(bytes32,uint8,uint32)")]
+	fn add_proxy(
+		handle: &mut impl PrecompileHandle,
+		delegate: H256,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult {
+		let delegate = Runtime::AccountId::from(delegate.0);
+		let delegate = <Runtime as frame_system::Config>::Lookup::unlookup(delegate);
+		let proxy_type = Self::u8_to_proxy_type(proxy_type).in_field("proxyType")?;
+		let delay = delay.into();
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = ProxyCall::<Runtime>::add_proxy { delegate, proxy_type, delay };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	/// Unregister a proxy account of the caller, its deposit is returned.
+	/// @param delegate The 32 bytes account to unregister
+	/// @param proxyType The permissions of the proxy account
+	/// @param delay The announcement period of the proxy account
+	#[precompile::public("removeProxy(bytes32,uint8,uint32)")]
+	fn remove_proxy(
+		handle: &mut impl PrecompileHandle,
+		delegate: H256,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult {
+		let delegate = Runtime::AccountId::from(delegate.0);
+		let delegate = <Runtime as frame_system::Config>::Lookup::unlookup(delegate);
+		let proxy_type = Self::u8_to_proxy_type(proxy_type).in_field("proxyType")?;
+		let delay = delay.into();
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = ProxyCall::<Runtime>::remove_proxy { delegate, proxy_type, delay };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	/// Unregister all the proxy accounts of the caller, their deposits are returned.
+	#[precompile::public("removeProxies()")]
+	fn remove_proxies(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = ProxyCall::<Runtime>::remove_proxies {};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	/// Make an EVM call on behalf of an account the caller is a proxy for.
+	/// The call must be allowed by the proxy type and the proxy must not require announcements.
+	/// The value sent with the call is paid by the real account.
+	/// @param real The account the caller is a proxy for, it must not be a smart contract
+	/// @param callTo The address to call on behalf of the real account
+	/// @param callData The data of the call
+	#[precompile::public("proxy(address,address,bytes)")]
+	#[precompile::payable]
+	fn proxy(
+		handle: &mut impl PrecompileHandle,
+		real: Address,
+		call_to: Address,
+		call_data: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult {
+		// Only externally owned accounts can be impersonated
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		ensure!(
+			get_address_type::<Runtime>(real.into()) == AddressType::EOA,
+			revert("real address must be EOA")
+		);
+
+		let real_account = Runtime::AddressMapping::into_account_id(real.into());
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let def = ProxyOf::<Runtime>::find_proxy(&real_account, &who, None)
+			.map_err(|_| revert("Not proxy"))?;
+		ensure!(def.delay.is_zero(), revert("Unannounced"));
+
+		// Code of the recipient
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let recipient_has_code =
+			pallet_evm::AccountCodes::<Runtime>::decode_len(call_to.0).unwrap_or(0) > 0;
+
+		let call = EvmSubCall { to: call_to, value: handle.context().apparent_value, call_data };
+		ensure!(
+			def.proxy_type.is_evm_proxy_call_allowed(&call, recipient_has_code),
+			revert("CallFiltered")
+		);
+
+		let EvmSubCall { to, value, call_data } = call;
+		let sub_context = Context { caller: real.0, address: to.0, apparent_value: value };
+		let transfer = if value.is_zero() {
+			None
+		} else {
+			// The value is sent by `real`, give back what the caller sent to the precompile
+			let precompile = Runtime::AddressMapping::into_account_id(handle.context().address);
+			let amount: BalanceOf<Runtime> = value
+				.try_into()
+				.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("value"))?;
+			let call = pallet_balances::Call::<Runtime>::transfer {
+				dest: <Runtime as frame_system::Config>::Lookup::unlookup(who),
+				value: amount,
+			};
+			RuntimeHelper::<Runtime>::try_dispatch(handle, Some(precompile).into(), call)?;
+
+			Some(Transfer { source: real.0, target: to.0, value })
+		};
+
+		let (reason, output) = handle.call(
+			to.0,
+			transfer,
+			call_data.into(),
+			Some(handle.remaining_gas()),
+			false,
+			&sub_context,
+		);
+		match reason {
+			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+			ExitReason::Revert(exit_status) =>
+				Err(PrecompileFailure::Revert { exit_status, output }),
+			ExitReason::Succeed(_) => Ok(()),
+		}
+	}
+
+	/// Check if an account is a registered proxy of another one
+	/// @param real The 32 bytes account that registered the proxy
+	/// @param delegate The 32 bytes proxy account
+	/// @param proxyType The permissions of the proxy account
+	/// @param delay The announcement period of the proxy account
+	/// @return True if the proxy is registered with these parameters
+	#[precompile::public("isProxy(bytes32,bytes32,uint8,uint32)")]
+	#[precompile::view]
+	fn is_proxy(
+		handle: &mut impl PrecompileHandle,
+		real: H256,
+		delegate: H256,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult<bool> {
+		let real = Runtime::AccountId::from(real.0);
+		let delegate = Runtime::AccountId::from(delegate.0);
+		let proxy_type = Self::u8_to_proxy_type(proxy_type).in_field("proxyType")?;
+		let delay: Runtime::BlockNumber = delay.into();
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let is_proxy = ProxyOf::<Runtime>::proxies(real).0.iter().any(|proxy_def| {
+			proxy_def.delegate == delegate &&
+				proxy_def.proxy_type == proxy_type &&
+				proxy_def.delay == delay
+		});
+
+		Ok(is_proxy)
+	}
+
+	fn u8_to_proxy_type(proxy_type: u8) -> MayRevert<ProxyTypeOf<Runtime>> {
+		ProxyTypeOf::<Runtime>::decode(&mut &[proxy_type][..])
+			.map_err(|_| RevertReason::custom("Unknown proxy type").into())
+	}
+}
@@ -0,0 +1,118 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Test utilities
+use crate::{EvmProxyCallFilter, EvmSubCall, OnlyIsProxy, OnlyIsProxyAndProxy, ProxyPrecompile};
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{ConstU128, ConstU32, InstanceFilter};
+use precompile_utils::{precompile_set::*, testing::ExtBuilder};
+use scale_info::TypeInfo;
+use sp_io::TestExternalities;
+use sp_runtime::{traits::BlakeTwo256, RuntimeDebug};
+
+precompile_utils::mock_runtime! {
+	precompiles: Precompiles<Runtime>,
+	pallets: {
+		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
+	},
+}
+
+/// Subset of the runtime proxy types.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum ProxyType {
+	Any = 0,
+	NonTransfer = 1,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::NonTransfer => matches!(c, RuntimeCall::System(..) | RuntimeCall::Proxy(..)),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		self == o || *self == ProxyType::Any
+	}
+}
+
+impl EvmProxyCallFilter for ProxyType {
+	fn is_evm_proxy_call_allowed(&self, call: &EvmSubCall, recipient_has_code: bool) -> bool {
+		match self {
+			ProxyType::Any => !recipient_has_code,
+			ProxyType::NonTransfer => call.value.is_zero() && !recipient_has_code,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ConstU128<10>;
+	type ProxyDepositFactor = ConstU128<1>;
+	type MaxProxies = ConstU32<4>;
+	type WeightInfo = ();
+	type MaxPending = ConstU32<4>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ConstU128<10>;
+	type AnnouncementDepositFactor = ConstU128<1>;
+}
+
+/// Same checks as in the runtime.
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		PrecompileAt<
+			PrecompileAddress,
+			ProxyPrecompile<R>,
+			(
+				SubcallWithMaxNesting<0>,
+				CallableByContract<OnlyIsProxyAndProxy<R>>,
+				CallableByPrecompile<OnlyIsProxy<R>>,
+			),
+		>,
+	),
+>;
+
+pub(crate) fn new_test_ext() -> TestExternalities {
+	ExtBuilder::<Runtime>::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000), (CHARLIE, 1_000)])
+		.build()
+}
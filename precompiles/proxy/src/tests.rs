@@ -0,0 +1,403 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use precompile_utils::testing::*;
+use std::{cell::RefCell, rc::Rc};
+
+type PCall = ProxyPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Whether the reverted `output` is the dispatch error `error`.
+fn dispatch_error(output: &[u8], error: &str) -> bool {
+	let output = std::str::from_utf8(output).unwrap();
+	output.starts_with("Dispatched call failed with error: ") && output.contains(error)
+}
+
+fn account_h256(address: H160) -> H256 {
+	H256::from(<[u8; 32]>::from(account(address)))
+}
+
+fn add_proxy(real: H160, delegate: H160, proxy_type: ProxyType, delay: u32) {
+	precompiles()
+		.prepare_test(
+			real,
+			PRECOMPILE,
+			PCall::add_proxy {
+				delegate: account_h256(delegate),
+				proxy_type: proxy_type as u8,
+				delay,
+			},
+		)
+		.expect_no_logs()
+		.execute_returns(vec![]);
+}
+
+/// Checks the `isProxy` view of the precompile.
+fn assert_is_proxy(real: H160, delegate: H160, proxy_type: ProxyType, delay: u32, expected: bool) {
+	precompiles()
+		.prepare_test(
+			CHARLIE,
+			PRECOMPILE,
+			PCall::is_proxy {
+				real: account_h256(real),
+				delegate: account_h256(delegate),
+				proxy_type: proxy_type as u8,
+				delay,
+			},
+		)
+		.expect_no_logs()
+		.execute_returns_encoded(expected);
+}
+
+/// Input of a `proxy` call from the delegate, calling `to` on behalf of `real`.
+fn proxy(real: H160, to: H160) -> PCall {
+	PCall::proxy { real: Address(real), call_to: Address(to), call_data: vec![1, 2, 3].into() }
+}
+
+/// Subcall handle recording the subcalls made by the precompile, answered with `output`.
+fn recording_subcalls(
+	output: SubcallOutput,
+) -> (Rc<RefCell<Vec<Subcall>>>, impl FnMut(Subcall) -> SubcallOutput) {
+	let subcalls = Rc::new(RefCell::new(Vec::new()));
+	let recorded = subcalls.clone();
+	(subcalls, move |subcall| {
+		recorded.borrow_mut().push(subcall);
+		output.clone()
+	})
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["ProxyInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn add_proxy_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+
+		// Base deposit of 10 and 1 per proxy
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 11);
+		assert_eq!(Balances::reserved_balance(account(BOB)), 0);
+		assert_is_proxy(ALICE, BOB, ProxyType::Any, 0, true);
+		// The relationship is not symmetric and matches the exact type and delay
+		assert_is_proxy(BOB, ALICE, ProxyType::Any, 0, false);
+		assert_is_proxy(ALICE, BOB, ProxyType::NonTransfer, 0, false);
+		assert_is_proxy(ALICE, BOB, ProxyType::Any, 1, false);
+	});
+}
+
+#[test]
+fn add_proxy_rejects_unknown_proxy_type() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::add_proxy { delegate: account_h256(BOB), proxy_type: 2, delay: 0 },
+			)
+			.execute_reverts(|output| output == b"proxyType: Unknown proxy type");
+
+		precompiles()
+			.prepare_test(
+				CHARLIE,
+				PRECOMPILE,
+				PCall::is_proxy {
+					real: account_h256(ALICE),
+					delegate: account_h256(BOB),
+					proxy_type: 2,
+					delay: 0,
+				},
+			)
+			.execute_reverts(|output| output == b"proxyType: Unknown proxy type");
+	});
+}
+
+#[test]
+fn proxy_cannot_be_added_twice() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::add_proxy {
+					delegate: account_h256(BOB),
+					proxy_type: ProxyType::Any as u8,
+					delay: 0,
+				},
+			)
+			.execute_reverts(|output| dispatch_error(output, "Duplicate"));
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 11);
+	});
+}
+
+#[test]
+fn remove_proxy_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+
+		// Only the real account can remove its proxies
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::remove_proxy {
+					delegate: account_h256(BOB),
+					proxy_type: ProxyType::Any as u8,
+					delay: 0,
+				},
+			)
+			.execute_reverts(|output| dispatch_error(output, "NotFound"));
+		assert_is_proxy(ALICE, BOB, ProxyType::Any, 0, true);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::remove_proxy {
+					delegate: account_h256(BOB),
+					proxy_type: ProxyType::Any as u8,
+					delay: 0,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_is_proxy(ALICE, BOB, ProxyType::Any, 0, false);
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+	});
+}
+
+#[test]
+fn remove_proxies_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+		add_proxy(ALICE, CHARLIE, ProxyType::NonTransfer, 0);
+		add_proxy(BOB, CHARLIE, ProxyType::Any, 0);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::remove_proxies {})
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_is_proxy(ALICE, BOB, ProxyType::Any, 0, false);
+		assert_is_proxy(ALICE, CHARLIE, ProxyType::NonTransfer, 0, false);
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+		// Proxies of other accounts are kept
+		assert_is_proxy(BOB, CHARLIE, ProxyType::Any, 0, true);
+	});
+}
+
+#[test]
+fn proxy_calls_on_behalf_of_the_real_account() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+		let (subcalls, subcall_handle) = recording_subcalls(SubcallOutput::succeed());
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, proxy(ALICE, CHARLIE))
+			.with_subcall_handle(subcall_handle)
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		let subcalls = subcalls.borrow();
+		assert_eq!(subcalls.len(), 1);
+		assert_eq!(subcalls[0].address, CHARLIE);
+		assert_eq!(subcalls[0].context.caller, ALICE);
+		assert_eq!(subcalls[0].context.address, CHARLIE);
+		assert_eq!(subcalls[0].input, vec![1, 2, 3]);
+		assert!(subcalls[0].transfer.is_none());
+	});
+}
+
+#[test]
+fn proxy_call_value_is_paid_by_the_real_account() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+		// Value sent by BOB to the precompile with the call
+		Balances::make_free_balance_be(&account(PRECOMPILE), 100);
+		assert_eq!(Balances::free_balance(account(BOB)), 1_000);
+		let (subcalls, subcall_handle) = recording_subcalls(SubcallOutput::succeed());
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, proxy(ALICE, CHARLIE))
+			.with_value(100)
+			.with_subcall_handle(subcall_handle)
+			.execute_returns(vec![]);
+
+		// BOB gets its value back, the subcall transfers the value of ALICE
+		assert_eq!(Balances::free_balance(account(BOB)), 1_100);
+		assert_eq!(Balances::free_balance(account(PRECOMPILE)), 0);
+		let transfer = subcalls.borrow()[0].transfer.clone().unwrap();
+		assert_eq!(transfer.source, ALICE);
+		assert_eq!(transfer.target, CHARLIE);
+		assert_eq!(transfer.value, 100.into());
+	});
+}
+
+#[test]
+fn proxy_forwards_subcall_revert() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+		let (subcalls, subcall_handle) = recording_subcalls(SubcallOutput::revert());
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, proxy(ALICE, CHARLIE))
+			.with_subcall_handle(subcall_handle)
+			.execute_reverts(|output| output.is_empty());
+
+		assert_eq!(subcalls.borrow().len(), 1);
+	});
+}
+
+#[test]
+fn only_a_proxy_can_call_for_the_real_account() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+		let (subcalls, subcall_handle) = recording_subcalls(SubcallOutput::succeed());
+
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, proxy(ALICE, CHARLIE))
+			.with_subcall_handle(subcall_handle)
+			.execute_reverts(|output| output == b"Not proxy");
+
+		// The delegate cannot be used the other way around
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, proxy(BOB, ALICE))
+			.execute_reverts(|output| output == b"Not proxy");
+
+		assert!(subcalls.borrow().is_empty());
+	});
+}
+
+#[test]
+fn proxy_type_filters_the_subcall() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::NonTransfer, 0);
+		add_proxy(ALICE, CHARLIE, ProxyType::Any, 0);
+		pallet_evm::AccountCodes::<Runtime>::insert(DAVE, vec![0x60, 0x00]);
+		let (subcalls, subcall_handle) = recording_subcalls(SubcallOutput::succeed());
+
+		// A `NonTransfer` proxy cannot send value
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, proxy(ALICE, CHARLIE))
+			.with_value(100)
+			.with_subcall_handle(subcall_handle)
+			.execute_reverts(|output| output == b"CallFiltered");
+
+		// Smart contracts cannot be called through a proxy
+		precompiles()
+			.prepare_test(CHARLIE, PRECOMPILE, proxy(ALICE, DAVE))
+			.execute_reverts(|output| output == b"CallFiltered");
+
+		assert!(subcalls.borrow().is_empty());
+	});
+}
+
+#[test]
+fn delayed_proxy_cannot_make_unannounced_call() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 5);
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, proxy(ALICE, CHARLIE))
+			.execute_reverts(|output| output == b"Unannounced");
+	});
+}
+
+#[test]
+fn proxy_cannot_call_on_behalf_of_a_contract() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+		pallet_evm::AccountCodes::<Runtime>::insert(ALICE, vec![0x60, 0x00]);
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, proxy(ALICE, CHARLIE))
+			.execute_reverts(|output| output == b"real address must be EOA");
+	});
+}
+
+#[test]
+fn contracts_cannot_change_their_proxies() {
+	new_test_ext().execute_with(|| {
+		pallet_evm::AccountCodes::<Runtime>::insert(ALICE, vec![0x60, 0x00]);
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::add_proxy {
+					delegate: account_h256(BOB),
+					proxy_type: ProxyType::Any as u8,
+					delay: 0,
+				},
+			)
+			.execute_reverts(|output| output == b"Function not callable by smart contracts");
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::remove_proxies {})
+			.execute_reverts(|output| output == b"Function not callable by smart contracts");
+	});
+}
+
+#[test]
+fn precompiles_can_only_check_proxies() {
+	new_test_ext().execute_with(|| {
+		add_proxy(ALICE, BOB, ProxyType::Any, 0);
+
+		// Such as batch or call permit acting for ALICE
+		precompiles()
+			.prepare_test(
+				PRECOMPILE,
+				PRECOMPILE,
+				PCall::add_proxy {
+					delegate: account_h256(CHARLIE),
+					proxy_type: ProxyType::Any as u8,
+					delay: 0,
+				},
+			)
+			.execute_reverts(|output| output == b"Function not callable by precompiles");
+
+		precompiles()
+			.prepare_test(PRECOMPILE, PRECOMPILE, proxy(ALICE, CHARLIE))
+			.execute_reverts(|output| output == b"Function not callable by precompiles");
+
+		precompiles()
+			.prepare_test(
+				PRECOMPILE,
+				PRECOMPILE,
+				PCall::is_proxy {
+					real: account_h256(ALICE),
+					delegate: account_h256(BOB),
+					proxy_type: ProxyType::Any as u8,
+					delay: 0,
+				},
+			)
+			.execute_returns_encoded(true);
+	});
+}
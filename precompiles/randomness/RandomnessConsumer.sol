@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+import "./RandomnessInterface.sol";
+
+/// @notice Base contract of the contracts requesting randomness
+/// @dev Inherit from it and override `fulfillRandomWords` to receive the random words
+abstract contract RandomnessConsumer {
+    /// @notice Only the randomness precompile can fulfill the requests
+    error OnlyRandomnessPrecompileCanFulfill(address have, address want);
+
+    /// @notice Receives the random words of a request
+    /// @param requestId The id of the fulfilled request
+    /// @param randomWords The random words
+    function fulfillRandomWords(
+        uint256 requestId,
+        uint256[] memory randomWords
+    ) internal virtual;
+
+    /// @notice Called by the randomness precompile to fulfill a request
+    /// @custom:selector 1fe543e3
+    function rawFulfillRandomWords(
+        uint256 requestId,
+        uint256[] memory randomWords
+    ) external {
+        if (msg.sender != RANDOMNESS_ADDRESS) {
+            revert OnlyRandomnessPrecompileCanFulfill(
+                msg.sender,
+                RANDOMNESS_ADDRESS
+            );
+        }
+        fulfillRandomWords(requestId, randomWords);
+    }
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @dev The Randomness contract's address.
+address constant RANDOMNESS_ADDRESS = 0x0000000000000000000000000000000000000802;
+
+/// @dev The Randomness contract's instance.
+Randomness constant RANDOMNESS_CONTRACT = Randomness(RANDOMNESS_ADDRESS);
+
+/// @author The Diora Team
+/// @title Pallet Randomness Interface
+/// @dev The interface through which solidity contracts will request randomness
+/// We follow this same interface including four-byte function selectors, in the precompile that
+/// wraps the pallet
+/// @custom:address 0x0000000000000000000000000000000000000802
+interface Randomness {
+    /// @notice Event emitted when the request has been successfully executed
+    event FulFillmentSucceeded();
+
+    /// @notice Event emitted when the request has failed to execute fulfillment
+    event FulFillmentFailed();
+
+    /// @notice The status of the request
+    /// @param DoesNotExist The request doesn't exist
+    /// @param Pending The request cannot be fulfilled yet
+    /// @param Ready The request is ready to be fulfilled
+    /// @param Expired The request has expired
+    enum RequestStatus {
+        DoesNotExist,
+        Pending,
+        Ready,
+        Expired
+    }
+
+    /// @notice Return the current relay epoch index
+    /// @dev An epoch represents real time and not a block number
+    /// @custom:selector 81797566
+    function relayEpochIndex() external view returns (uint64);
+
+    /// @notice Return the deposit required to perform a request
+    /// @dev Each request will need a deposit, returned once the request is fulfilled or purged
+    /// @custom:selector fb7cfdd7
+    function requiredDeposit() external view returns (uint256);
+
+    /// @notice Returns the request status
+    /// @custom:selector d8a4676f
+    /// @param requestId The id of the request to check
+    /// @return status Status of the request, see RequestStatus
+    function getRequestStatus(uint256 requestId)
+        external
+        view
+        returns (RequestStatus);
+
+    /// @notice Returns the request, reverts if it does not exist
+    /// @custom:selector c58343ef
+    /// @param requestId The id of the request
+    /// @return id The id of the request
+    /// @return refundAddress Address receiving the left-over fees after the fulfillment
+    /// @return contractAddress Address of the contract receiving the callback
+    /// @return fee Amount to set aside to pay for the fulfillment
+    /// @return gasLimit Gas limit of the callback
+    /// @return salt Salt mixed with the randomness
+    /// @return numWords Number of random words requested
+    /// @return source 0 for local VRF randomness, 1 for relay BABE epoch randomness
+    /// @return fulfillment Block (local) or relay epoch (BABE) from which it can be fulfilled
+    /// @return expiration Block (local) or relay epoch (BABE) from which it can be purged
+    /// @return deposit Deposit reserved for the request
+    function getRequest(uint256 requestId)
+        external
+        view
+        returns (
+            uint256 id,
+            address refundAddress,
+            address contractAddress,
+            uint256 fee,
+            uint64 gasLimit,
+            bytes32 salt,
+            uint8 numWords,
+            uint8 source,
+            uint64 fulfillment,
+            uint64 expiration,
+            uint256 deposit
+        );
+
+    /// @notice Request random words generated from the relay chain BABE consensus
+    /// @dev The randomness comes from the relay epoch starting two epochs after the request
+    /// @dev The deposit and the fee are reserved from the calling contract
+    /// @custom:selector 33c14a63
+    /// @param refundAddress The address receiving the left-over fees after the fulfillment
+    /// @param fee The amount to set aside to pay for the fulfillment
+    /// @param gasLimit The gas limit to use for the fulfillment callback
+    /// @param salt A string to customize the randomness
+    /// @param numWords The number of random words requested
+    /// @return requestId The id of the request
+    function requestRelayBabeEpochRandomWords(
+        address refundAddress,
+        uint256 fee,
+        uint64 gasLimit,
+        bytes32 salt,
+        uint8 numWords
+    ) external returns (uint256);
+
+    /// @notice Request random words generated from the parachain VRF
+    /// @dev The randomness is the VRF output of the block `delay` blocks after the request
+    /// @dev The deposit and the fee are reserved from the calling contract
+    /// @custom:selector 9478430c
+    /// @param refundAddress The address receiving the left-over fees after the fulfillment
+    /// @param fee The amount to set aside to pay for the fulfillment
+    /// @param gasLimit The gas limit to use for the fulfillment callback
+    /// @param salt A string to customize the randomness
+    /// @param numWords The number of random words requested
+    /// @param delay The number of blocks until the randomness is produced
+    /// @return requestId The id of the request
+    function requestLocalVRFRandomWords(
+        address refundAddress,
+        uint256 fee,
+        uint64 gasLimit,
+        bytes32 salt,
+        uint8 numWords,
+        uint64 delay
+    ) external returns (uint256);
+
+    /// @notice Fulfill the request, calling `rawFulfillRandomWords` on the requesting contract
+    /// @dev Anyone can fulfill a request once it is ready, the caller's gas is paid out of the fee
+    /// @custom:selector 9a91eb0d
+    /// @param requestId The id of the request to fulfill
+    function fulfillRequest(uint256 requestId) external;
+
+    /// @notice Increase the fee of a pending request
+    /// @dev Only the contract that made the request can increase its fee
+    /// @custom:selector d0408a7f
+    /// @param requestId The id of the request
+    /// @param feeIncrease The amount added to the fee
+    function increaseRequestFee(uint256 requestId, uint256 feeIncrease)
+        external;
+
+    /// @notice Remove an expired request, the caller receives the fee of the request
+    /// @custom:selector 1d26cbab
+    /// @param requestId The id of the expired request
+    function purgeExpiredRequest(uint256 requestId) external;
+}
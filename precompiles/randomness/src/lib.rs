@@ -0,0 +1,378 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to request randomness from pallet-randomness via the EVM
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Context, ExitReason, PrecompileFailure, PrecompileHandle};
+use frame_support::traits::Get;
+use pallet_evm::{AddressMapping, FeeCalculator, GasWeightMapping};
+use pallet_randomness::{
+	weights::WeightInfo, BalanceOf, FulfillArgs, Request, RequestInfo, RequestType,
+};
+use precompile_utils::{
+	costs::{call_cost, memory_cost},
+	prelude::*,
+};
+use sp_core::{H256, U256};
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError, Saturating};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the `FulFillmentSucceeded` log.
+pub const SELECTOR_LOG_FULFILLMENT_SUCCEEDED: [u8; 32] = keccak256!("FulFillmentSucceeded()");
+
+/// Solidity selector of the `FulFillmentFailed` log.
+pub const SELECTOR_LOG_FULFILLMENT_FAILED: [u8; 32] = keccak256!("FulFillmentFailed()");
+
+/// Gas of the transaction calling `fulfillRequest`, refunded on top of the precompile execution.
+pub const TRANSACTION_BASE_GAS: u64 = 21_000;
+
+/// Callback implemented by the `RandomnessConsumer` contracts.
+#[generate_function_selector]
+pub enum ConsumerAction {
+	RawFulfillRandomWords = "rawFulfillRandomWords(uint256,uint256[])",
+}
+
+/// Status of a randomness request, as returned by `getRequestStatus`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RequestStatus {
+	DoesNotExist = 0,
+	Pending = 1,
+	Ready = 2,
+	Expired = 3,
+}
+
+/// A precompile to request randomness from pallet_randomness.
+///
+/// A contract requests random words with a deposit and a fee. Once the randomness is available
+/// anyone can call `fulfillRequest`, which calls `rawFulfillRandomWords` on the requesting contract
+/// and pays the gas of the caller out of the fee.
+pub struct RandomnessPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> RandomnessPrecompile<Runtime>
+where
+	Runtime: pallet_randomness::Config + pallet_evm::Config,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	#[precompile::public("relayEpochIndex()")]
+	#[precompile::view]
+	fn relay_epoch_index(handle: &mut impl PrecompileHandle) -> EvmResult<u64> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let relay_epoch = pallet_randomness::Pallet::<Runtime>::relay_epoch();
+
+		Ok(relay_epoch)
+	}
+
+	#[precompile::public("requiredDeposit()")]
+	#[precompile::view]
+	fn required_deposit(_handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		Ok(<Runtime as pallet_randomness::Config>::Deposit::get().into())
+	}
+
+	#[precompile::public("getRequestStatus(uint256)")]
+	#[precompile::view]
+	fn get_request_status(handle: &mut impl PrecompileHandle, request_id: U256) -> EvmResult<u8> {
+		let request_id = Self::request_id(request_id)?;
+
+		// Request and relay epoch or block number
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+		let status = match pallet_randomness::Pallet::<Runtime>::requests(request_id) {
+			None => RequestStatus::DoesNotExist,
+			Some(state) =>
+				if pallet_randomness::Pallet::<Runtime>::is_expired(&state.request.info) {
+					RequestStatus::Expired
+				} else if pallet_randomness::Pallet::<Runtime>::can_be_fulfilled(
+					&state.request.info,
+				) {
+					RequestStatus::Ready
+				} else {
+					RequestStatus::Pending
+				},
+		};
+
+		Ok(status as u8)
+	}
+
+	#[precompile::public("getRequest(uint256)")]
+	#[precompile::view]
+	fn get_request(
+		handle: &mut impl PrecompileHandle,
+		request_id: U256,
+	) -> EvmResult<(U256, Address, Address, U256, u64, H256, u8, u8, u64, u64, U256)> {
+		let request_id = Self::request_id(request_id)?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let state = pallet_randomness::Pallet::<Runtime>::requests(request_id)
+			.ok_or_else(|| revert("Request does not exist"))?;
+		let request = state.request;
+
+		// The source is 0 for local VRF and 1 for relay BABE epoch randomness, followed by the
+		// block or epoch the request can be fulfilled from and the one it expires at.
+		let (source, fulfillment, expiration) = match request.info {
+			RequestInfo::Local(block, expires) =>
+				(0u8, block.unique_saturated_into(), expires.unique_saturated_into()),
+			RequestInfo::BabeEpoch(epoch, expires) => (1u8, epoch, expires),
+		};
+
+		Ok((
+			request_id.into(),
+			request.refund_address.into(),
+			request.contract_address.into(),
+			request.fee.into(),
+			request.gas_limit,
+			request.salt,
+			request.num_words,
+			source,
+			fulfillment,
+			expiration,
+			state.deposit.into(),
+		))
+	}
+
+	#[precompile::public("requestRelayBabeEpochRandomWords(address,uint256,uint64,bytes32,uint8)")]
+	fn request_babe_randomness(
+		handle: &mut impl PrecompileHandle,
+		refund_address: Address,
+		fee: U256,
+		gas_limit: u64,
+		salt: H256,
+		num_words: u8,
+	) -> EvmResult<U256> {
+		handle.record_cost(Self::weight_to_gas(
+			<Runtime as pallet_randomness::Config>::WeightInfo::request_randomness(),
+		))?;
+
+		// The randomness recorded two epochs from now is not known to anyone yet
+		let epoch = pallet_randomness::Pallet::<Runtime>::relay_epoch().saturating_add(2);
+		Self::request_randomness(
+			handle,
+			refund_address,
+			fee,
+			gas_limit,
+			salt,
+			num_words,
+			RequestType::BabeEpoch(epoch),
+		)
+	}
+
+	#[precompile::public("requestLocalVRFRandomWords(address,uint256,uint64,bytes32,uint8,uint64)")]
+	fn request_local_randomness(
+		handle: &mut impl PrecompileHandle,
+		refund_address: Address,
+		fee: U256,
+		gas_limit: u64,
+		salt: H256,
+		num_words: u8,
+		delay: u64,
+	) -> EvmResult<U256> {
+		handle.record_cost(Self::weight_to_gas(
+			<Runtime as pallet_randomness::Config>::WeightInfo::request_randomness(),
+		))?;
+
+		let delay: Runtime::BlockNumber = delay
+			.try_into()
+			.map_err(|_| revert("Delay is too large for block number type"))?;
+		let block = frame_system::Pallet::<Runtime>::block_number().saturating_add(delay);
+		Self::request_randomness(
+			handle,
+			refund_address,
+			fee,
+			gas_limit,
+			salt,
+			num_words,
+			RequestType::Local(block),
+		)
+	}
+
+	/// Fulfills a request whose randomness is available by calling `rawFulfillRandomWords` on
+	/// the requesting contract. The gas used by the caller is paid out of the request fee.
+	///
+	/// The request is removed even if the callback fails, a `FulFillmentFailed` log is emitted in
+	/// that case.
+	#[precompile::public("fulfillRequest(uint256)")]
+	fn fulfill_request(handle: &mut impl PrecompileHandle, request_id: U256) -> EvmResult {
+		let request_id = Self::request_id(request_id)?;
+
+		handle.record_cost(Self::weight_to_gas(
+			<Runtime as pallet_randomness::Config>::WeightInfo::prepare_fulfillment(),
+		))?;
+		let FulfillArgs { request, deposit, randomness } =
+			pallet_randomness::Pallet::<Runtime>::prepare_fulfillment(request_id)
+				.map_err(Self::dispatch_error)?;
+
+		let input = EvmDataWriter::new_with_selector(ConsumerAction::RawFulfillRandomWords)
+			.write(U256::from(request_id))
+			.write(randomness.into_iter().map(H256).collect::<Vec<_>>())
+			.build();
+
+		// Make sure the callback gets its full gas limit and the rest of the fulfillment can
+		// always be paid once the callback ran. The request is already removed, a callback
+		// running out of the gas it asked for would lose its words.
+		let finish_gas = Self::weight_to_gas(
+			<Runtime as pallet_randomness::Config>::WeightInfo::finish_fulfillment(),
+		);
+		let log_gas = precompile_utils::costs::log_costs(1, 0)?;
+		let required_gas = Self::subcall_required_gas(request.gas_limit, input.len())
+			.saturating_add(finish_gas)
+			.saturating_add(log_gas);
+		if handle.remaining_gas() < required_gas {
+			return Err(revert("Not enough gas left to execute the fulfillment callback"));
+		}
+
+		let context = Context {
+			caller: handle.code_address(),
+			address: request.contract_address,
+			apparent_value: U256::zero(),
+		};
+		let (reason, _) = handle.call(
+			request.contract_address,
+			None,
+			input,
+			Some(request.gas_limit),
+			false,
+			&context,
+		);
+
+		let selector = match reason {
+			ExitReason::Succeed(_) => SELECTOR_LOG_FULFILLMENT_SUCCEEDED,
+			_ => SELECTOR_LOG_FULFILLMENT_FAILED,
+		};
+		log1(handle.context().address, selector, Vec::new()).record(handle)?;
+		handle.record_cost(finish_gas)?;
+
+		// Everything the caller paid for: the transaction base cost and the precompile execution
+		let used_gas = handle
+			.gas_limit()
+			.unwrap_or_default()
+			.saturating_sub(handle.remaining_gas())
+			.saturating_add(TRANSACTION_BASE_GAS);
+		let (gas_price, _) = <Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+		// The cost is capped to the request fee, a cost too large for the balance type is too
+		let cost = gas_price.saturating_mul(used_gas.into()).try_into().unwrap_or(request.fee);
+
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		pallet_randomness::Pallet::<Runtime>::finish_fulfillment(
+			request_id, request, deposit, &caller, cost,
+		)
+		.map_err(Self::dispatch_error)?;
+
+		Ok(())
+	}
+
+	/// Increases the fee of a pending request. Only the contract that made the request can
+	/// increase its fee.
+	#[precompile::public("increaseRequestFee(uint256,uint256)")]
+	fn increase_request_fee(
+		handle: &mut impl PrecompileHandle,
+		request_id: U256,
+		fee_increase: U256,
+	) -> EvmResult {
+		handle.record_cost(Self::weight_to_gas(
+			<Runtime as pallet_randomness::Config>::WeightInfo::increase_fee(),
+		))?;
+
+		let request_id = Self::request_id(request_id)?;
+		let fee_increase = Self::u256_to_amount(fee_increase).in_field("feeIncrease")?;
+		pallet_randomness::Pallet::<Runtime>::increase_request_fee(
+			&handle.context().caller,
+			request_id,
+			fee_increase,
+		)
+		.map_err(Self::dispatch_error)?;
+
+		Ok(())
+	}
+
+	/// Purges an expired request. The fee of the request is paid to the caller.
+	#[precompile::public("purgeExpiredRequest(uint256)")]
+	fn purge_expired_request(handle: &mut impl PrecompileHandle, request_id: U256) -> EvmResult {
+		handle.record_cost(Self::weight_to_gas(
+			<Runtime as pallet_randomness::Config>::WeightInfo::execute_request_expiration(),
+		))?;
+
+		let request_id = Self::request_id(request_id)?;
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		pallet_randomness::Pallet::<Runtime>::execute_request_expiration(&caller, request_id)
+			.map_err(Self::dispatch_error)?;
+
+		Ok(())
+	}
+
+	fn request_randomness(
+		handle: &mut impl PrecompileHandle,
+		refund_address: Address,
+		fee: U256,
+		gas_limit: u64,
+		salt: H256,
+		num_words: u8,
+		request_type: RequestType<Runtime::BlockNumber>,
+	) -> EvmResult<U256> {
+		let fee = Self::u256_to_amount(fee).in_field("fee")?;
+		let request = Request {
+			refund_address: refund_address.into(),
+			contract_address: handle.context().caller,
+			fee,
+			gas_limit,
+			num_words,
+			salt,
+			info: request_type,
+		};
+
+		let request_id = pallet_randomness::Pallet::<Runtime>::request_randomness(request)
+			.map_err(Self::dispatch_error)?;
+
+		Ok(request_id.into())
+	}
+
+	/// Gas that must be left to call the consumer with `input_len` bytes of input and
+	/// `gas_limit` gas. Only 63/64 of the gas left once the call is paid is forwarded (EIP-150).
+	fn subcall_required_gas(gas_limit: u64, input_len: usize) -> u64 {
+		let forwarded = gas_limit.saturating_mul(64).saturating_add(62) / 63;
+		call_cost(U256::zero(), <Runtime as pallet_evm::Config>::config())
+			.saturating_add(memory_cost(input_len))
+			.saturating_add(forwarded)
+	}
+
+	fn request_id(request_id: U256) -> MayRevert<u64> {
+		request_id
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("request id").in_field("requestId"))
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+
+	fn weight_to_gas(weight: frame_support::weights::Weight) -> u64 {
+		<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+	}
+
+	fn dispatch_error(error: DispatchError) -> PrecompileFailure {
+		let reason: &'static str = error.into();
+		revert(reason)
+	}
+}
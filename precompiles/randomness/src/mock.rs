@@ -0,0 +1,105 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Test utilities
+use crate::RandomnessPrecompile;
+
+use frame_support::{
+	parameter_types,
+	traits::{ConstU128, ConstU64, ConstU8},
+};
+use nimbus_primitives::NimbusId;
+use pallet_randomness::GetBabeData;
+use precompile_utils::{precompile_set::*, testing::ExtBuilder};
+use session_keys_primitives::{KeysLookup, VrfId};
+use sp_core::{H160, H256};
+use sp_io::TestExternalities;
+use sp_runtime::traits::Convert;
+
+precompile_utils::mock_runtime! {
+	precompiles: Precompiles<Runtime>,
+	pallets: {
+		Randomness: pallet_randomness::{Pallet, Call, Storage, Event<T>},
+	},
+}
+
+/// No author has a VRF key, blocks are not authored in the tests.
+pub struct NoVrfKeys;
+impl KeysLookup<NimbusId, VrfId> for NoVrfKeys {
+	fn lookup_keys(_: &NimbusId) -> Option<VrfId> {
+		None
+	}
+}
+
+parameter_types! {
+	pub static RelayEpochIndex: u64 = 0;
+}
+
+pub struct MockBabeDataGetter;
+impl GetBabeData<u64, Option<H256>> for MockBabeDataGetter {
+	fn get_epoch_index() -> u64 {
+		RelayEpochIndex::get()
+	}
+	fn get_epoch_randomness() -> Option<H256> {
+		None
+	}
+}
+
+/// Same mapping as the one of `pallet_evm`.
+pub struct EvmAddressMapping;
+impl Convert<H160, AccountId> for EvmAddressMapping {
+	fn convert(address: H160) -> AccountId {
+		account(address)
+	}
+}
+
+pub(crate) const DEPOSIT: Balance = 10;
+
+impl pallet_randomness::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type VrfKeyLookup = NoVrfKeys;
+	type BabeDataGetter = MockBabeDataGetter;
+	type Currency = Balances;
+	type AddressMapping = EvmAddressMapping;
+	type Deposit = ConstU128<DEPOSIT>;
+	type MaxRandomWords = ConstU8<100>;
+	type MinBlockDelay = ConstU64<2>;
+	type MaxBlockDelay = ConstU64<20>;
+	type BlockExpirationDelay = ConstU64<50>;
+	type EpochExpirationDelay = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+/// Same checks as in the runtime: subcalls are needed to call back the requesting contract.
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		PrecompileAt<
+			PrecompileAddress,
+			RandomnessPrecompile<R>,
+			(SubcallWithMaxNesting<0>, CallableByContract, CallableByPrecompile),
+		>,
+	),
+>;
+
+pub(crate) fn new_test_ext() -> TestExternalities {
+	// ALICE is the requesting contract, BOB fulfills the requests
+	ExtBuilder::<Runtime>::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000)])
+		.build()
+}
@@ -0,0 +1,257 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use pallet_randomness::{RandomnessResults, Requests, WeightInfo};
+use precompile_utils::{
+	costs::{call_cost, log_costs, memory_cost},
+	testing::{solidity::get_selectors, *},
+};
+use std::{cell::RefCell, rc::Rc};
+
+type PCall = RandomnessPrecompileCall<Runtime>;
+
+const FEE: Balance = 100;
+const GAS_LIMIT: u64 = 100_000;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// ALICE requests one local random word, available after block 3.
+fn request_local_randomness() {
+	precompiles()
+		.prepare_test(
+			ALICE,
+			PRECOMPILE,
+			PCall::request_local_randomness {
+				refund_address: Address(ALICE),
+				fee: FEE.into(),
+				gas_limit: GAS_LIMIT,
+				salt: H256::repeat_byte(1),
+				num_words: 1,
+				delay: 2,
+			},
+		)
+		.execute_returns_encoded(U256::zero());
+}
+
+/// Makes the randomness of the request of `request_local_randomness` available.
+fn make_randomness_available() {
+	RandomnessResults::<Runtime>::mutate(RequestType::Local(3), |result| {
+		result.as_mut().unwrap().randomness = Some(H256::repeat_byte(2))
+	});
+	System::set_block_number(4);
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["RandomnessInterface.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn consumer_callback_matches_solidity_consumer() {
+	let source = std::fs::read_to_string("RandomnessConsumer.sol").unwrap();
+	let callback = get_selectors(&source)
+		.into_iter()
+		.find(|function| function.signature == "rawFulfillRandomWords(uint256,uint256[])")
+		.expect("RandomnessConsumer.sol declares rawFulfillRandomWords");
+
+	assert_eq!(callback.selector, ConsumerAction::RawFulfillRandomWords as u32);
+	assert_eq!(callback.documented_selector, Some(callback.selector));
+}
+
+#[test]
+fn request_reserves_deposit_and_fee() {
+	new_test_ext().execute_with(|| {
+		request_local_randomness();
+
+		assert_eq!(Balances::reserved_balance(account(ALICE)), DEPOSIT + FEE);
+		let state = Requests::<Runtime>::get(0).unwrap();
+		assert_eq!(state.request.contract_address, ALICE);
+		assert_eq!(state.request.gas_limit, GAS_LIMIT);
+	})
+}
+
+#[test]
+fn request_status_follows_the_request() {
+	new_test_ext().execute_with(|| {
+		let expect_status = |expected: RequestStatus| {
+			precompiles()
+				.prepare_test(BOB, PRECOMPILE, PCall::get_request_status { request_id: 0.into() })
+				.execute_returns_encoded(expected as u8);
+		};
+
+		expect_status(RequestStatus::DoesNotExist);
+
+		request_local_randomness();
+		expect_status(RequestStatus::Pending);
+
+		make_randomness_available();
+		expect_status(RequestStatus::Ready);
+
+		System::set_block_number(53);
+		expect_status(RequestStatus::Expired);
+	})
+}
+
+#[test]
+fn fulfill_request_calls_back_the_consumer() {
+	new_test_ext().execute_with(|| {
+		request_local_randomness();
+		make_randomness_available();
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::fulfill_request { request_id: 0.into() })
+			.with_subcall_handle(|Subcall { address, input, target_gas, context, .. }| {
+				assert_eq!(address, ALICE);
+				assert_eq!(context.caller, PRECOMPILE);
+				assert_eq!(target_gas, Some(GAS_LIMIT));
+				assert_eq!(
+					input,
+					EvmDataWriter::new_with_selector(ConsumerAction::RawFulfillRandomWords)
+						.write(U256::zero())
+						.write(vec![H256::repeat_byte(2)])
+						.build()
+				);
+				SubcallOutput::succeed()
+			})
+			.expect_log(log1(PRECOMPILE, SELECTOR_LOG_FULFILLMENT_SUCCEEDED, vec![]))
+			.execute_returns(vec![]);
+
+		assert!(Requests::<Runtime>::get(0).is_none());
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+	})
+}
+
+#[test]
+fn failing_callback_still_removes_the_request() {
+	new_test_ext().execute_with(|| {
+		request_local_randomness();
+		make_randomness_available();
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::fulfill_request { request_id: 0.into() })
+			.with_subcall_handle(|_| SubcallOutput::revert())
+			.expect_log(log1(PRECOMPILE, SELECTOR_LOG_FULFILLMENT_FAILED, vec![]))
+			.execute_returns(vec![]);
+
+		assert!(Requests::<Runtime>::get(0).is_none());
+		assert_eq!(Balances::reserved_balance(account(ALICE)), 0);
+	})
+}
+
+#[test]
+fn fulfillment_requires_the_callback_gas_limit_after_eip150() {
+	// Gas needed to fulfill the request of `request_local_randomness` with its full gas limit
+	let weight_to_gas = |weight: frame_support::weights::Weight| {
+		<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+	};
+	// Selector, request id, offset and length of the words, one word
+	let input_len = 4 + 32 * 4;
+	let forwarded = (GAS_LIMIT * 64 + 62) / 63;
+	let required_gas = weight_to_gas(<() as WeightInfo>::prepare_fulfillment()) +
+		call_cost(U256::zero(), <Runtime as pallet_evm::Config>::config()) +
+		memory_cost(input_len) +
+		forwarded +
+		weight_to_gas(<() as WeightInfo>::finish_fulfillment()) +
+		log_costs(1, 0).unwrap();
+	// Only 63/64 of the gas left once the call is paid reaches the callback
+	assert!(forwarded - forwarded / 64 >= GAS_LIMIT);
+
+	new_test_ext().execute_with(|| {
+		request_local_randomness();
+		make_randomness_available();
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::fulfill_request { request_id: 0.into() })
+			.with_target_gas(Some(required_gas - 1))
+			.with_subcall_handle(|_| panic!("the consumer must not be called"))
+			.execute_reverts(|output| {
+				output == b"Not enough gas left to execute the fulfillment callback"
+			});
+	});
+
+	new_test_ext().execute_with(|| {
+		request_local_randomness();
+		make_randomness_available();
+
+		let callback_gas = Rc::new(RefCell::new(None));
+		let callback_gas_in_subcall = callback_gas.clone();
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::fulfill_request { request_id: 0.into() })
+			.with_target_gas(Some(required_gas))
+			.with_subcall_handle(move |Subcall { target_gas, .. }| {
+				*callback_gas_in_subcall.borrow_mut() = target_gas;
+				SubcallOutput::succeed()
+			})
+			.expect_log(log1(PRECOMPILE, SELECTOR_LOG_FULFILLMENT_SUCCEEDED, vec![]))
+			.execute_returns(vec![]);
+
+		assert_eq!(*callback_gas.borrow(), Some(GAS_LIMIT));
+	})
+}
+
+#[test]
+fn only_the_requesting_contract_can_increase_the_fee() {
+	new_test_ext().execute_with(|| {
+		request_local_randomness();
+
+		precompiles()
+			.prepare_test(
+				BOB,
+				PRECOMPILE,
+				PCall::increase_request_fee { request_id: 0.into(), fee_increase: 10.into() },
+			)
+			.execute_reverts(|output| output == b"OnlyRequesterCanIncreaseFee");
+
+		precompiles()
+			.prepare_test(
+				ALICE,
+				PRECOMPILE,
+				PCall::increase_request_fee { request_id: 0.into(), fee_increase: 10.into() },
+			)
+			.execute_returns(vec![]);
+
+		assert_eq!(Requests::<Runtime>::get(0).unwrap().request.fee, FEE + 10);
+		assert_eq!(Balances::reserved_balance(account(ALICE)), DEPOSIT + FEE + 10);
+	})
+}
+
+#[test]
+fn purge_expired_request_pays_the_fee_to_the_caller() {
+	new_test_ext().execute_with(|| {
+		request_local_randomness();
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::purge_expired_request { request_id: 0.into() })
+			.execute_reverts(|output| output == b"RequestHasNotExpired");
+
+		System::set_block_number(53);
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::purge_expired_request { request_id: 0.into() })
+			.execute_returns(vec![]);
+
+		assert!(Requests::<Runtime>::get(0).is_none());
+		assert_eq!(Balances::free_balance(account(BOB)), 1_000 + FEE);
+		assert_eq!(Balances::free_balance(account(ALICE)), 1_000 - FEE);
+	})
+}
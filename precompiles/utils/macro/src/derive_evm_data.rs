@@ -56,6 +56,7 @@ pub fn main(input: TokenStream) -> TokenStream {
 		.collect();
 	let fields_name_lit: Vec<_> =
 		fields_ident.iter().map(|i| LitStr::new(&i.to_string(), i.span())).collect();
+	let struct_name_lit = LitStr::new(&ident.to_string(), ident.span());
 
 	let evm_data_trait_path = {
 		let mut segments = Punctuated::<PathSegment, _>::new();
@@ -113,9 +114,13 @@ pub fn main(input: TokenStream) -> TokenStream {
 				<(#(#fields_ty,)*)>::has_static_size()
 			}
 
-			fn solidity_type() -> String {
+			fn solidity_type() -> ::precompile_utils::data::String {
 				<(#(#fields_ty,)*)>::solidity_type()
 			}
+
+			fn solidity_struct_name() -> Option<::precompile_utils::data::String> {
+				Some(::precompile_utils::data::String::from(#struct_name_lit))
+			}
 		}
 	}
 	.into()
@@ -25,12 +25,14 @@ impl Precompile {
 		let enum_impl = self.expand_enum_impl();
 		let precomp_impl = self.expand_precompile_impl();
 		let test_signature = self.expand_test_solidity_signature();
+		let test_interface = self.expand_test_solidity_interface();
 
 		quote! {
 			#enum_
 			#enum_impl
 			#precomp_impl
 			#test_signature
+			#test_interface
 		}
 	}
 
@@ -491,4 +493,91 @@ impl Precompile {
 			.to_token_stream()
 		}
 	}
+
+	/// Expands the Solidity interface test, if an interface file is provided.
+	/// Like the signature test, it relies on an "inner" function describing the functions
+	/// of the precompile, which is expanded in all build profiles and is generic over the type
+	/// parameters of the precompile if concrete types are provided for tests.
+	pub fn expand_test_solidity_interface(&self) -> impl ToTokens {
+		use case::CaseExt;
+
+		let interface_file = match &self.solidity_interface {
+			Some(path) => path,
+			None => return quote!().to_token_stream(),
+		};
+
+		let span = Span::call_site();
+
+		let functions: Vec<_> = self
+			.variants_content
+			.values()
+			.filter_map(|variant| {
+				let name = variant.solidity_name.as_ref()?;
+				let selector = variant.selectors.first()?;
+
+				let arguments_name =
+					variant.arguments.iter().map(|arg| arg.ident.to_string().to_camel_lowercase());
+				let arguments_type = variant.arguments.iter().map(|arg| &arg.ty);
+				let output_type = &variant.output_type;
+				let docs = &variant.docs;
+
+				let modifier = match variant.modifier {
+					Modifier::NonPayable => "NonPayable",
+					Modifier::Payable => "Payable",
+					Modifier::View => "View",
+				};
+				let modifier = syn::Ident::new(modifier, span);
+
+				Some(quote!(
+					SolidityFunction {
+						name: #name,
+						selector: #selector,
+						arguments: ::sp_std::vec![#(
+							(#arguments_name, declaration_type::<#arguments_type>())
+						),*],
+						outputs: output_types::<#output_type>(),
+						modifier: FunctionModifier::#modifier,
+						docs: &[#(#docs),*],
+					}
+				))
+			})
+			.collect();
+
+		let test_name = format_ident!("__{}_test_solidity_interface", self.impl_ident);
+		let inner_name = format_ident!("__{}_solidity_functions", self.impl_ident);
+
+		let (inner_generics, where_clause, concrete_types) = match &self.test_concrete_types {
+			Some(test_types) => {
+				let (impl_generics, _ty_generics, where_clause) = self.generics.split_for_impl();
+				(quote!(#impl_generics), quote!(#where_clause), quote!(::< #(#test_types),* >))
+			},
+			None => (quote!(), quote!(), quote!()),
+		};
+
+		quote!(
+			#[allow(non_snake_case)]
+			pub(crate) fn #inner_name #inner_generics ()
+				-> ::sp_std::vec::Vec<::precompile_utils::solidity::SolidityFunction>
+				#where_clause
+			{
+				use ::precompile_utils::{
+					data::EvmData,
+					modifier::FunctionModifier,
+					solidity::{declaration_type, output_types, SolidityFunction},
+				};
+
+				::sp_std::vec![#(#functions),*]
+			}
+
+			#[test]
+			#[allow(non_snake_case)]
+			fn #test_name() {
+				::precompile_utils::solidity::check_interface_file(
+					concat!(env!("CARGO_MANIFEST_DIR"), "/", #interface_file),
+					&#inner_name #concrete_types(),
+				);
+			}
+		)
+		.to_token_stream()
+	}
 }
@@ -87,6 +87,10 @@ struct Precompile {
 	/// Ident of a function that performs a check before the call is dispatched to the proper
 	/// function.
 	pre_check: Option<syn::Ident>,
+
+	/// Path of the Solidity interface file, relative to the crate root. A test is generated to
+	/// check that the functions it declares match the precompile.
+	solidity_interface: Option<syn::LitStr>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -121,6 +125,17 @@ struct Variant {
 
 	/// Output of the variant fn (for better error messages).
 	fn_output: syn::Type,
+
+	/// Type wrapped in the `EvmResult` returned by the variant fn, used to describe
+	/// its Solidity output.
+	output_type: syn::Type,
+
+	/// Name of the function in Solidity, extracted from the first selector attribute.
+	/// None if it is only the fallback function.
+	solidity_name: Option<String>,
+
+	/// Lines of the doc comment of the method, carried over to the Solidity interface.
+	docs: Vec<String>,
 }
 
 #[derive(Debug)]
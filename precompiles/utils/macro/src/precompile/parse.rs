@@ -39,6 +39,7 @@ impl Precompile {
 			precompile_set_discriminant_type: None,
 			test_concrete_types: None,
 			pre_check: None,
+			solidity_interface: None,
 		};
 
 		precompile.process_impl_attr(impl_)?;
@@ -62,7 +63,8 @@ impl Precompile {
 	}
 
 	/// Process the attributes used on the `impl` block, which allows to declare
-	/// if it is a PrecompileSet or not, and to provide concrete types for tests if necessary.
+	/// if it is a PrecompileSet or not, to provide concrete types for tests if necessary and
+	/// the Solidity interface file of the precompile.
 	fn process_impl_attr(&mut self, impl_: &mut syn::ItemImpl) -> syn::Result<()> {
 		let attrs = attr::take_attributes::<attr::ImplAttr>(&mut impl_.attrs)?;
 
@@ -85,6 +87,14 @@ impl Precompile {
 
 					self.test_concrete_types = Some(types);
 				},
+				attr::ImplAttr::SolidityInterface(span, path) => {
+					if self.solidity_interface.is_some() {
+						let msg = "Only one Solidity interface file can be provided";
+						return Err(syn::Error::new(span, msg));
+					}
+
+					self.solidity_interface = Some(path);
+				},
 			}
 		}
 
@@ -131,6 +141,7 @@ impl Precompile {
 		let mut arguments = vec![];
 		let mut is_fallback = false;
 		let mut selectors = vec![];
+		let mut solidity_name = None;
 		let initial_arguments = if self.tagged_as_precompile_set { 2 } else { 1 };
 
 		// We first look for unique attributes.
@@ -202,6 +213,12 @@ impl Precompile {
 				attr::MethodAttr::Public(_, signature_lit) => {
 					used = true;
 
+					if solidity_name.is_none() {
+						let signature = signature_lit.value();
+						let name = signature.split('(').next().unwrap_or_default();
+						solidity_name = Some(name.trim().to_string());
+					}
+
 					let selector = self.parse_public_attr(
 						signature_lit,
 						&method_name,
@@ -292,6 +309,23 @@ impl Precompile {
 			},
 		};
 
+		// The Solidity output is described from the type wrapped in `EvmResult`, which
+		// is only needed for methods exposed in a Solidity interface.
+		let wrapped_output_type = match extract_evm_result_output(output_type) {
+			Some(ty) => ty,
+			None if selectors.is_empty() || self.solidity_interface.is_none() =>
+				syn::parse_quote!(()),
+			None => {
+				let msg = "A precompile method must have a return type of `EvmResult<_>` (exposed \
+				by `precompile_utils`, no type alias) to be described in a Solidity interface";
+				return Err(syn::Error::new(output_type.span(), msg));
+			},
+		};
+
+		if self.solidity_interface.is_some() {
+			self.check_type_parameter_usage(&wrapped_output_type)?;
+		}
+
 		// We insert the collected data in self.
 		if let Some(_) = self.variants_content.insert(
 			method_name.clone(),
@@ -301,6 +335,9 @@ impl Precompile {
 				modifier,
 				selectors,
 				fn_output: output_type.as_ref().clone(),
+				output_type: wrapped_output_type,
+				solidity_name,
+				docs: extract_docs(&method.attrs),
 			},
 		) {
 			let msg = "Duplicate method name";
@@ -612,3 +649,39 @@ ensuring the Solidity function signatures are correct.";
 fn is_same_type(a: &syn::Type, b: &syn::Type) -> bool {
 	a == b
 }
+
+/// Extract the type wrapped in an `EvmResult<_>`, which is `()` if no type is provided.
+fn extract_evm_result_output(ty: &syn::Type) -> Option<syn::Type> {
+	let path = match ty {
+		syn::Type::Path(syn::TypePath { qself: None, path }) => path,
+		_ => return None,
+	};
+
+	let segment = path.segments.last()?;
+	if segment.ident != "EvmResult" {
+		return None;
+	}
+
+	match &segment.arguments {
+		syn::PathArguments::None => Some(syn::parse_quote!(())),
+		syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+			syn::GenericArgument::Type(ty) => Some(ty.clone()),
+			_ => None,
+		},
+		_ => None,
+	}
+}
+
+/// Extract the lines of the doc comment from the attributes of an item.
+fn extract_docs(attrs: &[syn::Attribute]) -> Vec<String> {
+	attrs
+		.iter()
+		.filter(|attr| attr.path.is_ident("doc"))
+		.filter_map(|attr| match attr.parse_meta() {
+			Ok(syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(lit), .. })) =>
+				Some(lit.value()),
+			_ => None,
+		})
+		.flat_map(|doc| doc.lines().map(|line| line.trim().to_string()).collect::<Vec<_>>())
+		.collect()
+}
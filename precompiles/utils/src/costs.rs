@@ -46,6 +46,16 @@ pub fn log_costs(topics: usize, data_len: usize) -> EvmResult<u64> {
 		.ok_or(PrecompileFailure::Error { exit_status: ExitError::OutOfGas })
 }
 
+// Compute the cost of expanding the memory to `len` bytes, paid when passing `len` bytes of input
+// to a subcall.
+pub fn memory_cost(len: usize) -> u64 {
+	// Copied from EVM code since not public.
+	const G_MEMORY: u64 = 3;
+
+	let words = (len as u64).saturating_add(31) / 32;
+	G_MEMORY.saturating_mul(words).saturating_add(words.saturating_mul(words) / 512)
+}
+
 // Compute the cost of doing a subcall.
 // Some parameters cannot be known in advance, so we estimate the worst possible cost.
 pub fn call_cost(value: U256, config: &evm::Config) -> u64 {
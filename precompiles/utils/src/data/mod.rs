@@ -45,6 +45,10 @@ pub trait EvmData: Sized {
 	fn is_explicit_tuple() -> bool {
 		false
 	}
+	/// Name of the struct declaring this type in Solidity interfaces, if any.
+	fn solidity_struct_name() -> Option<String> {
+		None
+	}
 }
 
 /// Wrapper around an EVM input slice, helping to parse it.
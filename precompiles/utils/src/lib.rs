@@ -30,8 +30,12 @@ pub mod logs;
 pub mod modifier;
 pub mod precompile_set;
 pub mod revert;
+pub mod solidity;
 pub mod substrate;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,240 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generation of Solidity interfaces from the functions exposed by a precompile.
+//!
+//! The `precompile` macro describes each `public` function of a precompile in a
+//! [`SolidityFunction`], available through the `solidity_functions` function of the
+//! generated call enum. Those descriptions are rendered as Solidity declarations, which
+//! are checked against the interface files shipped with the precompiles.
+
+use crate::{data::EvmData, modifier::FunctionModifier};
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec,
+	vec::Vec,
+};
+
+/// Width above which the arguments of a function declaration are split on multiple lines.
+const MAX_LINE_WIDTH: usize = 100;
+
+/// Description of a precompile function as exposed to Solidity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolidityFunction {
+	/// Name of the function, taken from its first `public` signature.
+	pub name: &'static str,
+	/// Selector of the first `public` signature. Other signatures of the function are
+	/// aliases kept for backward compatibility and are not part of the interface.
+	pub selector: u32,
+	/// Name and Solidity type of each argument.
+	pub arguments: Vec<(&'static str, String)>,
+	/// Solidity types of the returned values.
+	pub outputs: Vec<String>,
+	/// Modifier of the function.
+	pub modifier: FunctionModifier,
+	/// Lines of the doc comment of the function.
+	pub docs: &'static [&'static str],
+}
+
+impl SolidityFunction {
+	/// Render the function as a Solidity declaration with its NatSpec, indented to be part
+	/// of an interface.
+	///
+	/// Doc comment lines are carried over as the `@dev` description of the function, up to
+	/// the first line starting with a NatSpec tag (such as `@param` or `@return`) from which
+	/// lines are carried over as is.
+	pub fn render(&self) -> String {
+		let mut natspec = vec![];
+		let mut tags = vec![];
+
+		for line in self.docs.iter().map(|line| line.trim()).filter(|line| !line.is_empty()) {
+			if !tags.is_empty() || line.starts_with('@') {
+				tags.push(line.to_string());
+			} else if natspec.is_empty() {
+				natspec.push(format!("@dev {line}"));
+			} else {
+				natspec.push(line.to_string());
+			}
+		}
+
+		natspec.push(format!("@custom:selector {:08x}", self.selector));
+		natspec.append(&mut tags);
+
+		let arguments: Vec<_> = self
+			.arguments
+			.iter()
+			.map(|(name, ty)| format!("{} {name}", with_data_location(ty)))
+			.collect();
+
+		let modifier = match self.modifier {
+			FunctionModifier::View => " view",
+			FunctionModifier::NonPayable => "",
+			FunctionModifier::Payable => " payable",
+		};
+
+		let returns = if self.outputs.is_empty() {
+			String::new()
+		} else {
+			let outputs: Vec<_> = self.outputs.iter().map(|ty| with_data_location(ty)).collect();
+			format!(" returns ({})", outputs.join(", "))
+		};
+
+		let mut output = String::new();
+		for line in natspec {
+			output.push_str(&format!("    /// {line}\n"));
+		}
+		let declaration = format!(
+			"    function {}({}) external{modifier}{returns};",
+			self.name,
+			arguments.join(", ")
+		);
+
+		// Declarations too long to fit on a line have one argument per line.
+		if declaration.len() <= MAX_LINE_WIDTH || arguments.is_empty() {
+			output.push_str(&declaration);
+			output.push('\n');
+		} else {
+			output.push_str(&format!("    function {}(\n", self.name));
+			output.push_str(&format!("        {}\n", arguments.join(",\n        ")));
+			output.push_str(&format!("    ) external{modifier}{returns};\n"));
+		}
+
+		output
+	}
+}
+
+/// Render the declarations of the provided functions, sorted by name.
+pub fn render_functions(functions: &[SolidityFunction]) -> String {
+	let mut functions: Vec<_> = functions.iter().collect();
+	functions.sort_by_key(|function| function.name);
+
+	let rendered: Vec<_> = functions.iter().map(|function| function.render()).collect();
+	rendered.join("\n")
+}
+
+/// Type of an argument of type `T` in a function declaration: the name of its struct for the
+/// types deriving `EvmData`, which the interface must declare, its Solidity type otherwise.
+pub fn declaration_type<T: EvmData>() -> String {
+	T::solidity_struct_name().unwrap_or_else(T::solidity_type)
+}
+
+/// Solidity types of the values returned by a function returning `T`.
+pub fn output_types<T: EvmData>() -> Vec<String> {
+	let solidity_type = T::solidity_type();
+
+	if solidity_type == "()" {
+		vec![]
+	} else if T::is_explicit_tuple() {
+		split_tuple(&solidity_type)
+	} else {
+		vec![solidity_type]
+	}
+}
+
+/// Split a Solidity tuple type into the types of its members.
+fn split_tuple(tuple: &str) -> Vec<String> {
+	let inner = tuple.strip_prefix('(').and_then(|t| t.strip_suffix(')')).unwrap_or(tuple);
+
+	let mut members = vec![];
+	let mut depth = 0usize;
+	let mut start = 0;
+
+	for (index, c) in inner.char_indices() {
+		match c {
+			'(' => depth += 1,
+			')' => depth = depth.saturating_sub(1),
+			',' if depth == 0 => {
+				members.push(inner[start..index].to_string());
+				start = index + 1;
+			},
+			_ => (),
+		}
+	}
+	members.push(inner[start..].to_string());
+
+	members
+}
+
+/// Solidity types which are not value types must be declared with a data location.
+fn with_data_location(solidity_type: &str) -> String {
+	let is_reference = solidity_type == "bytes" ||
+		solidity_type == "string" ||
+		solidity_type.ends_with(']') ||
+		solidity_type.starts_with('(') ||
+		solidity_type.starts_with(char::is_uppercase);
+
+	if is_reference {
+		format!("{solidity_type} memory")
+	} else {
+		solidity_type.to_string()
+	}
+}
+
+/// Line opening the generated part of an interface file.
+#[cfg(feature = "testing")]
+pub const GENERATED_START: &str =
+	"    // --- Generated from the precompile implementation, do not edit ---";
+
+/// Line closing the generated part of an interface file.
+#[cfg(feature = "testing")]
+pub const GENERATED_END: &str = "    // --- End of generated functions ---";
+
+/// Environment variable which makes [`check_interface_file`] write the generated functions
+/// in the interface file instead of failing if it is out of date.
+#[cfg(feature = "testing")]
+pub const UPDATE_ENV_VAR: &str = "UPDATE_SOLIDITY_INTERFACES";
+
+/// Check that the function declarations of an interface file match the ones generated from
+/// the precompile, panicking if they drifted.
+///
+/// Only the lines between [`GENERATED_START`] and [`GENERATED_END`] are generated, which
+/// allows the rest of the file (header, events, structs) to be written by hand.
+/// If the [`UPDATE_ENV_VAR`] environment variable is set, the file is updated instead.
+#[cfg(feature = "testing")]
+pub fn check_interface_file(path: impl AsRef<std::path::Path>, functions: &[SolidityFunction]) {
+	let path = path.as_ref();
+	let content = std::fs::read_to_string(path)
+		.unwrap_or_else(|e| panic!("cannot read interface file {}: {e}", path.display()));
+
+	let (head, rest) = content
+		.split_once(&format!("{GENERATED_START}\n"))
+		.unwrap_or_else(|| panic!("{} lacks the line: {GENERATED_START}", path.display()));
+	let (_, tail) = rest
+		.split_once(&format!("{GENERATED_END}\n"))
+		.unwrap_or_else(|| panic!("{} lacks the line: {GENERATED_END}", path.display()));
+
+	let expected =
+		format!("{head}{GENERATED_START}\n{}{GENERATED_END}\n{tail}", render_functions(functions));
+
+	if expected == content {
+		return;
+	}
+
+	if std::env::var_os(UPDATE_ENV_VAR).is_some() {
+		std::fs::write(path, expected)
+			.unwrap_or_else(|e| panic!("cannot write interface file {}: {e}", path.display()));
+	} else {
+		panic!(
+			"{} is out of date with the precompile, run the tests with {UPDATE_ENV_VAR}=1 to \
+			update it.\n\n{}",
+			path.display(),
+			render_functions(functions)
+		);
+	}
+}
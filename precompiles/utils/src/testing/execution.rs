@@ -0,0 +1,266 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{decode_revert_message, MockHandle, PrettyLog, SubcallHandle, SubcallTrait};
+use crate::{data::encode_as_function_return_value, revert::Revert, EvmData};
+use evm::{Context, ExitError, ExitSucceed};
+use fp_evm::{Log, PrecompileFailure, PrecompileOutput, PrecompileResult, PrecompileSet};
+use sp_core::{H160, U256};
+
+/// Fluent builder to call a precompile set in a test and check the outcome of the call.
+/// Expectations on the cost and logs are checked when the call is executed with one of
+/// the `execute_*` functions.
+#[must_use]
+pub struct PrecompilesTester<'p, P> {
+	precompiles: &'p P,
+	handle: MockHandle,
+
+	target_gas: Option<u64>,
+	subcall_handle: Option<SubcallHandle>,
+
+	expected_cost: Option<u64>,
+	expected_logs: Option<Vec<PrettyLog>>,
+	static_call: bool,
+}
+
+impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
+	pub fn new(
+		precompiles: &'p P,
+		from: impl Into<H160>,
+		to: impl Into<H160>,
+		data: Vec<u8>,
+	) -> Self {
+		let to = to.into();
+		let mut handle = MockHandle::new(
+			to,
+			Context { address: to, caller: from.into(), apparent_value: U256::zero() },
+		);
+
+		handle.input = data;
+
+		Self {
+			precompiles,
+			handle,
+
+			target_gas: None,
+			subcall_handle: None,
+
+			expected_cost: None,
+			expected_logs: None,
+			static_call: false,
+		}
+	}
+
+	/// Value transferred with the call.
+	pub fn with_value(mut self, value: impl Into<U256>) -> Self {
+		self.handle.context.apparent_value = value.into();
+		self
+	}
+
+	/// Function answering the subcalls made by the precompile.
+	pub fn with_subcall_handle(mut self, subcall_handle: impl SubcallTrait) -> Self {
+		self.subcall_handle = Some(Box::new(subcall_handle));
+		self
+	}
+
+	/// Gas limit of the call, unlimited if `None`.
+	pub fn with_target_gas(mut self, target_gas: Option<u64>) -> Self {
+		self.target_gas = target_gas;
+		self
+	}
+
+	pub fn with_static_call(mut self, static_call: bool) -> Self {
+		self.static_call = static_call;
+		self
+	}
+
+	/// Expect the call to cost exactly this amount of gas.
+	pub fn expect_cost(mut self, cost: u64) -> Self {
+		self.expected_cost = Some(cost);
+		self
+	}
+
+	/// Expect the call to emit no logs.
+	pub fn expect_no_logs(mut self) -> Self {
+		self.expected_logs = Some(vec![]);
+		self
+	}
+
+	/// Expect the call to emit this log, in addition to the logs previously expected.
+	pub fn expect_log(mut self, log: Log) -> Self {
+		self.expected_logs = Some({
+			let mut logs = self.expected_logs.unwrap_or_default();
+			logs.push(PrettyLog(log));
+			logs
+		});
+		self
+	}
+
+	fn assert_optionals(&self) {
+		if let Some(cost) = &self.expected_cost {
+			assert_eq!(&self.handle.gas_used, cost, "the call doesn't have the expected cost");
+		}
+
+		if let Some(logs) = &self.expected_logs {
+			similar_asserts::assert_eq!(
+				&self.handle.logs.clone().into_iter().map(PrettyLog).collect::<Vec<_>>(),
+				logs
+			);
+		}
+	}
+
+	fn execute(&mut self) -> Option<PrecompileResult> {
+		self.handle.gas_limit = self.target_gas.unwrap_or(u64::MAX);
+		self.handle.subcall_handle = self.subcall_handle.take();
+		self.handle.is_static = self.static_call;
+
+		let res = self.precompiles.execute(&mut self.handle);
+
+		self.subcall_handle = self.handle.subcall_handle.take();
+
+		res
+	}
+
+	/// Execute the call and check that the address is not a precompile of the set.
+	pub fn execute_none(mut self) {
+		let res = self.execute();
+		assert!(res.is_none(), "the address should not be a precompile");
+
+		self.assert_optionals();
+	}
+
+	/// Execute the call and check it returns the provided raw output.
+	pub fn execute_returns(mut self, output: Vec<u8>) {
+		let res = self.execute();
+
+		match res {
+			Some(Ok(PrecompileOutput {
+				exit_status: ExitSucceed::Returned,
+				output: res_output,
+			})) => similar_asserts::assert_eq!(
+				decode_chunks(&res_output),
+				decode_chunks(&output),
+				"the call doesn't return the expected output"
+			),
+			other => panic!("the call didn't return successfully: {}", describe(other)),
+		}
+
+		self.assert_optionals();
+	}
+
+	/// Execute the call and check it returns the provided value, encoded as a function
+	/// return value.
+	pub fn execute_returns_encoded(self, output: impl EvmData) {
+		self.execute_returns(encode_as_function_return_value(output))
+	}
+
+	/// Execute the call and check it reverts with an output satisfying the provided check.
+	pub fn execute_reverts(mut self, check: impl Fn(&[u8]) -> bool) {
+		let res = self.execute();
+
+		match res {
+			Some(Err(PrecompileFailure::Revert { output, .. })) => {
+				let decoded = decode_revert_message(&output).unwrap_or_else(|| output.clone());
+				if !check(&decoded) {
+					panic!(
+						"the revert output doesn't satisfy the check: {}",
+						String::from_utf8_lossy(&decoded)
+					);
+				}
+			},
+			other => panic!("the call didn't revert: {}", describe(other)),
+		}
+
+		self.assert_optionals();
+	}
+
+	/// Execute the call and check it reverts with the provided reason and backtrace, such as
+	/// `RevertReason::value_is_too_large("balance type").in_field("value")`.
+	pub fn execute_reverts_with(self, revert: impl Into<Revert>) {
+		let expected = revert.into().to_bytes();
+
+		self.execute_reverts(|output| {
+			similar_asserts::assert_eq!(
+				String::from_utf8_lossy(output),
+				String::from_utf8_lossy(&expected)
+			);
+			true
+		})
+	}
+
+	/// Execute the call and check it fails with the provided error.
+	pub fn execute_error(mut self, error: ExitError) {
+		let res = self.execute();
+
+		match res {
+			Some(Err(PrecompileFailure::Error { exit_status })) => assert_eq!(exit_status, error),
+			other => panic!("the call didn't fail with an error: {}", describe(other)),
+		}
+
+		self.assert_optionals();
+	}
+
+	/// Execute the call and check the address is a precompile of the set, whatever the
+	/// outcome of the call.
+	pub fn execute_some(mut self) {
+		let res = self.execute();
+		assert!(res.is_some(), "the address should be a precompile");
+
+		self.assert_optionals();
+	}
+}
+
+/// Allows to call a precompile set with a [`PrecompilesTester`].
+pub trait PrecompileTesterExt: PrecompileSet + Sized {
+	fn prepare_test(
+		&self,
+		from: impl Into<H160>,
+		to: impl Into<H160>,
+		data: impl Into<Vec<u8>>,
+	) -> PrecompilesTester<Self>;
+}
+
+impl<T: PrecompileSet> PrecompileTesterExt for T {
+	fn prepare_test(
+		&self,
+		from: impl Into<H160>,
+		to: impl Into<H160>,
+		data: impl Into<Vec<u8>>,
+	) -> PrecompilesTester<Self> {
+		PrecompilesTester::new(self, from, to, data.into())
+	}
+}
+
+/// Split the output in chunks of 32 bytes to make differences easier to read.
+fn decode_chunks(output: &[u8]) -> Vec<String> {
+	output.chunks(32).map(super::to_hex).collect()
+}
+
+/// Describe the unexpected outcome of a call.
+fn describe(res: Option<PrecompileResult>) -> String {
+	match res {
+		None => String::from("the address is not a precompile"),
+		Some(Ok(PrecompileOutput { exit_status, output })) =>
+			format!("returned with status {exit_status:?}: {:?}", decode_chunks(&output)),
+		Some(Err(PrecompileFailure::Revert { output, .. })) => format!(
+			"reverted: {}",
+			String::from_utf8_lossy(&decode_revert_message(&output).unwrap_or(output))
+		),
+		Some(Err(failure)) => format!("failed: {failure:?}"),
+	}
+}
@@ -0,0 +1,246 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use evm::{Context, ExitError, ExitReason, ExitRevert, ExitSucceed, Transfer};
+use fp_evm::{Log, PrecompileHandle};
+use sp_core::{H160, H256};
+
+/// Subcall made by a precompile through the handle.
+#[derive(Debug, Clone)]
+pub struct Subcall {
+	pub address: H160,
+	pub transfer: Option<Transfer>,
+	pub input: Vec<u8>,
+	pub target_gas: Option<u64>,
+	pub is_static: bool,
+	pub context: Context,
+}
+
+/// Result of a subcall, provided by the test.
+#[derive(Debug, Clone)]
+pub struct SubcallOutput {
+	pub reason: ExitReason,
+	pub output: Vec<u8>,
+	pub cost: u64,
+	pub logs: Vec<Log>,
+}
+
+impl SubcallOutput {
+	/// Subcall returning successfully without output.
+	pub fn succeed() -> Self {
+		Self {
+			reason: ExitReason::Succeed(ExitSucceed::Returned),
+			output: Vec::new(),
+			cost: 0,
+			logs: Vec::new(),
+		}
+	}
+
+	/// Subcall reverting without output.
+	pub fn revert() -> Self {
+		Self {
+			reason: ExitReason::Revert(ExitRevert::Reverted),
+			output: Vec::new(),
+			cost: 0,
+			logs: Vec::new(),
+		}
+	}
+
+	/// Subcall running out of gas.
+	pub fn out_of_gas() -> Self {
+		Self {
+			reason: ExitReason::Error(ExitError::OutOfGas),
+			output: Vec::new(),
+			cost: 0,
+			logs: Vec::new(),
+		}
+	}
+}
+
+/// Function answering the subcalls made by a precompile.
+pub trait SubcallTrait: FnMut(Subcall) -> SubcallOutput + 'static {}
+
+impl<T: FnMut(Subcall) -> SubcallOutput + 'static> SubcallTrait for T {}
+
+pub type SubcallHandle = Box<dyn SubcallTrait>;
+
+/// Mock handle to write tests for precompiles.
+pub struct MockHandle {
+	pub gas_limit: u64,
+	pub gas_used: u64,
+	pub logs: Vec<Log>,
+	pub subcall_handle: Option<SubcallHandle>,
+	pub code_address: H160,
+	pub input: Vec<u8>,
+	pub context: Context,
+	pub is_static: bool,
+}
+
+impl MockHandle {
+	/// Create a handle calling the precompile at `code_address` with the provided context,
+	/// an empty input and no gas limit.
+	pub fn new(code_address: H160, context: Context) -> Self {
+		Self {
+			gas_limit: u64::MAX,
+			gas_used: 0,
+			logs: Vec::new(),
+			subcall_handle: None,
+			code_address,
+			input: Vec::new(),
+			context,
+			is_static: false,
+		}
+	}
+}
+
+impl PrecompileHandle for MockHandle {
+	/// Perform a subcall in provided context.
+	/// Precompile specifies in which context the subcall is executed.
+	fn call(
+		&mut self,
+		address: H160,
+		transfer: Option<Transfer>,
+		input: Vec<u8>,
+		target_gas: Option<u64>,
+		is_static: bool,
+		context: &Context,
+	) -> (ExitReason, Vec<u8>) {
+		let cost = crate::costs::call_cost(context.apparent_value, &evm::Config::london());
+		if self.record_cost(cost).is_err() {
+			return (ExitReason::Error(ExitError::OutOfGas), vec![]);
+		}
+
+		let subcall_handle = self
+			.subcall_handle
+			.as_mut()
+			.expect("the precompile made a subcall but no subcall handle was provided");
+
+		let SubcallOutput { reason, output, cost, logs } = subcall_handle(Subcall {
+			address,
+			transfer,
+			input,
+			target_gas,
+			is_static,
+			context: context.clone(),
+		});
+
+		if self.record_cost(cost).is_err() {
+			return (ExitReason::Error(ExitError::OutOfGas), vec![]);
+		}
+
+		self.logs.extend(logs);
+
+		(reason, output)
+	}
+
+	fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
+		self.gas_used = self.gas_used.saturating_add(cost);
+
+		if self.gas_used > self.gas_limit {
+			Err(ExitError::OutOfGas)
+		} else {
+			Ok(())
+		}
+	}
+
+	fn remaining_gas(&self) -> u64 {
+		self.gas_limit.saturating_sub(self.gas_used)
+	}
+
+	fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) -> Result<(), ExitError> {
+		self.logs.push(Log { address, topics, data });
+		Ok(())
+	}
+
+	/// Retrieve the code address (what is the address of the precompile being called).
+	fn code_address(&self) -> H160 {
+		self.code_address
+	}
+
+	/// Retrieve the input data the precompile is called with.
+	fn input(&self) -> &[u8] {
+		&self.input
+	}
+
+	/// Retrieve the context in which the precompile is executed.
+	fn context(&self) -> &Context {
+		&self.context
+	}
+
+	/// Is the precompile call done statically.
+	fn is_static(&self) -> bool {
+		self.is_static
+	}
+
+	/// Retrieve the gas limit of this call.
+	fn gas_limit(&self) -> Option<u64> {
+		Some(self.gas_limit)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::U256;
+
+	fn handle() -> MockHandle {
+		let context = Context {
+			address: H160::repeat_byte(1),
+			caller: H160::repeat_byte(2),
+			apparent_value: U256::zero(),
+		};
+
+		MockHandle::new(H160::repeat_byte(1), context)
+	}
+
+	#[test]
+	fn record_cost_fails_above_gas_limit() {
+		let mut handle = handle();
+		handle.gas_limit = 100;
+
+		assert_eq!(handle.record_cost(60), Ok(()));
+		assert_eq!(handle.remaining_gas(), 40);
+		assert_eq!(handle.record_cost(60), Err(ExitError::OutOfGas));
+		assert_eq!(handle.remaining_gas(), 0);
+	}
+
+	#[test]
+	fn subcall_cost_and_logs_are_recorded() {
+		let mut handle = handle();
+		let log = Log { address: H160::repeat_byte(3), topics: vec![], data: vec![1] };
+		let subcall_log = log.clone();
+
+		handle.subcall_handle = Some(Box::new(move |subcall: Subcall| {
+			assert_eq!(subcall.address, H160::repeat_byte(3));
+			assert_eq!(subcall.input, vec![42]);
+
+			SubcallOutput {
+				cost: 1_000,
+				logs: vec![subcall_log.clone()],
+				..SubcallOutput::succeed()
+			}
+		}));
+
+		let context = handle.context.clone();
+		let (reason, _) = handle.call(H160::repeat_byte(3), None, vec![42], None, false, &context);
+
+		assert_eq!(reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert!(handle.gas_used >= 1_000);
+		assert_eq!(handle.logs, vec![log]);
+	}
+}
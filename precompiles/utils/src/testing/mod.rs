@@ -0,0 +1,73 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Utilities to test precompiles, available with the `testing` feature.
+//!
+//! [`MockHandle`] implements `PrecompileHandle` with configurable gas, context and subcalls,
+//! while [`PrecompileTesterExt::prepare_test`] provides a fluent way to call a precompile
+//! set and check its cost, logs and output. [`mock_runtime!`](crate::mock_runtime) and
+//! [`ExtBuilder`] provide the mock runtime the precompile crates test against.
+
+pub mod execution;
+pub mod handle;
+pub mod runtime;
+pub mod solidity;
+
+pub use execution::{PrecompileTesterExt, PrecompilesTester};
+pub use handle::{MockHandle, Subcall, SubcallHandle, SubcallOutput, SubcallTrait};
+pub use runtime::{accounts, ExtBuilder, PRECOMPILE};
+pub use solidity::check_precompile_implements_solidity_interfaces;
+
+use crate::{data::UnboundedBytes, revert::RevertSelector, EvmDataReader};
+use fp_evm::Log;
+
+/// Wrapper around a log providing a readable `Debug` implementation, to be used in assertions.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrettyLog(pub Log);
+
+impl core::fmt::Debug for PrettyLog {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+		let topics: Vec<_> = self.0.topics.iter().map(|topic| format!("{topic:?}")).collect();
+
+		// Data is displayed in chunks of 32 bytes, which are the size of EVM words.
+		let data: Vec<_> = self.0.data.chunks(32).map(to_hex).collect();
+
+		f.debug_struct("Log")
+			.field("address", &self.0.address)
+			.field("topics", &topics)
+			.field("data", &data)
+			.finish()
+	}
+}
+
+/// Decode the message of a revert output encoded as `Error(string)`.
+/// Returns `None` if the output is not encoded this way.
+pub fn decode_revert_message(output: &[u8]) -> Option<Vec<u8>> {
+	if output.get(0..4)? != (RevertSelector::Generic as u32).to_be_bytes() {
+		return None;
+	}
+
+	let mut reader = EvmDataReader::new_skip_selector(output).ok()?;
+	let message: UnboundedBytes = reader.read().ok()?;
+
+	Some(message.into())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
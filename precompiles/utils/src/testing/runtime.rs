@@ -0,0 +1,213 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mock runtime shared by the precompile tests.
+//!
+//! [`mock_runtime!`](crate::mock_runtime) declares a runtime with `System`, `Balances`,
+//! `Timestamp` and `Evm` next to the pallets of the tested precompile, [`ExtBuilder`] builds its
+//! externalities.
+
+use frame_support::traits::GenesisBuild;
+use pallet_evm::AddressMapping;
+use sp_core::H160;
+use sp_io::TestExternalities;
+
+/// Accounts used by the precompile tests.
+pub mod accounts {
+	use sp_core::H160;
+
+	pub const ALICE: H160 = H160([0xAA; 20]);
+	pub const BOB: H160 = H160([0xBB; 20]);
+	pub const CHARLIE: H160 = H160([0xCC; 20]);
+	pub const DAVE: H160 = H160([0xDD; 20]);
+}
+
+/// Address of the tested precompile in the mock runtimes.
+pub const PRECOMPILE: H160 = H160([0x08; 20]);
+
+/// Declares the mock `Runtime` of a precompile crate.
+///
+/// `precompiles` is the `PrecompilesType` of `pallet_evm`, usually a `PrecompileSetBuilder` with
+/// the tested precompile at `PrecompileAddress`. The pallets listed in `pallets` are added to
+/// `construct_runtime!` after `System`, `Balances`, `Timestamp` and `Evm`, their `Config` is
+/// implemented by the caller.
+///
+/// The calling crate must depend on `frame-support`, `frame-system`, `pallet-balances`,
+/// `pallet-timestamp`, `pallet-evm`, `sp-core` and `sp-runtime`.
+#[macro_export]
+macro_rules! mock_runtime {
+	(
+		precompiles: $precompiles:ty,
+		pallets: { $($pallets:tt)* } $(,)?
+	) => {
+		pub(crate) type AccountId = sp_runtime::AccountId32;
+		pub(crate) type Balance = u128;
+		pub(crate) type BlockNumber = u64;
+
+		type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+		type Block = frame_system::mocking::MockBlock<Runtime>;
+
+		frame_support::construct_runtime!(
+			pub enum Runtime where
+				Block = Block,
+				NodeBlock = Block,
+				UncheckedExtrinsic = UncheckedExtrinsic,
+			{
+				System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+				Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+				Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+				Evm: pallet_evm::{Pallet, Call, Storage, Event<T>},
+				$($pallets)*
+			}
+		);
+
+		impl frame_system::Config for Runtime {
+			type BaseCallFilter = frame_support::traits::Everything;
+			type DbWeight = ();
+			type RuntimeOrigin = RuntimeOrigin;
+			type Index = u64;
+			type BlockNumber = BlockNumber;
+			type RuntimeCall = RuntimeCall;
+			type Hash = sp_core::H256;
+			type Hashing = sp_runtime::traits::BlakeTwo256;
+			type AccountId = AccountId;
+			type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+			type Header = sp_runtime::testing::Header;
+			type RuntimeEvent = RuntimeEvent;
+			type BlockHashCount = frame_support::traits::ConstU64<250>;
+			type Version = ();
+			type PalletInfo = PalletInfo;
+			type AccountData = pallet_balances::AccountData<Balance>;
+			type OnNewAccount = ();
+			type OnKilledAccount = ();
+			type SystemWeightInfo = ();
+			type BlockWeights = ();
+			type BlockLength = ();
+			type SS58Prefix = ();
+			type OnSetCode = ();
+			type MaxConsumers = frame_support::traits::ConstU32<16>;
+		}
+
+		impl pallet_balances::Config for Runtime {
+			type MaxReserves = ();
+			type ReserveIdentifier = [u8; 4];
+			type MaxLocks = ();
+			type Balance = Balance;
+			type RuntimeEvent = RuntimeEvent;
+			type DustRemoval = ();
+			type ExistentialDeposit = frame_support::traits::ConstU128<1>;
+			type AccountStore = System;
+			type WeightInfo = ();
+		}
+
+		impl pallet_timestamp::Config for Runtime {
+			type Moment = u64;
+			type OnTimestampSet = ();
+			type MinimumPeriod = frame_support::traits::ConstU64<5>;
+			type WeightInfo = ();
+		}
+
+		pub(crate) use $crate::testing::{accounts::*, PRECOMPILE};
+
+		frame_support::parameter_types! {
+			pub PrecompileAddress: sp_core::H160 = PRECOMPILE;
+			pub BlockGasLimit: sp_core::U256 = sp_core::U256::max_value();
+			pub PrecompilesValue: $precompiles = <$precompiles>::new();
+			pub const WeightPerGas: frame_support::weights::Weight =
+				frame_support::weights::Weight::from_ref_time(1);
+		}
+
+		impl pallet_evm::Config for Runtime {
+			type FeeCalculator = ();
+			type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+			type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+			type CallOrigin = pallet_evm::EnsureAddressRoot<AccountId>;
+			type WithdrawOrigin = pallet_evm::EnsureAddressNever<AccountId>;
+			type AddressMapping = pallet_evm::HashedAddressMapping<sp_runtime::traits::BlakeTwo256>;
+			type Currency = Balances;
+			type RuntimeEvent = RuntimeEvent;
+			type Runner = pallet_evm::runner::stack::Runner<Self>;
+			type PrecompilesType = $precompiles;
+			type PrecompilesValue = PrecompilesValue;
+			type ChainId = ();
+			type OnChargeTransaction = ();
+			type BlockGasLimit = BlockGasLimit;
+			type FindAuthor = ();
+			type WeightPerGas = WeightPerGas;
+			type OnCreate = ();
+		}
+
+		/// Substrate account of an EVM address.
+		#[allow(dead_code)]
+		pub(crate) fn account(address: sp_core::H160) -> AccountId {
+			<<Runtime as pallet_evm::Config>::AddressMapping as pallet_evm::AddressMapping<
+				AccountId,
+			>>::into_account_id(address)
+		}
+	};
+}
+
+/// Builds the externalities of a mock runtime declared with
+/// [`mock_runtime!`](crate::mock_runtime), starting at block 1.
+pub struct ExtBuilder<R: pallet_balances::Config> {
+	balances: Vec<(H160, R::Balance)>,
+}
+
+impl<R: pallet_balances::Config> Default for ExtBuilder<R> {
+	fn default() -> Self {
+		Self { balances: Vec::new() }
+	}
+}
+
+impl<R> ExtBuilder<R>
+where
+	R: pallet_balances::Config + pallet_evm::Config,
+{
+	/// Endows the given EVM accounts.
+	pub fn with_balances(mut self, balances: Vec<(H160, R::Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn build(self) -> TestExternalities {
+		self.build_with(|_| ())
+	}
+
+	/// Builds the externalities, `genesis` adds the genesis of the pallets of the precompile.
+	pub fn build_with(
+		self,
+		genesis: impl FnOnce(&mut sp_core::storage::Storage),
+	) -> TestExternalities {
+		let mut storage = frame_system::GenesisConfig::default().build_storage::<R>().unwrap();
+
+		pallet_balances::GenesisConfig::<R> {
+			balances: self
+				.balances
+				.into_iter()
+				.map(|(address, balance)| (R::AddressMapping::into_account_id(address), balance))
+				.collect(),
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+		genesis(&mut storage);
+
+		let mut ext = TestExternalities::from(storage);
+		ext.execute_with(|| frame_system::Pallet::<R>::set_block_number(1u32.into()));
+		ext
+	}
+}
@@ -0,0 +1,270 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks of the Solidity interface files shipped with the precompiles.
+
+use sha3::{Digest, Keccak256};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Token inserted in place of a `@custom:selector` NatSpec tag when stripping comments.
+const SELECTOR_MARKER: &str = "@selector:";
+
+/// Function declared in a Solidity interface file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolidityFunctionDeclaration {
+	/// Canonical signature of the function, such as `transfer(address,uint256)`.
+	pub signature: String,
+	/// Selector computed from the signature.
+	pub selector: u32,
+	/// Selector written in the `@custom:selector` NatSpec tag of the function, if any.
+	pub documented_selector: Option<u32>,
+}
+
+/// Parse the functions declared in a Solidity source file.
+/// Struct types are replaced by the tuple of their members and enums by `uint8`, which is
+/// how they are encoded in function selectors.
+pub fn get_selectors(source: &str) -> Vec<SolidityFunctionDeclaration> {
+	let tokens = tokenize(source);
+	let mut tokens = tokens.iter().map(String::as_str);
+
+	let mut structs: BTreeMap<String, Vec<String>> = BTreeMap::new();
+	let mut enums = BTreeSet::new();
+	let mut functions = vec![];
+	let mut documented_selector = None;
+
+	while let Some(token) = tokens.next() {
+		match token {
+			"struct" => {
+				let name = tokens.next().expect("struct must have a name").to_string();
+				let body: Vec<_> = tokens.by_ref().skip(1).take_while(|t| *t != "}").collect();
+				let members = body
+					.split(|t| *t == ";")
+					.filter_map(|member| member.first().map(|ty| ty.to_string()))
+					.collect();
+				structs.insert(name, members);
+			},
+			"enum" => {
+				enums.insert(tokens.next().expect("enum must have a name").to_string());
+				tokens.by_ref().take_while(|t| *t != "}").for_each(drop);
+			},
+			"event" => {
+				documented_selector = None;
+			},
+			"function" => {
+				let name = tokens.next().expect("function must have a name").to_string();
+				let params: Vec<_> = tokens.by_ref().skip(1).take_while(|t| *t != ")").collect();
+				let types: Vec<_> = params
+					.split(|t| *t == ",")
+					.filter_map(|param| param.first().map(|ty| ty.to_string()))
+					.collect();
+
+				functions.push((name, types, documented_selector.take()));
+			},
+			token =>
+				if let Some(selector) = token.strip_prefix(SELECTOR_MARKER) {
+					documented_selector = Some(
+						u32::from_str_radix(selector, 16)
+							.unwrap_or_else(|_| panic!("invalid @custom:selector {selector}")),
+					);
+				},
+		}
+	}
+
+	functions
+		.into_iter()
+		.map(|(name, types, documented_selector)| {
+			let types: Vec<_> =
+				types.iter().map(|ty| canonical_type(ty, &structs, &enums)).collect();
+			let signature = format!("{name}({})", types.join(","));
+
+			let digest = Keccak256::digest(signature.as_bytes());
+			let selector = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+
+			SolidityFunctionDeclaration { signature, selector, documented_selector }
+		})
+		.collect()
+}
+
+/// Check that every function declared in the provided Solidity files is supported by the
+/// precompile and has a correct `@custom:selector` tag, if any.
+/// `supports_selector` is usually the `supports_selector` function of the call enum
+/// generated by the `precompile` macro.
+pub fn check_precompile_implements_solidity_interfaces<F: Fn(u32) -> bool>(
+	files: &[&'static str],
+	supports_selector: F,
+) {
+	for file in files {
+		let source = std::fs::read_to_string(file)
+			.unwrap_or_else(|e| panic!("cannot read Solidity file {file}: {e}"));
+
+		for function in get_selectors(&source) {
+			if let Some(documented) = function.documented_selector {
+				assert_eq!(
+					documented, function.selector,
+					"{file}: @custom:selector {documented:08x} of {} doesn't match its \
+					selector {:08x}",
+					function.signature, function.selector,
+				);
+			}
+
+			assert!(
+				supports_selector(function.selector),
+				"{file}: {} (selector {:08x}) is not supported by the precompile",
+				function.signature,
+				function.selector,
+			);
+		}
+	}
+}
+
+/// Split the source code into tokens, ignoring comments except `@custom:selector` tags.
+fn tokenize(source: &str) -> Vec<String> {
+	let mut code = String::new();
+
+	for line in source.lines() {
+		let (content, comment) = match line.find("//") {
+			Some(index) => (&line[..index], Some(&line[index..])),
+			None => (line, None),
+		};
+
+		code.push_str(content);
+		code.push(' ');
+
+		if let Some(selector) = comment.and_then(|c| c.split("@custom:selector").nth(1)) {
+			code.push_str(&format!(" {SELECTOR_MARKER}{} ", selector.trim()));
+		}
+	}
+
+	for punctuation in ["(", ")", "{", "}", ";", ","] {
+		code = code.replace(punctuation, &format!(" {punctuation} "));
+	}
+
+	code.split_whitespace().map(String::from).collect()
+}
+
+/// Type of a parameter as used in function signatures.
+fn canonical_type(
+	ty: &str,
+	structs: &BTreeMap<String, Vec<String>>,
+	enums: &BTreeSet<String>,
+) -> String {
+	let (base, array_suffix) = match ty.find('[') {
+		Some(index) => ty.split_at(index),
+		None => (ty, ""),
+	};
+
+	let base = match base {
+		"uint" => String::from("uint256"),
+		"int" => String::from("int256"),
+		base if enums.contains(base) => String::from("uint8"),
+		base => match structs.get(base) {
+			Some(members) => {
+				let members: Vec<_> =
+					members.iter().map(|member| canonical_type(member, structs, enums)).collect();
+				format!("({})", members.join(","))
+			},
+			None => base.to_string(),
+		},
+	};
+
+	format!("{base}{array_suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SOURCE: &str = "
+		interface Example {
+			/// @dev Some data.
+			struct Data {
+				/// Is `true` if it contains data.
+				bool hasData;
+				bytes value;
+			}
+
+			struct Info {
+				Data display;
+				bool verified;
+			}
+
+			struct SubAccount {
+				bytes32 account;
+				Data name;
+			}
+
+			enum RewardDestination {FreeBalance, StakeBalance}
+
+			/// @custom:selector 63fa9a87
+			function points(uint32 round) external view returns (uint256);
+
+			/// @param destination Where rewards are paid
+			/// @custom:selector 08170573
+			function setRewardDestination(RewardDestination destination) external;
+
+			event Delegated(address indexed delegator, uint256 amount);
+
+			function setIdentity(Info memory info) external;
+
+			/// @custom:selector 32ca728b
+			function setSubs(
+				SubAccount[] memory subs
+			) external;
+
+			/// @custom:selector 77da73b5
+			function delegate(
+				bytes32 candidate,
+				uint amount,
+				uint32 candidateDelegationCount,
+				uint32 delegatorDelegationCount
+			) external;
+		}
+	";
+
+	#[test]
+	fn functions_are_parsed_with_canonical_types() {
+		let signatures: Vec<_> =
+			get_selectors(SOURCE).into_iter().map(|function| function.signature).collect();
+
+		assert_eq!(
+			signatures,
+			vec![
+				"points(uint32)",
+				"setRewardDestination(uint8)",
+				"setIdentity(((bool,bytes),bool))",
+				"setSubs((bytes32,(bool,bytes))[])",
+				"delegate(bytes32,uint256,uint32,uint32)",
+			]
+		);
+	}
+
+	#[test]
+	fn documented_selectors_are_associated_with_their_function() {
+		let functions = get_selectors(SOURCE);
+
+		assert_eq!(functions[0].selector, 0x63fa9a87);
+		assert_eq!(functions[0].documented_selector, Some(0x63fa9a87));
+		assert_eq!(functions[1].documented_selector, Some(0x08170573));
+		assert_eq!(functions[2].selector, 0xb5032292);
+		assert_eq!(functions[2].documented_selector, None);
+		assert_eq!(functions[3].selector, 0x32ca728b);
+		assert_eq!(functions[3].documented_selector, Some(0x32ca728b));
+		assert_eq!(functions[4].selector, 0x77da73b5);
+		assert_eq!(functions[4].documented_selector, Some(0x77da73b5));
+	}
+}
@@ -23,6 +23,7 @@ use crate::{
 	},
 	prelude::*,
 	revert::Backtrace,
+	solidity::{declaration_type, output_types, render_functions, SolidityFunction},
 };
 use frame_support::traits::ConstU32;
 use hex_literal::hex;
@@ -1050,3 +1051,118 @@ fn evm_data_solidity_types() {
 	// Struct encode like tuples
 	assert_eq!(MultiLocation::solidity_type(), "(uint8,bytes[])");
 }
+
+#[test]
+fn solidity_output_types() {
+	assert_eq!(output_types::<()>(), Vec::<String>::new());
+	assert_eq!(output_types::<u32>(), vec!["uint32"]);
+	assert_eq!(output_types::<Vec<H256>>(), vec!["bytes32[]"]);
+	assert_eq!(output_types::<(bool, U256)>(), vec!["bool", "uint256"]);
+	assert_eq!(output_types::<(Vec<(bool, Address)>, u8)>(), vec!["(bool,address)[]", "uint8"]);
+}
+
+#[test]
+fn solidity_function_render() {
+	let function = SolidityFunction {
+		name: "delegationAmount",
+		selector: 0x177c8556,
+		arguments: vec![
+			("delegator", UnboundedBytes::solidity_type()),
+			("candidate", H256::solidity_type()),
+		],
+		outputs: output_types::<U256>(),
+		modifier: FunctionModifier::View,
+		docs: &[
+			"The amount delegated in support of the candidate by the delegator.",
+			"",
+			"@param delegator Who made this delegation",
+			"@param candidate The candidate for which the delegation is in support of",
+			"@return The amount of the delegation",
+		],
+	};
+
+	assert_eq!(
+		function.render(),
+		"    /// @dev The amount delegated in support of the candidate by the delegator.
+    /// @custom:selector 177c8556
+    /// @param delegator Who made this delegation
+    /// @param candidate The candidate for which the delegation is in support of
+    /// @return The amount of the delegation
+    function delegationAmount(bytes memory delegator, bytes32 candidate) external view returns (uint256);
+"
+	);
+}
+
+#[test]
+fn solidity_function_render_long_declaration() {
+	let function = SolidityFunction {
+		name: "delegateWithAutoCompound",
+		selector: 0x61b2d247,
+		arguments: vec![
+			("candidate", H256::solidity_type()),
+			("amount", U256::solidity_type()),
+			("autoCompound", u8::solidity_type()),
+			("candidateDelegationCount", u32::solidity_type()),
+		],
+		outputs: vec![],
+		modifier: FunctionModifier::Payable,
+		docs: &[],
+	};
+
+	assert_eq!(
+		function.render(),
+		"    /// @custom:selector 61b2d247
+    function delegateWithAutoCompound(
+        bytes32 candidate,
+        uint256 amount,
+        uint8 autoCompound,
+        uint32 candidateDelegationCount
+    ) external payable;
+"
+	);
+}
+
+#[test]
+fn solidity_function_render_struct_argument() {
+	assert_eq!(MultiLocation::solidity_struct_name(), Some("MultiLocation".into()));
+	assert_eq!(declaration_type::<MultiLocation>(), "MultiLocation");
+	assert_eq!(declaration_type::<(u8, Vec<UnboundedBytes>)>(), "(uint8,bytes[])");
+
+	let function = SolidityFunction {
+		name: "transfer",
+		selector: 0,
+		arguments: vec![("destination", declaration_type::<MultiLocation>())],
+		outputs: vec![],
+		modifier: FunctionModifier::NonPayable,
+		docs: &[],
+	};
+
+	assert_eq!(
+		function.render(),
+		"    /// @custom:selector 00000000
+    function transfer(MultiLocation memory destination) external;
+"
+	);
+}
+
+#[test]
+fn solidity_functions_are_rendered_by_name() {
+	let function = |name| SolidityFunction {
+		name,
+		selector: 0,
+		arguments: vec![],
+		outputs: vec![],
+		modifier: FunctionModifier::NonPayable,
+		docs: &[],
+	};
+
+	assert_eq!(
+		render_functions(&[function("round"), function("points")]),
+		"    /// @custom:selector 00000000
+    function points() external;
+
+    /// @custom:selector 00000000
+    function round() external;
+"
+	);
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.0;
+
+/// @author The Diora Team
+/// @title Pallet Vesting Interface
+/// @dev The interface through which solidity contracts will interact with pallet-vesting.
+/// Accounts other than the caller are given as 32 bytes.
+/// @custom:address 0x000000000000000000000000000000000000080C
+interface Vesting {
+    // --- Generated from the precompile implementation, do not edit ---
+    /// @dev Unlock the vested funds of the caller.
+    /// @custom:selector 458efde3
+    function vest() external;
+
+    /// @dev Unlock the vested funds of another account.
+    /// @custom:selector 00c00052
+    /// @param target The 32 bytes account whose vested funds are unlocked
+    function vestOther(bytes32 target) external;
+
+    /// @dev Get the amount of an account that is still locked by its vesting schedules
+    /// @custom:selector fbf7a0fd
+    /// @param account The 32 bytes account id to look up
+    /// @return The locked amount, zero if the account has no vesting schedule
+    function vestingBalance(bytes32 account) external view returns (uint256);
+    // --- End of generated functions ---
+}
@@ -0,0 +1,107 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet vesting through an evm precompile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::Currency,
+};
+use pallet_evm::AddressMapping;
+use pallet_vesting::Call as VestingCall;
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_runtime::traits::{Saturating, StaticLookup, Zero};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_vesting::Config>::Currency as Currency<
+	<Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+type BlockNumberToBalanceOf<Runtime> = <Runtime as pallet_vesting::Config>::BlockNumberToBalance;
+
+type VestingOf<Runtime> = pallet_vesting::Pallet<Runtime>;
+
+/// A precompile to wrap the functionality from pallet vesting.
+///
+/// Accounts other than the caller are given as 32 bytes account ids.
+pub struct VestingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+#[precompile::solidity_interface("VestingInterface.sol")]
+impl<Runtime> VestingPrecompile<Runtime>
+where
+	Runtime: pallet_vesting::Config + pallet_evm::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<VestingCall<Runtime>>,
+	BalanceOf<Runtime>: Into<U256>,
+{
+	/// Get the amount of an account that is still locked by its vesting schedules
+	/// @param account The 32 bytes account id to look up
+	/// @return The locked amount, zero if the account has no vesting schedule
+	#[precompile::public("vestingBalance(bytes32)")]
+	#[precompile::view]
+	fn vesting_balance(handle: &mut impl PrecompileHandle, account: H256) -> EvmResult<U256> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let account = Runtime::AccountId::from(account.0);
+		let now = frame_system::Pallet::<Runtime>::block_number();
+		let schedules = VestingOf::<Runtime>::vesting(&account).unwrap_or_default();
+		let locked = schedules.iter().fold(BalanceOf::<Runtime>::zero(), |total, schedule| {
+			total.saturating_add(schedule.locked_at::<BlockNumberToBalanceOf<Runtime>>(now))
+		});
+
+		Ok(locked.into())
+	}
+
+	/// Unlock the vested funds of the caller.
+	#[precompile::public("vest()")]
+	fn vest(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = VestingCall::<Runtime>::vest {};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	/// Unlock the vested funds of another account.
+	/// @param target The 32 bytes account whose vested funds are unlocked
+	#[precompile::public("vestOther(bytes32)")]
+	fn vest_other(handle: &mut impl PrecompileHandle, target: H256) -> EvmResult {
+		let target = Runtime::AccountId::from(target.0);
+		let target = <Runtime as frame_system::Config>::Lookup::unlookup(target);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = VestingCall::<Runtime>::vest_other { target };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+}
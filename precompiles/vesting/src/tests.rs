@@ -0,0 +1,131 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use precompile_utils::testing::*;
+use sp_core::H160;
+
+type PCall = VestingPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Whether the reverted `output` is the dispatch error `error`.
+fn dispatch_error(output: &[u8], error: &str) -> bool {
+	let output = std::str::from_utf8(output).unwrap();
+	output.starts_with("Dispatched call failed with error: ") && output.contains(error)
+}
+
+fn account_h256(address: H160) -> H256 {
+	H256::from(<[u8; 32]>::from(account(address)))
+}
+
+/// Amount locked in the balances pallet for `address`.
+fn locked(address: H160) -> Balance {
+	Balances::locks(account(address)).iter().map(|lock| lock.amount).sum()
+}
+
+fn assert_vesting_balance(address: H160, expected: Balance) {
+	precompiles()
+		.prepare_test(BOB, PRECOMPILE, PCall::vesting_balance { account: account_h256(address) })
+		.expect_no_logs()
+		.execute_returns_encoded(U256::from(expected));
+}
+
+#[test]
+fn selectors_match_solidity_interface() {
+	check_precompile_implements_solidity_interfaces(
+		&["VestingInterface.sol"],
+		PCall::supports_selector,
+	);
+}
+
+#[test]
+fn vesting_balance_decreases_over_time() {
+	new_test_ext().execute_with(|| {
+		// 50 are unlocked per block
+		assert_vesting_balance(ALICE, 450);
+
+		System::set_block_number(4);
+		assert_vesting_balance(ALICE, 300);
+
+		System::set_block_number(10);
+		assert_vesting_balance(ALICE, 0);
+
+		assert_vesting_balance(BOB, 0);
+	});
+}
+
+#[test]
+fn vest_unlocks_vested_funds_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(4);
+		assert_eq!(locked(ALICE), 500);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::vest {})
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_eq!(locked(ALICE), 300);
+	});
+}
+
+#[test]
+fn vest_removes_finished_schedule() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::vest {})
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_eq!(locked(ALICE), 0);
+		assert!(Vesting::vesting(account(ALICE)).is_none());
+	});
+}
+
+#[test]
+fn vest_requires_vesting_schedule() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::vest {})
+			.execute_reverts(|output| dispatch_error(output, "NotVesting"));
+	});
+}
+
+#[test]
+fn vest_other_unlocks_vested_funds_of_the_target() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(4);
+
+		precompiles()
+			.prepare_test(BOB, PRECOMPILE, PCall::vest_other { target: account_h256(ALICE) })
+			.expect_no_logs()
+			.execute_returns(vec![]);
+
+		assert_eq!(locked(ALICE), 300);
+		assert_eq!(locked(BOB), 0);
+
+		precompiles()
+			.prepare_test(ALICE, PRECOMPILE, PCall::vest_other { target: account_h256(BOB) })
+			.execute_reverts(|output| dispatch_error(output, "NotVesting"));
+	});
+}
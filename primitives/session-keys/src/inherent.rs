@@ -0,0 +1,82 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Inherent used to record the relay chain epoch randomness
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use sp_inherents::{Error, InherentData};
+use sp_inherents::{InherentIdentifier, IsFatalError};
+use sp_runtime::RuntimeString;
+
+#[derive(Encode)]
+#[cfg_attr(feature = "std", derive(Debug, Decode))]
+pub enum InherentError {
+	Other(RuntimeString),
+}
+
+impl IsFatalError for InherentError {
+	fn is_fatal_error(&self) -> bool {
+		match *self {
+			InherentError::Other(_) => true,
+		}
+	}
+}
+
+impl InherentError {
+	/// Try to create an instance ouf of the given identifier and data.
+	#[cfg(feature = "std")]
+	pub fn try_from(id: &InherentIdentifier, data: &[u8]) -> Option<Self> {
+		if id == &INHERENT_IDENTIFIER {
+			<InherentError as Decode>::decode(&mut &data[..]).ok()
+		} else {
+			None
+		}
+	}
+}
+
+/// The InherentIdentifier to set the babe randomness results
+pub const INHERENT_IDENTIFIER: InherentIdentifier = *b"baberand";
+
+/// A bare minimum inherent data provider that provides no real data.
+/// The inherent is only used to make sure the randomness pallet reads the relay chain epoch
+/// randomness in every block.
+pub struct InherentDataProvider;
+
+#[cfg(feature = "std")]
+#[async_trait::async_trait]
+impl sp_inherents::InherentDataProvider for InherentDataProvider {
+	async fn provide_inherent_data(&self, inherent_data: &mut InherentData) -> Result<(), Error> {
+		inherent_data.put_data(INHERENT_IDENTIFIER, &())
+	}
+
+	async fn try_handle_error(
+		&self,
+		identifier: &InherentIdentifier,
+		_error: &[u8],
+	) -> Option<Result<(), Error>> {
+		// Don't process modules from other inherents
+		if *identifier != INHERENT_IDENTIFIER {
+			return None;
+		}
+
+		// All errors with the randomness inherent are fatal
+		Some(Err(Error::Application(Box::from(String::from(
+			"Error processing randomness inherent",
+		)))))
+	}
+}
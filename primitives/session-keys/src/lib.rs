@@ -19,7 +19,11 @@
 //! Primitives for session keys
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod digest;
+pub mod inherent;
 pub mod vrf;
+pub use digest::*;
+pub use inherent::*;
 pub use vrf::*;
 
 /// A Trait to lookup keys from AuthorIds
@@ -33,3 +37,13 @@ impl<AuthorId, Keys> KeysLookup<AuthorId, Keys> for () {
 		None
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// Provides the block author with the data needed to produce the VRF pre-digest.
+	pub trait VrfApi {
+		/// VRF input of the next block, derived from the relay chain epoch randomness.
+		fn get_vrf_input() -> Option<Block::Hash>;
+		/// VRF key registered for the given author.
+		fn vrf_key_lookup(nimbus_id: nimbus_primitives::NimbusId) -> Option<crate::VrfId>;
+	}
+}
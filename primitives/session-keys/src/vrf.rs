@@ -19,8 +19,27 @@
 //! VRF Key type, which is sr25519
 use nimbus_primitives::NimbusId;
 use sp_application_crypto::{sr25519, KeyTypeId, UncheckedFrom};
+use sp_consensus_babe::Transcript;
+#[cfg(feature = "std")]
+use sp_keystore::vrf::{VRFTranscriptData, VRFTranscriptValue};
 use sp_runtime::{BoundToRuntimeAppPublic, ConsensusEngineId};
 
+/// Make the VRF transcript from the VRF input of the block
+pub fn make_transcript<Hash: AsRef<[u8]>>(vrf_input: Hash) -> Transcript {
+	let mut transcript = Transcript::new(&VRF_ENGINE_ID);
+	transcript.append_message(b"vrf input", vrf_input.as_ref());
+	transcript
+}
+
+/// Make the VRF transcript data signed by the keystore
+#[cfg(feature = "std")]
+pub fn make_transcript_data<Hash: AsRef<[u8]>>(vrf_input: Hash) -> VRFTranscriptData {
+	VRFTranscriptData {
+		label: &VRF_ENGINE_ID,
+		items: vec![("vrf input", VRFTranscriptValue::Bytes(vrf_input.as_ref().to_vec()))],
+	}
+}
+
 /// Struct to implement `BoundToRuntimeAppPublic` by assigning Public = VrfId
 pub struct VrfSessionKey;
 
@@ -39,6 +58,9 @@ impl From<NimbusId> for VrfId {
 /// The ConsensusEngineId for VRF keys
 pub const VRF_ENGINE_ID: ConsensusEngineId = *b"rand";
 
+/// The VRF inout context used in `make_bytes` to obtain the final randomness value
+pub const VRF_INOUT_CONTEXT: &[u8] = b"VRFInOutContext";
+
 /// The KeyTypeId used for VRF keys
 pub const VRF_KEY_ID: KeyTypeId = KeyTypeId(VRF_ENGINE_ID);
 
@@ -0,0 +1,228 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Upper thresholds of the bags of the parachain staking candidate pool.
+//!
+//! The 200 thresholds grow geometrically, by a ratio of 1.067, from the minimum candidate stake
+//! of 25_000 DIOR up to 10_000_000_000 DIOR. Candidates with more stake are held in a last bag.
+
+use core_primitives::Balance;
+
+/// Upper thresholds of the bags.
+pub const THRESHOLDS: [Balance; 200] = [
+	25_000_000_000_000_000_000_000,
+	26_674_179_164_501_007_855_141,
+	28_460_473_363_995_987_419_098,
+	30_366_390_624_709_507_044_420,
+	32_399_941_764_109_003_063_689,
+	34_569_674_061_409_695_915_047,
+	36_884_707_182_897_817_819_966,
+	39_354_771_513_070_945_490_704,
+	41_990_249_052_706_192_701_089,
+	44_802_217_055_756_148_311_035,
+	47_802_494_588_484_093_608_630,
+	51_003_692_206_532_583_574_595,
+	54_419_264_958_724_555_036_368,
+	58_063_568_940_378_813_007_263,
+	61_951_921_633_840_815_852_880,
+	66_100_666_289_847_837_075_811,
+	70_527_240_620_331_732_539_972,
+	75_250_250_091_384_073_457_517,
+	80_289_546_124_443_484_581_561,
+	85_666_309_534_394_921_632_602,
+	91_403_139_555_282_042_158_297,
+	97_524_148_827_819_286_639_357,
+	104_055_064_747_948_504_154_461,
+	111_023_337_602_421_252_242_711,
+	118_458_255_945_914_649_870_642,
+	126_391_069_704_625_767_249_480,
+	134_855_121_523_764_927_757_009,
+	143_885_986_911_018_473_679_973,
+	153_521_623_765_022_148_093_621,
+	163_802_531_917_332_662_164_704,
+	174_771_923_358_473_048_910_116,
+	186_475_903_863_533_952_422_176,
+	198_963_666_780_726_813_829_451,
+	212_287_699_797_407_379_766_815,
+	226_504_005_552_633_950_229_762,
+	241_672_337_023_523_564_261_617,
+	257_856_448_674_765_510_996_368,
+	275_124_364_426_906_148_491_595,
+	293_548_663_569_710_490_191_858,
+	313_206_785_822_331_504_212_094,
+	334_181_356_822_494_587_021_414,
+	356_560_535_412_770_473_777_561,
+	380_438_384_183_625_837_609_071,
+	405_915_264_830_692_083_455_877,
+	433_098_259_987_990_219_856_808,
+	462_101_623_310_131_569_721_679,
+	493_047_259_695_248_190_812_809,
+	526_065_237_667_092_273_250_025,
+	561_294_336_069_912_939_999_154,
+	598_882_627_373_939_930_513_456,
+	638_988_100_043_182_777_268_450,
+	681_779_322_581_438_063_804_831,
+	727_436_152_047_576_267_631_404,
+	776_150_490_018_089_841_641_744,
+	828_127_089_174_311_181_170_823,
+	883_584_413_904_491_177_982_804,
+	942_755_558_537_960_527_912_669,
+	1_005_889_227_070_831_078_280_303,
+	1_073_250_778_501_151_420_183_678,
+	1_145_123_342_167_195_977_313_494,
+	1_221_809_007_776_799_093_275_636,
+	1_303_630_095_129_581_769_873_794,
+	1_390_930_508_868_878_273_754_379,
+	1_484_077_183_957_560_686_749_177,
+	1_583_461_627_953_283_786_301_927,
+	1_689_501_566_565_533_162_074_891,
+	1_802_642_699_410_966_290_739_634,
+	1_923_360_573_346_714_010_318_814,
+	2_052_162_581_255_505_254_297_101,
+	2_189_590_094_683_768_191_507_958,
+	2_336_220_739_296_462_339_996_091,
+	2_492_670_822_712_673_468_406_577,
+	2_659_597_924_926_479_210_086_611,
+	2_837_703_662_200_960_297_179_015,
+	3_027_736_636_052_362_454_605_112,
+	3_230_495_579_719_371_905_502_517,
+	3_446_832_715_342_522_988_414_351,
+	3_677_657_335_964_398_403_459_818,
+	3_923_939_627_406_233_558_127_862,
+	4_186_714_746_084_768_124_654_399,
+	4_467_087_169_908_937_978_537_975,
+	4_766_235_340_543_790_678_648_333,
+	5_085_416_616_553_661_883_801_607,
+	5_425_972_558_243_315_959_214_319,
+	5_789_334_566_409_923_595_000_030,
+	6_177_029_898_706_282_413_375_617,
+	6_590_688_088_902_835_576_298_161,
+	7_032_047_796_029_479_314_125_302,
+	7_502_964_112_184_990_809_365_815,
+	8_005_416_359_729_747_372_710_407,
+	8_541_516_410_634_349_300_801_270,
+	9_113_517_562_951_447_798_346_870,
+	9_723_824_011_719_740_443_731_948,
+	10_375_000_954_107_572_182_542_387,
+	11_069_785_371_269_371_164_051_910,
+	11_811_097_532_232_460_558_450_514,
+	12_602_053_268_166_574_818_966_132,
+	13_445_977_068_626_427_287_822_632,
+	14_346_416_054_812_535_399_680_893,
+	15_307_154_888_581_731_232_830_299,
+	16_332_231_679_871_862_464_033_760,
+	17_425_954_959_401_653_131_663_830,
+	18_592_921_787_984_183_287_590_802,
+	19_838_037_078_569_781_091_088_294,
+	21_166_534_212_231_379_924_275_338,
+	22_583_997_034_744_001_129_180_172,
+	24_096_383_326_212_839_156_354_002,
+	25_710_049_842_395_840_263_224_561,
+	27_431_779_032_972_701_724_666_990,
+	29_268_807_549_060_641_844_036_037,
+	31_228_856_659_797_727_317_653_275,
+	33_320_164_705_838_603_078_588_823,
+	35_551_521_726_168_876_785_518_622,
+	37_932_306_403_779_150_333_783_125,
+	40_472_525_485_486_158_646_516_669,
+	43_182_855_841_587_637_222_596_880,
+	46_074_689_342_133_103_498_681_046,
+	49_160_180_738_431_339_117_709_889,
+	52_452_298_751_046_759_865_932_933,
+	55_964_880_579_014_148_756_001_523,
+	59_712_690_059_381_052_287_625_069,
+	63_711_479_721_529_940_577_531_677,
+	67_978_056_997_102_496_598_338_966,
+	72_530_354_863_814_934_546_741_646,
+	77_387_507_220_089_466_486_465_191,
+	82_569_929_307_311_270_327_046_579,
+	88_099_403_517_736_136_920_057_930,
+	93_999_170_948_710_562_443_138_072,
+	100_294_029_088_018_548_816_379_482,
+	107_010_436_040_939_295_731_207_534,
+	114_176_621_737_095_627_288_829_864,
+	121_822_706_584_509_962_066_676_908,
+	129_980_828_069_586_214_729_708_694,
+	138_685_275_835_133_774_677_014_969,
+	147_972_635_804_184_017_769_122_119,
+	157_881_943_955_370_446_547_307_417,
+	168_454_850_396_210_328_212_003_174,
+	179_735_794_423_909_115_385_570_418,
+	191_772_191_309_490_918_156_151_095,
+	204_614_631_590_332_955_950_864_993,
+	218_317_093_708_756_362_206_022_373,
+	232_937_170_890_420_920_798_715_315,
+	248_536_313_216_123_056_205_165_064,
+	265_180_085_904_456_243_995_871_555,
+	282_938_444_890_929_365_776_375_259,
+	301_886_030_861_837_788_983_982_339,
+	322_102_482_978_749_671_459_335_326,
+	343_672_773_612_232_200_613_804_328,
+	366_687_565_491_747_037_612_812_370,
+	391_243_592_772_862_303_813_766_799,
+	417_444_067_623_456_030_491_314_754,
+	445_399_114_037_845_624_126_511_675,
+	475_226_230_702_220_404_373_184_768,
+	507_050_784_856_860_666_936_702_147,
+	541_006_539_230_910_238_024_905_676,
+	577_236_214_264_477_719_268_614_500,
+	615_892_087_981_158_841_691_010_861,
+	657_136_636_032_321_950_944_250_877,
+	701_143_214_603_345_777_238_794_438,
+	748_096_789_052_152_988_560_211_086,
+	798_194_711_342_601_796_980_549_506,
+	851_647_549_540_389_005_555_275_884,
+	908_679_972_857_943_372_022_729_561,
+	969_531_695_968_267_777_204_456_542,
+	1_034_458_486_556_803_765_734_705_810,
+	1_103_733_240_346_189_637_519_171_666,
+	1_177_647_128_112_380_591_971_995_318,
+	1_256_510_819_513_188_459_943_420_421,
+	1_340_655_788_873_151_121_282_934_146,
+	1_430_435_708_413_114_790_384_548_376,
+	1_526_227_934_780_453_822_888_175_817,
+	1_628_435_095_128_007_378_440_320_847,
+	1_737_486_779_408_228_444_043_076_349,
+	1_853_841_345_995_477_037_477_300_610,
+	1_977_987_848_225_722_300_313_828_174,
+	2_110_448_089_951_149_744_048_604_010,
+	2_251_778_818_749_436_294_059_092_333,
+	2_402_574_066_006_036_200_318_768_562,
+	2_563_467_643_705_147_199_695_954_226,
+	2_735_135_808_423_693_224_783_117_408,
+	2_918_300_103_725_435_921_013_381_391,
+	3_113_730_392_902_166_115_869_459_920,
+	3_322_248_094_808_979_851_361_044_720,
+	3_544_729_636_394_274_372_139_837_851,
+	3_782_110_136_435_895_477_536_601_131,
+	4_035_387_335_966_657_090_238_003_453,
+	4_305_625_791_909_321_324_360_552_265,
+	4_593_961_351_547_430_839_627_274_825,
+	4_901_605_926_638_774_783_338_187_558,
+	5_229_852_587_237_706_478_090_323_056,
+	5_580_080_996_636_308_787_218_337_926,
+	5_953_763_210_268_169_853_981_013_753,
+	6_352_469_862_948_313_972_298_601_292,
+	6_777_876_770_455_059_578_787_950_509,
+	7_231_771_973_201_109_220_722_299_014,
+	7_716_063_251_599_334_740_889_550_505,
+	8_232_786_144_711_314_908_860_429_656,
+	8_784_112_505_882_045_418_606_435_582,
+	9_372_359_631_321_263_708_503_181_820,
+	10_000_000_000_000_000_000_000_000_000,
+];
@@ -29,7 +29,7 @@ use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, ConstU16, ConstU64, ConstU8, Get, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT},
+	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto},
 	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
 	ApplyExtrinsicResult, Percent,
 };
@@ -46,7 +46,7 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
 		ConstBool, ConstU128, ConstU32, EitherOfDiverse, EqualPrivilegeOnly, Everything,
-		OnUnbalanced,
+		InstanceFilter, OnUnbalanced, WithdrawReasons,
 	},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
@@ -56,6 +56,7 @@ use frame_support::{
 };
 use frame_system::EnsureRoot;
 use pallet_balances::NegativeImbalance;
+use pallet_block_reward::Beneficiary;
 
 pub use sp_runtime::{MultiAddress, Perbill, Permill, RuntimeDebug};
 
@@ -71,15 +72,22 @@ use frame_support::{
 };
 use pallet_ethereum::{Call::transact, Transaction as EthereumTransaction};
 use pallet_evm::{
-	Account as EVMAccount, EnsureAddressNever, EnsureAddressRoot, FeeCalculator,
+	Account as EVMAccount, AddressMapping, EnsureAddressNever, EnsureAddressRoot, FeeCalculator,
 	HashedAddressMapping, Runner,
 };
+use pallet_evm_precompile_dapps_staking::DappsStakingPrecompileCall;
+use pallet_evm_precompile_parachain_staking::ParachainStakingPrecompileCall;
+use pallet_evm_precompile_proxy::{EvmProxyCallFilter, EvmSubCall};
 use smallvec::smallvec;
 use sp_core::{H160, H256, U256};
 use sp_runtime::traits::{AccountIdConversion, DispatchInfoOf, Dispatchable, PostDispatchInfoOf};
 
+mod candidate_bags;
 mod precompiles;
+#[cfg(test)]
+mod tests;
 pub use precompiles::DioraPrecompiles;
+use precompiles::PrecompileName;
 
 pub type Precompiles = DioraPrecompiles<Runtime>;
 
@@ -112,10 +120,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("Diora"),
 	impl_name: create_runtime_str!("Diora"),
 	authoring_version: 1,
-	spec_version: 9380,
+	spec_version: 9381,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -294,7 +302,217 @@ impl pallet_utility::Config for Runtime {
 	type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
 }
 
-impl pallet_randomness_collective_flip::Config for Runtime {}
+/// The type used to represent the kinds of proxying allowed.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum ProxyType {
+	/// All calls can be proxied.
+	Any = 0,
+	/// Can execute any call that does not transfer funds.
+	NonTransfer = 1,
+	/// Proxy with the right to execute governance related calls.
+	Governance = 2,
+	/// Proxy with the right to execute parachain staking, dapps staking and author mapping calls.
+	Staking = 3,
+	/// Proxy with the right to reject announcements made by other proxies.
+	CancelProxy = 4,
+	/// Proxy with the right to manage the author mapping of a collator.
+	AuthorMapping = 5,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+/// Instant unstakes charge a fee to the proxied account, so only `Any` proxies may submit them.
+fn is_instant_unstake(c: &RuntimeCall) -> bool {
+	matches!(
+		c,
+		RuntimeCall::ParachainStaking(pallet_parachain_staking::Call::instant_unstake { .. }) |
+			RuntimeCall::DappsStaking(pallet_dapps_staking::Call::instant_unstake { .. })
+	)
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::NonTransfer | ProxyType::Staking if is_instant_unstake(c) => false,
+			ProxyType::NonTransfer =>
+				matches!(
+					c,
+					RuntimeCall::System(..) |
+						RuntimeCall::Timestamp(..) |
+						RuntimeCall::Utility(..) | RuntimeCall::Democracy(..) |
+						RuntimeCall::Council(..) | RuntimeCall::TechnicalCommittee(..) |
+						RuntimeCall::Treasury(..) |
+						RuntimeCall::Preimage(..) |
+						RuntimeCall::Identity(..) |
+						RuntimeCall::Vesting(pallet_vesting::Call::vest { .. }) |
+						RuntimeCall::Vesting(pallet_vesting::Call::vest_other { .. }) |
+						RuntimeCall::Proxy(..) | RuntimeCall::ParachainStaking(..) |
+						RuntimeCall::DappsStaking(..) |
+						RuntimeCall::AuthorMapping(..)
+				),
+			ProxyType::Governance => matches!(
+				c,
+				RuntimeCall::Democracy(..) |
+					RuntimeCall::Council(..) |
+					RuntimeCall::TechnicalCommittee(..) |
+					RuntimeCall::Treasury(..) |
+					RuntimeCall::Preimage(..) |
+					RuntimeCall::Utility(..)
+			),
+			ProxyType::Staking => matches!(
+				c,
+				RuntimeCall::ParachainStaking(..) |
+					RuntimeCall::DappsStaking(..) |
+					RuntimeCall::AuthorMapping(..) |
+					RuntimeCall::Utility(..)
+			),
+			ProxyType::CancelProxy => matches!(
+				c,
+				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
+					RuntimeCall::Utility(..)
+			),
+			ProxyType::AuthorMapping =>
+				matches!(c, RuntimeCall::AuthorMapping(..) | RuntimeCall::Utility(..)),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, _) => true,
+			(ProxyType::Staking, ProxyType::AuthorMapping) => true,
+			_ => false,
+		}
+	}
+}
+
+fn is_governance_precompile(precompile: &PrecompileName) -> bool {
+	matches!(
+		precompile,
+		PrecompileName::DemocracyPrecompile |
+			PrecompileName::CouncilInstance |
+			PrecompileName::TechCommitteeInstance |
+			PrecompileName::PreimagePrecompile
+	)
+}
+
+fn is_staking_precompile(precompile: &PrecompileName) -> bool {
+	matches!(
+		precompile,
+		PrecompileName::ParachainStakingPrecompile |
+			PrecompileName::DappsStakingPrecompile |
+			PrecompileName::AuthorMappingPrecompile
+	)
+}
+
+/// EVM counterpart of [`is_instant_unstake`].
+fn is_evm_instant_unstake(precompile: &PrecompileName, call: &EvmSubCall) -> bool {
+	let selector = match call.call_data.as_bytes().get(0..4) {
+		Some(selector) => u32::from_be_bytes([selector[0], selector[1], selector[2], selector[3]]),
+		None => return false,
+	};
+	match precompile {
+		PrecompileName::ParachainStakingPrecompile =>
+			ParachainStakingPrecompileCall::<Runtime>::instant_unstake_selectors()
+				.contains(&selector),
+		PrecompileName::DappsStakingPrecompile =>
+			DappsStakingPrecompileCall::<Runtime>::instant_unstake_selectors().contains(&selector),
+		_ => false,
+	}
+}
+
+impl EvmProxyCallFilter for ProxyType {
+	fn is_evm_proxy_call_allowed(&self, call: &EvmSubCall, recipient_has_code: bool) -> bool {
+		let precompile = PrecompileName::from_address(call.to.0);
+		match (self, precompile) {
+			// Precompiles making subcalls could reach any smart contract, only the precompiles
+			// of the restricted proxy types can be called.
+			(ProxyType::Any, Some(ref precompile)) =>
+				is_governance_precompile(precompile) || is_staking_precompile(precompile),
+			// Smart contracts are not known to be safe to call through a proxy, only transfers
+			// to accounts are allowed.
+			(ProxyType::Any, None) => !recipient_has_code,
+			(ProxyType::NonTransfer | ProxyType::Staking, Some(ref precompile))
+				if is_evm_instant_unstake(precompile, call) =>
+				false,
+			(ProxyType::NonTransfer, Some(ref precompile)) =>
+				call.value.is_zero() &&
+					(is_governance_precompile(precompile) || is_staking_precompile(precompile)),
+			(ProxyType::Governance, Some(ref precompile)) =>
+				call.value.is_zero() && is_governance_precompile(precompile),
+			(ProxyType::Staking, Some(ref precompile)) =>
+				call.value.is_zero() && is_staking_precompile(precompile),
+			(ProxyType::AuthorMapping, Some(PrecompileName::AuthorMappingPrecompile)) =>
+				call.value.is_zero(),
+			_ => false,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	// One storage item; key size 32, value size 8
+	type ProxyDepositBase = ConstU128<{ deposit(1, 8) }>;
+	// Additional storage item size of 37 bytes: account, proxy type and delay
+	type ProxyDepositFactor = ConstU128<{ deposit(0, 37) }>;
+	type MaxProxies = ConstU32<32>;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = ConstU32<32>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ConstU128<{ deposit(1, 8) }>;
+	// Additional storage item size of 68 bytes: account, call hash and block number
+	type AnnouncementDepositFactor = ConstU128<{ deposit(0, 68) }>;
+}
+
+impl pallet_multisig::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
+	type DepositBase = ConstU128<{ deposit(1, 88) }>;
+	// Additional storage item size of 32 bytes.
+	type DepositFactor = ConstU128<{ deposit(0, 32) }>;
+	type MaxSignatories = ConstU32<100>;
+	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	/// Unvested funds can be used for anything but transfers and reserves, e.g. staking
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = ConstU128<{ 1 * DIOR }>;
+	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
 
 impl parachain_info::Config for Runtime {}
 
@@ -600,11 +818,85 @@ impl pallet_author_inherent::Config for Runtime {
 
 impl pallet_author_slot_filter::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type RandomnessSource = RandomnessCollectiveFlip;
+	type RandomnessSource = Randomness;
 	type PotentialAuthors = ParachainStaking;
 	type WeightInfo = pallet_author_slot_filter::weights::SubstrateWeight<Runtime>;
 }
 
+/// Reads the relay chain BABE epoch data from the relay state proof of the current block.
+pub struct BabeDataGetter;
+
+impl pallet_randomness::GetBabeData<u64, Option<Hash>> for BabeDataGetter {
+	// Tolerate panic here because this is only ever called in an inherent (so can be omitted)
+	fn get_epoch_index() -> u64 {
+		if cfg!(feature = "runtime-benchmarks") {
+			// storage reads as per actual reads
+			let _relay_storage_root = ParachainSystem::validation_data();
+			let _relay_chain_state = ParachainSystem::relay_state_proof();
+			const BENCHMARKING_NEW_EPOCH: u64 = 10u64;
+			return BENCHMARKING_NEW_EPOCH;
+		}
+		relay_chain_state_proof()
+			.read_optional_entry(cumulus_primitives_core::relay_chain::well_known_keys::EPOCH_INDEX)
+			.ok()
+			.flatten()
+			.expect("expected to be able to read epoch index from relay chain state proof")
+	}
+	fn get_epoch_randomness() -> Option<Hash> {
+		if cfg!(feature = "runtime-benchmarks") {
+			// storage reads as per actual reads
+			let _relay_storage_root = ParachainSystem::validation_data();
+			let _relay_chain_state = ParachainSystem::relay_state_proof();
+			let benchmarking_babe_output = Hash::default();
+			return Some(benchmarking_babe_output);
+		}
+		relay_chain_state_proof()
+			.read_optional_entry(
+				cumulus_primitives_core::relay_chain::well_known_keys::ONE_EPOCH_AGO_RANDOMNESS,
+			)
+			.ok()
+			.flatten()
+	}
+}
+
+fn relay_chain_state_proof() -> cumulus_pallet_parachain_system::RelayChainStateProof {
+	let relay_storage_root = ParachainSystem::validation_data()
+		.expect("set in `set_validation_data`")
+		.relay_parent_storage_root;
+	let relay_chain_state =
+		ParachainSystem::relay_state_proof().expect("set in `set_validation_data`");
+	cumulus_pallet_parachain_system::RelayChainStateProof::new(
+		ParachainInfo::get(),
+		relay_storage_root,
+		relay_chain_state,
+	)
+	.expect("Invalid relay chain state proof, already constructed in `set_validation_data`")
+}
+
+/// Maps the EVM address of a contract to its account, as `pallet_evm` does.
+pub struct EvmAddressToAccountId;
+
+impl sp_runtime::traits::Convert<H160, AccountId> for EvmAddressToAccountId {
+	fn convert(address: H160) -> AccountId {
+		<Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+	}
+}
+
+impl pallet_randomness::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type VrfKeyLookup = AuthorMapping;
+	type BabeDataGetter = BabeDataGetter;
+	type Currency = Balances;
+	type AddressMapping = EvmAddressToAccountId;
+	type Deposit = ConstU128<{ DIOR * SUPPLY_FACTOR }>;
+	type MaxRandomWords = ConstU8<100>;
+	type MinBlockDelay = ConstU32<2>;
+	type MaxBlockDelay = ConstU32<2_000>;
+	type BlockExpirationDelay = ConstU32<10_000>;
+	type EpochExpirationDelay = ConstU64<10_000>;
+	type WeightInfo = pallet_randomness::weights::SubstrateWeight<Runtime>;
+}
+
 // This is a simple session key manager. It should probably either work with, or be replaced
 // entirely by pallet sessions
 impl pallet_author_mapping::Config for Runtime {
@@ -620,6 +912,13 @@ parameter_types! {
 	pub const DefaultCollatorCommission: Perbill = Perbill::from_percent(20);
 	/// Default percent of inflation set aside for parachain bond every round
 	pub const DefaultParachainBondReservePercent: Percent = Percent::from_percent(30);
+	/// Pot funded by the block reward, out of which staking rewards are paid
+	pub const ParachainStakingPotId: PalletId = PalletId(*b"dio/stkp");
+	pub ParachainStakingPot: AccountId = ParachainStakingPotId::get().into_account_truncating();
+	/// Upper thresholds of the bags of the candidate pool
+	pub const CandidateBagThresholds: &'static [Balance] = &candidate_bags::THRESHOLDS;
+	/// Highest fee governance can charge on instant unstakes, in both staking pallets
+	pub const MaxInstantUnstakeFee: Perbill = Perbill::from_percent(50);
 }
 
 impl pallet_parachain_staking::Config for Runtime {
@@ -636,8 +935,15 @@ impl pallet_parachain_staking::Config for Runtime {
 	type LeaveDelegatorsDelay = ConstU32<{ 4 * 7 }>;
 	/// Rounds before the delegator revocation can be executed
 	type RevokeDelegationDelay = ConstU32<{ 4 * 7 }>;
+	/// Governance can raise the exit and bond decrease delays up to four times their minimum
+	type MaxLeaveCandidatesDelay = ConstU32<{ 4 * 4 * 7 }>;
+	type MaxCandidateBondLessDelay = ConstU32<{ 4 * 4 * 7 }>;
+	type MaxLeaveDelegatorsDelay = ConstU32<{ 4 * 4 * 7 }>;
+	type MaxRevokeDelegationDelay = ConstU32<{ 4 * 4 * 7 }>;
 	/// Rounds before the delegator bond increase/decrease can be executed
 	type DelegationBondLessDelay = ConstU32<{ 4 * 7 }>;
+	/// Rounds between two delegation transfers of a delegator
+	type DelegationTransferDelay = ConstU32<4>;
 	/// Rounds before the reward is paid
 	type RewardPaymentDelay = ConstU32<2>;
 	/// Minimum collators selected per round, default at genesis and minimum forever after
@@ -656,8 +962,19 @@ impl pallet_parachain_staking::Config for Runtime {
 	type MinDelegation = ConstU128<{ 125 * MILLIDIOR * SUPPLY_FACTOR }>;
 	/// Minimum stake required to be reserved to be a delegator
 	type MinDelegatorStk = ConstU128<{ 125 * MILLIDIOR * SUPPLY_FACTOR }>;
+	/// Governance can raise the minimum stakes up to ten times their minimum
+	type MaxMinCollatorStk = ConstU128<{ 10 * 250 * DIOR * SUPPLY_FACTOR }>;
+	type MaxMinCandidateStk = ConstU128<{ 10 * 250 * DIOR * SUPPLY_FACTOR }>;
+	type MaxMinDelegation = ConstU128<{ 10 * 125 * MILLIDIOR * SUPPLY_FACTOR }>;
+	type MaxMinDelegatorStk = ConstU128<{ 10 * 125 * MILLIDIOR * SUPPLY_FACTOR }>;
+	type CandidateBagThresholds = CandidateBagThresholds;
 	type WeightInfo = pallet_parachain_staking::weights::SubstrateWeight<Runtime>;
 	type BlockAuthor = AuthorInherent;
+	type RewardSource =
+		pallet_parachain_staking::RewardPot<ParachainStakingPot, ParachainStakingBlockReward>;
+	type OnInstantUnstakeFee = Treasury;
+	type MaxInstantUnstakeFee = MaxInstantUnstakeFee;
+	type MaxInstantUnstakeCap = ConstU128<{ 1_000_000 * DIOR }>;
 	type PayoutCollatorReward = ();
 	type OnNewRound = ();
 	type OnCollatorPayout = ();
@@ -668,25 +985,33 @@ parameter_types! {
 }
 impl pallet_dapps_staking::Config for Runtime {
 	type Currency = Balances;
+	type MonetaryGovernanceOrigin = EnsureRoot<AccountId>;
 	type BlockPerEra = ConstU32<{ 1 * DAYS }>;
+	type MaxBlockPerEra = ConstU32<{ 7 * DAYS }>;
 	type SmartContract = SmartContract<AccountId>;
 	type RegisterDeposit = ConstU128<{ 1000 * DIOR }>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_dapps_staking::weights::SubstrateWeight<Runtime>;
 	type MaxNumberOfStakersPerContract = ConstU32<16384>;
 	type MinimumStakingAmount = ConstU128<{ 500 * DIOR }>;
+	type MaxMinimumStakingAmount = ConstU128<{ 10 * 500 * DIOR }>;
 	type PalletId = DappsStakingPalletId;
 	type MaxUnlockingChunks = ConstU32<4>;
 	type UnbondingPeriod = ConstU32<10>;
+	type MaxUnbondingPeriod = ConstU32<100>;
 	type MinimumRemainingAmount = ConstU128<{ 1 * DIOR }>;
 	type MaxEraStakeValues = ConstU32<5>;
 	type UnregisteredDappRewardRetention = ConstU32<{ u32::MAX }>;
+	type OnInstantUnstakeFee = Treasury;
+	type MaxInstantUnstakeFee = MaxInstantUnstakeFee;
+	type MaxInstantUnstakeCap = ConstU128<{ 1_000_000 * DIOR }>;
 }
 
 /// Multi-VM pointer to smart contract instance.
 #[derive(
 	PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, scale_info::TypeInfo,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum SmartContract<AccountId> {
 	/// EVM smart contract instance.
 	Evm(H160),
@@ -714,21 +1039,69 @@ impl Get<Balance> for DappsStakingTvlProvider {
 }
 
 pub struct BeneficiaryPayout();
-impl pallet_block_reward::BeneficiaryPayout<NegativeImbalance<Runtime>> for BeneficiaryPayout {
-	fn treasury(reward: NegativeImbalance<Runtime>) {
-		Balances::resolve_creating(&TreasuryPalletId::get().into_account_truncating(), reward);
+impl pallet_block_reward::BeneficiaryPayout<NegativeImbalance<Runtime>, AccountId>
+	for BeneficiaryPayout
+{
+	fn payout(beneficiary: &Beneficiary<AccountId>, reward: NegativeImbalance<Runtime>) {
+		match beneficiary {
+			Beneficiary::Treasury => Balances::resolve_creating(
+				&TreasuryPalletId::get().into_account_truncating(),
+				reward,
+			),
+			Beneficiary::DappsStakers => DappsStaking::rewards(reward, NegativeImbalance::zero()),
+			Beneficiary::Dapps => DappsStaking::rewards(NegativeImbalance::zero(), reward),
+			Beneficiary::Burn => drop(reward),
+			Beneficiary::Account(account) => Balances::resolve_creating(account, reward),
+			Beneficiary::ParachainStaking =>
+				Balances::resolve_creating(&ParachainStakingPot::get(), reward),
+		}
 	}
+}
 
-	fn dapps_staking(stakers: NegativeImbalance<Runtime>, dapps: NegativeImbalance<Runtime>) {
-		DappsStaking::rewards(stakers, dapps)
+/// Per-block share of the block reward paid to the parachain staking pot, used to project
+/// staking rewards.
+pub struct ParachainStakingBlockReward;
+impl Get<Balance> for ParachainStakingBlockReward {
+	fn get() -> Balance {
+		BlockReward::reward_info()
+			.payouts
+			.into_iter()
+			.filter(|(beneficiary, _)| *beneficiary == Beneficiary::ParachainStaking)
+			.fold(0, |total, (_, amount)| total.saturating_add(amount))
 	}
 }
 
+/// Per-block share of the ideal staking issuance, used to move staking rewards over to the
+/// block reward without changing total issuance.
+pub struct StakingIssuancePerBlock;
+impl Get<Balance> for StakingIssuancePerBlock {
+	fn get() -> Balance {
+		let round_issuance = pallet_parachain_staking::inflation::round_issuance_range::<Runtime>(
+			ParachainStaking::inflation_config().round,
+		);
+		round_issuance.ideal / Balance::from(ParachainStaking::round().length.max(1))
+	}
+}
+
+parameter_types! {
+	pub StakingBeneficiary: Beneficiary<AccountId> = Beneficiary::ParachainStaking;
+}
+
+parameter_types! {
+	/// Issuance schedule set at genesis and by the `v1` block reward migration, keeps the
+	/// former constant block reward.
+	pub InitialIssuanceSchedule: pallet_block_reward::IssuanceSchedule<Balance, BlockNumber> =
+		pallet_block_reward::IssuanceSchedule {
+			initial_reward: 253_080 * MILLIDIOR,
+			..Default::default()
+		};
+}
+
 impl pallet_block_reward::Config for Runtime {
 	type Currency = Balances;
 	type DappsStakingTvlProvider = DappsStakingTvlProvider;
 	type BeneficiaryPayout = BeneficiaryPayout;
-	type RewardAmount = ConstU128<{ 253_080 * MILLIDIOR }>;
+	type BlocksPerYear = ConstU32<{ 365 * DAYS }>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_block_reward::weights::SubstrateWeight<Runtime>;
 }
@@ -745,12 +1118,15 @@ construct_runtime!(
 		// System support stuff.
 		System: frame_system = 0,
 		ParachainSystem: cumulus_pallet_parachain_system = 1,
-		RandomnessCollectiveFlip: pallet_randomness_collective_flip = 2,
 		Timestamp: pallet_timestamp = 3,
 		ParachainInfo: parachain_info = 4,
 		Utility: pallet_utility = 5,
 		Balances: pallet_balances = 6,
 		TransactionPayment: pallet_transaction_payment = 7,
+		Proxy: pallet_proxy = 8,
+		Multisig: pallet_multisig = 9,
+		// Declared after `Balances`, its genesis locks the endowed balances.
+		Vesting: pallet_vesting = 18,
 
 		// Governance stuff.
 		Democracy: pallet_democracy = 10,
@@ -780,6 +1156,7 @@ construct_runtime!(
 		DappsStaking: pallet_dapps_staking = 43,
 		AuthorMapping: pallet_author_mapping = 44,
 		BlockReward: pallet_block_reward = 45,
+		Randomness: pallet_randomness = 46,
 
 	}
 );
@@ -805,6 +1182,99 @@ pub type UncheckedExtrinsic =
 pub type CheckedExtrinsic =
 	fp_self_contained::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra, H160>;
 
+/// Removes the storage of the `RandomnessCollectiveFlip` pallet, superseded by `Randomness`.
+pub struct RemoveCollectiveFlip;
+
+impl frame_support::traits::OnRuntimeUpgrade for RemoveCollectiveFlip {
+	fn on_runtime_upgrade() -> Weight {
+		use frame_support::storage::migration;
+		// Only the first upgrade finds the value, later upgrades have nothing left to remove
+		if !migration::have_storage_value(b"RandomnessCollectiveFlip", b"RandomMaterial", b"") {
+			return RocksDbWeight::get().reads(1);
+		}
+		// Remove the storage value `RandomMaterial` from removed pallet `RandomnessCollectiveFlip`
+		#[allow(deprecated)]
+		migration::remove_storage_prefix(b"RandomnessCollectiveFlip", b"RandomMaterial", b"");
+		RocksDbWeight::get().reads_writes(1, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		use frame_support::storage::migration;
+		frame_support::ensure!(
+			!migration::have_storage_value(b"RandomnessCollectiveFlip", b"RandomMaterial", b""),
+			"RandomnessCollectiveFlip storage was not removed"
+		);
+		Ok(())
+	}
+}
+
+/// Deploys the revert bytecode under precompile addresses that have no code yet, as the genesis
+/// does for the precompiles known at that time. Without code high-level Solidity calls to the
+/// precompiles added since genesis revert on their `extcodesize` check.
+pub struct DeployPrecompilesRevertCode;
+
+impl DeployPrecompilesRevertCode {
+	/// This is the simplest bytecode to revert without returning any data.
+	/// (PUSH1 0x00 PUSH1 0x00 REVERT)
+	pub const REVERT_BYTECODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xFD];
+}
+
+impl frame_support::traits::OnRuntimeUpgrade for DeployPrecompilesRevertCode {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+		for address in Precompiles::used_addresses() {
+			reads = reads.saturating_add(1);
+			if pallet_evm::AccountCodes::<Runtime>::contains_key(address) {
+				continue;
+			}
+			// Same as the genesis of `pallet_evm`, which also makes the account sufficient
+			EVM::create_account(address, Self::REVERT_BYTECODE.to_vec());
+			reads = reads.saturating_add(1);
+			writes = writes.saturating_add(2);
+		}
+		RocksDbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		frame_support::ensure!(
+			Precompiles::used_addresses()
+				.all(|address| pallet_evm::AccountCodes::<Runtime>::contains_key(address)),
+			"Precompile address without code"
+		);
+		Ok(())
+	}
+}
+
+/// Storage migrations executed on runtime upgrade.
+///
+/// Every migration checks the on-chain storage version of its pallet or whether the storage it
+/// removes still exists, so entries can stay here until the next release has been enacted on all
+/// networks.
+pub type Migrations = (
+	pallet_parachain_staking::migrations::RemoveEmptyDelegationRequestsOfExitedCandidates<Runtime>,
+	pallet_dapps_staking::migrations::v4::MigrateToFrameStorageVersion<Runtime>,
+	pallet_block_reward::migrations::v1::InitializeIssuanceSchedule<
+		Runtime,
+		InitialIssuanceSchedule,
+	>,
+	pallet_block_reward::migrations::v2::MigrateToBeneficiaryList<Runtime>,
+	pallet_parachain_staking::migrations::FundRewardPotForDelayedPayouts<
+		Runtime,
+		ParachainStakingPot,
+	>,
+	pallet_block_reward::migrations::v3::IncludeBeneficiary<
+		Runtime,
+		StakingBeneficiary,
+		StakingIssuancePerBlock,
+	>,
+	pallet_parachain_staking::migrations::MigrateCandidatePoolToBagsList<Runtime>,
+	RemoveCollectiveFlip,
+	DeployPrecompilesRevertCode,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -812,7 +1282,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	(),
+	Migrations,
 >;
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -1126,6 +1596,35 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_block_reward_rpc_runtime_api::BlockRewardApi<Block, AccountId, Balance> for Runtime {
+		fn reward_info() -> pallet_block_reward::RewardInfo<AccountId, Balance> {
+			BlockReward::reward_info()
+		}
+	}
+
+	impl pallet_parachain_staking_rpc_runtime_api::ParachainStakingApi<Block, AccountId, Balance> for Runtime {
+		fn candidates() -> Vec<pallet_parachain_staking::CandidateSummary<AccountId, Balance>> {
+			ParachainStaking::candidate_summaries()
+		}
+
+		fn delegator_summary(
+			delegator: AccountId,
+		) -> Option<pallet_parachain_staking::DelegatorSummary<AccountId, Balance>> {
+			ParachainStaking::delegator_summary(delegator)
+		}
+	}
+
+	impl session_keys_primitives::VrfApi<Block> for Runtime {
+		fn get_vrf_input() -> Option<<Block as BlockT>::Hash> {
+			Randomness::vrf_input(System::block_number() + 1)
+		}
+		fn vrf_key_lookup(
+			nimbus_id: nimbus_primitives::NimbusId
+		) -> Option<session_keys_primitives::VrfId> {
+			AuthorMapping::keys_of(&nimbus_id)
+		}
+	}
+
 	impl nimbus_primitives::NimbusApi<Block> for Runtime {
 				fn can_author(
 					author: nimbus_primitives::NimbusId,
@@ -1134,16 +1633,14 @@ impl_runtime_apis! {
 				) -> bool {
 					let block_number = parent_header.number + 1;
 
-					// The Diora runtimes use an entropy source that needs to do some accounting
-					// work during block initialization. Therefore we initialize it here to match
-					// the state it will be in when the next block is being executed.
-					use frame_support::traits::OnInitialize;
+					// Initialize the block so that the state matches the one the next block is
+					// executed with. The VRF randomness used by the author filter is only updated
+					// when a block is finalized, so it already holds its final value here.
 					System::initialize(
 						&block_number,
 						&parent_header.hash(),
 						&parent_header.digest,
 					);
-					RandomnessCollectiveFlip::on_initialize(block_number);
 
 					// Because the staking solution calculates the next staking set at the beginning
 					// of the first block in the new round, the only way to accurately predict the
@@ -1170,6 +1667,31 @@ impl_runtime_apis! {
 				}
 			}
 
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade(checks: bool) -> (Weight, Weight) {
+			log::info!("try-runtime::on_runtime_upgrade diora.");
+			let weight = Executive::try_runtime_upgrade(checks).unwrap();
+			(weight, BlockWeights::get().max_block)
+		}
+
+		fn execute_block(
+			block: Block,
+			state_root_check: bool,
+			signature_check: bool,
+			select: frame_try_runtime::TryStateSelect
+		) -> Weight {
+			log::info!(
+				"try-runtime: executing block #{} / root checks: {:?} / sanity-checks: {:?}",
+				block.header.number,
+				state_root_check,
+				select,
+			);
+			Executive::try_execute_block(block, state_root_check, signature_check, select)
+				.expect("execute-block failed")
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -16,15 +16,27 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{CouncilInstance, TechCommitteeInstance};
 use frame_support::parameter_types;
 
+use pallet_evm_precompile_author_mapping::AuthorMappingPrecompile;
+use pallet_evm_precompile_batch::BatchPrecompile;
 use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
+use pallet_evm_precompile_call_permit::CallPermitPrecompile;
+use pallet_evm_precompile_collective::CollectivePrecompile;
 use pallet_evm_precompile_dapps_staking::DappsStakingPrecompile;
+use pallet_evm_precompile_democracy::DemocracyPrecompile;
+use pallet_evm_precompile_identity::IdentityPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_multisig::MultisigPrecompile;
 use pallet_evm_precompile_parachain_staking::ParachainStakingPrecompile;
+use pallet_evm_precompile_preimage::PreimagePrecompile;
+use pallet_evm_precompile_proxy::{OnlyIsProxy, OnlyIsProxyAndProxy, ProxyPrecompile};
+use pallet_evm_precompile_randomness::RandomnessPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_vesting::VestingPrecompile;
 
 use precompile_utils::precompile_set::*;
 
@@ -70,6 +82,70 @@ type DioraPrecompilesAt<R> = (
 		DappsStakingPrecompile<R>,
 		(CallableByContract, CallableByPrecompile),
 	>,
+	// Subcalls are needed to call back the requesting contract when fulfilling a request.
+	PrecompileAt<
+		AddressU64<2050>,
+		RandomnessPrecompile<R>,
+		(SubcallWithMaxNesting<0>, CallableByContract, CallableByPrecompile),
+	>,
+	// Only callable by EOAs, subcalls are made on behalf of the caller.
+	PrecompileAt<AddressU64<2051>, BatchPrecompile<R>, SubcallWithMaxNesting<2>>,
+	PrecompileAt<
+		AddressU64<2052>,
+		CallPermitPrecompile<R>,
+		(SubcallWithMaxNesting<0>, CallableByContract),
+	>,
+	PrecompileAt<
+		AddressU64<2053>,
+		DemocracyPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2054>,
+		CollectivePrecompile<R, CouncilInstance>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2055>,
+		CollectivePrecompile<R, TechCommitteeInstance>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2056>,
+		PreimagePrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2057>,
+		IdentityPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2058>,
+		AuthorMappingPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	// Smart contracts can only make proxy calls, other precompiles such as batch and call permit
+	// cannot change the proxies of their caller nor make proxy calls.
+	PrecompileAt<
+		AddressU64<2059>,
+		ProxyPrecompile<R>,
+		(
+			SubcallWithMaxNesting<0>,
+			CallableByContract<OnlyIsProxyAndProxy<R>>,
+			CallableByPrecompile<OnlyIsProxy<R>>,
+		),
+	>,
+	PrecompileAt<
+		AddressU64<2060>,
+		VestingPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2061>,
+		MultisigPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
 );
 
 /// The PrecompileSet installed in the Diora runtime.
@@ -0,0 +1,177 @@
+// This file is part of Diora.
+
+// Copyright (C) 2019-2022 Diora-Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::traits::GenesisBuild;
+use pallet_evm_precompile_randomness::{
+	ConsumerAction, RandomnessPrecompileCall, SELECTOR_LOG_FULFILLMENT_SUCCEEDED,
+};
+use pallet_randomness::{Request, RequestType};
+use precompile_utils::{
+	prelude::log1,
+	testing::{PrecompileTesterExt, Subcall, SubcallOutput},
+};
+use sp_io::TestExternalities;
+use sp_runtime::{traits::Convert, BuildStorage};
+use std::{cell::Cell, rc::Rc};
+
+const FULFILLER: H160 = H160([0xAA; 20]);
+const CONSUMER: H160 = H160([0xBB; 20]);
+
+fn new_test_ext() -> TestExternalities {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(EvmAddressToAccountId::convert(CONSUMER), 10 * DIOR * SUPPLY_FACTOR)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = TestExternalities::from(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+#[test]
+fn randomness_precompile_calls_back_the_consumer() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Randomness::request_randomness(Request {
+				refund_address: CONSUMER,
+				contract_address: CONSUMER,
+				fee: DIOR,
+				gas_limit: 100_000,
+				num_words: 1,
+				salt: H256::repeat_byte(1),
+				info: RequestType::Local(3),
+			}),
+			Ok(0)
+		);
+		pallet_randomness::RandomnessResults::<Runtime>::mutate(RequestType::Local(3), |result| {
+			result.as_mut().unwrap().randomness = Some(H256::repeat_byte(2));
+		});
+		System::set_block_number(4);
+
+		let precompile = H160::from_low_u64_be(2050);
+		let called_back = Rc::new(Cell::new(false));
+		let subcall_called_back = called_back.clone();
+		Precompiles::new()
+			.prepare_test(
+				FULFILLER,
+				precompile,
+				RandomnessPrecompileCall::<Runtime>::fulfill_request { request_id: 0.into() },
+			)
+			.with_subcall_handle(move |subcall: Subcall| {
+				assert_eq!(subcall.address, CONSUMER);
+				assert_eq!(subcall.context.caller, precompile);
+				assert_eq!(
+					&subcall.input[..4],
+					&(ConsumerAction::RawFulfillRandomWords as u32).to_be_bytes()
+				);
+				subcall_called_back.set(true);
+				SubcallOutput::succeed()
+			})
+			.expect_log(log1(precompile, SELECTOR_LOG_FULFILLMENT_SUCCEEDED, vec![]))
+			.execute_returns(vec![]);
+
+		assert!(called_back.get());
+		assert!(Randomness::requests(0).is_none());
+	});
+}
+
+#[test]
+fn genesis_with_vesting_schedules_builds() {
+	let account = EvmAddressToAccountId::convert(CONSUMER);
+	let storage = GenesisConfig {
+		balances: BalancesConfig { balances: vec![(account.clone(), 100 * DIOR)] },
+		vesting: VestingConfig { vesting: vec![(account.clone(), 10, 100, 20 * DIOR)] },
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	TestExternalities::from(storage).execute_with(|| {
+		assert_eq!(
+			Vesting::vesting(&account).unwrap().to_vec(),
+			vec![pallet_vesting::VestingInfo::new(80 * DIOR, 80 * DIOR / 100, 10)]
+		);
+		assert_eq!(Balances::locks(&account)[0].amount, 80 * DIOR);
+	});
+}
+
+#[test]
+fn precompiles_without_code_get_revert_code_on_upgrade() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	new_test_ext().execute_with(|| {
+		let genesis_precompile = H160::from_low_u64_be(2048);
+		EVM::create_account(genesis_precompile, vec![0x00]);
+
+		DeployPrecompilesRevertCode::on_runtime_upgrade();
+
+		for address in Precompiles::used_addresses() {
+			let expected = if address == genesis_precompile {
+				vec![0x00]
+			} else {
+				DeployPrecompilesRevertCode::REVERT_BYTECODE.to_vec()
+			};
+			assert_eq!(pallet_evm::AccountCodes::<Runtime>::get(address), expected);
+		}
+	});
+}
+
+#[test]
+fn evm_proxy_calls_are_filtered_by_proxy_type() {
+	use pallet_evm_precompile_proxy::{EvmProxyCallFilter, EvmSubCall};
+
+	let call = |to: u64, value: u128, selector: u32| EvmSubCall {
+		to: H160::from_low_u64_be(to).into(),
+		value: value.into(),
+		call_data: selector.to_be_bytes().to_vec().into(),
+	};
+	let instant_unstake = ParachainStakingPrecompileCall::<Runtime>::instant_unstake_selectors()[0];
+	let other_staking_call = *ParachainStakingPrecompileCall::<Runtime>::selectors()
+		.iter()
+		.find(|selector| **selector != instant_unstake)
+		.unwrap();
+	let allowed = |proxy_type: ProxyType, call: &EvmSubCall, recipient_has_code: bool| {
+		proxy_type.is_evm_proxy_call_allowed(call, recipient_has_code)
+	};
+
+	// Transfers to accounts, smart contracts cannot be called through a proxy
+	let account = EvmSubCall {
+		to: H160::repeat_byte(0x11).into(),
+		value: 100.into(),
+		call_data: Vec::<u8>::new().into(),
+	};
+	assert!(allowed(ProxyType::Any, &account, false));
+	assert!(!allowed(ProxyType::Any, &account, true));
+	assert!(!allowed(ProxyType::NonTransfer, &account, false));
+
+	// Parachain staking
+	assert!(allowed(ProxyType::Staking, &call(2048, 0, other_staking_call), true));
+	assert!(!allowed(ProxyType::Staking, &call(2048, 1, other_staking_call), true));
+	assert!(!allowed(ProxyType::Staking, &call(2048, 0, instant_unstake), true));
+	assert!(!allowed(ProxyType::NonTransfer, &call(2048, 0, instant_unstake), true));
+	assert!(allowed(ProxyType::Any, &call(2048, 0, instant_unstake), true));
+	assert!(!allowed(ProxyType::Governance, &call(2048, 0, other_staking_call), true));
+
+	// Precompiles making subcalls cannot be reached through a proxy
+	for precompile in [2051, 2052, 2059] {
+		assert!(!allowed(ProxyType::Any, &call(precompile, 0, other_staking_call), true));
+	}
+}